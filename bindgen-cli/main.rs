@@ -46,6 +46,10 @@ pub fn main() {
 
             let _ = std::panic::take_hook();
 
+            for entry in bindings.allowlist_trace() {
+                eprintln!("{}: {}", entry.name, entry.decision);
+            }
+
             bindings.write(output).expect("Unable to write output");
         }
         Err(error) => {
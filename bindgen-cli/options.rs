@@ -1,8 +1,10 @@
 use bindgen::callbacks::TypeKind;
 use bindgen::{
-    builder, Abi, AliasVariation, Builder, CodegenConfig, EnumVariation,
-    FieldVisibilityKind, Formatter, MacroTypeVariation, NonCopyUnionStyle,
-    RegexSet, RustTarget, DEFAULT_ANON_FIELDS_PREFIX, RUST_TARGET_STRINGS,
+    builder, Abi, AliasVariation, Builder, CodegenConfig, DoxygenStyle,
+    EnumDefaultVariant, EnumVariation, ErrnoConvention, FieldVisibilityKind,
+    Formatter, MacroTypeVariation, NonCopyUnionStyle, RegexSet, RustEdition,
+    RustTarget, TranslationUnitFlags, DEFAULT_ANON_FIELDS_PREFIX,
+    RUST_TARGET_STRINGS,
 };
 use clap::error::{Error, ErrorKind};
 use clap::{CommandFactory, Parser};
@@ -75,6 +77,38 @@ fn parse_abi_override(abi_override: &str) -> Result<(Abi, String), Error> {
     Ok((abi, regex.to_owned()))
 }
 
+fn parse_constant_group(
+    constant_group: &str,
+) -> Result<(String, String), Error> {
+    let (regex, enum_name) = constant_group
+        .rsplit_once('=')
+        .ok_or_else(|| Error::raw(ErrorKind::InvalidValue, "Missing `=`"))?;
+
+    Ok((regex.to_owned(), enum_name.to_owned()))
+}
+
+fn parse_out_param(out_param: &str) -> Result<(String, String), Error> {
+    let (regex, param) = out_param
+        .rsplit_once('=')
+        .ok_or_else(|| Error::raw(ErrorKind::InvalidValue, "Missing `=`"))?;
+
+    Ok((regex.to_owned(), param.to_owned()))
+}
+
+fn parse_errno_wrapper(
+    errno_wrapper: &str,
+) -> Result<(String, ErrnoConvention), Error> {
+    let (regex, convention) = errno_wrapper
+        .rsplit_once('=')
+        .ok_or_else(|| Error::raw(ErrorKind::InvalidValue, "Missing `=`"))?;
+
+    let convention = convention
+        .parse()
+        .map_err(|err| Error::raw(ErrorKind::InvalidValue, err))?;
+
+    Ok((regex.to_owned(), convention))
+}
+
 fn parse_custom_derive(
     custom_derive: &str,
 ) -> Result<(Vec<String>, String), Error> {
@@ -117,6 +151,9 @@ struct BindgenCommand {
     /// Mark any enum whose name matches REGEX as a non-exhaustive Rust enum.
     #[arg(long, value_name = "REGEX")]
     rustified_non_exhaustive_enum: Vec<String>,
+    /// Give any rustified enum whose name matches REGEX an `Unknown` variant holding the values that don't match any other variant.
+    #[arg(long, value_name = "REGEX")]
+    with_enum_unknown_variant: Vec<String>,
     /// Mark any enum whose name matches REGEX as a series of constants.
     #[arg(long, value_name = "REGEX")]
     constified_enum: Vec<String>,
@@ -126,6 +163,9 @@ struct BindgenCommand {
     /// The default signed/unsigned TYPE for C macro constants.
     #[arg(long, value_name = "TYPE")]
     default_macro_constant_type: Option<MacroTypeVariation>,
+    /// Which VARIANT of rustified enums is marked #[default] when deriving Default: zero or first. Defaults to zero.
+    #[arg(long, value_name = "VARIANT")]
+    enum_default_variant: Option<EnumDefaultVariant>,
     /// The default STYLE of code used to generate typedefs.
     #[arg(long, value_name = "STYLE")]
     default_alias_style: Option<AliasVariation>,
@@ -138,6 +178,12 @@ struct BindgenCommand {
     /// Mark any typedef alias whose name matches REGEX to have a new type with Deref and DerefMut to the inner type.
     #[arg(long, value_name = "REGEX")]
     new_type_alias_deref: Vec<String>,
+    /// Mark any integer typedef whose name matches REGEX as an opaque handle newtype, converted explicitly with `from_raw` and `into_raw`.
+    #[arg(long, value_name = "REGEX")]
+    opaque_handle: Vec<String>,
+    /// Mark any typedef of a primitive type whose name matches REGEX as a newtype that implements Deref and DerefMut to the aliased type.
+    #[arg(long, value_name = "REGEX")]
+    strong_typedef: Vec<String>,
     /// The default STYLE of code used to generate unions with non-Copy members. Note that ManuallyDrop was first stabilized in Rust 1.20.0.
     #[arg(long, value_name = "STYLE")]
     default_non_copy_union_style: Option<NonCopyUnionStyle>,
@@ -165,6 +211,24 @@ struct BindgenCommand {
     /// Avoid generating layout tests for any type.
     #[arg(long)]
     no_layout_tests: bool,
+    /// Group the layout tests in a `#[cfg(test)]` module named NAME.
+    #[arg(long, value_name = "NAME", conflicts_with = "no_layout_tests")]
+    layout_tests_in_module: Option<String>,
+    /// Generate a `bindgen_debug_assert_layouts` function checking the layout of every type with `debug_assert_eq!`.
+    #[arg(long)]
+    layout_debug_asserts: bool,
+    /// Generate a `verify_layouts` function returning the first mismatch in the layout of the generated types.
+    #[arg(long)]
+    layout_verification_fn: bool,
+    /// Document the size, alignment and field offsets of the generated structs and unions.
+    #[arg(long)]
+    document_layout: bool,
+    /// Group the generated items in a module per header they are declared in, re-exported at the root.
+    #[arg(long)]
+    modules_per_header: bool,
+    /// Generate accessors performing volatile reads and writes for `volatile` fields, instead of exposing them.
+    #[arg(long)]
+    volatile_accessors: bool,
     /// Avoid deriving Copy on any type.
     #[arg(long)]
     no_derive_copy: bool,
@@ -180,6 +244,15 @@ struct BindgenCommand {
     /// Create a PartialEq implementation if it cannot be derived automatically.
     #[arg(long)]
     impl_partialeq: bool,
+    /// Create a Hash implementation hashing the fields that are Hash, for structs that cannot derive it.
+    #[arg(long)]
+    impl_hash: bool,
+    /// Hash the bit patterns of floating point fields in the Hash implementations created by --impl-hash.
+    #[arg(long, requires = "impl_hash")]
+    impl_hash_float_bits: bool,
+    /// Create a Clone implementation for structs that cannot derive Copy but whose fields are all Clone.
+    #[arg(long)]
+    impl_clone_fallback: bool,
     /// Derive Default on any type.
     #[arg(long)]
     with_derive_default: bool,
@@ -198,9 +271,15 @@ struct BindgenCommand {
     /// Derive Ord on any type.
     #[arg(long)]
     with_derive_ord: bool,
+    /// List the derives of a type in the order of the given coma-separated list of DERIVES, before the ones that aren't part of it.
+    #[arg(long, value_name = "DERIVES", value_delimiter = ',')]
+    derive_order: Vec<String>,
     /// Avoid including doc comments in the output, see: <https://github.com/rust-lang/rust-bindgen/issues/426>
     #[arg(long)]
     no_doc_comments: bool,
+    /// Set how Doxygen commands in documentation comments are translated. STYLE can be either `verbatim` (the default) or `rustdoc`.
+    #[arg(long, value_name = "STYLE", conflicts_with = "no_doc_comments")]
+    doxygen_style: Option<DoxygenStyle>,
     /// Disable allowlisting types recursively. This will cause bindgen to emit Rust code that won't compile! See the `bindgen::Builder::allowlist_recursively` method's documentation for details.
     #[arg(long)]
     no_recursive_allowlist: bool,
@@ -222,12 +301,30 @@ struct BindgenCommand {
     /// Output bindings for builtin definitions, e.g. __builtin_va_list.
     #[arg(long)]
     builtins: bool,
+    /// Output constants for builtin macros, e.g. __STDC_VERSION__.
+    #[arg(long)]
+    builtin_macros: bool,
     /// Use the given PREFIX before raw types instead of ::std::os::raw.
     #[arg(long, value_name = "PREFIX")]
     ctypes_prefix: Option<String>,
+    /// Take raw types from a generated module re-exporting them from `libc` if the `libc` feature is enabled, and from `core::ffi` otherwise.
+    #[arg(long)]
+    libc_ctypes_shim: bool,
     /// Use the given PREFIX for anonymous fields.
     #[arg(long, default_value = DEFAULT_ANON_FIELDS_PREFIX, value_name = "PREFIX")]
     anon_fields_prefix: String,
+    /// Use the given NAME for the type of the bitfield allocation units.
+    #[arg(long, value_name = "NAME")]
+    bitfield_unit_name: Option<String>,
+    /// Set the VISIBILITY of the type of the bitfield allocation units.
+    #[arg(long, value_name = "VISIBILITY")]
+    bitfield_unit_visibility: Option<FieldVisibilityKind>,
+    /// Generate types nested deeper than DEPTH as opaque blobs and emit an error diagnostic for them.
+    #[arg(long, value_name = "DEPTH")]
+    max_recursion_depth: Option<usize>,
+    /// Warn about the templates instantiated with more than LIMIT different sets of template arguments.
+    #[arg(long, value_name = "LIMIT")]
+    max_monomorphization_warning: Option<usize>,
     /// Time the different bindgen phases and print to stderr
     #[arg(long)]
     time_phases: bool,
@@ -249,12 +346,18 @@ struct BindgenCommand {
     /// Disable nested struct naming, causing bindgen to generate names like `bar` instead of `foo_bar` for a nested definition `struct foo { struct bar { } b; };`.
     #[arg(long)]
     disable_nested_struct_naming: bool,
+    /// Name anonymous types after a hash of their structure instead of a counter that depends on the declaration order.
+    #[arg(long)]
+    structural_anon_type_names: bool,
     /// Disable support for native Rust unions.
     #[arg(long)]
     disable_untagged_union: bool,
     /// Suppress insertion of bindgen's version identifier into generated bindings.
     #[arg(long)]
     disable_header_comment: bool,
+    /// Emit a banner listing the input headers and the equivalent command line.
+    #[arg(long)]
+    emit_header_banner: bool,
     /// Do not generate bindings for functions or methods. This is useful when you only care about struct layouts.
     #[arg(long)]
     ignore_functions: bool,
@@ -267,18 +370,42 @@ struct BindgenCommand {
     /// Do not automatically convert floats to f32/f64.
     #[arg(long)]
     no_convert_floats: bool,
+    /// Use the Rust types at F32_PATH and F64_PATH for the C `float` and `double` types instead of `f32` and `f64`.
+    #[arg(long, number_of_values = 2, value_names = ["F32_PATH", "F64_PATH"])]
+    map_float: Vec<String>,
     /// Do not prepend the enum name to constant or newtype variants.
     #[arg(long)]
     no_prepend_enum_name: bool,
+    /// Emit a global constant for each variant of named enums generated as Rust enums, newtypes or modules.
+    #[arg(long)]
+    flat_enum_constants: bool,
+    /// Emit an `ALL` associated constant listing the variants of each enum generated as a Rust enum.
+    #[arg(long)]
+    enum_variants_slice: bool,
+    /// Emit a `name` method returning the name of the C enumerator of the value of each enum generated as a Rust enum or a newtype.
+    #[arg(long)]
+    enum_name_fn: bool,
     /// Do not try to detect default include paths
     #[arg(long)]
     no_include_path_detection: bool,
+    /// Warn when the system include paths cannot be detected.
+    #[arg(long)]
+    warn_on_include_path_detection_failure: bool,
     /// Try to fit macro constants into types smaller than u32/i32
     #[arg(long)]
     fit_macro_constant_types: bool,
     /// Mark TYPE as opaque.
     #[arg(long, value_name = "TYPE")]
     opaque_type: Vec<String>,
+    /// Mark TYPEDEF as opaque, without the type it aliases.
+    #[arg(long, value_name = "TYPEDEF")]
+    typedef_opaque: Vec<String>,
+    /// Mark all the instantiations of TEMPLATE as opaque, whatever their template arguments.
+    #[arg(long, value_name = "TEMPLATE")]
+    with_opaque_template_instantiations: Vec<String>,
+    /// Generate TYPE as an opaque type that can only be used behind a pointer.
+    #[arg(long, value_name = "TYPE")]
+    opaque_no_construct: Vec<String>,
     ///  Write Rust bindings to OUTPUT.
     #[arg(long, short, value_name = "OUTPUT")]
     output: Option<String>,
@@ -290,6 +417,18 @@ struct BindgenCommand {
     module_raw_line: Vec<String>,
     #[arg(long, help = rust_target_help())]
     rust_target: Option<RustTarget>,
+    /// Rust EDITION of the crate the bindings are used in. Valid options are 2015, 2018 and 2021. Defaults to 2015.
+    #[arg(long, value_name = "EDITION")]
+    rust_edition: Option<RustEdition>,
+    /// Emit nightly-only constructs behind the Cargo FEATURE, with a fallback for stable Rust.
+    #[arg(long, value_name = "FEATURE")]
+    nightly_cfg_feature: Option<String>,
+    /// Emit `#[cfg_attr(docsrs, doc(cfg(...)))]` alongside every generated `#[cfg(...)]`.
+    #[arg(long)]
+    emit_doc_cfg: bool,
+    /// Guard the items declared in OS-specific system headers with `#[cfg(target_os = "...")]`, inferring their OS from the path of the header.
+    #[arg(long)]
+    infer_os_cfg: bool,
     /// Use types from Rust core instead of std.
     #[arg(long)]
     use_core: bool,
@@ -302,6 +441,15 @@ struct BindgenCommand {
     /// Generate inline functions.
     #[arg(long)]
     generate_inline_functions: bool,
+    /// Translate the body of trivial `static inline` functions into Rust.
+    #[arg(long)]
+    translate_inline_functions: bool,
+    /// Translate function-like macros expanding to arithmetic expressions into Rust `const fn`s.
+    #[arg(long)]
+    translate_function_macros: bool,
+    /// Translate function-like macros calling the function selected by a `_Generic` selection into Rust traits.
+    #[arg(long)]
+    translate_generic_macros: bool,
     /// Only generate types matching REGEX. Other non-allowlisted types will not be generated.
     #[arg(long, value_name = "REGEX")]
     allowlist_type: Vec<String>,
@@ -358,9 +506,30 @@ struct BindgenCommand {
     /// Add `#[must_use]` annotation to types matching REGEX.
     #[arg(long, value_name = "REGEX")]
     must_use_type: Vec<String>,
+    /// Generate a `CStr` accessor for `char` pointer fields whose `Struct::field` path matches REGEX.
+    #[arg(long, value_name = "REGEX")]
+    cstr_accessor: Vec<String>,
+    /// Add a `PhantomPinned` field to types matching REGEX, so they don't implement `Unpin`.
+    #[arg(long, value_name = "REGEX")]
+    mark_pinned: Vec<String>,
+    /// Generate `from_bytes` and `read_from` methods parsing types matching REGEX from bytes.
+    #[arg(long, value_name = "REGEX")]
+    derive_from_bytes: Vec<String>,
+    /// Implement `AsRef` and `AsMut` for the type of the first field of any struct matching REGEX.
+    #[arg(long, value_name = "REGEX")]
+    first_field_as_ref: Vec<String>,
+    /// Derive `arbitrary::Arbitrary`, behind the `arbitrary` feature, for any struct or enum matching REGEX.
+    #[arg(long, value_name = "REGEX")]
+    derive_arbitrary: Vec<String>,
     /// Enables detecting unexposed attributes in functions (slow). Used to generate `#[must_use]` annotations.
     #[arg(long)]
     enable_function_attribute_detection: bool,
+    /// Enables detecting the `pure`, `const`, `malloc` and `returns_nonnull` attributes in functions. Used to document them and generate `NonNull` return types.
+    #[arg(long)]
+    detect_function_attributes: bool,
+    /// Add `#[must_use]` to the functions and types marked as `warn_unused_result` or `[[nodiscard]]`.
+    #[arg(long)]
+    respect_nodiscard: bool,
     /// Use `*const [T; size]` instead of `*const T` for C arrays
     #[arg(long)]
     use_array_pointers_in_arguments: bool,
@@ -385,6 +554,9 @@ struct BindgenCommand {
     /// Generate types with C style naming.
     #[arg(long)]
     c_naming: bool,
+    /// Replace the matches of REGEX in the names of the generated items with REPLACEMENT, which can refer to the capture groups of REGEX, e.g. `$1`.
+    #[arg(long, number_of_values = 2, value_names = ["REGEX", "REPLACEMENT"])]
+    rename_pattern: Vec<String>,
     /// Always output explicit padding fields.
     #[arg(long)]
     explicit_padding: bool,
@@ -397,18 +569,39 @@ struct BindgenCommand {
     /// Deduplicates extern blocks.
     #[arg(long)]
     merge_extern_blocks: bool,
+    /// Deduplicates structurally identical anonymous structs and unions.
+    #[arg(long)]
+    dedup_identical_anonymous_types: bool,
     /// Overrides the ABI of functions matching REGEX. The OVERRIDE value must be of the shape REGEX=ABI where ABI can be one of C, stdcall, efiapi, fastcall, thiscall, aapcs, win64 or C-unwind<.>
     #[arg(long, value_name = "OVERRIDE", value_parser = parse_abi_override)]
     override_abi: Vec<(Abi, String)>,
+    /// Groups the integer macro constants matching REGEX into an enum. The GROUP value must be of the shape REGEX=ENUM where ENUM is the name of the enum.
+    #[arg(long, value_name = "GROUP", value_parser = parse_constant_group)]
+    constant_group_as_enum: Vec<(String, String)>,
+    /// Generates a wrapper returning a `std::io::Result` for the functions matching REGEX, which return an error value and set `errno` on failure. The WRAPPER value must be of the shape REGEX=VALUE[:SUCCESS] where VALUE is an integer, `negative` or `null`, and SUCCESS is either `value` (the default) or `unit`.
+    #[arg(long, value_name = "WRAPPER", value_parser = parse_errno_wrapper)]
+    errno_wrapper: Vec<(String, ErrnoConvention)>,
+    /// Generates a wrapper returning the pointer written through an out parameter for the functions matching REGEX. The OUT value must be of the shape REGEX=PARAM where PARAM is the name of the parameter.
+    #[arg(long, value_name = "OUT", value_parser = parse_out_param)]
+    out_param: Vec<(String, String)>,
     /// Wrap unsafe operations in unsafe blocks.
     #[arg(long)]
     wrap_unsafe_ops: bool,
+    /// Put #[allow(clippy::...)] attributes on the generated items known to trigger clippy lints.
+    #[arg(long)]
+    suppress_clippy: bool,
+    /// Emit stubs of the functions behind `#[cfg(doc)]`, and their `extern` declarations behind `#[cfg(not(doc))]`.
+    #[arg(long)]
+    docsrs_stubs: bool,
     /// Enable fallback for clang macro parsing.
     #[arg(long)]
     clang_macro_fallback: bool,
     /// Set path for temporary files generated by fallback for clang macro parsing.
     #[arg(long)]
     clang_macro_fallback_build_dir: Option<PathBuf>,
+    /// Ignore the clang arguments that aren't supported by the loaded version of libclang instead of failing.
+    #[arg(long)]
+    clang_version_fallback: bool,
     /// Use DSTs to represent structures with flexible array members.
     #[arg(long)]
     flexarray_dst: bool,
@@ -442,6 +635,31 @@ struct BindgenCommand {
     /// Whether to emit diagnostics or not.
     #[arg(long, requires = "experimental")]
     emit_diagnostics: bool,
+    /// Return an error instead of empty bindings when no items were generated.
+    #[arg(long)]
+    error_on_empty: bool,
+    /// Return an error instead of the bindings when any warning was emitted.
+    #[arg(long)]
+    treat_warnings_as_errors: bool,
+    /// Mark the items only generated as dependencies of allowlisted items with `#[doc(hidden)]`.
+    #[arg(long)]
+    hide_transitive_deps: bool,
+    /// Print why each function, variable and named type was or wasn't generated to `stderr`.
+    #[arg(long)]
+    trace_allowlist: bool,
+    /// Write a report listing whether each function, variable and named type was generated,
+    /// blocklisted or not matched to PATH.
+    #[arg(long, value_name = "PATH")]
+    write_coverage_report: Option<PathBuf>,
+    /// Set the FLAGS used by libclang to parse the translation unit, separated by `|`. Accepted flags are `none`, `detailed_preprocessing_record`, `incomplete`, `skip_function_bodies`, `keep_going` and `single_file_parse`.
+    #[arg(long, value_name = "FLAGS")]
+    translation_unit_flags: Option<TranslationUnitFlags>,
+    /// Parse the input headers for the given target TRIPLE, overriding any `--target` clang argument.
+    #[arg(long, value_name = "TRIPLE")]
+    clang_target: Option<String>,
+    /// Use the given target TRIPLE to pick the `long double` mapping and the OS inferred by `--infer-os-cfg`. Defaults to the target the headers are parsed for.
+    #[arg(long, value_name = "TRIPLE")]
+    output_target: Option<String>,
     /// Generates completions for the specified SHELL, sends them to `stdout` and exits.
     #[arg(long, value_name = "SHELL")]
     generate_shell_completions: Option<clap_complete::Shell>,
@@ -473,13 +691,17 @@ where
         newtype_global_enum,
         rustified_enum,
         rustified_non_exhaustive_enum,
+        with_enum_unknown_variant,
         constified_enum,
         constified_enum_module,
         default_macro_constant_type,
+        enum_default_variant,
         default_alias_style,
         normal_alias,
         new_type_alias,
         new_type_alias_deref,
+        opaque_handle,
+        strong_typedef,
         default_non_copy_union_style,
         bindgen_wrapper_union,
         manually_drop_union,
@@ -489,18 +711,29 @@ where
         blocklist_file,
         blocklist_var,
         no_layout_tests,
+        layout_tests_in_module,
+        layout_debug_asserts,
+        layout_verification_fn,
+        document_layout,
+        modules_per_header,
+        volatile_accessors,
         no_derive_copy,
         no_derive_debug,
         no_derive_default,
         impl_debug,
         impl_partialeq,
+        impl_hash,
+        impl_hash_float_bits,
+        impl_clone_fallback,
         with_derive_default,
         with_derive_hash,
         with_derive_partialeq,
         with_derive_partialord,
         with_derive_eq,
         with_derive_ord,
+        derive_order,
         no_doc_comments,
+        doxygen_style,
         no_recursive_allowlist,
         objc_extern_crate,
         generate_block,
@@ -508,8 +741,14 @@ where
         block_extern_crate,
         distrust_clang_mangling,
         builtins,
+        builtin_macros,
         ctypes_prefix,
+        libc_ctypes_shim,
         anon_fields_prefix,
+        bitfield_unit_name,
+        bitfield_unit_visibility,
+        max_recursion_depth,
+        max_monomorphization_warning,
         time_phases,
         emit_clang_ast,
         emit_ir,
@@ -517,24 +756,41 @@ where
         enable_cxx_namespaces,
         disable_name_namespacing,
         disable_nested_struct_naming,
+        structural_anon_type_names,
         disable_untagged_union,
         disable_header_comment,
+        emit_header_banner,
         ignore_functions,
         generate,
         ignore_methods,
         no_convert_floats,
+        map_float,
         no_prepend_enum_name,
+        flat_enum_constants,
+        enum_variants_slice,
+        enum_name_fn,
         no_include_path_detection,
+        warn_on_include_path_detection_failure,
         fit_macro_constant_types,
         opaque_type,
+        typedef_opaque,
+        with_opaque_template_instantiations,
+        opaque_no_construct,
         output,
         raw_line,
         module_raw_line,
         rust_target,
+        rust_edition,
+        nightly_cfg_feature,
+        emit_doc_cfg,
+        infer_os_cfg,
         use_core,
         conservative_inline_namespaces,
         allowlist_function,
         generate_inline_functions,
+        translate_inline_functions,
+        translate_function_macros,
+        translate_generic_macros,
         allowlist_type,
         allowlist_var,
         allowlist_file,
@@ -552,7 +808,14 @@ where
         no_default,
         no_hash,
         must_use_type,
+        cstr_accessor,
+        mark_pinned,
+        derive_from_bytes,
+        first_field_as_ref,
+        derive_arbitrary,
         enable_function_attribute_detection,
+        detect_function_attributes,
+        respect_nodiscard,
         use_array_pointers_in_arguments,
         wasm_import_module_name,
         dynamic_loading,
@@ -561,14 +824,22 @@ where
         respect_cxx_access_specs,
         translate_enum_integer_types,
         c_naming,
+        rename_pattern,
         explicit_padding,
         vtable_generation,
         sort_semantically,
         merge_extern_blocks,
+        dedup_identical_anonymous_types,
         override_abi,
+        constant_group_as_enum,
+        errno_wrapper,
+        out_param,
         wrap_unsafe_ops,
+        suppress_clippy,
+        docsrs_stubs,
         clang_macro_fallback,
         clang_macro_fallback_build_dir,
+        clang_version_fallback,
         flexarray_dst,
         with_derive_custom,
         with_derive_custom_struct,
@@ -579,6 +850,14 @@ where
         wrap_static_fns_suffix,
         default_visibility,
         emit_diagnostics,
+        error_on_empty,
+        treat_warnings_as_errors,
+        hide_transitive_deps,
+        trace_allowlist,
+        write_coverage_report,
+        translation_unit_flags,
+        clang_target,
+        output_target,
         generate_shell_completions,
         experimental: _,
         version,
@@ -619,6 +898,22 @@ where
         builder = builder.rust_target(rust_target);
     }
 
+    if let Some(rust_edition) = rust_edition {
+        builder = builder.rust_edition(rust_edition);
+    }
+
+    if let Some(feature) = nightly_cfg_feature {
+        builder = builder.nightly_cfg_feature(feature);
+    }
+
+    if emit_doc_cfg {
+        builder = builder.emit_doc_cfg(true);
+    }
+
+    if infer_os_cfg {
+        builder = builder.infer_os_cfg(true);
+    }
+
     if let Some(variant) = default_enum_style {
         builder = builder.default_enum_style(variant);
     }
@@ -643,6 +938,10 @@ where
         builder = builder.rustified_non_exhaustive_enum(regex);
     }
 
+    for regex in with_enum_unknown_variant {
+        builder = builder.with_enum_unknown_variant(regex);
+    }
+
     for regex in constified_enum {
         builder = builder.constified_enum(regex);
     }
@@ -656,6 +955,10 @@ where
             builder.default_macro_constant_type(default_macro_constant_type)
     }
 
+    if let Some(variant) = enum_default_variant {
+        builder = builder.enum_default_variant(variant);
+    }
+
     if let Some(variant) = default_alias_style {
         builder = builder.default_alias_style(variant);
     }
@@ -672,6 +975,14 @@ where
         builder = builder.new_type_alias_deref(regex);
     }
 
+    for regex in opaque_handle {
+        builder = builder.opaque_handle(regex);
+    }
+
+    for regex in strong_typedef {
+        builder = builder.strong_typedef(regex);
+    }
+
     if let Some(variant) = default_non_copy_union_style {
         builder = builder.default_non_copy_union_style(variant);
     }
@@ -708,10 +1019,38 @@ where
         builder = builder.emit_builtins();
     }
 
+    if builtin_macros {
+        builder = builder.generate_builtin_macros(true);
+    }
+
     if no_layout_tests {
         builder = builder.layout_tests(false);
     }
 
+    if let Some(name) = layout_tests_in_module {
+        builder = builder.layout_tests_in_module(Some(name));
+    }
+
+    if layout_debug_asserts {
+        builder = builder.layout_debug_asserts(true);
+    }
+
+    if layout_verification_fn {
+        builder = builder.layout_verification_fn(true);
+    }
+
+    if document_layout {
+        builder = builder.document_layout(true);
+    }
+
+    if modules_per_header {
+        builder = builder.modules_per_header(true);
+    }
+
+    if volatile_accessors {
+        builder = builder.volatile_accessors(true);
+    }
+
     if no_derive_copy {
         builder = builder.derive_copy(false);
     }
@@ -728,6 +1067,18 @@ where
         builder = builder.impl_partialeq(true);
     }
 
+    if impl_hash {
+        builder = builder.impl_hash(true);
+    }
+
+    if impl_hash_float_bits {
+        builder = builder.impl_hash_float_bits(true);
+    }
+
+    if impl_clone_fallback {
+        builder = builder.impl_clone_fallback(true);
+    }
+
     if with_derive_default {
         builder = builder.derive_default(true);
     }
@@ -752,6 +1103,10 @@ where
         builder = builder.derive_ord(true);
     }
 
+    if !derive_order.is_empty() {
+        builder = builder.derive_order(derive_order);
+    }
+
     if no_derive_default {
         builder = builder.derive_default(false);
     }
@@ -760,14 +1115,38 @@ where
         builder = builder.prepend_enum_name(false);
     }
 
+    if flat_enum_constants {
+        builder = builder.flat_enum_constants(true);
+    }
+
+    if enum_variants_slice {
+        builder = builder.enum_variants_slice(true);
+    }
+
+    if enum_name_fn {
+        builder = builder.enum_name_fn(true);
+    }
+
     if no_include_path_detection {
         builder = builder.detect_include_paths(false);
     }
 
+    if warn_on_include_path_detection_failure {
+        builder = builder.warn_on_include_path_detection_failure(true);
+    }
+
     if fit_macro_constant_types {
         builder = builder.fit_macro_constants(true);
     }
 
+    if let Some(depth) = max_recursion_depth {
+        builder = builder.max_recursion_depth(depth);
+    }
+
+    if let Some(limit) = max_monomorphization_warning {
+        builder = builder.max_monomorphization_warning(limit);
+    }
+
     if time_phases {
         builder = builder.time_phases(true);
     }
@@ -784,8 +1163,20 @@ where
         builder = builder.ctypes_prefix(prefix);
     }
 
+    if libc_ctypes_shim {
+        builder = builder.libc_ctypes_shim(true);
+    }
+
     builder = builder.anon_fields_prefix(anon_fields_prefix);
 
+    if let Some(name) = bitfield_unit_name {
+        builder = builder.bitfield_unit_name(name);
+    }
+
+    if let Some(visibility) = bitfield_unit_visibility {
+        builder = builder.bitfield_unit_visibility(visibility);
+    }
+
     if let Some(config) = generate {
         builder = builder.with_codegen_config(config);
     }
@@ -810,6 +1201,14 @@ where
         builder = builder.enable_function_attribute_detection();
     }
 
+    if detect_function_attributes {
+        builder = builder.detect_function_attributes(true);
+    }
+
+    if respect_nodiscard {
+        builder = builder.respect_nodiscard(true);
+    }
+
     if disable_name_namespacing {
         builder = builder.disable_name_namespacing();
     }
@@ -818,6 +1217,10 @@ where
         builder = builder.disable_nested_struct_naming();
     }
 
+    if structural_anon_type_names {
+        builder = builder.structural_anon_type_names(true);
+    }
+
     if disable_untagged_union {
         builder = builder.disable_untagged_union();
     }
@@ -826,6 +1229,10 @@ where
         builder = builder.disable_header_comment();
     }
 
+    if emit_header_banner {
+        builder = builder.emit_header_banner(true);
+    }
+
     if ignore_functions {
         builder = builder.ignore_functions();
     }
@@ -838,10 +1245,18 @@ where
         builder = builder.no_convert_floats();
     }
 
+    if let [.., f32_path, f64_path] = &map_float[..] {
+        builder = builder.map_float(f32_path, f64_path);
+    }
+
     if no_doc_comments {
         builder = builder.generate_comments(false);
     }
 
+    if let Some(style) = doxygen_style {
+        builder = builder.doxygen_style(style);
+    }
+
     if no_recursive_allowlist {
         builder = builder.allowlist_recursively(false);
     }
@@ -866,6 +1281,18 @@ where
         builder = builder.opaque_type(ty);
     }
 
+    for typedef in typedef_opaque {
+        builder = builder.typedef_opaque(typedef);
+    }
+
+    for template in with_opaque_template_instantiations {
+        builder = builder.with_opaque_template_instantiations(template);
+    }
+
+    for ty in opaque_no_construct {
+        builder = builder.opaque_no_construct(ty);
+    }
+
     for line in raw_line {
         builder = builder.raw_line(line);
     }
@@ -892,6 +1319,18 @@ where
         builder = builder.generate_inline_functions(true);
     }
 
+    if translate_inline_functions {
+        builder = builder.translate_inline_functions(true);
+    }
+
+    if translate_function_macros {
+        builder = builder.translate_function_macros(true);
+    }
+
+    if translate_generic_macros {
+        builder = builder.translate_generic_macros(true);
+    }
+
     for regex in allowlist_function {
         builder = builder.allowlist_function(regex);
     }
@@ -977,6 +1416,26 @@ where
         builder = builder.must_use_type(regex);
     }
 
+    for regex in cstr_accessor {
+        builder = builder.cstr_accessor(regex);
+    }
+
+    for regex in mark_pinned {
+        builder = builder.mark_pinned(regex);
+    }
+
+    for regex in derive_from_bytes {
+        builder = builder.derive_from_bytes(regex);
+    }
+
+    for regex in first_field_as_ref {
+        builder = builder.first_field_as_ref(regex);
+    }
+
+    for regex in derive_arbitrary {
+        builder = builder.derive_arbitrary(regex);
+    }
+
     if let Some(dynamic_library_name) = dynamic_loading {
         builder = builder.dynamic_library_name(dynamic_library_name);
     }
@@ -1019,6 +1478,12 @@ where
         builder = builder.c_naming(true);
     }
 
+    let mut values = rename_pattern.into_iter();
+    while let Some(regex) = values.next() {
+        let replacement = values.next().unwrap();
+        builder = builder.rename_pattern(regex, replacement);
+    }
+
     if explicit_padding {
         builder = builder.explicit_padding(true);
     }
@@ -1035,14 +1500,38 @@ where
         builder = builder.merge_extern_blocks(true);
     }
 
+    if dedup_identical_anonymous_types {
+        builder = builder.dedup_identical_anonymous_types(true);
+    }
+
     for (abi, regex) in override_abi {
         builder = builder.override_abi(abi, regex);
     }
 
+    for (regex, enum_name) in constant_group_as_enum {
+        builder = builder.constant_group_as_enum(regex, enum_name);
+    }
+
+    for (regex, convention) in errno_wrapper {
+        builder = builder.errno_wrappers(regex, convention);
+    }
+
+    for (regex, param) in out_param {
+        builder = builder.out_param(regex, param);
+    }
+
     if wrap_unsafe_ops {
         builder = builder.wrap_unsafe_ops(true);
     }
 
+    if suppress_clippy {
+        builder = builder.suppress_clippy(true);
+    }
+
+    if docsrs_stubs {
+        builder = builder.docsrs_stubs(true);
+    }
+
     if clang_macro_fallback {
         builder = builder.clang_macro_fallback();
     }
@@ -1051,6 +1540,10 @@ where
         builder = builder.clang_macro_fallback_build_dir(path);
     }
 
+    if clang_version_fallback {
+        builder = builder.clang_version_fallback(true);
+    }
+
     if flexarray_dst {
         builder = builder.flexarray_dst(true);
     }
@@ -1150,5 +1643,37 @@ where
         builder = builder.emit_diagnostics();
     }
 
+    if error_on_empty {
+        builder = builder.error_on_empty(true);
+    }
+
+    if treat_warnings_as_errors {
+        builder = builder.treat_warnings_as_errors(true);
+    }
+
+    if hide_transitive_deps {
+        builder = builder.hide_transitive_deps(true);
+    }
+
+    if trace_allowlist {
+        builder = builder.trace_allowlist(true);
+    }
+
+    if let Some(path) = write_coverage_report {
+        builder = builder.write_coverage_report(path);
+    }
+
+    if let Some(flags) = translation_unit_flags {
+        builder = builder.translation_unit_flags(flags);
+    }
+
+    if let Some(triple) = clang_target {
+        builder = builder.clang_target(triple);
+    }
+
+    if let Some(triple) = output_target {
+        builder = builder.output_target(triple);
+    }
+
     Ok((builder, output, verbose))
 }
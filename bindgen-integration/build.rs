@@ -195,6 +195,9 @@ fn setup_macro_test() {
         .constified_enum("my_prefixed_enum_to_be_constified")
         .opaque_type("my_prefixed_templated_foo<my_prefixed_baz>")
         .new_type_alias("TestDeriveOnAlias")
+        .opaque_handle("TestOpaqueHandle")
+        .derive_from_bytes("PacketHeader")
+        .layout_verification_fn(true)
         .depfile(out_rust_file_relative.display().to_string(), &out_dep_file)
         .generate()
         .expect("Unable to generate bindings");
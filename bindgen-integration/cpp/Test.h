@@ -244,3 +244,13 @@ enum MyOrderedEnum {
 
 // Used to test custom derives on new-type alias. See `test_custom_derive`.
 typedef int TestDeriveOnAlias;
+
+// Used to test formatting opaque handles as pointers. See
+// `test_opaque_handle_pointer`.
+typedef unsigned long TestOpaqueHandle;
+
+// Used to test parsing structs from bytes. See `test_from_bytes`.
+struct PacketHeader {
+  unsigned short kind;
+  unsigned short length;
+};
@@ -78,6 +78,37 @@ fn test_bitfields_second() {
     assert!(unsafe { second.assert(1337, true) });
 }
 
+#[test]
+fn test_bitfields_const_constructor() {
+    const UNIT: bindings::__BindgenBitfieldUnit<[u8; 4]> =
+        bindings::bitfields::Second::new_bitfield_1(1337, true);
+    assert_eq!(UNIT.get_const(0, 31), 1337);
+    assert!(UNIT.get_bit_const(31));
+
+    let mut second: bindings::bitfields::Second = unsafe { mem::zeroed() };
+    second._bitfield_1 = UNIT;
+    assert!(unsafe { second.assert(1337, true) });
+}
+
+#[test]
+fn test_from_bytes() {
+    let storage = [1u16, 20u16];
+    let bytes =
+        unsafe { std::slice::from_raw_parts(storage.as_ptr() as *const u8, 4) };
+
+    let header = bindings::PacketHeader::from_bytes(bytes).unwrap();
+    assert_eq!(header.kind, 1);
+    assert_eq!(header.length, 20);
+    assert!(bindings::PacketHeader::from_bytes(&bytes[..3]).is_none());
+
+    // The copying variant doesn't need the bytes to be aligned.
+    let mut unaligned = [0u8; 5];
+    unaligned[1..].copy_from_slice(bytes);
+    let header = bindings::PacketHeader::read_from(&unaligned[1..]).unwrap();
+    assert_eq!(header.kind, 1);
+    assert_eq!(header.length, 20);
+}
+
 #[test]
 fn test_bitfields_third() {
     let mut third: bindings::bitfields::Third = unsafe { mem::zeroed() };
@@ -297,6 +328,17 @@ fn test_custom_derive() {
     assert!(!(test1 > test2));
 }
 
+#[test]
+fn test_opaque_handle_pointer() {
+    let handle = bindings::TestOpaqueHandle::from_raw(0x1234);
+    assert_eq!(format!("{:p}", handle), "0x1234");
+}
+
+#[test]
+fn test_verify_layouts() {
+    assert_eq!(bindings::verify_layouts(), Ok(()));
+}
+
 #[test]
 fn test_wrap_static_fns() {
     // GH-1090: https://github.com/rust-lang/rust-bindgen/issues/1090
@@ -7,8 +7,9 @@ pub struct Test {
 }
 pub const Test_T_NONE: Test__bindgen_ty_1 = Test__bindgen_ty_1::T_NONE;
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum Test__bindgen_ty_1 {
+    #[default]
     T_NONE = 0,
 }
 const _: () = {
@@ -18,8 +19,9 @@ const _: () = {
     ["Offset of field: Test::bar"][::std::mem::offset_of!(Test, bar) - 4usize];
 };
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum Baz {
+    #[default]
     Foo = 0,
     Bar = 1,
 }
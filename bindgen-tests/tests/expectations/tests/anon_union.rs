@@ -10,8 +10,9 @@ impl TErrorResult_UnionState {
     pub const HasException: TErrorResult_UnionState = TErrorResult_UnionState::HasMessage;
 }
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum TErrorResult_UnionState {
+    #[default]
     HasMessage = 0,
 }
 #[repr(C)]
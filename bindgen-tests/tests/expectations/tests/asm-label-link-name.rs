@@ -0,0 +1,12 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    #[link_name = "\u{1}real_foo"]
+    pub fn foo(x: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}real_bar"]
+    pub static mut bar: ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn baz() -> ::std::os::raw::c_int;
+}
@@ -83,6 +83,82 @@ where
         }
     }
 }
+impl<const N: usize> __BindgenBitfieldUnit<[u8; N]> {
+    #[inline]
+    pub const fn get_bit_const(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < N);
+        let byte_index = index / 8;
+        let byte = self.storage[byte_index];
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub const fn set_bit_const(mut self, index: usize, val: bool) -> Self {
+        debug_assert!(index / 8 < N);
+        let byte_index = index / 8;
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        if val {
+            self.storage[byte_index] |= mask;
+        } else {
+            self.storage[byte_index] &= !mask;
+        }
+        self
+    }
+    #[inline]
+    pub const fn get_const(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < N);
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= N);
+        let mut val = 0;
+        let mut i = 0;
+        while i < bit_width as usize {
+            if self.get_bit_const(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+            i += 1;
+        }
+        val
+    }
+    #[inline]
+    pub const fn set_const(
+        mut self,
+        bit_offset: usize,
+        bit_width: u8,
+        val: u64,
+    ) -> Self {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < N);
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= N);
+        let mut i = 0;
+        while i < bit_width as usize {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self = self.set_bit_const(index + bit_offset, val_bit_is_set);
+            i += 1;
+        }
+        self
+    }
+}
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct MuchBitfield {
@@ -458,7 +534,7 @@ impl MuchBitfield {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(
+    pub const fn new_bitfield_1(
         m0: ::std::os::raw::c_char,
         m1: ::std::os::raw::c_char,
         m2: ::std::os::raw::c_char,
@@ -493,9 +569,11 @@ impl MuchBitfield {
         m31: ::std::os::raw::c_char,
         m32: ::std::os::raw::c_char,
     ) -> __BindgenBitfieldUnit<[u8; 5usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 5usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 5usize],
+        >>::new([0; 5usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 1u8,
                 {
@@ -503,8 +581,8 @@ impl MuchBitfield {
                     m0 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 1usize,
                 1u8,
                 {
@@ -512,8 +590,8 @@ impl MuchBitfield {
                     m1 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 2usize,
                 1u8,
                 {
@@ -521,8 +599,8 @@ impl MuchBitfield {
                     m2 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 3usize,
                 1u8,
                 {
@@ -530,8 +608,8 @@ impl MuchBitfield {
                     m3 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 4usize,
                 1u8,
                 {
@@ -539,8 +617,8 @@ impl MuchBitfield {
                     m4 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 5usize,
                 1u8,
                 {
@@ -548,8 +626,8 @@ impl MuchBitfield {
                     m5 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 6usize,
                 1u8,
                 {
@@ -557,8 +635,8 @@ impl MuchBitfield {
                     m6 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 7usize,
                 1u8,
                 {
@@ -566,8 +644,8 @@ impl MuchBitfield {
                     m7 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 8usize,
                 1u8,
                 {
@@ -575,8 +653,8 @@ impl MuchBitfield {
                     m8 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 9usize,
                 1u8,
                 {
@@ -584,8 +662,8 @@ impl MuchBitfield {
                     m9 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 10usize,
                 1u8,
                 {
@@ -593,8 +671,8 @@ impl MuchBitfield {
                     m10 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 11usize,
                 1u8,
                 {
@@ -602,8 +680,8 @@ impl MuchBitfield {
                     m11 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 12usize,
                 1u8,
                 {
@@ -611,8 +689,8 @@ impl MuchBitfield {
                     m12 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 13usize,
                 1u8,
                 {
@@ -620,8 +698,8 @@ impl MuchBitfield {
                     m13 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 14usize,
                 1u8,
                 {
@@ -629,8 +707,8 @@ impl MuchBitfield {
                     m14 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 15usize,
                 1u8,
                 {
@@ -638,8 +716,8 @@ impl MuchBitfield {
                     m15 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 16usize,
                 1u8,
                 {
@@ -647,8 +725,8 @@ impl MuchBitfield {
                     m16 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 17usize,
                 1u8,
                 {
@@ -656,8 +734,8 @@ impl MuchBitfield {
                     m17 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 18usize,
                 1u8,
                 {
@@ -665,8 +743,8 @@ impl MuchBitfield {
                     m18 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 19usize,
                 1u8,
                 {
@@ -674,8 +752,8 @@ impl MuchBitfield {
                     m19 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 20usize,
                 1u8,
                 {
@@ -683,8 +761,8 @@ impl MuchBitfield {
                     m20 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 21usize,
                 1u8,
                 {
@@ -692,8 +770,8 @@ impl MuchBitfield {
                     m21 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 22usize,
                 1u8,
                 {
@@ -701,8 +779,8 @@ impl MuchBitfield {
                     m22 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 23usize,
                 1u8,
                 {
@@ -710,8 +788,8 @@ impl MuchBitfield {
                     m23 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 24usize,
                 1u8,
                 {
@@ -719,8 +797,8 @@ impl MuchBitfield {
                     m24 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 25usize,
                 1u8,
                 {
@@ -728,8 +806,8 @@ impl MuchBitfield {
                     m25 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 26usize,
                 1u8,
                 {
@@ -737,8 +815,8 @@ impl MuchBitfield {
                     m26 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 27usize,
                 1u8,
                 {
@@ -746,8 +824,8 @@ impl MuchBitfield {
                     m27 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 28usize,
                 1u8,
                 {
@@ -755,8 +833,8 @@ impl MuchBitfield {
                     m28 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 29usize,
                 1u8,
                 {
@@ -764,8 +842,8 @@ impl MuchBitfield {
                     m29 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 30usize,
                 1u8,
                 {
@@ -773,8 +851,8 @@ impl MuchBitfield {
                     m30 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 31usize,
                 1u8,
                 {
@@ -782,8 +860,8 @@ impl MuchBitfield {
                     m31 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 32usize,
                 1u8,
                 {
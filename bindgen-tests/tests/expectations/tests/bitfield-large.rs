@@ -83,6 +83,82 @@ where
         }
     }
 }
+impl<const N: usize> __BindgenBitfieldUnit<[u8; N]> {
+    #[inline]
+    pub const fn get_bit_const(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < N);
+        let byte_index = index / 8;
+        let byte = self.storage[byte_index];
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub const fn set_bit_const(mut self, index: usize, val: bool) -> Self {
+        debug_assert!(index / 8 < N);
+        let byte_index = index / 8;
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        if val {
+            self.storage[byte_index] |= mask;
+        } else {
+            self.storage[byte_index] &= !mask;
+        }
+        self
+    }
+    #[inline]
+    pub const fn get_const(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < N);
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= N);
+        let mut val = 0;
+        let mut i = 0;
+        while i < bit_width as usize {
+            if self.get_bit_const(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+            i += 1;
+        }
+        val
+    }
+    #[inline]
+    pub const fn set_const(
+        mut self,
+        bit_offset: usize,
+        bit_width: u8,
+        val: u64,
+    ) -> Self {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < N);
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= N);
+        let mut i = 0;
+        while i < bit_width as usize {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self = self.set_bit_const(index + bit_offset, val_bit_is_set);
+            i += 1;
+        }
+        self
+    }
+}
 #[repr(C)]
 #[repr(align(16))]
 #[derive(Debug, Default, Copy, Clone)]
@@ -107,10 +183,12 @@ impl HasBigBitfield {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(x: i128) -> __BindgenBitfieldUnit<[u8; 16usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 16usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+    pub const fn new_bitfield_1(x: i128) -> __BindgenBitfieldUnit<[u8; 16usize]> {
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 16usize],
+        >>::new([0; 16usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 128u8,
                 {
@@ -160,10 +238,15 @@ impl HasTwoBigBitfields {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(x: i128, y: i128) -> __BindgenBitfieldUnit<[u8; 16usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 16usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+    pub const fn new_bitfield_1(
+        x: i128,
+        y: i128,
+    ) -> __BindgenBitfieldUnit<[u8; 16usize]> {
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 16usize],
+        >>::new([0; 16usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 80u8,
                 {
@@ -171,8 +254,8 @@ impl HasTwoBigBitfields {
                     x as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 80usize,
                 48u8,
                 {
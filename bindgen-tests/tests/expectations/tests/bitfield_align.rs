@@ -83,6 +83,82 @@ where
         }
     }
 }
+impl<const N: usize> __BindgenBitfieldUnit<[u8; N]> {
+    #[inline]
+    pub const fn get_bit_const(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < N);
+        let byte_index = index / 8;
+        let byte = self.storage[byte_index];
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub const fn set_bit_const(mut self, index: usize, val: bool) -> Self {
+        debug_assert!(index / 8 < N);
+        let byte_index = index / 8;
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        if val {
+            self.storage[byte_index] |= mask;
+        } else {
+            self.storage[byte_index] &= !mask;
+        }
+        self
+    }
+    #[inline]
+    pub const fn get_const(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < N);
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= N);
+        let mut val = 0;
+        let mut i = 0;
+        while i < bit_width as usize {
+            if self.get_bit_const(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+            i += 1;
+        }
+        val
+    }
+    #[inline]
+    pub const fn set_const(
+        mut self,
+        bit_offset: usize,
+        bit_width: u8,
+        val: u64,
+    ) -> Self {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < N);
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= N);
+        let mut i = 0;
+        while i < bit_width as usize {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self = self.set_bit_const(index + bit_offset, val_bit_is_set);
+            i += 1;
+        }
+        self
+    }
+}
 #[repr(C)]
 #[repr(align(4))]
 #[derive(Debug, Default, Copy, Clone)]
@@ -210,7 +286,7 @@ impl A {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(
+    pub const fn new_bitfield_1(
         b1: ::std::os::raw::c_uint,
         b2: ::std::os::raw::c_uint,
         b3: ::std::os::raw::c_uint,
@@ -222,9 +298,11 @@ impl A {
         b9: ::std::os::raw::c_uint,
         b10: ::std::os::raw::c_uint,
     ) -> __BindgenBitfieldUnit<[u8; 2usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 2usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 2usize],
+        >>::new([0; 2usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 1u8,
                 {
@@ -232,8 +310,8 @@ impl A {
                     b1 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 1usize,
                 1u8,
                 {
@@ -241,8 +319,8 @@ impl A {
                     b2 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 2usize,
                 1u8,
                 {
@@ -250,8 +328,8 @@ impl A {
                     b3 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 3usize,
                 1u8,
                 {
@@ -259,8 +337,8 @@ impl A {
                     b4 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 4usize,
                 1u8,
                 {
@@ -268,8 +346,8 @@ impl A {
                     b5 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 5usize,
                 1u8,
                 {
@@ -277,8 +355,8 @@ impl A {
                     b6 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 6usize,
                 1u8,
                 {
@@ -286,8 +364,8 @@ impl A {
                     b7 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 7usize,
                 1u8,
                 {
@@ -295,8 +373,8 @@ impl A {
                     b8 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 8usize,
                 1u8,
                 {
@@ -304,8 +382,8 @@ impl A {
                     b9 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 9usize,
                 1u8,
                 {
@@ -350,13 +428,15 @@ impl B {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(
+    pub const fn new_bitfield_1(
         foo: ::std::os::raw::c_uint,
         bar: ::std::os::raw::c_uchar,
     ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 4usize],
+        >>::new([0; 4usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 31u8,
                 {
@@ -364,8 +444,8 @@ impl B {
                     foo as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 31usize,
                 1u8,
                 {
@@ -414,13 +494,15 @@ impl C {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(
+    pub const fn new_bitfield_1(
         b1: ::std::os::raw::c_uint,
         b2: ::std::os::raw::c_uint,
     ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 1usize],
+        >>::new([0; 1usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 1u8,
                 {
@@ -428,8 +510,8 @@ impl C {
                     b1 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 1usize,
                 1u8,
                 {
@@ -498,15 +580,17 @@ impl Date1 {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(
+    pub const fn new_bitfield_1(
         nWeekDay: ::std::os::raw::c_ushort,
         nMonthDay: ::std::os::raw::c_ushort,
         nMonth: ::std::os::raw::c_ushort,
         nYear: ::std::os::raw::c_ushort,
     ) -> __BindgenBitfieldUnit<[u8; 3usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 3usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 3usize],
+        >>::new([0; 3usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 3u8,
                 {
@@ -514,8 +598,8 @@ impl Date1 {
                     nWeekDay as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 3usize,
                 6u8,
                 {
@@ -523,8 +607,8 @@ impl Date1 {
                     nMonthDay as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 9usize,
                 5u8,
                 {
@@ -532,8 +616,8 @@ impl Date1 {
                     nMonth as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 16usize,
                 8u8,
                 {
@@ -612,16 +696,18 @@ impl Date2 {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(
+    pub const fn new_bitfield_1(
         nWeekDay: ::std::os::raw::c_ushort,
         nMonthDay: ::std::os::raw::c_ushort,
         nMonth: ::std::os::raw::c_ushort,
         nYear: ::std::os::raw::c_ushort,
         byte: ::std::os::raw::c_uchar,
     ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 4usize],
+        >>::new([0; 4usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 3u8,
                 {
@@ -629,8 +715,8 @@ impl Date2 {
                     nWeekDay as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 3usize,
                 6u8,
                 {
@@ -638,8 +724,8 @@ impl Date2 {
                     nMonthDay as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 9usize,
                 5u8,
                 {
@@ -647,8 +733,8 @@ impl Date2 {
                     nMonth as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 16usize,
                 8u8,
                 {
@@ -656,8 +742,8 @@ impl Date2 {
                     nYear as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 24usize,
                 8u8,
                 {
@@ -727,15 +813,17 @@ impl Date3 {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(
+    pub const fn new_bitfield_1(
         nWeekDay: ::std::os::raw::c_ushort,
         nMonthDay: ::std::os::raw::c_ushort,
         nMonth: ::std::os::raw::c_ushort,
         nYear: ::std::os::raw::c_ushort,
     ) -> __BindgenBitfieldUnit<[u8; 3usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 3usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 3usize],
+        >>::new([0; 3usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 3u8,
                 {
@@ -743,8 +831,8 @@ impl Date3 {
                     nWeekDay as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 3usize,
                 6u8,
                 {
@@ -752,8 +840,8 @@ impl Date3 {
                     nMonthDay as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 9usize,
                 5u8,
                 {
@@ -761,8 +849,8 @@ impl Date3 {
                     nMonth as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 16usize,
                 8u8,
                 {
@@ -0,0 +1,5 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub const SEP: u8 = 47u8;
+pub const C_SEP: ::std::os::raw::c_char = 47;
+pub const C_HIGH: ::std::os::raw::c_char = -1;
+pub const INVALID_UTF8: &[u8] = b"\xF0(\x8C(\0";
@@ -102,8 +102,9 @@ const _: () = {
     ["Offset of field: B::d"][::std::mem::offset_of!(B, d) - 0usize];
 };
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum StepSyntax {
+    #[default]
     Keyword = 0,
     FunctionalWithoutKeyword = 1,
     FunctionalWithStartKeyword = 2,
@@ -0,0 +1,14 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub const UNGROUPED: u32 = 8;
+#[repr(u32)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Color {
+    #[default]
+    COLOR_RED = 0,
+    COLOR_GREEN = 2,
+    COLOR_BLUE = 4,
+}
+pub const Level_LEVEL_LOW: Level = -1;
+pub const Level_LEVEL_DEFAULT: Level = 0;
+pub const Level_LEVEL_NORMAL: Level = 0;
+pub type Level = ::std::os::raw::c_int;
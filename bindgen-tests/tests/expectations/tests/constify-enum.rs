@@ -4,8 +4,9 @@ impl nsCSSPropertyID {
     pub const eCSSProperty_COUNT: nsCSSPropertyID = nsCSSPropertyID::eCSSPropertyAlias_aa;
 }
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum nsCSSPropertyID {
+    #[default]
     eCSSProperty_a = 0,
     eCSSProperty_b = 1,
     eCSSPropertyAlias_aa = 2,
@@ -0,0 +1,46 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Person {
+    pub name: *const ::std::os::raw::c_char,
+    pub nickname: *mut ::std::os::raw::c_char,
+    pub email: *const ::std::os::raw::c_char,
+    pub age: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Person"][::std::mem::size_of::<Person>() - 32usize];
+    ["Alignment of Person"][::std::mem::align_of::<Person>() - 8usize];
+    ["Offset of field: Person::name"][::std::mem::offset_of!(Person, name) - 0usize];
+    [
+        "Offset of field: Person::nickname",
+    ][::std::mem::offset_of!(Person, nickname) - 8usize];
+    ["Offset of field: Person::email"][::std::mem::offset_of!(Person, email) - 16usize];
+    ["Offset of field: Person::age"][::std::mem::offset_of!(Person, age) - 24usize];
+};
+impl Default for Person {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+impl Person {
+    #[inline]
+    pub unsafe fn name_cstr(&self) -> ::std::option::Option<&::std::ffi::CStr> {
+        if self.name.is_null() {
+            ::std::option::Option::None
+        } else {
+            ::std::option::Option::Some(::std::ffi::CStr::from_ptr(self.name))
+        }
+    }
+    #[inline]
+    pub unsafe fn nickname_cstr(&self) -> ::std::option::Option<&::std::ffi::CStr> {
+        if self.nickname.is_null() {
+            ::std::option::Option::None
+        } else {
+            ::std::option::Option::Some(::std::ffi::CStr::from_ptr(self.nickname))
+        }
+    }
+}
@@ -0,0 +1,35 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Shapes {
+    pub a: Shapes__bindgen_ty_1,
+    pub b: Shapes__bindgen_ty_1,
+    pub c: Shapes__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Shapes__bindgen_ty_1 {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+const _: () = {
+    [
+        "Size of Shapes__bindgen_ty_1",
+    ][::std::mem::size_of::<Shapes__bindgen_ty_1>() - 8usize];
+    [
+        "Alignment of Shapes__bindgen_ty_1",
+    ][::std::mem::align_of::<Shapes__bindgen_ty_1>() - 4usize];
+    [
+        "Offset of field: Shapes__bindgen_ty_1::x",
+    ][::std::mem::offset_of!(Shapes__bindgen_ty_1, x) - 0usize];
+    [
+        "Offset of field: Shapes__bindgen_ty_1::y",
+    ][::std::mem::offset_of!(Shapes__bindgen_ty_1, y) - 4usize];
+};
+const _: () = {
+    ["Size of Shapes"][::std::mem::size_of::<Shapes>() - 24usize];
+    ["Alignment of Shapes"][::std::mem::align_of::<Shapes>() - 4usize];
+    ["Offset of field: Shapes::a"][::std::mem::offset_of!(Shapes, a) - 0usize];
+    ["Offset of field: Shapes::b"][::std::mem::offset_of!(Shapes, b) - 8usize];
+    ["Offset of field: Shapes::c"][::std::mem::offset_of!(Shapes, c) - 16usize];
+};
@@ -0,0 +1,73 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[cfg(feature = "arbitrary")]
+const _: () = {
+    fn assert_arbitrary<T: for<'a> arbitrary::Arbitrary<'a>>() {}
+    let _ = assert_arbitrary::<Color>;
+    let _ = assert_arbitrary::<Point>;
+};
+#[repr(u32)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Color {
+    #[default]
+    Red = 0,
+    Green = 1,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+    pub weight: f32,
+    pub tag: [::std::os::raw::c_uchar; 4usize],
+}
+const _: () = {
+    ["Size of Point"][::std::mem::size_of::<Point>() - 16usize];
+    ["Alignment of Point"][::std::mem::align_of::<Point>() - 4usize];
+    ["Offset of field: Point::x"][::std::mem::offset_of!(Point, x) - 0usize];
+    ["Offset of field: Point::y"][::std::mem::offset_of!(Point, y) - 4usize];
+    ["Offset of field: Point::weight"][::std::mem::offset_of!(Point, weight) - 8usize];
+    ["Offset of field: Point::tag"][::std::mem::offset_of!(Point, tag) - 12usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Node {
+    pub value: Point,
+    pub next: *mut Node,
+}
+const _: () = {
+    ["Size of Node"][::std::mem::size_of::<Node>() - 24usize];
+    ["Alignment of Node"][::std::mem::align_of::<Node>() - 8usize];
+    ["Offset of field: Node::value"][::std::mem::offset_of!(Node, value) - 0usize];
+    ["Offset of field: Node::next"][::std::mem::offset_of!(Node, next) - 16usize];
+};
+impl Default for Node {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Other {
+    pub value: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Other"][::std::mem::size_of::<Other>() - 4usize];
+    ["Alignment of Other"][::std::mem::align_of::<Other>() - 4usize];
+    ["Offset of field: Other::value"][::std::mem::offset_of!(Other, value) - 0usize];
+};
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Holder {
+    pub other: Other,
+}
+const _: () = {
+    ["Size of Holder"][::std::mem::size_of::<Holder>() - 4usize];
+    ["Alignment of Holder"][::std::mem::align_of::<Holder>() - 4usize];
+    ["Offset of field: Holder::other"][::std::mem::offset_of!(Holder, other) - 0usize];
+};
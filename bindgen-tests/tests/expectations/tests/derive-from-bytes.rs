@@ -0,0 +1,51 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Point"][::std::mem::size_of::<Point>() - 8usize];
+    ["Alignment of Point"][::std::mem::align_of::<Point>() - 4usize];
+    ["Offset of field: Point::x"][::std::mem::offset_of!(Point, x) - 0usize];
+    ["Offset of field: Point::y"][::std::mem::offset_of!(Point, y) - 4usize];
+};
+impl Point {
+    /// Reinterprets the start of `bytes` as a reference to `Self`, without
+    /// copying, using the native byte order.
+    ///
+    /// Returns `None` if `bytes` is too short or is not suitably aligned.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> ::std::option::Option<&Self> {
+        if bytes.len() < ::std::mem::size_of::<Self>()
+            || bytes.as_ptr() as usize % ::std::mem::align_of::<Self>() != 0
+        {
+            return ::std::option::Option::None;
+        }
+        ::std::option::Option::Some(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+    /// Copies the start of `bytes` into a new `Self`, using the native
+    /// byte order.
+    ///
+    /// Returns `None` if `bytes` is too short.
+    #[inline]
+    pub fn read_from(bytes: &[u8]) -> ::std::option::Option<Self> {
+        if bytes.len() < ::std::mem::size_of::<Self>() {
+            return ::std::option::Option::None;
+        }
+        ::std::option::Option::Some(unsafe {
+            ::std::ptr::read_unaligned(bytes.as_ptr() as *const Self)
+        })
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct WithBool {
+    pub flag: bool,
+}
+const _: () = {
+    ["Size of WithBool"][::std::mem::size_of::<WithBool>() - 1usize];
+    ["Alignment of WithBool"][::std::mem::align_of::<WithBool>() - 1usize];
+    ["Offset of field: WithBool::flag"][::std::mem::offset_of!(WithBool, flag) - 0usize];
+};
@@ -0,0 +1,19 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug, Default, PartialOrd)]
+pub struct point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of point"][::std::mem::size_of::<point>() - 8usize];
+    ["Alignment of point"][::std::mem::align_of::<point>() - 4usize];
+    ["Offset of field: point::x"][::std::mem::offset_of!(point, x) - 0usize];
+    ["Offset of field: point::y"][::std::mem::offset_of!(point, y) - 4usize];
+};
+#[repr(u32)]
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub enum color {
+    red = 0,
+    green = 1,
+}
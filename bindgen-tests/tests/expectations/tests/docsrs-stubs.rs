@@ -0,0 +1,22 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[cfg(not(doc))]
+extern "C" {
+    /// Adds the two numbers.
+    pub fn add(
+        a: ::std::os::raw::c_int,
+        b: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+#[cfg(doc)]
+/// Adds the two numbers.
+#[allow(unused_variables)]
+pub unsafe extern "C" fn add(
+    a: ::std::os::raw::c_int,
+    b: ::std::os::raw::c_int,
+) -> ::std::os::raw::c_int {
+    unimplemented!()
+}
+extern "C" {
+    /// This function is variadic: the variadic arguments are passed with the C default argument promotions, e.g. `float` as `f64` and integer types smaller than `int` as `c_int`.
+    pub fn log_message(msg: *const ::std::os::raw::c_char, ...);
+}
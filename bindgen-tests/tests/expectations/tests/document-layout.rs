@@ -0,0 +1,37 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+/// A point in the plane.
+///
+/// Size: 8 bytes, alignment: 4 bytes.
+///
+/// Field offsets:
+/// - `x`: 0 bytes
+/// - `y`: 4 bytes
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Point"][::std::mem::size_of::<Point>() - 8usize];
+    ["Alignment of Point"][::std::mem::align_of::<Point>() - 4usize];
+    ["Offset of field: Point::x"][::std::mem::offset_of!(Point, x) - 0usize];
+    ["Offset of field: Point::y"][::std::mem::offset_of!(Point, y) - 4usize];
+};
+/// Size: 16 bytes, alignment: 8 bytes.
+///
+/// Field offsets:
+/// - `tag`: 0 bytes
+/// - `value`: 8 bytes
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Tagged {
+    pub tag: ::std::os::raw::c_char,
+    pub value: f64,
+}
+const _: () = {
+    ["Size of Tagged"][::std::mem::size_of::<Tagged>() - 16usize];
+    ["Alignment of Tagged"][::std::mem::align_of::<Tagged>() - 8usize];
+    ["Offset of field: Tagged::tag"][::std::mem::offset_of!(Tagged, tag) - 0usize];
+    ["Offset of field: Tagged::value"][::std::mem::offset_of!(Tagged, value) - 8usize];
+};
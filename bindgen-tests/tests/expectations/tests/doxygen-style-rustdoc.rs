@@ -0,0 +1,25 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    /** Copies bytes between buffers.
+
+ > **Warning:** Both buffers must be at least `len` bytes long.
+
+ ```c
+ copy(dst, src, sizeof(src));
+ ```
+
+ # Arguments
+
+ * `dst` - The destination buffer.
+ * `src` - The source buffer, which must not overlap with the destination.
+ * `len` - The number of bytes to copy.
+
+ # Returns
+
+ The number of bytes copied.*/
+    pub fn copy(
+        dst: *mut ::std::os::raw::c_char,
+        src: *const ::std::os::raw::c_char,
+        len: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
@@ -13,8 +13,9 @@ pub mod root {
             pub const Foo3: root::foo::Bar = Bar::Foo2;
         }
         #[repr(u32)]
-        #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+        #[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
         pub enum Bar {
+            #[default]
             Foo = 0,
             Foo2 = 1,
         }
@@ -0,0 +1,54 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(u8)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Color {
+    #[default]
+    Red = 0,
+    Green = 1,
+    Blue = 2,
+}
+#[repr(i32)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Plain {
+    #[default]
+    First = 0,
+    Second = 1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Pixel {
+    pub color: Color,
+}
+const _: () = {
+    ["Size of Pixel"][::std::mem::size_of::<Pixel>() - 1usize];
+    ["Alignment of Pixel"][::std::mem::align_of::<Pixel>() - 1usize];
+    ["Offset of field: Pixel::color"][::std::mem::offset_of!(Pixel, color) - 0usize];
+};
+impl Default for Pixel {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Tagged {
+    pub plain: Plain,
+}
+const _: () = {
+    ["Size of Tagged"][::std::mem::size_of::<Tagged>() - 4usize];
+    ["Alignment of Tagged"][::std::mem::align_of::<Tagged>() - 4usize];
+    ["Offset of field: Tagged::plain"][::std::mem::offset_of!(Tagged, plain) - 0usize];
+};
+impl Default for Tagged {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
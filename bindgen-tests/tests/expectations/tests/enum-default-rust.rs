@@ -7,8 +7,9 @@ pub struct foo {
 pub const foo_FOO_A: foo__bindgen_ty_1 = foo__bindgen_ty_1::FOO_A;
 pub const foo_FOO_B: foo__bindgen_ty_1 = foo__bindgen_ty_1::FOO_B;
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum foo__bindgen_ty_1 {
+    #[default]
     FOO_A = 0,
     FOO_B = 1,
 }
@@ -27,8 +28,9 @@ impl Default for foo {
     }
 }
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum Foo {
+    #[default]
     Bar = 0,
     Qux = 1,
 }
@@ -39,15 +41,17 @@ pub mod Neg {
 }
 #[repr(u32)]
 /// <div rustbindgen nodebug></div>
-#[derive(Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum NoDebug {
+    #[default]
     NoDebug1 = 0,
     NoDebug2 = 1,
 }
 #[repr(u32)]
 /// <div rustbindgen derive="Debug"></div>
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum Debug {
+    #[default]
     Debug1 = 0,
     Debug2 = 1,
 }
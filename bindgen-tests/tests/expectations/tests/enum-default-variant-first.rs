@@ -0,0 +1,8 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(u32)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Flags {
+    #[default]
+    A = 1,
+    B = 2,
+}
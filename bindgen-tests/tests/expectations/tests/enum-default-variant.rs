@@ -0,0 +1,16 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(u32)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Color {
+    Red = 1,
+    #[default]
+    Unset = 0,
+    Blue = 2,
+}
+#[repr(u32)]
+/// Has no zero-valued variant, so cannot derive Default.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Flags {
+    A = 1,
+    B = 2,
+}
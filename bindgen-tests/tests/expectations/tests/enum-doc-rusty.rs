@@ -1,9 +1,10 @@
 #![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
 #[repr(u32)]
 /// Document enum
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum B {
     /// Document field with three slashes
+    #[default]
     VAR_A = 0,
     /// Document field with preceding star
     VAR_B = 1,
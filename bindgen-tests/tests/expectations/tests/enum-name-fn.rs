@@ -0,0 +1,95 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[test]
+fn enum_name_fn() {
+    assert_eq!(Level::LEVEL_LOW.name(), "LEVEL_LOW");
+    assert_eq!(Level::LEVEL_DEFAULT.name(), "LEVEL_MEDIUM");
+    assert_eq!(Mode::MODE_READ.name(), "MODE_READ");
+    assert_eq!(Mode::from(42).name(), "<unknown>");
+    assert_eq!(Flags::FLAGS_B.name(), "FLAGS_B");
+    assert_eq!(Flags::FLAGS_ALIAS.name(), "FLAGS_B");
+    assert_eq!(Flags(42).name(), "<unknown>");
+}
+impl Level {
+    pub const LEVEL_DEFAULT: Level = Level::LEVEL_MEDIUM;
+}
+impl Level {
+    /// The name of the C enumerator of this value, or
+    /// `"<unknown>"` if it doesn't have one.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::LEVEL_HIGH => "LEVEL_HIGH",
+            Self::LEVEL_LOW => "LEVEL_LOW",
+            Self::LEVEL_MEDIUM => "LEVEL_MEDIUM",
+        }
+    }
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Level {
+    LEVEL_HIGH = 2,
+    LEVEL_LOW = 0,
+    LEVEL_MEDIUM = 1,
+}
+impl Mode {
+    /// The name of the C enumerator of this value, or
+    /// `"<unknown>"` if it doesn't have one.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::MODE_READ => "MODE_READ",
+            Self::MODE_WRITE => "MODE_WRITE",
+            Self::Unknown(_) => "<unknown>",
+        }
+    }
+}
+impl ::std::convert::From<u32> for Mode {
+    #[inline]
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::MODE_READ,
+            1 => Self::MODE_WRITE,
+            value => Self::Unknown(value),
+        }
+    }
+}
+impl ::std::convert::From<Mode> for u32 {
+    #[inline]
+    fn from(value: Mode) -> Self {
+        match value {
+            Mode::MODE_READ => 0,
+            Mode::MODE_WRITE => 1,
+            Mode::Unknown(value) => value,
+        }
+    }
+}
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Mode {
+    MODE_READ,
+    MODE_WRITE,
+    Unknown(u32),
+}
+impl Flags {
+    pub const FLAGS_A: Flags = Flags(1);
+}
+impl Flags {
+    pub const FLAGS_B: Flags = Flags(2);
+}
+impl Flags {
+    pub const FLAGS_ALIAS: Flags = Flags(2);
+}
+impl Flags {
+    /// The name of the C enumerator of this value, or
+    /// `"<unknown>"` if it doesn't have one.
+    pub fn name(&self) -> &'static str {
+        match self.0 {
+            1 => "FLAGS_A",
+            2 => "FLAGS_B",
+            _ => "<unknown>",
+        }
+    }
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Flags(pub ::std::os::raw::c_uint);
+const _: () = {
+    ["Size of Flags"][::std::mem::size_of::<Flags>() - 4usize];
+};
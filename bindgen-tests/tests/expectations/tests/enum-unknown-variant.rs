@@ -0,0 +1,60 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[test]
+fn unknown_variant_round_trip() {
+    assert_eq!(Color::from(42), Color::Unknown(42));
+    assert_eq!(u32::from(Color::from(42)), 42);
+    assert_eq!(Color::from(2), Color::COLOR_BLUE);
+    assert_eq!(u32::from(Color::COLOR_GREEN), 1);
+    assert_eq!(Color::COLOR_DEFAULT, Color::COLOR_RED);
+}
+impl Color {
+    pub const COLOR_DEFAULT: Color = Color::COLOR_RED;
+}
+impl ::std::convert::From<u32> for Color {
+    #[inline]
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::COLOR_RED,
+            1 => Self::COLOR_GREEN,
+            2 => Self::COLOR_BLUE,
+            value => Self::Unknown(value),
+        }
+    }
+}
+impl ::std::convert::From<Color> for u32 {
+    #[inline]
+    fn from(value: Color) -> Self {
+        match value {
+            Color::COLOR_RED => 0,
+            Color::COLOR_GREEN => 1,
+            Color::COLOR_BLUE => 2,
+            Color::Unknown(value) => value,
+        }
+    }
+}
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Color {
+    COLOR_RED,
+    COLOR_GREEN,
+    COLOR_BLUE,
+    Unknown(u32),
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Pixel {
+    pub color: u32,
+}
+const _: () = {
+    ["Size of Pixel"][::std::mem::size_of::<Pixel>() - 4usize];
+    ["Alignment of Pixel"][::std::mem::align_of::<Pixel>() - 4usize];
+    ["Offset of field: Pixel::color"][::std::mem::offset_of!(Pixel, color) - 0usize];
+};
+extern "C" {
+    pub fn pixel_color(pixel: *const Pixel) -> u32;
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Shape {
+    Circle = 0,
+    Unknown = 1,
+}
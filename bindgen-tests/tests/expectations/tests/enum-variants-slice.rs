@@ -0,0 +1,30 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+impl Level {
+    pub const LEVEL_DEFAULT: Level = Level::LEVEL_MEDIUM;
+}
+impl Level {
+    pub const ALL: &'static [Self] = &[
+        Self::LEVEL_HIGH,
+        Self::LEVEL_LOW,
+        Self::LEVEL_MEDIUM,
+    ];
+}
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Level {
+    LEVEL_HIGH = 2,
+    LEVEL_LOW = 0,
+    LEVEL_MEDIUM = 1,
+}
+impl Flags {
+    pub const FLAGS_A: Flags = Flags(1);
+}
+impl Flags {
+    pub const FLAGS_B: Flags = Flags(2);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Flags(pub ::std::os::raw::c_uint);
+const _: () = {
+    ["Size of Flags"][::std::mem::size_of::<Flags>() - 4usize];
+};
@@ -0,0 +1,68 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    pub fn close(fd: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+/// Calls [`close`], returning the error stored in `errno` if it fails.
+pub unsafe fn close_checked(
+    fd: ::std::os::raw::c_int,
+) -> ::std::io::Result<::std::os::raw::c_int> {
+    let ret = close(fd);
+    if ret == -1 { Err(::std::io::Error::last_os_error()) } else { Ok(ret) }
+}
+extern "C" {
+    pub fn read_bytes(
+        fd: ::std::os::raw::c_int,
+        buf: *mut ::std::os::raw::c_void,
+        len: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_long;
+}
+/// Calls [`read_bytes`], returning the error stored in `errno` if it fails.
+pub unsafe fn read_bytes_checked(
+    fd: ::std::os::raw::c_int,
+    buf: *mut ::std::os::raw::c_void,
+    len: ::std::os::raw::c_ulong,
+) -> ::std::io::Result<::std::os::raw::c_long> {
+    let ret = read_bytes(fd, buf, len);
+    if ret == -1 { Err(::std::io::Error::last_os_error()) } else { Ok(ret) }
+}
+extern "C" {
+    pub fn unlink(path: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+/// Calls [`unlink`], returning the error stored in `errno` if it fails.
+pub unsafe fn unlink_checked(
+    path: *const ::std::os::raw::c_char,
+) -> ::std::io::Result<()> {
+    let ret = unlink(path);
+    if ret == -1 { Err(::std::io::Error::last_os_error()) } else { Ok(()) }
+}
+extern "C" {
+    pub fn open_stream(
+        path: *const ::std::os::raw::c_char,
+        mode: *const ::std::os::raw::c_char,
+    ) -> *mut ::std::os::raw::c_void;
+}
+/// Calls [`open_stream`], returning the error stored in `errno` if it fails.
+pub unsafe fn open_stream_checked(
+    path: *const ::std::os::raw::c_char,
+    mode: *const ::std::os::raw::c_char,
+) -> ::std::io::Result<*mut ::std::os::raw::c_void> {
+    let ret = open_stream(path, mode);
+    if ret.is_null() { Err(::std::io::Error::last_os_error()) } else { Ok(ret) }
+}
+extern "C" {
+    pub fn to_wide(src: *const ::std::os::raw::c_char) -> ::std::os::raw::c_ulong;
+}
+/// Calls [`to_wide`], returning the error stored in `errno` if it fails.
+pub unsafe fn to_wide_checked(
+    src: *const ::std::os::raw::c_char,
+) -> ::std::io::Result<::std::os::raw::c_ulong> {
+    let ret = to_wide(src);
+    if ret == -1i64 as ::std::os::raw::c_ulong {
+        Err(::std::io::Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+extern "C" {
+    pub fn not_wrapped(fd: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,38 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[cfg(not(doc))]
+#[cfg(feature = "gpu")]
+extern "C" {
+    pub fn gpu_init();
+}
+#[cfg(doc)]
+#[cfg(feature = "gpu")]
+#[allow(unused_variables)]
+pub unsafe extern "C" fn gpu_init() {
+    unimplemented!()
+}
+#[cfg(not(doc))]
+#[cfg(feature = "gpu")]
+extern "C" {
+    pub fn gpu_submit(queue: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+#[cfg(doc)]
+#[cfg(feature = "gpu")]
+#[allow(unused_variables)]
+pub unsafe extern "C" fn gpu_submit(
+    queue: ::std::os::raw::c_int,
+) -> ::std::os::raw::c_int {
+    unimplemented!()
+}
+#[cfg(feature = "gpu")]
+extern "C" {
+    pub static mut gpu_count: ::std::os::raw::c_int;
+}
+#[cfg(not(doc))]
+extern "C" {
+    pub fn cpu_init();
+}
+#[cfg(doc)]
+#[allow(unused_variables)]
+pub unsafe extern "C" fn cpu_init() {
+    unimplemented!()
+}
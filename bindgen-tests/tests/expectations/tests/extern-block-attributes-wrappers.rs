@@ -0,0 +1,60 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct gpu_buffer {
+    pub size: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of gpu_buffer"][::std::mem::size_of::<gpu_buffer>() - 4usize];
+    ["Alignment of gpu_buffer"][::std::mem::align_of::<gpu_buffer>() - 4usize];
+    [
+        "Offset of field: gpu_buffer::size",
+    ][::std::mem::offset_of!(gpu_buffer, size) - 0usize];
+};
+#[cfg(feature = "gpu")]
+extern "C" {
+    pub fn gpu_reset() -> ::std::os::raw::c_int;
+}
+#[cfg(feature = "gpu")]
+/// Calls [`gpu_reset`], returning the error stored in `errno` if it fails.
+pub unsafe fn gpu_reset_checked() -> ::std::io::Result<::std::os::raw::c_int> {
+    let ret = gpu_reset();
+    if ret == -1 { Err(::std::io::Error::last_os_error()) } else { Ok(ret) }
+}
+#[cfg(feature = "gpu")]
+extern "C" {
+    pub fn gpu_upload(
+        data: *const ::std::os::raw::c_void,
+        len: usize,
+    ) -> ::std::os::raw::c_int;
+}
+#[cfg(feature = "gpu")]
+/// Calls [`gpu_upload`], taking slices instead of pointer and length pairs.
+pub unsafe fn gpu_upload_slice(data: &[u8]) -> ::std::os::raw::c_int {
+    gpu_upload(data.as_ptr().cast(), data.len())
+}
+#[cfg(feature = "gpu")]
+extern "C" {
+    pub fn gpu_map(
+        queue: ::std::os::raw::c_int,
+        out: *mut *mut gpu_buffer,
+    ) -> ::std::os::raw::c_int;
+}
+#[cfg(feature = "gpu")]
+/// Calls [`gpu_map`], returning the pointer it writes through `out`.
+pub unsafe fn gpu_map_out(
+    queue: ::std::os::raw::c_int,
+) -> (::std::os::raw::c_int, ::std::option::Option<::std::ptr::NonNull<gpu_buffer>>) {
+    let mut out: *mut gpu_buffer = ::std::ptr::null_mut();
+    (gpu_map(queue, &mut out), ::std::ptr::NonNull::new(out))
+}
+#[cfg(feature = "gpu")]
+extern "C" {
+    pub static gpu_generation: ::std::os::raw::c_int;
+}
+#[cfg(feature = "gpu")]
+#[used]
+#[allow(unused_unsafe)]
+pub static mut __bindgen_retain_gpu_generation: *const ::std::os::raw::c_int = unsafe {
+    ::std::ptr::addr_of!(gpu_generation)
+};
@@ -0,0 +1,11 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[cfg(feature = "gpu")]
+extern "C" {
+    pub fn gpu_init();
+    pub fn gpu_submit(queue: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub static mut gpu_count: ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn cpu_init();
+    pub fn cpu_count() -> ::std::os::raw::c_int;
+}
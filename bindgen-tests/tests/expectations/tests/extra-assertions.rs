@@ -0,0 +1,23 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Callbacks {
+    pub on_event: ::std::option::Option<
+        unsafe extern "C" fn(event: ::std::os::raw::c_int),
+    >,
+    pub count: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Callbacks"][::std::mem::size_of::<Callbacks>() - 16usize];
+    ["Alignment of Callbacks"][::std::mem::align_of::<Callbacks>() - 8usize];
+    [
+        "Offset of field: Callbacks::on_event",
+    ][::std::mem::offset_of!(Callbacks, on_event) - 0usize];
+    [
+        "Offset of field: Callbacks::count",
+    ][::std::mem::offset_of!(Callbacks, count) - 8usize];
+};
+#[test]
+fn extra_assertions_Callbacks() {
+    assert_eq!(::std::mem::offset_of!(Callbacks, on_event), 0usize);
+}
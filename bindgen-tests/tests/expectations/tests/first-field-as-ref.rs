@@ -0,0 +1,61 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[test]
+fn upcast_to_first_field() {
+    let mut derived = Derived {
+        base: Base { kind: 1 },
+        extra: 2,
+    };
+    assert_eq!(AsRef::<Base>::as_ref(&derived).kind, 1);
+    AsMut::<Base>::as_mut(&mut derived).kind = 3;
+    assert_eq!(derived.base.kind, 3);
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Base {
+    pub kind: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Base"][::std::mem::size_of::<Base>() - 4usize];
+    ["Alignment of Base"][::std::mem::align_of::<Base>() - 4usize];
+    ["Offset of field: Base::kind"][::std::mem::offset_of!(Base, kind) - 0usize];
+};
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Derived {
+    pub base: Base,
+    pub extra: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Derived"][::std::mem::size_of::<Derived>() - 8usize];
+    ["Alignment of Derived"][::std::mem::align_of::<Derived>() - 4usize];
+    ["Offset of field: Derived::base"][::std::mem::offset_of!(Derived, base) - 0usize];
+    ["Offset of field: Derived::extra"][::std::mem::offset_of!(Derived, extra) - 4usize];
+};
+impl ::std::convert::AsRef<Base> for Derived {
+    #[inline]
+    fn as_ref(&self) -> &Base {
+        &self.base
+    }
+}
+impl ::std::convert::AsMut<Base> for Derived {
+    #[inline]
+    fn as_mut(&mut self) -> &mut Base {
+        &mut self.base
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct NotDerived {
+    pub kind: ::std::os::raw::c_int,
+    pub base: Base,
+}
+const _: () = {
+    ["Size of NotDerived"][::std::mem::size_of::<NotDerived>() - 8usize];
+    ["Alignment of NotDerived"][::std::mem::align_of::<NotDerived>() - 4usize];
+    [
+        "Offset of field: NotDerived::kind",
+    ][::std::mem::offset_of!(NotDerived, kind) - 0usize];
+    [
+        "Offset of field: NotDerived::base",
+    ][::std::mem::offset_of!(NotDerived, base) - 4usize];
+};
@@ -0,0 +1,12 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub const COLOR_RED: Color = Color::COLOR_RED;
+pub const COLOR_GREEN: Color = Color::COLOR_GREEN;
+pub const COLOR_BLUE: Color = Color::COLOR_BLUE;
+#[repr(u32)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Color {
+    #[default]
+    COLOR_RED = 0,
+    COLOR_GREEN = 1,
+    COLOR_BLUE = 4,
+}
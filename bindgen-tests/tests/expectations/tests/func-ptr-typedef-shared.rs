@@ -0,0 +1,42 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub type callback_t = ::std::option::Option<
+    unsafe extern "C" fn(
+        data: *mut ::std::os::raw::c_void,
+        event: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int,
+>;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct timer {
+    pub on_expire: callback_t,
+    pub interval: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of timer"][::std::mem::size_of::<timer>() - 16usize];
+    ["Alignment of timer"][::std::mem::align_of::<timer>() - 8usize];
+    [
+        "Offset of field: timer::on_expire",
+    ][::std::mem::offset_of!(timer, on_expire) - 0usize];
+    [
+        "Offset of field: timer::interval",
+    ][::std::mem::offset_of!(timer, interval) - 8usize];
+};
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct button {
+    pub on_click: callback_t,
+    pub on_release: callback_t,
+}
+const _: () = {
+    ["Size of button"][::std::mem::size_of::<button>() - 16usize];
+    ["Alignment of button"][::std::mem::align_of::<button>() - 8usize];
+    [
+        "Offset of field: button::on_click",
+    ][::std::mem::offset_of!(button, on_click) - 0usize];
+    [
+        "Offset of field: button::on_release",
+    ][::std::mem::offset_of!(button, on_release) - 8usize];
+};
+extern "C" {
+    pub fn register_callback(callback: callback_t);
+}
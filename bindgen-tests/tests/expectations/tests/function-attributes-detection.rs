@@ -0,0 +1,23 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    /// This function is marked as `malloc`: the returned pointer doesn't alias any other valid pointer.
+    ///
+    /// This function is marked as `returns_nonnull`: it never returns a null pointer.
+    pub fn alloc_buffer(
+        size: ::std::os::raw::c_int,
+    ) -> ::std::ptr::NonNull<::std::os::raw::c_void>;
+}
+extern "C" {
+    /// This function is marked as `pure`: it has no side effects and its return value only depends on its arguments and on global memory.
+    pub fn buffer_len(buf: *const ::std::os::raw::c_void) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    /// This function is marked as `const`: it has no side effects and its return value only depends on its arguments.
+    pub fn square(x: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    /// Returns the name of the library.
+    ///
+    /// This function is marked as `returns_nonnull`: it never returns a null pointer.
+    pub fn library_name() -> ::std::ptr::NonNull<::std::os::raw::c_char>;
+}
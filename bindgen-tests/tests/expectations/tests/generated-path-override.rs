@@ -0,0 +1,25 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+mod external {
+    #[repr(C)]
+    #[derive(Debug, Default, Copy, Clone)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+}
+pub use self::external::Point as Point;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+const _: () = {
+    ["Size of Line"][::std::mem::size_of::<Line>() - 16usize];
+    ["Alignment of Line"][::std::mem::align_of::<Line>() - 4usize];
+    ["Offset of field: Line::start"][::std::mem::offset_of!(Line, start) - 0usize];
+    ["Offset of field: Line::end"][::std::mem::offset_of!(Line, end) - 8usize];
+};
+extern "C" {
+    pub fn line_length(line: Line) -> ::std::os::raw::c_int;
+}
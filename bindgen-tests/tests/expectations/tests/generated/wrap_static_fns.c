@@ -30,3 +30,8 @@ void wrap_as_variadic_fn2__extern(int i, ...) {
     wrap_as_variadic_fn2(i, ap);
     va_end(ap);
 }
+int counter_get__extern(void) { return counter; }
+void counter_set__extern(int value) { counter = value; }
+const double scale_get__extern(void) { return scale; }
+int (*callback_get__extern(void)) (int) { return callback; }
+void callback_set__extern(int (*value) (int)) { callback = value; }
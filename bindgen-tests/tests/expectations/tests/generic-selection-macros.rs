@@ -0,0 +1,71 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    pub fn abs_int(value: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    pub fn abs_long(value: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+}
+extern "C" {
+    pub fn abs_float(value: f32) -> f32;
+}
+extern "C" {
+    pub fn abs_double(value: f64) -> f64;
+}
+extern "C" {
+    pub fn scale_float(value: f32, exponent: ::std::os::raw::c_int) -> f32;
+}
+extern "C" {
+    pub fn scale_double(value: f64, exponent: ::std::os::raw::c_int) -> f64;
+}
+/// The functions selected by the `_Generic` selection of the `ABS` macro, by the type of its `x` parameter.
+pub trait ABS {
+    type Output;
+    unsafe fn ABS(self) -> Self::Output;
+}
+impl ABS for ::std::os::raw::c_int {
+    type Output = ::std::os::raw::c_int;
+    #[inline]
+    unsafe fn ABS(self) -> Self::Output {
+        abs_int(self)
+    }
+}
+impl ABS for ::std::os::raw::c_long {
+    type Output = ::std::os::raw::c_long;
+    #[inline]
+    unsafe fn ABS(self) -> Self::Output {
+        abs_long(self)
+    }
+}
+impl ABS for f32 {
+    type Output = f32;
+    #[inline]
+    unsafe fn ABS(self) -> Self::Output {
+        abs_float(self)
+    }
+}
+impl ABS for f64 {
+    type Output = f64;
+    #[inline]
+    unsafe fn ABS(self) -> Self::Output {
+        abs_double(self)
+    }
+}
+/// The functions selected by the `_Generic` selection of the `SCALE` macro, by the type of its `x` parameter.
+pub trait SCALE {
+    type Output;
+    unsafe fn SCALE(self, exp: ::std::os::raw::c_int) -> Self::Output;
+}
+impl SCALE for f32 {
+    type Output = f32;
+    #[inline]
+    unsafe fn SCALE(self, exp: ::std::os::raw::c_int) -> Self::Output {
+        scale_float(self, exp)
+    }
+}
+impl SCALE for f64 {
+    type Output = f64;
+    #[inline]
+    unsafe fn SCALE(self, exp: ::std::os::raw::c_int) -> Self::Output {
+        scale_double(self, exp)
+    }
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[doc(hidden)]
+pub type port_t = ::std::os::raw::c_ushort;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+#[doc(hidden)]
+pub struct Address {
+    pub port: port_t,
+}
+const _: () = {
+    ["Size of Address"][::std::mem::size_of::<Address>() - 2usize];
+    ["Alignment of Address"][::std::mem::align_of::<Address>() - 2usize];
+    ["Offset of field: Address::port"][::std::mem::offset_of!(Address, port) - 0usize];
+};
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Options {
+    pub flags: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Options"][::std::mem::size_of::<Options>() - 4usize];
+    ["Alignment of Options"][::std::mem::align_of::<Options>() - 4usize];
+    ["Offset of field: Options::flags"][::std::mem::offset_of!(Options, flags) - 0usize];
+};
+extern "C" {
+    pub fn connect(addr: *mut Address, opts: *mut Options) -> ::std::os::raw::c_int;
+}
@@ -0,0 +1,64 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug)]
+pub struct Buffer {
+    pub data: [::std::os::raw::c_uchar; 64usize],
+    pub len: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Buffer"][::std::mem::size_of::<Buffer>() - 68usize];
+    ["Alignment of Buffer"][::std::mem::align_of::<Buffer>() - 4usize];
+    ["Offset of field: Buffer::data"][::std::mem::offset_of!(Buffer, data) - 0usize];
+    ["Offset of field: Buffer::len"][::std::mem::offset_of!(Buffer, len) - 64usize];
+};
+impl ::std::clone::Clone for Buffer {
+    fn clone(&self) -> Self {
+        Self {
+            data: ::std::clone::Clone::clone(&self.data),
+            len: ::std::clone::Clone::clone(&self.len),
+        }
+    }
+}
+impl Default for Buffer {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct Wrapper {
+    pub buffers: [Buffer; 2usize],
+    pub next: *mut Buffer,
+    pub id: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Wrapper"][::std::mem::size_of::<Wrapper>() - 152usize];
+    ["Alignment of Wrapper"][::std::mem::align_of::<Wrapper>() - 8usize];
+    [
+        "Offset of field: Wrapper::buffers",
+    ][::std::mem::offset_of!(Wrapper, buffers) - 0usize];
+    ["Offset of field: Wrapper::next"][::std::mem::offset_of!(Wrapper, next) - 136usize];
+    ["Offset of field: Wrapper::id"][::std::mem::offset_of!(Wrapper, id) - 144usize];
+};
+impl ::std::clone::Clone for Wrapper {
+    fn clone(&self) -> Self {
+        Self {
+            buffers: ::std::clone::Clone::clone(&self.buffers),
+            next: ::std::clone::Clone::clone(&self.next),
+            id: ::std::clone::Clone::clone(&self.id),
+        }
+    }
+}
+impl Default for Wrapper {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
@@ -0,0 +1,19 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+/// A struct containing a float, which cannot derive Hash.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Sample {
+    pub count: ::std::os::raw::c_int,
+    pub weight: f32,
+}
+const _: () = {
+    ["Size of Sample"][::std::mem::size_of::<Sample>() - 8usize];
+    ["Alignment of Sample"][::std::mem::align_of::<Sample>() - 4usize];
+    ["Offset of field: Sample::count"][::std::mem::offset_of!(Sample, count) - 0usize];
+    ["Offset of field: Sample::weight"][::std::mem::offset_of!(Sample, weight) - 4usize];
+};
+impl ::std::hash::Hash for Sample {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        ::std::hash::Hash::hash(&self.count, state);
+    }
+}
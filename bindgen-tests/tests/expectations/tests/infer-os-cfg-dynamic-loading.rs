@@ -0,0 +1,70 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[cfg(target_os = "linux")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct input_id {
+    pub bustype: ::std::os::raw::c_ushort,
+    pub vendor: ::std::os::raw::c_ushort,
+}
+#[cfg(target_os = "linux")]
+const _: () = {
+    ["Size of input_id"][::std::mem::size_of::<input_id>() - 4usize];
+    ["Alignment of input_id"][::std::mem::align_of::<input_id>() - 2usize];
+    [
+        "Offset of field: input_id::bustype",
+    ][::std::mem::offset_of!(input_id, bustype) - 0usize];
+    [
+        "Offset of field: input_id::vendor",
+    ][::std::mem::offset_of!(input_id, vendor) - 2usize];
+};
+pub struct TestLib {
+    __library: ::libloading::Library,
+    #[cfg(target_os = "linux")]
+    pub input_open: Result<
+        unsafe extern "C" fn(
+            path: *const ::std::os::raw::c_char,
+        ) -> ::std::os::raw::c_int,
+        ::libloading::Error,
+    >,
+    pub app_init: Result<
+        unsafe extern "C" fn(flags: ::std::os::raw::c_int) -> ::std::os::raw::c_int,
+        ::libloading::Error,
+    >,
+}
+impl TestLib {
+    pub unsafe fn new<P>(path: P) -> Result<Self, ::libloading::Error>
+    where
+        P: AsRef<::std::ffi::OsStr>,
+    {
+        let library = ::libloading::Library::new(path)?;
+        Self::from_library(library)
+    }
+    pub unsafe fn from_library<L>(library: L) -> Result<Self, ::libloading::Error>
+    where
+        L: Into<::libloading::Library>,
+    {
+        let __library = library.into();
+        #[cfg(target_os = "linux")]
+        let input_open = __library.get(b"input_open\0").map(|sym| *sym);
+        let app_init = __library.get(b"app_init\0").map(|sym| *sym);
+        Ok(TestLib {
+            __library,
+            #[cfg(target_os = "linux")]
+            input_open,
+            app_init,
+        })
+    }
+    #[cfg(target_os = "linux")]
+    pub unsafe fn input_open(
+        &self,
+        path: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int {
+        (self.input_open.as_ref().expect("Expected function, got error."))(path)
+    }
+    pub unsafe fn app_init(
+        &self,
+        flags: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int {
+        (self.app_init.as_ref().expect("Expected function, got error."))(flags)
+    }
+}
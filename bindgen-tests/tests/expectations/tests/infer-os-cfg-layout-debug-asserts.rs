@@ -0,0 +1,58 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[cfg(target_os = "linux")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct input_id {
+    pub bustype: ::std::os::raw::c_ushort,
+    pub vendor: ::std::os::raw::c_ushort,
+}
+#[cfg(target_os = "linux")]
+extern "C" {
+    pub fn input_open(path: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct app_state {
+    pub count: ::std::os::raw::c_int,
+}
+/// Check the layout of the generated types using `debug_assert_eq!`.
+///
+/// The checks are compiled out when debug assertions are disabled.
+pub fn bindgen_debug_assert_layouts() {
+    #[cfg(target_os = "linux")]
+    {
+        debug_assert_eq!(::std::mem::size_of::<input_id>(), 4usize, "Size of input_id");
+        debug_assert_eq!(
+            ::std::mem::align_of::<input_id>(),
+            2usize,
+            "Alignment of input_id",
+        );
+        debug_assert_eq!(
+            ::std::mem::offset_of!(input_id, bustype),
+            0usize,
+            "Offset of field: input_id::bustype",
+        );
+        debug_assert_eq!(
+            ::std::mem::offset_of!(input_id, vendor),
+            2usize,
+            "Offset of field: input_id::vendor",
+        );
+    }
+    {
+        debug_assert_eq!(
+            ::std::mem::size_of::<app_state>(),
+            4usize,
+            "Size of app_state",
+        );
+        debug_assert_eq!(
+            ::std::mem::align_of::<app_state>(),
+            4usize,
+            "Alignment of app_state",
+        );
+        debug_assert_eq!(
+            ::std::mem::offset_of!(app_state, count),
+            0usize,
+            "Offset of field: app_state::count",
+        );
+    }
+}
@@ -0,0 +1,56 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[cfg(target_os = "linux")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct input_id {
+    pub bustype: ::std::os::raw::c_ushort,
+    pub vendor: ::std::os::raw::c_ushort,
+}
+#[cfg(target_os = "linux")]
+const _: () = {
+    ["Size of input_id"][::std::mem::size_of::<input_id>() - 4usize];
+    ["Alignment of input_id"][::std::mem::align_of::<input_id>() - 2usize];
+    [
+        "Offset of field: input_id::bustype",
+    ][::std::mem::offset_of!(input_id, bustype) - 0usize];
+    [
+        "Offset of field: input_id::vendor",
+    ][::std::mem::offset_of!(input_id, vendor) - 2usize];
+};
+#[cfg(target_os = "linux")]
+extern "C" {
+    pub fn input_open(path: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct app_state {
+    pub count: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of app_state"][::std::mem::size_of::<app_state>() - 4usize];
+    ["Alignment of app_state"][::std::mem::align_of::<app_state>() - 4usize];
+    [
+        "Offset of field: app_state::count",
+    ][::std::mem::offset_of!(app_state, count) - 0usize];
+};
+extern "C" {
+    pub fn app_init(state: *mut app_state) -> ::std::os::raw::c_int;
+}
+#[cfg(target_os = "linux")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct app_device {
+    pub id: input_id,
+    pub fd: ::std::os::raw::c_int,
+}
+#[cfg(target_os = "linux")]
+const _: () = {
+    ["Size of app_device"][::std::mem::size_of::<app_device>() - 8usize];
+    ["Alignment of app_device"][::std::mem::align_of::<app_device>() - 4usize];
+    ["Offset of field: app_device::id"][::std::mem::offset_of!(app_device, id) - 0usize];
+    ["Offset of field: app_device::fd"][::std::mem::offset_of!(app_device, fd) - 4usize];
+};
+#[cfg(target_os = "linux")]
+extern "C" {
+    pub fn app_open(device: *mut app_device) -> ::std::os::raw::c_int;
+}
@@ -1 +1,14 @@
-#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
\ No newline at end of file
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Foo {
+    pub foo: i128,
+    pub bar: u128,
+}
+const _: () = {
+    ["Size of Foo"][::std::mem::size_of::<Foo>() - 32usize];
+    ["Alignment of Foo"][::std::mem::align_of::<Foo>() - 16usize];
+    ["Offset of field: Foo::foo"][::std::mem::offset_of!(Foo, foo) - 0usize];
+    ["Offset of field: Foo::bar"][::std::mem::offset_of!(Foo, bar) - 16usize];
+};
@@ -1,5 +1,6 @@
 #![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
 extern "C" {
+    /// This function is variadic: the variadic arguments are passed with the C default argument promotions, e.g. `float` as `f64` and integer types smaller than `int` as `c_int`.
     pub fn f(a: ::std::os::raw::c_int, ...);
 }
 #[repr(C)]
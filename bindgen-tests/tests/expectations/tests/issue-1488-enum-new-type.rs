@@ -14,8 +14,9 @@ pub mod Bar {
 #[derive(Debug, Copy, Clone)]
 pub struct BarAlias(pub Bar::Type);
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum Qux {
+    #[default]
     E = 0,
     F = 1,
 }
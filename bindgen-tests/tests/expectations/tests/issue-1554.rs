@@ -3,8 +3,9 @@
 #![feature(non_exhaustive)]
 #[repr(u32)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum Planet {
+    #[default]
     earth = 0,
     mars = 1,
 }
@@ -23,8 +23,8 @@ pub mod root {
         #[allow(unused_imports)]
         use self::super::super::root;
         extern "C" {
-            #[link_name = "\u{1}_ZN3fooL22kFallbackIntrinsicSizeE"]
-            pub static kFallbackIntrinsicSize: root::nsSize;
+            #[link_name = "\u{1}_ZN3foo21kDefaultIntrinsicSizeE"]
+            pub static kDefaultIntrinsicSize: root::nsSize;
         }
     }
 }
@@ -83,6 +83,82 @@ where
         }
     }
 }
+impl<const N: usize> __BindgenBitfieldUnit<[u8; N]> {
+    #[inline]
+    pub const fn get_bit_const(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < N);
+        let byte_index = index / 8;
+        let byte = self.storage[byte_index];
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub const fn set_bit_const(mut self, index: usize, val: bool) -> Self {
+        debug_assert!(index / 8 < N);
+        let byte_index = index / 8;
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        if val {
+            self.storage[byte_index] |= mask;
+        } else {
+            self.storage[byte_index] &= !mask;
+        }
+        self
+    }
+    #[inline]
+    pub const fn get_const(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < N);
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= N);
+        let mut val = 0;
+        let mut i = 0;
+        while i < bit_width as usize {
+            if self.get_bit_const(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+            i += 1;
+        }
+        val
+    }
+    #[inline]
+    pub const fn set_const(
+        mut self,
+        bit_offset: usize,
+        bit_width: u8,
+        val: u64,
+    ) -> Self {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < N);
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= N);
+        let mut i = 0;
+        while i < bit_width as usize {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self = self.set_bit_const(index + bit_offset, val_bit_is_set);
+            i += 1;
+        }
+        self
+    }
+}
 #[repr(C)]
 #[repr(align(4))]
 #[derive(Debug, Default, Copy, Clone)]
@@ -547,7 +623,7 @@ impl capabilities {
         }
     }
     #[inline]
-    pub fn new_bitfield_1(
+    pub const fn new_bitfield_1(
         bit_1: ::std::os::raw::c_uint,
         bit_2: ::std::os::raw::c_uint,
         bit_3: ::std::os::raw::c_uint,
@@ -590,9 +666,11 @@ impl capabilities {
         bit_40: ::std::os::raw::c_uint,
         bit_41: ::std::os::raw::c_uint,
     ) -> __BindgenBitfieldUnit<[u8; 16usize]> {
-        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 16usize]> = Default::default();
-        __bindgen_bitfield_unit
-            .set(
+        let mut __bindgen_bitfield_unit = <__BindgenBitfieldUnit<
+            [u8; 16usize],
+        >>::new([0; 16usize]);
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 0usize,
                 1u8,
                 {
@@ -600,8 +678,8 @@ impl capabilities {
                     bit_1 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 1usize,
                 1u8,
                 {
@@ -609,8 +687,8 @@ impl capabilities {
                     bit_2 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 2usize,
                 1u8,
                 {
@@ -618,8 +696,8 @@ impl capabilities {
                     bit_3 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 3usize,
                 1u8,
                 {
@@ -627,8 +705,8 @@ impl capabilities {
                     bit_4 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 4usize,
                 1u8,
                 {
@@ -636,8 +714,8 @@ impl capabilities {
                     bit_5 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 5usize,
                 1u8,
                 {
@@ -645,8 +723,8 @@ impl capabilities {
                     bit_6 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 6usize,
                 1u8,
                 {
@@ -654,8 +732,8 @@ impl capabilities {
                     bit_7 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 7usize,
                 1u8,
                 {
@@ -663,8 +741,8 @@ impl capabilities {
                     bit_8 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 8usize,
                 1u8,
                 {
@@ -672,8 +750,8 @@ impl capabilities {
                     bit_9 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 9usize,
                 1u8,
                 {
@@ -681,8 +759,8 @@ impl capabilities {
                     bit_10 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 10usize,
                 1u8,
                 {
@@ -690,8 +768,8 @@ impl capabilities {
                     bit_11 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 11usize,
                 1u8,
                 {
@@ -699,8 +777,8 @@ impl capabilities {
                     bit_12 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 12usize,
                 1u8,
                 {
@@ -708,8 +786,8 @@ impl capabilities {
                     bit_13 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 13usize,
                 1u8,
                 {
@@ -717,8 +795,8 @@ impl capabilities {
                     bit_14 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 14usize,
                 1u8,
                 {
@@ -726,8 +804,8 @@ impl capabilities {
                     bit_15 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 15usize,
                 1u8,
                 {
@@ -735,8 +813,8 @@ impl capabilities {
                     bit_16 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 16usize,
                 1u8,
                 {
@@ -744,8 +822,8 @@ impl capabilities {
                     bit_17 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 17usize,
                 1u8,
                 {
@@ -753,8 +831,8 @@ impl capabilities {
                     bit_18 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 18usize,
                 1u8,
                 {
@@ -762,8 +840,8 @@ impl capabilities {
                     bit_19 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 19usize,
                 1u8,
                 {
@@ -771,8 +849,8 @@ impl capabilities {
                     bit_20 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 20usize,
                 1u8,
                 {
@@ -780,8 +858,8 @@ impl capabilities {
                     bit_21 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 21usize,
                 1u8,
                 {
@@ -789,8 +867,8 @@ impl capabilities {
                     bit_22 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 22usize,
                 1u8,
                 {
@@ -798,8 +876,8 @@ impl capabilities {
                     bit_23 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 23usize,
                 1u8,
                 {
@@ -807,8 +885,8 @@ impl capabilities {
                     bit_24 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 24usize,
                 1u8,
                 {
@@ -816,8 +894,8 @@ impl capabilities {
                     bit_25 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 25usize,
                 1u8,
                 {
@@ -825,8 +903,8 @@ impl capabilities {
                     bit_26 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 26usize,
                 1u8,
                 {
@@ -834,8 +912,8 @@ impl capabilities {
                     bit_27 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 27usize,
                 1u8,
                 {
@@ -843,8 +921,8 @@ impl capabilities {
                     bit_28 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 28usize,
                 1u8,
                 {
@@ -852,8 +930,8 @@ impl capabilities {
                     bit_29 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 29usize,
                 1u8,
                 {
@@ -861,8 +939,8 @@ impl capabilities {
                     bit_30 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 30usize,
                 1u8,
                 {
@@ -870,8 +948,8 @@ impl capabilities {
                     bit_31 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 31usize,
                 1u8,
                 {
@@ -879,8 +957,8 @@ impl capabilities {
                     bit_32 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 32usize,
                 1u8,
                 {
@@ -888,8 +966,8 @@ impl capabilities {
                     bit_33 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 33usize,
                 1u8,
                 {
@@ -897,8 +975,8 @@ impl capabilities {
                     bit_34 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 34usize,
                 1u8,
                 {
@@ -906,8 +984,8 @@ impl capabilities {
                     bit_35 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 35usize,
                 1u8,
                 {
@@ -915,8 +993,8 @@ impl capabilities {
                     bit_36 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 36usize,
                 1u8,
                 {
@@ -924,8 +1002,8 @@ impl capabilities {
                     bit_37 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 37usize,
                 1u8,
                 {
@@ -933,8 +1011,8 @@ impl capabilities {
                     bit_38 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 38usize,
                 1u8,
                 {
@@ -942,8 +1020,8 @@ impl capabilities {
                     bit_39 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 39usize,
                 1u8,
                 {
@@ -951,8 +1029,8 @@ impl capabilities {
                     bit_40 as u64
                 },
             );
-        __bindgen_bitfield_unit
-            .set(
+        __bindgen_bitfield_unit = __bindgen_bitfield_unit
+            .set_const(
                 40usize,
                 1u8,
                 {
@@ -0,0 +1,50 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Padded {
+    pub a: ::std::os::raw::c_char,
+    pub b: ::std::os::raw::c_long,
+}
+/// Check the layout of the generated types using `debug_assert_eq!`.
+///
+/// The checks are compiled out when debug assertions are disabled.
+pub fn bindgen_debug_assert_layouts() {
+    {
+        debug_assert_eq!(::std::mem::size_of::<Point>(), 8usize, "Size of Point");
+        debug_assert_eq!(::std::mem::align_of::<Point>(), 4usize, "Alignment of Point");
+        debug_assert_eq!(
+            ::std::mem::offset_of!(Point, x),
+            0usize,
+            "Offset of field: Point::x",
+        );
+        debug_assert_eq!(
+            ::std::mem::offset_of!(Point, y),
+            4usize,
+            "Offset of field: Point::y",
+        );
+    }
+    {
+        debug_assert_eq!(::std::mem::size_of::<Padded>(), 16usize, "Size of Padded");
+        debug_assert_eq!(
+            ::std::mem::align_of::<Padded>(),
+            8usize,
+            "Alignment of Padded",
+        );
+        debug_assert_eq!(
+            ::std::mem::offset_of!(Padded, a),
+            0usize,
+            "Offset of field: Padded::a",
+        );
+        debug_assert_eq!(
+            ::std::mem::offset_of!(Padded, b),
+            8usize,
+            "Offset of field: Padded::b",
+        );
+    }
+}
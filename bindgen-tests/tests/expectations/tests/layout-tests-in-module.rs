@@ -0,0 +1,28 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[cfg(test)]
+mod layout_tests {
+    use super::*;
+    #[test]
+    fn bindgen_test_layout_Point() {
+        const UNINIT: ::std::mem::MaybeUninit<Point> = ::std::mem::MaybeUninit::uninit();
+        let ptr = UNINIT.as_ptr();
+        assert_eq!(::std::mem::size_of::<Point>(), 8usize, "Size of Point");
+        assert_eq!(::std::mem::align_of::<Point>(), 4usize, "Alignment of Point");
+        assert_eq!(
+            unsafe { ::std::ptr::addr_of!((*ptr).x) as usize - ptr as usize },
+            0usize,
+            "Offset of field: Point::x",
+        );
+        assert_eq!(
+            unsafe { ::std::ptr::addr_of!((*ptr).y) as usize - ptr as usize },
+            4usize,
+            "Offset of field: Point::y",
+        );
+    }
+}
@@ -0,0 +1,150 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[test]
+fn verify_layouts_of_correct_bindings() {
+    assert_eq!(verify_layouts(), Ok(()));
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Padded {
+    pub a: ::std::os::raw::c_char,
+    pub b: ::std::os::raw::c_long,
+}
+/// A mismatch between the layout of a generated type and the layout
+/// it has in C, as found by `verify_layouts`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The size of the type doesn't match.
+    Size {
+        /// The name of the type.
+        ty: &'static str,
+        /// The value in C.
+        expected: usize,
+        /// The value in Rust.
+        actual: usize,
+    },
+    /// The alignment of the type doesn't match.
+    Align {
+        /// The name of the type.
+        ty: &'static str,
+        /// The value in C.
+        expected: usize,
+        /// The value in Rust.
+        actual: usize,
+    },
+    /// The offset of a field of the type doesn't match.
+    Offset {
+        /// The name of the type.
+        ty: &'static str,
+        /// The name of the field.
+        field: &'static str,
+        /// The value in C.
+        expected: usize,
+        /// The value in Rust.
+        actual: usize,
+    },
+}
+impl ::std::fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match *self {
+            LayoutError::Size { ty, expected, actual } => {
+                write!(f, "size of {} is {}, expected {}", ty, actual, expected)
+            }
+            LayoutError::Align { ty, expected, actual } => {
+                write!(f, "alignment of {} is {}, expected {}", ty, actual, expected)
+            }
+            LayoutError::Offset { ty, field, expected, actual } => {
+                write!(
+                    f,
+                    "offset of field {}::{} is {}, expected {}",
+                    ty,
+                    field,
+                    actual,
+                    expected,
+                )
+            }
+        }
+    }
+}
+/// Check the size, alignment and field offsets of the generated
+/// types, returning the first mismatch.
+pub fn verify_layouts() -> ::std::result::Result<(), LayoutError> {
+    {
+        let actual = ::std::mem::size_of::<Point>();
+        if actual != 8usize {
+            return Err(LayoutError::Size {
+                ty: "Point",
+                expected: 8usize,
+                actual,
+            });
+        }
+        let actual = ::std::mem::align_of::<Point>();
+        if actual != 4usize {
+            return Err(LayoutError::Align {
+                ty: "Point",
+                expected: 4usize,
+                actual,
+            });
+        }
+        let actual = ::std::mem::offset_of!(Point, x);
+        if actual != 0usize {
+            return Err(LayoutError::Offset {
+                ty: "Point",
+                field: "x",
+                expected: 0usize,
+                actual,
+            });
+        }
+        let actual = ::std::mem::offset_of!(Point, y);
+        if actual != 4usize {
+            return Err(LayoutError::Offset {
+                ty: "Point",
+                field: "y",
+                expected: 4usize,
+                actual,
+            });
+        }
+    }
+    {
+        let actual = ::std::mem::size_of::<Padded>();
+        if actual != 16usize {
+            return Err(LayoutError::Size {
+                ty: "Padded",
+                expected: 16usize,
+                actual,
+            });
+        }
+        let actual = ::std::mem::align_of::<Padded>();
+        if actual != 8usize {
+            return Err(LayoutError::Align {
+                ty: "Padded",
+                expected: 8usize,
+                actual,
+            });
+        }
+        let actual = ::std::mem::offset_of!(Padded, a);
+        if actual != 0usize {
+            return Err(LayoutError::Offset {
+                ty: "Padded",
+                field: "a",
+                expected: 0usize,
+                actual,
+            });
+        }
+        let actual = ::std::mem::offset_of!(Padded, b);
+        if actual != 8usize {
+            return Err(LayoutError::Offset {
+                ty: "Padded",
+                field: "b",
+                expected: 8usize,
+                actual,
+            });
+        }
+    }
+    Ok(())
+}
@@ -103,8 +103,9 @@ const _: () = {
     ][::std::mem::offset_of!(cmdline_token_ops, get_help) - 24usize];
 };
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum cmdline_numtype {
+    #[default]
     UINT8 = 0,
     UINT16 = 1,
     UINT32 = 2,
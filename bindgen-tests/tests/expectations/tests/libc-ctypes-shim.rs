@@ -0,0 +1,47 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[allow(unused_imports)]
+mod __bindgen_ctypes {
+    #[cfg(feature = "libc")]
+    pub use ::libc::{
+        c_char, c_schar, c_uchar, c_short, c_ushort, c_int, c_uint, c_long, c_ulong,
+        c_longlong, c_ulonglong, c_float, c_double, c_void,
+    };
+    #[cfg(not(feature = "libc"))]
+    pub use ::core::ffi::{
+        c_char, c_schar, c_uchar, c_short, c_ushort, c_int, c_uint, c_long, c_ulong,
+        c_longlong, c_ulonglong, c_float, c_double, c_void,
+    };
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Data {
+    pub c: __bindgen_ctypes::c_char,
+    pub i: __bindgen_ctypes::c_int,
+    pub l: __bindgen_ctypes::c_ulong,
+    pub d: f64,
+    pub p: *mut __bindgen_ctypes::c_void,
+}
+const _: () = {
+    ["Size of Data"][::std::mem::size_of::<Data>() - 32usize];
+    ["Alignment of Data"][::std::mem::align_of::<Data>() - 8usize];
+    ["Offset of field: Data::c"][::std::mem::offset_of!(Data, c) - 0usize];
+    ["Offset of field: Data::i"][::std::mem::offset_of!(Data, i) - 4usize];
+    ["Offset of field: Data::l"][::std::mem::offset_of!(Data, l) - 8usize];
+    ["Offset of field: Data::d"][::std::mem::offset_of!(Data, d) - 16usize];
+    ["Offset of field: Data::p"][::std::mem::offset_of!(Data, p) - 24usize];
+};
+impl Default for Data {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+extern "C" {
+    pub fn process(
+        data: *mut Data,
+        name: *const __bindgen_ctypes::c_char,
+    ) -> __bindgen_ctypes::c_int;
+}
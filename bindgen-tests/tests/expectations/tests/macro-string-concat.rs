@@ -0,0 +1,5 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub const GREETING: &[u8; 12] = b"Hello World\0";
+pub const PREFIX: &[u8; 4] = b"lib\0";
+pub const LIB_NAME: &[u8; 7] = b"libfoo\0";
+pub const UTF8_GREETING: &[u8; 13] = b"Hello, World\0";
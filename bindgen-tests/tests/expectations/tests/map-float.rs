@@ -0,0 +1,26 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Real64(pub f64);
+pub const SCALE: f64 = 2.5;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Sample {
+    pub gain: f32,
+    pub value: Real64,
+}
+const _: () = {
+    ["Size of Sample"][::std::mem::size_of::<Sample>() - 16usize];
+    ["Alignment of Sample"][::std::mem::align_of::<Sample>() - 8usize];
+    ["Offset of field: Sample::gain"][::std::mem::offset_of!(Sample, gain) - 0usize];
+    ["Offset of field: Sample::value"][::std::mem::offset_of!(Sample, value) - 8usize];
+};
+extern "C" {
+    pub fn average(samples: *const Sample, count: ::std::os::raw::c_int) -> Real64;
+}
+const _: () = {
+    ["Size of the type `float` is mapped to"][::std::mem::size_of::<f32>() - 4usize];
+};
+const _: () = {
+    ["Size of the type `double` is mapped to"][::std::mem::size_of::<Real64>() - 8usize];
+};
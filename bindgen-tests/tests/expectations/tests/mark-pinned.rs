@@ -0,0 +1,63 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+const _: () = {
+    trait AmbiguousIfUnpin<A> {
+        fn some_item() {}
+    }
+    impl<T: ?Sized> AmbiguousIfUnpin<()> for T {}
+    impl<T: ?Sized + Unpin> AmbiguousIfUnpin<u8> for T {}
+    let _ = <List as AmbiguousIfUnpin<_>>::some_item;
+};
+/// An intrusive list whose head points to itself when empty.
+#[repr(C)]
+#[derive(Debug)]
+pub struct List {
+    pub next: *mut List,
+    pub prev: *mut List,
+    _pin: ::std::marker::PhantomPinned,
+}
+const _: () = {
+    ["Size of List"][::std::mem::size_of::<List>() - 16usize];
+    ["Alignment of List"][::std::mem::align_of::<List>() - 8usize];
+    ["Offset of field: List::next"][::std::mem::offset_of!(List, next) - 0usize];
+    ["Offset of field: List::prev"][::std::mem::offset_of!(List, prev) - 8usize];
+};
+impl Default for List {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug)]
+pub struct Owner {
+    pub list: List,
+}
+const _: () = {
+    ["Size of Owner"][::std::mem::size_of::<Owner>() - 16usize];
+    ["Alignment of Owner"][::std::mem::align_of::<Owner>() - 8usize];
+    ["Offset of field: Owner::list"][::std::mem::offset_of!(Owner, list) - 0usize];
+};
+impl Default for Owner {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Unpinned {
+    pub value: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Unpinned"][::std::mem::size_of::<Unpinned>() - 4usize];
+    ["Alignment of Unpinned"][::std::mem::align_of::<Unpinned>() - 4usize];
+    [
+        "Offset of field: Unpinned::value",
+    ][::std::mem::offset_of!(Unpinned, value) - 0usize];
+};
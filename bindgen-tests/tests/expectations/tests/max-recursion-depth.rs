@@ -0,0 +1,11 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Outer {
+    pub inner: [u32; 1usize],
+}
+const _: () = {
+    ["Size of Outer"][::std::mem::size_of::<Outer>() - 4usize];
+    ["Alignment of Outer"][::std::mem::align_of::<Outer>() - 4usize];
+    ["Offset of field: Outer::inner"][::std::mem::offset_of!(Outer, inner) - 0usize];
+};
@@ -0,0 +1,58 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub mod geometry {
+    #[allow(unused_imports)]
+    use super::*;
+    #[repr(C)]
+    #[derive(Debug, Default, Copy, Clone)]
+    pub struct Point {
+        pub x: ::std::os::raw::c_int,
+        pub y: ::std::os::raw::c_int,
+    }
+    const _: () = {
+        ["Size of Point"][::std::mem::size_of::<Point>() - 8usize];
+        ["Alignment of Point"][::std::mem::align_of::<Point>() - 4usize];
+        ["Offset of field: Point::x"][::std::mem::offset_of!(Point, x) - 0usize];
+        ["Offset of field: Point::y"][::std::mem::offset_of!(Point, y) - 4usize];
+    };
+}
+pub use self::geometry::*;
+pub mod point_h {
+    #[allow(unused_imports)]
+    use super::*;
+    #[repr(C)]
+    #[derive(Debug, Default, Copy, Clone)]
+    pub struct point {
+        pub x: ::std::os::raw::c_int,
+        pub y: ::std::os::raw::c_int,
+    }
+    const _: () = {
+        ["Size of point"][::std::mem::size_of::<point>() - 8usize];
+        ["Alignment of point"][::std::mem::align_of::<point>() - 4usize];
+        ["Offset of field: point::x"][::std::mem::offset_of!(point, x) - 0usize];
+        ["Offset of field: point::y"][::std::mem::offset_of!(point, y) - 4usize];
+    };
+}
+pub use self::point_h::*;
+pub mod modules_per_header {
+    #[allow(unused_imports)]
+    use super::*;
+    #[repr(C)]
+    #[derive(Debug, Default, Copy, Clone)]
+    pub struct Line {
+        pub start: Point,
+        pub end: Point,
+    }
+    const _: () = {
+        ["Size of Line"][::std::mem::size_of::<Line>() - 16usize];
+        ["Alignment of Line"][::std::mem::align_of::<Line>() - 4usize];
+        ["Offset of field: Line::start"][::std::mem::offset_of!(Line, start) - 0usize];
+        ["Offset of field: Line::end"][::std::mem::offset_of!(Line, end) - 8usize];
+    };
+    extern "C" {
+        pub fn line_length(line: Line) -> ::std::os::raw::c_int;
+    }
+    extern "C" {
+        pub fn point_scale(p: point, factor: ::std::os::raw::c_int) -> point;
+    }
+}
+pub use self::modules_per_header::*;
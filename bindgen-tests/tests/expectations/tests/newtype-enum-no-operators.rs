@@ -0,0 +1,21 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+const _: () = {
+    trait AmbiguousIfBitOr<A> {
+        fn some_item() {}
+    }
+    impl<T> AmbiguousIfBitOr<()> for T {}
+    impl<T: ::std::ops::BitOr> AmbiguousIfBitOr<u8> for T {}
+    let _ = <Color as AmbiguousIfBitOr<_>>::some_item;
+};
+impl Color {
+    pub const COLOR_RED: Color = Color(0);
+}
+impl Color {
+    pub const COLOR_GREEN: Color = Color(1);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Color(pub ::std::os::raw::c_uint);
+const _: () = {
+    ["Size of Color"][::std::mem::size_of::<Color>() - 4usize];
+};
@@ -0,0 +1,35 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub type uint16_t = ::std::os::raw::c_ushort;
+impl Channel {
+    pub const Left: Channel = Channel(1);
+}
+impl Channel {
+    pub const Right: Channel = Channel(2);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Channel(pub uint16_t);
+const _: () = {
+    ["Size of Channel"][::std::mem::size_of::<Channel>() - 2usize];
+};
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Mixer {
+    pub channels: [Channel; 3usize],
+}
+const _: () = {
+    ["Size of Mixer"][::std::mem::size_of::<Mixer>() - 6usize];
+    ["Alignment of Mixer"][::std::mem::align_of::<Mixer>() - 2usize];
+    [
+        "Offset of field: Mixer::channels",
+    ][::std::mem::offset_of!(Mixer, channels) - 0usize];
+};
+impl Default for Mixer {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
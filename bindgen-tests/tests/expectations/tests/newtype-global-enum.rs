@@ -6,3 +6,7 @@ pub const Foo_Negative: Foo = Foo(-3);
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub struct Foo(pub ::std::os::raw::c_int);
+#[test]
+fn bindgen_test_layout_Foo() {
+    assert_eq!(::std::mem::size_of::<Foo>(), 4usize, "Size of Foo");
+}
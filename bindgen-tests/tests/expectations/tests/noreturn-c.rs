@@ -0,0 +1,7 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    pub fn die() -> !;
+}
+extern "C" {
+    pub fn die_with_status(status: ::std::os::raw::c_int) -> !;
+}
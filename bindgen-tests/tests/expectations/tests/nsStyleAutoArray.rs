@@ -22,8 +22,9 @@ pub struct nsStyleAutoArray<T> {
     pub mOtherElements: nsTArray<T>,
 }
 #[repr(i32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum nsStyleAutoArray_WithSingleInitialElement {
+    #[default]
     WITH_SINGLE_INITIAL_ELEMENT = 0,
 }
 impl<T> Default for nsStyleAutoArray<T> {
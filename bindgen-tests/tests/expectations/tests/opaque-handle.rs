@@ -0,0 +1,27 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Handle(::std::os::raw::c_int);
+impl Handle {
+    #[inline]
+    pub const fn from_raw(raw: ::std::os::raw::c_int) -> Self {
+        Handle(raw)
+    }
+    #[inline]
+    pub const fn into_raw(self) -> ::std::os::raw::c_int {
+        self.0
+    }
+}
+impl ::std::fmt::Pointer for Handle {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::std::fmt::Pointer::fmt(&(self.0 as usize as *const ()), f)
+    }
+}
+pub type Plain = ::std::os::raw::c_int;
+pub type NotAnInteger = *mut ::std::os::raw::c_void;
+extern "C" {
+    pub fn open_handle(name: *const ::std::os::raw::c_char) -> Handle;
+}
+extern "C" {
+    pub fn close_handle(handle: Handle);
+}
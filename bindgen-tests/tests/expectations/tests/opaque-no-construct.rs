@@ -0,0 +1,15 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+/// A handle that is only ever used through a pointer.
+#[repr(C)]
+pub struct Handle {
+    _unused: [u8; 0],
+    _marker: ::std::marker::PhantomData<(*mut u8, ::std::marker::PhantomPinned)>,
+}
+extern "C" {
+    #[link_name = "\u{1}_Z13handle_createv"]
+    pub fn handle_create() -> *mut Handle;
+}
+extern "C" {
+    #[link_name = "\u{1}_Z14handle_destroyP6Handle"]
+    pub fn handle_destroy(handle: *mut Handle);
+}
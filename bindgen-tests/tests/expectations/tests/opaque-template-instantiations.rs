@@ -0,0 +1,36 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Template<T> {
+    pub _phantom_0: ::std::marker::PhantomData<::std::cell::UnsafeCell<T>>,
+    pub member: T,
+}
+impl<T> Default for Template<T> {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ContainsInstantiations {
+    pub ints: u32,
+    pub doubles: u64,
+}
+const _: () = {
+    [
+        "Size of ContainsInstantiations",
+    ][::std::mem::size_of::<ContainsInstantiations>() - 16usize];
+    [
+        "Alignment of ContainsInstantiations",
+    ][::std::mem::align_of::<ContainsInstantiations>() - 8usize];
+    [
+        "Offset of field: ContainsInstantiations::ints",
+    ][::std::mem::offset_of!(ContainsInstantiations, ints) - 0usize];
+    [
+        "Offset of field: ContainsInstantiations::doubles",
+    ][::std::mem::offset_of!(ContainsInstantiations, doubles) - 8usize];
+};
@@ -0,0 +1,55 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Foo {
+    pub value: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Foo"][::std::mem::size_of::<Foo>() - 4usize];
+    ["Alignment of Foo"][::std::mem::align_of::<Foo>() - 4usize];
+    ["Offset of field: Foo::value"][::std::mem::offset_of!(Foo, value) - 0usize];
+};
+extern "C" {
+    pub fn get_foo(out: *mut *mut Foo);
+}
+/// Calls [`get_foo`], returning the pointer it writes through `out`.
+pub unsafe fn get_foo_out() -> ::std::option::Option<::std::ptr::NonNull<Foo>> {
+    let mut out: *mut Foo = ::std::ptr::null_mut();
+    get_foo(&mut out);
+    ::std::ptr::NonNull::new(out)
+}
+extern "C" {
+    pub fn get_foo_by_id(
+        id: ::std::os::raw::c_int,
+        out: *mut *mut Foo,
+    ) -> ::std::os::raw::c_int;
+}
+/// Calls [`get_foo_by_id`], returning the pointer it writes through `out`.
+pub unsafe fn get_foo_by_id_out(
+    id: ::std::os::raw::c_int,
+) -> (::std::os::raw::c_int, ::std::option::Option<::std::ptr::NonNull<Foo>>) {
+    let mut out: *mut Foo = ::std::ptr::null_mut();
+    (get_foo_by_id(id, &mut out), ::std::ptr::NonNull::new(out))
+}
+extern "C" {
+    pub fn find_foo(
+        name: *const ::std::os::raw::c_char,
+        result: *mut *const Foo,
+    ) -> ::std::os::raw::c_int;
+}
+/// Calls [`find_foo`], returning the pointer it writes through `result`.
+pub unsafe fn find_foo_out(
+    name: *const ::std::os::raw::c_char,
+) -> (::std::os::raw::c_int, *const Foo) {
+    let mut result: *const Foo = ::std::ptr::null();
+    (find_foo(name, &mut result), result)
+}
+extern "C" {
+    pub fn get_value(out: *mut ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn get_bar(out: *mut *mut Foo);
+}
+extern "C" {
+    pub fn get_bar_out();
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[export_name = "sram_buffer"]
+static BUFFER: [i32; 16] = [0; 16];
+#[export_name = "sram_magic"]
+static MAGIC: u32 = 0;
+extern "C" {
+    pub static mut sram_buffer: [::std::os::raw::c_int; 16usize];
+}
+#[used]
+#[link_section = ".sram"]
+#[allow(unused_unsafe)]
+pub static mut __bindgen_section_sram_buffer: *const [::std::os::raw::c_int; 16usize] = unsafe {
+    ::std::ptr::addr_of!(sram_buffer)
+};
+extern "C" {
+    pub static sram_magic: ::std::os::raw::c_uint;
+}
+#[used]
+#[link_section = ".sram"]
+#[allow(unused_unsafe)]
+pub static mut __bindgen_section_sram_magic: *const ::std::os::raw::c_uint = unsafe {
+    ::std::ptr::addr_of!(sram_magic)
+};
+extern "C" {
+    pub static mut counter: ::std::os::raw::c_int;
+}
@@ -0,0 +1,39 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub type size = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct point {
+    pub x: size,
+    pub y: size,
+}
+const _: () = {
+    ["Size of point"][::std::mem::size_of::<point>() - 8usize];
+    ["Alignment of point"][::std::mem::align_of::<point>() - 4usize];
+    ["Offset of field: point::x"][::std::mem::offset_of!(point, x) - 0usize];
+    ["Offset of field: point::y"][::std::mem::offset_of!(point, y) - 4usize];
+};
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct rect {
+    pub origin: point,
+    pub width: size,
+    pub height: size,
+}
+const _: () = {
+    ["Size of rect"][::std::mem::size_of::<rect>() - 16usize];
+    ["Alignment of rect"][::std::mem::align_of::<rect>() - 4usize];
+    ["Offset of field: rect::origin"][::std::mem::offset_of!(rect, origin) - 0usize];
+    ["Offset of field: rect::width"][::std::mem::offset_of!(rect, width) - 8usize];
+    ["Offset of field: rect::height"][::std::mem::offset_of!(rect, height) - 12usize];
+};
+pub const mode_LIB_MODE_FILL: mode = 0;
+pub const mode_LIB_MODE_STROKE: mode = 1;
+pub type mode = ::std::os::raw::c_uint;
+extern "C" {
+    #[link_name = "\u{1}lib_default_mode"]
+    pub static mut default_mode: mode;
+}
+extern "C" {
+    #[link_name = "\u{1}lib_rect_area"]
+    pub fn rect_area(rect: *const rect) -> size;
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    #[must_use]
+    #[link_name = "\u{1}_Z7checkedi"]
+    pub fn checked(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "\u{1}_Z9uncheckedi"]
+    pub fn unchecked(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[must_use]
+    #[link_name = "\u{1}_Z12nodiscard_fni"]
+    pub fn nodiscard_fn(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+#[must_use]
+pub struct Status {
+    pub code: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Status"][::std::mem::size_of::<Status>() - 4usize];
+    ["Alignment of Status"][::std::mem::align_of::<Status>() - 4usize];
+    ["Offset of field: Status::code"][::std::mem::offset_of!(Status, code) - 0usize];
+};
+extern "C" {
+    #[must_use]
+    #[link_name = "\u{1}_Z11make_statusv"]
+    pub fn make_status() -> Status;
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Plain {
+    pub value: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Plain"][::std::mem::size_of::<Plain>() - 4usize];
+    ["Alignment of Plain"][::std::mem::align_of::<Plain>() - 4usize];
+    ["Offset of field: Plain::value"][::std::mem::offset_of!(Plain, value) - 0usize];
+};
@@ -0,0 +1,23 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Cached {
+    pub value: ::std::os::raw::c_int,
+    pub cache: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Cached"][::std::mem::size_of::<Cached>() - 8usize];
+    ["Alignment of Cached"][::std::mem::align_of::<Cached>() - 4usize];
+    ["Offset of field: Cached::value"][::std::mem::offset_of!(Cached, value) - 0usize];
+    ["Offset of field: Cached::cache"][::std::mem::offset_of!(Cached, cache) - 4usize];
+};
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Plain {
+    pub value: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Plain"][::std::mem::size_of::<Plain>() - 4usize];
+    ["Alignment of Plain"][::std::mem::align_of::<Plain>() - 4usize];
+    ["Offset of field: Plain::value"][::std::mem::offset_of!(Plain, value) - 0usize];
+};
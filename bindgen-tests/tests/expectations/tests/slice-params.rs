@@ -0,0 +1,98 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    pub fn checksum(data: *const u8, len: usize) -> ::std::os::raw::c_int;
+}
+/// Calls [`checksum`], taking slices instead of pointer and length pairs.
+pub fn checksum_slice(data: &[u8]) -> ::std::os::raw::c_int {
+    unsafe { checksum(data.as_ptr(), data.len()) }
+}
+extern "C" {
+    pub fn fill(
+        values: *mut ::std::os::raw::c_int,
+        values_len: ::std::os::raw::c_uint,
+        value: ::std::os::raw::c_int,
+    );
+}
+/// Calls [`fill`], taking slices instead of pointer and length pairs.
+///
+/// # Panics
+///
+/// Panics if a slice is longer than its length parameter can hold.
+#[track_caller]
+pub fn fill_slice(values: &mut [::std::os::raw::c_int], value: ::std::os::raw::c_int) {
+    unsafe {
+        fill(
+            values.as_mut_ptr(),
+            ::std::convert::TryInto::try_into(values.len())
+                .expect("the length of `values` should fit in `values_len`"),
+            value,
+        )
+    }
+}
+extern "C" {
+    pub fn copy_bytes(
+        dst: *mut ::std::os::raw::c_void,
+        dst_len: usize,
+        src: *const ::std::os::raw::c_void,
+        src_len: usize,
+    );
+}
+/// Calls [`copy_bytes`], taking slices instead of pointer and length pairs.
+pub unsafe fn copy_bytes_slice(dst: &mut [u8], src: &[u8]) {
+    copy_bytes(dst.as_mut_ptr().cast(), dst.len(), src.as_ptr().cast(), src.len())
+}
+extern "C" {
+    pub fn unrelated(name: *const ::std::os::raw::c_char, flags: ::std::os::raw::c_int);
+}
+extern "C" {
+    pub fn write_named(
+        name: *const ::std::os::raw::c_char,
+        data: *const u8,
+        len: usize,
+    ) -> ::std::os::raw::c_int;
+}
+/// Calls [`write_named`], taking slices instead of pointer and length pairs.
+pub unsafe fn write_named_slice(
+    name: *const ::std::os::raw::c_char,
+    data: &[u8],
+) -> ::std::os::raw::c_int {
+    write_named(name, data.as_ptr(), data.len())
+}
+pub type callback = ::std::option::Option<
+    unsafe extern "C" fn(arg1: ::std::os::raw::c_int),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct buffer {
+    pub data: *const u8,
+    pub len: usize,
+}
+const _: () = {
+    ["Size of buffer"][::std::mem::size_of::<buffer>() - 16usize];
+    ["Alignment of buffer"][::std::mem::align_of::<buffer>() - 8usize];
+    ["Offset of field: buffer::data"][::std::mem::offset_of!(buffer, data) - 0usize];
+    ["Offset of field: buffer::len"][::std::mem::offset_of!(buffer, len) - 8usize];
+};
+impl Default for buffer {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+extern "C" {
+    pub fn for_each(values: *const ::std::os::raw::c_int, len: usize, cb: callback);
+}
+/// Calls [`for_each`], taking slices instead of pointer and length pairs.
+pub unsafe fn for_each_slice(values: &[::std::os::raw::c_int], cb: callback) {
+    for_each(values.as_ptr(), values.len(), cb)
+}
+extern "C" {
+    pub fn append(buf: buffer, data: *const u8, len: usize);
+}
+/// Calls [`append`], taking slices instead of pointer and length pairs.
+pub unsafe fn append_slice(buf: buffer, data: &[u8]) {
+    append(buf, data.as_ptr(), data.len())
+}
@@ -0,0 +1,23 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    pub fn fill(
+        values: *mut ::std::os::raw::c_int,
+        values_len: ::std::os::raw::c_uint,
+        value: ::std::os::raw::c_int,
+    );
+}
+/// Calls [`fill`], taking slices instead of pointer and length pairs.
+///
+/// # Panics
+///
+/// Panics if a slice is longer than its length parameter can hold.
+pub fn fill_slice(values: &mut [::std::os::raw::c_int], value: ::std::os::raw::c_int) {
+    unsafe {
+        fill(
+            values.as_mut_ptr(),
+            ::std::convert::TryInto::try_into(values.len())
+                .expect("the length of `values` should fit in `values_len`"),
+            value,
+        )
+    }
+}
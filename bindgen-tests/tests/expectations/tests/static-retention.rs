@@ -0,0 +1,24 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[export_name = "keep_registration"]
+static DEFINITION: i32 = 0;
+#[export_name = "export_counter"]
+static EXPORT_DEFINITION: i32 = 0;
+extern "C" {
+    pub static keep_registration: ::std::os::raw::c_int;
+}
+#[used]
+#[allow(unused_unsafe)]
+pub static mut __bindgen_retain_keep_registration: *const ::std::os::raw::c_int = unsafe {
+    ::std::ptr::addr_of!(keep_registration)
+};
+extern "C" {
+    pub static export_counter: ::std::os::raw::c_int;
+}
+#[no_mangle]
+#[allow(unused_unsafe)]
+pub static mut __bindgen_retain_export_counter: *const ::std::os::raw::c_int = unsafe {
+    ::std::ptr::addr_of!(export_counter)
+};
+extern "C" {
+    pub static mut counter: ::std::os::raw::c_int;
+}
@@ -0,0 +1,11 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[export_name = "export_counter"]
+static EXPORT_DEFINITION: i32 = 0;
+extern "C" {
+    pub static export_counter: ::std::os::raw::c_int;
+}
+#[unsafe(no_mangle)]
+#[allow(unused_unsafe)]
+pub static mut __bindgen_retain_export_counter: *const ::std::os::raw::c_int = unsafe {
+    ::std::ptr::addr_of!(export_counter)
+};
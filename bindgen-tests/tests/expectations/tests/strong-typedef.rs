@@ -0,0 +1,38 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub type uint32_t = ::std::os::raw::c_uint;
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Color(pub uint32_t);
+impl ::std::ops::Deref for Color {
+    type Target = uint32_t;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ::std::ops::DerefMut for Color {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Shade(pub Color);
+impl ::std::ops::Deref for Shade {
+    type Target = Color;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl ::std::ops::DerefMut for Shade {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+pub type Name = *const ::std::os::raw::c_char;
+extern "C" {
+    pub fn blend(a: Color, b: Shade) -> Color;
+}
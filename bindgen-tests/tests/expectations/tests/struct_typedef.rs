@@ -45,8 +45,9 @@ pub enum typedef_named_enum {
 }
 pub const ENUM_IS_ANON: _bindgen_ty_2 = _bindgen_ty_2::ENUM_IS_ANON;
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum _bindgen_ty_2 {
+    #[default]
     ENUM_IS_ANON = 0,
 }
 pub type enum_ptr_t = *mut _bindgen_ty_2;
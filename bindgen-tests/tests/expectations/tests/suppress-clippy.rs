@@ -0,0 +1,31 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Dense {
+    DENSE_LOW = 0,
+    DENSE_HIGH = 1,
+}
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Point {
+    pub x: ::std::os::raw::c_int,
+    pub y: ::std::os::raw::c_int,
+}
+#[allow(clippy::identity_op)]
+const _: () = {
+    ["Size of Point"][::std::mem::size_of::<Point>() - 8usize];
+    ["Alignment of Point"][::std::mem::align_of::<Point>() - 4usize];
+    ["Offset of field: Point::x"][::std::mem::offset_of!(Point, x) - 0usize];
+    ["Offset of field: Point::y"][::std::mem::offset_of!(Point, y) - 4usize];
+};
+extern "C" {
+    pub fn close(fd: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+}
+/// Calls [`close`], returning the error stored in `errno` if it fails.
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn close_checked(
+    fd: ::std::os::raw::c_int,
+) -> ::std::io::Result<::std::os::raw::c_int> {
+    let ret = close(fd);
+    if ret == -1 { Err(::std::io::Error::last_os_error()) } else { Ok(ret) }
+}
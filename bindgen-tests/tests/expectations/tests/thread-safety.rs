@@ -0,0 +1,68 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Handle {
+    pub ptr: *mut ::std::os::raw::c_void,
+}
+const _: () = {
+    ["Size of Handle"][::std::mem::size_of::<Handle>() - 8usize];
+    ["Alignment of Handle"][::std::mem::align_of::<Handle>() - 8usize];
+    ["Offset of field: Handle::ptr"][::std::mem::offset_of!(Handle, ptr) - 0usize];
+};
+impl Default for Handle {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+/// SAFETY: `Handle` can be sent between threads, as asserted by `ParseCallbacks::thread_safety`.
+unsafe impl ::std::marker::Send for Handle {}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SharedHandle {
+    pub ptr: *mut ::std::os::raw::c_void,
+}
+const _: () = {
+    ["Size of SharedHandle"][::std::mem::size_of::<SharedHandle>() - 8usize];
+    ["Alignment of SharedHandle"][::std::mem::align_of::<SharedHandle>() - 8usize];
+    [
+        "Offset of field: SharedHandle::ptr",
+    ][::std::mem::offset_of!(SharedHandle, ptr) - 0usize];
+};
+impl Default for SharedHandle {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
+/// SAFETY: `SharedHandle` can be sent between threads, as asserted by `ParseCallbacks::thread_safety`.
+unsafe impl ::std::marker::Send for SharedHandle {}
+/// SAFETY: `SharedHandle` can be shared between threads, as asserted by `ParseCallbacks::thread_safety`.
+unsafe impl ::std::marker::Sync for SharedHandle {}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NotThreadSafe {
+    pub ptr: *mut ::std::os::raw::c_void,
+}
+const _: () = {
+    ["Size of NotThreadSafe"][::std::mem::size_of::<NotThreadSafe>() - 8usize];
+    ["Alignment of NotThreadSafe"][::std::mem::align_of::<NotThreadSafe>() - 8usize];
+    [
+        "Offset of field: NotThreadSafe::ptr",
+    ][::std::mem::offset_of!(NotThreadSafe, ptr) - 0usize];
+};
+impl Default for NotThreadSafe {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
@@ -0,0 +1,14 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[inline]
+pub const fn SQUARE(x: i64) -> i64 {
+    x.wrapping_mul(x)
+}
+#[inline]
+pub const fn AREA(width: i64, height: i64) -> i64 {
+    width.wrapping_mul(height).wrapping_add(1)
+}
+#[inline]
+pub const fn ALIGN_DOWN(value: i64, align: i64) -> i64 {
+    value & !align.wrapping_sub(1)
+}
+pub const ANSWER: u32 = 42;
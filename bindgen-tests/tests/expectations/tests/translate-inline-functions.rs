@@ -0,0 +1,23 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[inline]
+pub fn add(a: ::std::os::raw::c_int, b: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
+    a.wrapping_add(b)
+}
+#[inline]
+pub fn mask(
+    value: ::std::os::raw::c_uint,
+    bits: ::std::os::raw::c_uint,
+) -> ::std::os::raw::c_uint {
+    value & (1 as ::std::os::raw::c_uint).wrapping_shl(bits as u32).wrapping_sub(1)
+}
+#[inline]
+pub fn scale(
+    x: ::std::os::raw::c_long,
+    y: ::std::os::raw::c_long,
+) -> ::std::os::raw::c_long {
+    x.wrapping_mul(3).wrapping_neg().wrapping_div(y.wrapping_rem(4))
+}
+#[inline]
+pub fn flip(x: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
+    !x ^ 15
+}
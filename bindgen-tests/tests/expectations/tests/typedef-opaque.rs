@@ -0,0 +1,36 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Inner {
+    pub a: ::std::os::raw::c_int,
+    pub b: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Inner"][::std::mem::size_of::<Inner>() - 8usize];
+    ["Alignment of Inner"][::std::mem::align_of::<Inner>() - 4usize];
+    ["Offset of field: Inner::a"][::std::mem::offset_of!(Inner, a) - 0usize];
+    ["Offset of field: Inner::b"][::std::mem::offset_of!(Inner, b) - 4usize];
+};
+pub type Handle = [u32; 2usize];
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Config {
+    pub flags: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Config"][::std::mem::size_of::<Config>() - 4usize];
+    ["Alignment of Config"][::std::mem::align_of::<Config>() - 4usize];
+    ["Offset of field: Config::flags"][::std::mem::offset_of!(Config, flags) - 0usize];
+};
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Holder {
+    pub inner: Inner,
+    pub handle: Handle,
+}
+const _: () = {
+    ["Size of Holder"][::std::mem::size_of::<Holder>() - 16usize];
+    ["Alignment of Holder"][::std::mem::align_of::<Holder>() - 4usize];
+    ["Offset of field: Holder::inner"][::std::mem::offset_of!(Holder, inner) - 0usize];
+    ["Offset of field: Holder::handle"][::std::mem::offset_of!(Holder, handle) - 8usize];
+};
@@ -0,0 +1,47 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct NonTrivial {
+    pub value: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of NonTrivial"][::std::mem::size_of::<NonTrivial>() - 4usize];
+    ["Alignment of NonTrivial"][::std::mem::align_of::<NonTrivial>() - 4usize];
+    [
+        "Offset of field: NonTrivial::value",
+    ][::std::mem::offset_of!(NonTrivial, value) - 0usize];
+};
+extern "C" {
+    #[link_name = "\u{1}_ZN10NonTrivialD1Ev"]
+    pub fn NonTrivial_NonTrivial_destructor(this: *mut NonTrivial);
+}
+impl NonTrivial {
+    #[inline]
+    pub unsafe fn destruct(&mut self) {
+        NonTrivial_NonTrivial_destructor(self)
+    }
+}
+#[repr(C)]
+pub union Storage {
+    pub non_trivial: ::std::mem::ManuallyDrop<NonTrivial>,
+    pub raw: ::std::os::raw::c_int,
+    pub real: f32,
+}
+const _: () = {
+    ["Size of Storage"][::std::mem::size_of::<Storage>() - 4usize];
+    ["Alignment of Storage"][::std::mem::align_of::<Storage>() - 4usize];
+    [
+        "Offset of field: Storage::non_trivial",
+    ][::std::mem::offset_of!(Storage, non_trivial) - 0usize];
+    ["Offset of field: Storage::raw"][::std::mem::offset_of!(Storage, raw) - 0usize];
+    ["Offset of field: Storage::real"][::std::mem::offset_of!(Storage, real) - 0usize];
+};
+impl Default for Storage {
+    fn default() -> Self {
+        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        unsafe {
+            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            s.assume_init()
+        }
+    }
+}
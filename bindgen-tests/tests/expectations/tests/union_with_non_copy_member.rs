@@ -86,7 +86,7 @@ impl Default for WithBindgenGeneratedWrapper {
 #[repr(C)]
 pub union WithManuallyDrop {
     pub non_copy_type: ::std::mem::ManuallyDrop<NonCopyType>,
-    pub bar: ::std::mem::ManuallyDrop<::std::os::raw::c_int>,
+    pub bar: ::std::os::raw::c_int,
 }
 const _: () = {
     ["Size of WithManuallyDrop"][::std::mem::size_of::<WithManuallyDrop>() - 4usize];
@@ -0,0 +1,13 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+extern "C" {
+    /// Writes the formatted arguments to the log.
+    ///
+    /// This function is variadic: the variadic arguments are passed with the C default argument promotions, e.g. `float` as `f64` and integer types smaller than `int` as `c_int`.
+    pub fn log_printf(fmt: *const ::std::os::raw::c_char, ...) -> ::std::os::raw::c_int;
+}
+pub type printf_fn = ::std::option::Option<
+    unsafe extern "C" fn(
+        fmt: *const ::std::os::raw::c_char,
+        ...
+    ) -> ::std::os::raw::c_int,
+>;
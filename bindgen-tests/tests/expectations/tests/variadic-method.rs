@@ -1,5 +1,6 @@
 #![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
 extern "C" {
+    /// This function is variadic: the variadic arguments are passed with the C default argument promotions, e.g. `float` as `f64` and integer types smaller than `int` as `c_int`.
     #[link_name = "\u{1}_Z3fooPKcz"]
     pub fn foo(fmt: *const ::std::os::raw::c_char, ...);
 }
@@ -13,6 +14,7 @@ const _: () = {
     ["Alignment of Bar"][::std::mem::align_of::<Bar>() - 1usize];
 };
 extern "C" {
+    /// This function is variadic: the variadic arguments are passed with the C default argument promotions, e.g. `float` as `f64` and integer types smaller than `int` as `c_int`.
     #[link_name = "\u{1}_ZN3Bar3fooEPKcz"]
     pub fn Bar_foo(this: *mut Bar, fmt: *const ::std::os::raw::c_char, ...);
 }
@@ -0,0 +1,42 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+pub type uint32_t = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Default)]
+pub struct Uart {
+    data: uint32_t,
+    status: uint32_t,
+    pub config: uint32_t,
+}
+const _: () = {
+    ["Size of Uart"][::std::mem::size_of::<Uart>() - 12usize];
+    ["Alignment of Uart"][::std::mem::align_of::<Uart>() - 4usize];
+    ["Offset of field: Uart::data"][::std::mem::offset_of!(Uart, data) - 0usize];
+    ["Offset of field: Uart::status"][::std::mem::offset_of!(Uart, status) - 4usize];
+    ["Offset of field: Uart::config"][::std::mem::offset_of!(Uart, config) - 8usize];
+};
+impl Uart {
+    #[inline]
+    pub fn data(&self) -> uint32_t {
+        unsafe { ::std::ptr::read_volatile(::std::ptr::addr_of!(self.data)) }
+    }
+    #[inline]
+    pub fn set_data(&mut self, val: uint32_t) {
+        unsafe { ::std::ptr::write_volatile(::std::ptr::addr_of_mut!(self.data), val) }
+    }
+    #[inline]
+    pub fn status(&self) -> uint32_t {
+        unsafe { ::std::ptr::read_volatile(::std::ptr::addr_of!(self.status)) }
+    }
+}
+#[repr(C)]
+#[derive(Default)]
+pub struct Board {
+    pub uart: Uart,
+    pub id: ::std::os::raw::c_int,
+}
+const _: () = {
+    ["Size of Board"][::std::mem::size_of::<Board>() - 16usize];
+    ["Alignment of Board"][::std::mem::align_of::<Board>() - 4usize];
+    ["Offset of field: Board::uart"][::std::mem::offset_of!(Board, uart) - 0usize];
+    ["Offset of field: Board::id"][::std::mem::offset_of!(Board, id) - 12usize];
+};
@@ -79,6 +79,32 @@ extern "C" {
     #[link_name = "wrap_as_variadic_fn2__extern"]
     pub fn wrap_as_variadic_fn2_wrapped(i: ::std::os::raw::c_int, ...);
 }
+extern "C" {
+    #[link_name = "counter_get__extern"]
+    pub fn counter_get() -> ::std::os::raw::c_int;
+}
+extern "C" {
+    #[link_name = "counter_set__extern"]
+    pub fn counter_set(value: ::std::os::raw::c_int);
+}
+extern "C" {
+    #[link_name = "scale_get__extern"]
+    pub fn scale_get() -> f64;
+}
+extern "C" {
+    #[link_name = "callback_get__extern"]
+    pub fn callback_get() -> ::std::option::Option<
+        unsafe extern "C" fn(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int,
+    >;
+}
+extern "C" {
+    #[link_name = "callback_set__extern"]
+    pub fn callback_set(
+        value: ::std::option::Option<
+            unsafe extern "C" fn(arg1: ::std::os::raw::c_int) -> ::std::os::raw::c_int,
+        >,
+    );
+}
 pub type __builtin_va_list = [__va_list_tag; 1usize];
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
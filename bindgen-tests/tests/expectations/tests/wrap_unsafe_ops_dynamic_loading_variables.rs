@@ -0,0 +1,46 @@
+#![allow(dead_code, non_snake_case, non_camel_case_types, non_upper_case_globals)]
+#![deny(unsafe_op_in_unsafe_fn)]
+pub struct TestLib {
+    __library: ::libloading::Library,
+    pub foo: Result<*mut ::std::os::raw::c_int, ::libloading::Error>,
+    pub baz: Result<*mut *mut ::std::os::raw::c_int, ::libloading::Error>,
+    pub bar: Result<
+        unsafe extern "C" fn(x: ::std::os::raw::c_int) -> ::std::os::raw::c_int,
+        ::libloading::Error,
+    >,
+}
+impl TestLib {
+    pub unsafe fn new<P>(path: P) -> Result<Self, ::libloading::Error>
+    where
+        P: AsRef<::std::ffi::OsStr>,
+    {
+        let library = unsafe { ::libloading::Library::new(path) }?;
+        unsafe { Self::from_library(library) }
+    }
+    pub unsafe fn from_library<L>(library: L) -> Result<Self, ::libloading::Error>
+    where
+        L: Into<::libloading::Library>,
+    {
+        let __library = library.into();
+        let foo = unsafe { __library.get::<*mut ::std::os::raw::c_int>(b"foo\0") }
+            .map(|sym| *sym);
+        let baz = unsafe { __library.get::<*mut *mut ::std::os::raw::c_int>(b"baz\0") }
+            .map(|sym| *sym);
+        let bar = unsafe { __library.get(b"bar\0") }.map(|sym| *sym);
+        Ok(TestLib {
+            __library,
+            foo,
+            baz,
+            bar,
+        })
+    }
+    pub unsafe fn foo(&self) -> *mut ::std::os::raw::c_int {
+        *self.foo.as_ref().expect("Expected variable, got error.")
+    }
+    pub unsafe fn baz(&self) -> *mut *mut ::std::os::raw::c_int {
+        *self.baz.as_ref().expect("Expected variable, got error.")
+    }
+    pub unsafe fn bar(&self, x: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
+        unsafe { (self.bar.as_ref().expect("Expected function, got error."))(x) }
+    }
+}
@@ -0,0 +1,7 @@
+// bindgen-flags: --distrust-clang-mangling
+
+int foo(int x) __asm__("real_foo");
+
+extern int bar __asm__("real_bar");
+
+int baz(void);
@@ -0,0 +1,6 @@
+// bindgen-flags: --bitfield-unit-name MyBitfieldUnit --bitfield-unit-visibility crate
+
+class C {
+    bool a: 1;
+    bool b: 7;
+};
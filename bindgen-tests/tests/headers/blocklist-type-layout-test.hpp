@@ -0,0 +1,14 @@
+// bindgen-flags: --blocklist-type Blocked --raw-line "#[repr(C)] #[derive(Debug, Default, Copy, Clone)] pub struct Blocked { pub value: u64 }" -- -std=c++11
+
+/// Supplied with a different layout by the raw line, so no layout test may
+/// reference it.
+struct Blocked {
+    int value;
+};
+
+template <typename T>
+struct Wrapper {
+    T inner;
+};
+
+void consume(Wrapper<Blocked> wrapper);
@@ -0,0 +1,6 @@
+// bindgen-parse-callbacks: char-macro-as-c-char
+
+#define SEP '/'
+#define C_SEP '/'
+#define C_HIGH '\xff'
+#define INVALID_UTF8 "\xf0\x28\x8c\x28"
@@ -0,0 +1,7 @@
+// bindgen-flags: --nightly-cfg-feature nightly --clang-target x86_64-unknown-linux --output-target aarch64-unknown-linux-gnu
+
+// The layout comes from the clang target, while `long double` is mapped like
+// the IEEE 754 binary128 type of the output target.
+struct foo {
+  long double bar;
+};
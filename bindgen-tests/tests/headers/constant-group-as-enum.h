@@ -0,0 +1,11 @@
+// bindgen-flags: --constant-group-as-enum "COLOR_.*=Color" --rustified-enum Color --constant-group-as-enum "LEVEL_.*=Level"
+
+#define COLOR_RED 0
+#define COLOR_GREEN 2
+#define COLOR_BLUE 4
+
+#define LEVEL_LOW -1
+#define LEVEL_DEFAULT 0
+#define LEVEL_NORMAL LEVEL_DEFAULT
+
+#define UNGROUPED 8
@@ -0,0 +1,13 @@
+// bindgen-flags: -- -std=c++11
+
+constexpr int kAnswer = 42;
+constexpr float kRatio = 1.5f;
+constexpr double kScale = 0.25;
+
+struct Point {
+  int x;
+  int y;
+};
+
+// Not an integer, floating point or string, so it is skipped.
+constexpr Point kOrigin = {0, 0};
@@ -0,0 +1,8 @@
+// bindgen-flags: --cstr-accessor "Person::(name|nickname)"
+
+struct Person {
+    const char* name;
+    char* nickname;
+    const char* email;
+    int age;
+};
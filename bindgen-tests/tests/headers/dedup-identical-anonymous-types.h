@@ -0,0 +1,16 @@
+// bindgen-flags: --dedup-identical-anonymous-types
+
+struct Shapes {
+    struct {
+        int x;
+        int y;
+    } a;
+    struct {
+        int x;
+        int y;
+    } b;
+    struct {
+        int x;
+        int y;
+    } c;
+};
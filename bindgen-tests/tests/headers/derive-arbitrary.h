@@ -0,0 +1,25 @@
+// bindgen-flags: --derive-arbitrary "Color|Point|Node|Holder" --rustified-enum Color --raw-line "#[cfg(feature = \"arbitrary\")] const _: () = { fn assert_arbitrary<T: for<'a> arbitrary::Arbitrary<'a>>() {} let _ = assert_arbitrary::<Color>; let _ = assert_arbitrary::<Point>; };"
+
+enum Color { Red, Green };
+
+struct Point {
+    int x;
+    int y;
+    float weight;
+    unsigned char tag[4];
+};
+
+// Contains a raw pointer, so `Arbitrary` isn't derived.
+struct Node {
+    struct Point value;
+    struct Node* next;
+};
+
+// Contains a type that doesn't derive `Arbitrary`, so it isn't derived.
+struct Other {
+    int value;
+};
+
+struct Holder {
+    struct Other other;
+};
@@ -0,0 +1,10 @@
+// bindgen-flags: --derive-from-bytes "Point|WithBool"
+
+struct Point {
+    int x;
+    int y;
+};
+
+struct WithBool {
+    _Bool flag;
+};
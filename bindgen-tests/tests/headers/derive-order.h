@@ -0,0 +1,9 @@
+// bindgen-flags: --default-enum-style rust --with-derive-hash --with-derive-partialeq --with-derive-eq --with-derive-custom-struct="point=PartialOrd" --derive-order Eq,PartialEq,Hash,Clone,Copy
+struct point {
+    int x;
+    int y;
+};
+enum color {
+    red,
+    green,
+};
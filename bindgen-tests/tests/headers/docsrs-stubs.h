@@ -0,0 +1,6 @@
+// bindgen-flags: --docsrs-stubs
+
+/** Adds the two numbers. */
+int add(int a, int b);
+
+void log_message(const char *msg, ...);
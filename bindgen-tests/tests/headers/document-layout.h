@@ -0,0 +1,12 @@
+// bindgen-flags: --document-layout
+
+/** A point in the plane. */
+struct Point {
+    int x;
+    int y;
+};
+
+struct Tagged {
+    char tag;
+    double value;
+};
@@ -0,0 +1,18 @@
+// bindgen-flags: --doxygen-style rustdoc
+
+/**
+ * @brief Copies bytes between buffers.
+ *
+ * @param[out] dst The destination buffer.
+ * @param[in] src The source buffer, which must not
+ *   overlap with the destination.
+ * @param len The number of bytes to copy.
+ * @return The number of bytes copied.
+ *
+ * @warning Both buffers must be at least `len` bytes long.
+ *
+ * @code
+ * copy(dst, src, sizeof(src));
+ * @endcode
+ */
+int copy(char *dst, const char *src, int len);
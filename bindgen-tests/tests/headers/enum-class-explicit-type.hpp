@@ -0,0 +1,22 @@
+// bindgen-flags: --rustified-enum ".*" -- -std=c++11
+
+typedef unsigned char uint8_t;
+
+enum class Color : uint8_t {
+    Red,
+    Green,
+    Blue,
+};
+
+enum class Plain {
+    First,
+    Second,
+};
+
+struct Pixel {
+    Color color;
+};
+
+struct Tagged {
+    Plain plain;
+};
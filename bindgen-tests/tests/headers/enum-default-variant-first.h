@@ -0,0 +1,6 @@
+// bindgen-flags: --rustified-enum ".*" --enum-default-variant first
+
+enum Flags {
+    A = 1,
+    B = 2,
+};
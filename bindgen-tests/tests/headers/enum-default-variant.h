@@ -0,0 +1,13 @@
+// bindgen-flags: --rustified-enum ".*"
+
+enum Color {
+    Red = 1,
+    Unset = 0,
+    Blue = 2,
+};
+
+/// Has no zero-valued variant, so cannot derive Default.
+enum Flags {
+    A = 1,
+    B = 2,
+};
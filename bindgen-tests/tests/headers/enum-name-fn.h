@@ -0,0 +1,19 @@
+// bindgen-flags: --rustified-enum "Level|Mode" --with-enum-unknown-variant "Mode" --newtype-enum "Flags" --enum-name-fn --raw-line "#[test] fn enum_name_fn() { assert_eq!(Level::LEVEL_LOW.name(), \"LEVEL_LOW\"); assert_eq!(Level::LEVEL_DEFAULT.name(), \"LEVEL_MEDIUM\"); assert_eq!(Mode::MODE_READ.name(), \"MODE_READ\"); assert_eq!(Mode::from(42).name(), \"<unknown>\"); assert_eq!(Flags::FLAGS_B.name(), \"FLAGS_B\"); assert_eq!(Flags::FLAGS_ALIAS.name(), \"FLAGS_B\"); assert_eq!(Flags(42).name(), \"<unknown>\"); }"
+
+enum Level {
+    LEVEL_HIGH = 2,
+    LEVEL_LOW = 0,
+    LEVEL_MEDIUM = 1,
+    LEVEL_DEFAULT = 1,
+};
+
+enum Mode {
+    MODE_READ,
+    MODE_WRITE,
+};
+
+enum Flags {
+    FLAGS_A = 1,
+    FLAGS_B = 2,
+    FLAGS_ALIAS = 2,
+};
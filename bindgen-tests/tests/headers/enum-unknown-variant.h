@@ -0,0 +1,20 @@
+// bindgen-flags: --rustified-enum "Color|Shape" --with-enum-unknown-variant "Color|Shape" --raw-line "#[test] fn unknown_variant_round_trip() { assert_eq!(Color::from(42), Color::Unknown(42)); assert_eq!(u32::from(Color::from(42)), 42); assert_eq!(Color::from(2), Color::COLOR_BLUE); assert_eq!(u32::from(Color::COLOR_GREEN), 1); assert_eq!(Color::COLOR_DEFAULT, Color::COLOR_RED); }"
+
+enum Color {
+    COLOR_RED,
+    COLOR_GREEN,
+    COLOR_BLUE,
+    COLOR_DEFAULT = 0,
+};
+
+struct Pixel {
+    enum Color color;
+};
+
+enum Color pixel_color(const struct Pixel *pixel);
+
+// It already has an `Unknown` variant, so it's a plain rustified enum.
+enum Shape {
+    Circle,
+    Unknown,
+};
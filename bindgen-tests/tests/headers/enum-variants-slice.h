@@ -0,0 +1,13 @@
+// bindgen-flags: --rustified-enum "Level" --newtype-enum "Flags" --enum-variants-slice
+
+enum Level {
+    LEVEL_HIGH = 2,
+    LEVEL_LOW = 0,
+    LEVEL_MEDIUM = 1,
+    LEVEL_DEFAULT = 1,
+};
+
+enum Flags {
+    FLAGS_A = 1,
+    FLAGS_B = 2,
+};
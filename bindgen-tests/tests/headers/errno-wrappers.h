@@ -0,0 +1,8 @@
+// bindgen-flags: --errno-wrapper "close|read_bytes=-1" --errno-wrapper "unlink=-1:unit" --errno-wrapper "open_stream=null" --errno-wrapper "to_wide=-1"
+
+int close(int fd);
+long read_bytes(int fd, void *buf, unsigned long len);
+int unlink(const char *path);
+void *open_stream(const char *path, const char *mode);
+unsigned long to_wide(const char *src);
+int not_wrapped(int fd);
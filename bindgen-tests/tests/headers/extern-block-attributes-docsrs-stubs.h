@@ -0,0 +1,6 @@
+// bindgen-flags: --docsrs-stubs -- -Itests/headers
+// bindgen-parse-callbacks: extern-block-attributes
+
+#include "extern-block-attributes/gpu.h"
+
+void cpu_init(void);
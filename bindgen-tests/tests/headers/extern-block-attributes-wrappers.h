@@ -0,0 +1,6 @@
+// bindgen-flags: --errno-wrapper "gpu_reset=-1" --out-param "gpu_map=out" -- -Itests/headers
+// bindgen-parse-callbacks: extern-block-attributes
+
+// The wrappers are only generated along with the `extern` blocks they call into, so they compile
+// without the `gpu` feature.
+#include "extern-block-attributes/gpu_wrappers.h"
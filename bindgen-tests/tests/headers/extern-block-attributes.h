@@ -0,0 +1,7 @@
+// bindgen-flags: --merge-extern-blocks -- -Itests/headers
+// bindgen-parse-callbacks: extern-block-attributes
+
+#include "extern-block-attributes/gpu.h"
+
+void cpu_init(void);
+int cpu_count(void);
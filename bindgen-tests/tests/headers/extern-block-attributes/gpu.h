@@ -0,0 +1,3 @@
+void gpu_init(void);
+int gpu_submit(int queue);
+extern int gpu_count;
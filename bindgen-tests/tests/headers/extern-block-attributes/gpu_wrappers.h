@@ -0,0 +1,10 @@
+typedef unsigned long size_t;
+
+struct gpu_buffer {
+    int size;
+};
+
+int gpu_reset(void);
+int gpu_upload(const void *data, size_t len);
+int gpu_map(int queue, struct gpu_buffer **out);
+extern const int gpu_generation;
@@ -0,0 +1,6 @@
+// bindgen-parse-callbacks: extra-assertions
+
+struct Callbacks {
+    void (*on_event)(int event);
+    int count;
+};
@@ -0,0 +1,16 @@
+// bindgen-flags: --first-field-as-ref 'Derived|NotDerived' --raw-line "#[test] fn upcast_to_first_field() { let mut derived = Derived { base: Base { kind: 1 }, extra: 2 }; assert_eq!(AsRef::<Base>::as_ref(&derived).kind, 1); AsMut::<Base>::as_mut(&mut derived).kind = 3; assert_eq!(derived.base.kind, 3); }"
+
+struct Base {
+    int kind;
+};
+
+struct Derived {
+    struct Base base;
+    int extra;
+};
+
+// The first field is not a struct, so no `AsRef` is generated.
+struct NotDerived {
+    int kind;
+    struct Base base;
+};
@@ -0,0 +1,7 @@
+// bindgen-flags: --rustified-enum "Color" --flat-enum-constants
+
+enum Color {
+    COLOR_RED,
+    COLOR_GREEN,
+    COLOR_BLUE = 4,
+};
@@ -0,0 +1,13 @@
+typedef int (*callback_t)(void *data, int event);
+
+struct timer {
+    callback_t on_expire;
+    int interval;
+};
+
+struct button {
+    callback_t on_click;
+    callback_t on_release;
+};
+
+void register_callback(callback_t callback);
@@ -0,0 +1,8 @@
+// bindgen-flags: --detect-function-attributes
+
+__attribute__((returns_nonnull, malloc)) void *alloc_buffer(int size);
+__attribute__((pure)) int buffer_len(const void *buf);
+__attribute__((const)) int square(int x);
+
+/** Returns the name of the library. */
+__attribute__((returns_nonnull)) const char *library_name(void);
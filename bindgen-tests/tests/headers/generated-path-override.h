@@ -0,0 +1,14 @@
+// bindgen-flags: --raw-line "mod external { #[repr(C)] #[derive(Debug, Default, Copy, Clone)] pub struct Point { pub x: i32, pub y: i32 } }"
+// bindgen-parse-callbacks: generated-path-override
+
+struct Point {
+    int x;
+    int y;
+};
+
+struct Line {
+    struct Point start;
+    struct Point end;
+};
+
+int line_length(struct Line line);
@@ -0,0 +1,18 @@
+// bindgen-flags: --translate-generic-macros
+
+int abs_int(int value);
+long abs_long(long value);
+float abs_float(float value);
+double abs_double(double value);
+
+#define ABS(x) _Generic((x), int: abs_int, long: abs_long, float: abs_float, double: abs_double)(x)
+
+float scale_float(float value, int exponent);
+double scale_double(double value, int exponent);
+
+// The `default` association is skipped.
+#define SCALE(x, exp) (_Generic((x), float: scale_float, double: scale_double, default: scale_double)(x, exp))
+
+// The selected function isn't called with the parameters, so the macro is
+// skipped.
+#define ABS_SUM(x, y) _Generic((x) + (y), int: abs_int)((x) + (y))
@@ -0,0 +1,13 @@
+// bindgen-flags: --allowlist-function connect --allowlist-type Options --hide-transitive-deps
+
+typedef unsigned short port_t;
+
+struct Address {
+    port_t port;
+};
+
+struct Options {
+    int flags;
+};
+
+int connect(struct Address *addr, struct Options *opts);
@@ -0,0 +1,7 @@
+// bindgen-flags: --impl-clone-fallback
+
+// Flexible array members aren't `Clone`, so no `Clone` is implemented.
+struct packet {
+    int len;
+    unsigned char data[];
+};
@@ -0,0 +1,12 @@
+// bindgen-flags: --impl-clone-fallback --no-copy Buffer
+
+struct Buffer {
+    unsigned char data[64];
+    int len;
+};
+
+struct Wrapper {
+    struct Buffer buffers[2];
+    struct Buffer* next;
+    int id;
+};
@@ -0,0 +1,8 @@
+// bindgen-flags: --with-derive-hash --impl-hash --impl-hash-float-bits
+
+struct Reading {
+    bool a: 1;
+    bool b: 7;
+    double value;
+    float samples[4];
+};
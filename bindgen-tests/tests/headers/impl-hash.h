@@ -0,0 +1,7 @@
+// bindgen-flags: --with-derive-hash --with-derive-partialeq --impl-hash
+
+/// A struct containing a float, which cannot derive Hash.
+struct Sample {
+    int count;
+    float weight;
+};
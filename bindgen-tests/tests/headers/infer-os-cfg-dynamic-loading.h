@@ -0,0 +1,5 @@
+// bindgen-flags: --infer-os-cfg --dynamic-loading TestLib -- -Itests/headers --target=x86_64-unknown-linux-gnu
+
+#include "infer-os-cfg/usr/include/linux/input.h"
+
+int app_init(int flags);
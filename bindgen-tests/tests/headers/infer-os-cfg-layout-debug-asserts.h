@@ -0,0 +1,7 @@
+// bindgen-flags: --infer-os-cfg --layout-debug-asserts --no-layout-tests -- -Itests/headers --target=x86_64-unknown-linux-gnu
+
+#include "infer-os-cfg/usr/include/linux/input.h"
+
+struct app_state {
+    int count;
+};
@@ -0,0 +1,17 @@
+// bindgen-flags: --infer-os-cfg -- -Itests/headers --target=x86_64-unknown-linux-gnu
+
+#include "infer-os-cfg/usr/include/linux/input.h"
+
+struct app_state {
+    int count;
+};
+
+int app_init(struct app_state *state);
+
+// These refer to items of the Linux headers, so they are Linux-specific too.
+struct app_device {
+    struct input_id id;
+    int fd;
+};
+
+int app_open(struct app_device *device);
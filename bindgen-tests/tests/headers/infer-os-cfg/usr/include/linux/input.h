@@ -0,0 +1,6 @@
+struct input_id {
+    unsigned short bustype;
+    unsigned short vendor;
+};
+
+int input_open(const char *path);
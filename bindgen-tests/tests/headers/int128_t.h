@@ -1,7 +1,4 @@
-/**
- * FIXME: Uncomment this once we can generate the proper alignment for the type,
- * i.e., when we use u128/i128.
 struct Foo {
   __int128 foo;
+  unsigned __int128 bar;
 };
- */
@@ -0,0 +1,9 @@
+#pragma once
+
+#include "nsSize.h"
+
+namespace foo {
+
+extern const nsSize kDefaultIntrinsicSize;
+
+}
@@ -1,4 +1,5 @@
 #include "nsSize.h"
+#include "IntrinsicSize.h"
 
 namespace foo {
 
@@ -0,0 +1,11 @@
+// bindgen-flags: --layout-debug-asserts --no-layout-tests
+
+struct Point {
+    int x;
+    int y;
+};
+
+struct Padded {
+    char a;
+    long b;
+};
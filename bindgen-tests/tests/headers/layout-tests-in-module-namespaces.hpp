@@ -0,0 +1,10 @@
+// bindgen-flags: --enable-cxx-namespaces --layout-tests-in-module layout_tests
+
+namespace outer {
+  namespace inner {
+    struct Helper {};
+  }
+  struct Test {
+    inner::Helper helper;
+  };
+}
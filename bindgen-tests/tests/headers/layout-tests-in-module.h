@@ -0,0 +1,6 @@
+// bindgen-flags: --rust-target 1.40 --layout-tests-in-module layout_tests
+
+struct Point {
+    int x;
+    int y;
+};
@@ -0,0 +1,11 @@
+// bindgen-flags: --layout-verification-fn --no-layout-tests --raw-line "#[test] fn verify_layouts_of_correct_bindings() { assert_eq!(verify_layouts(), Ok(())); }"
+
+struct Point {
+    int x;
+    int y;
+};
+
+struct Padded {
+    char a;
+    long b;
+};
@@ -0,0 +1,11 @@
+// bindgen-flags: --libc-ctypes-shim
+
+struct Data {
+    char c;
+    int i;
+    unsigned long l;
+    double d;
+    void* p;
+};
+
+int process(struct Data* data, const char* name);
@@ -0,0 +1,5 @@
+// bindgen-flags: --nightly-cfg-feature nightly -- --target=aarch64-unknown-linux-gnu
+
+struct foo {
+  long double bar;
+};
@@ -0,0 +1,9 @@
+#define GREETING "Hello" " " "World"
+#define PREFIX "lib"
+#define LIB_NAME PREFIX "foo"
+#define UTF8_GREETING u8"Hello" ", " "World"
+
+// Wide strings can't be represented as byte strings, so these are skipped.
+#define WIDE L"Hello"
+#define WIDE_CONCAT "Hello" L"World"
+#define WIDE_DERIVED WIDE "!"
@@ -0,0 +1,10 @@
+// bindgen-flags: --map-float f32 Real64 --raw-line '#[repr(transparent)] #[derive(Debug, Default, Copy, Clone)] pub struct Real64(pub f64);'
+
+#define SCALE 2.5
+
+struct Sample {
+    float gain;
+    double value;
+};
+
+double average(const struct Sample *samples, int count);
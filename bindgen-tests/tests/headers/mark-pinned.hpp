@@ -0,0 +1,16 @@
+// bindgen-flags: --mark-pinned "List" --raw-line "const _: () = { trait AmbiguousIfUnpin<A> { fn some_item() {} } impl<T: ?Sized> AmbiguousIfUnpin<()> for T {} impl<T: ?Sized + Unpin> AmbiguousIfUnpin<u8> for T {} let _ = <List as AmbiguousIfUnpin<_>>::some_item; };"
+
+/// An intrusive list whose head points to itself when empty.
+class List {
+    List* next;
+    List* prev;
+};
+
+// Holding a pinned type makes it non-`Copy` too.
+class Owner {
+    List list;
+};
+
+class Unpinned {
+    int value;
+};
@@ -0,0 +1,10 @@
+// bindgen-flags: --max-recursion-depth 1
+//
+// `Inner` is nested too deeply to be parsed along with `Outer`, so an error
+// diagnostic is emitted for it, and it's generated as an opaque blob.
+
+struct Outer {
+    struct Inner {
+        int value;
+    } inner;
+};
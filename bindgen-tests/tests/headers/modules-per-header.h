@@ -0,0 +1,13 @@
+// bindgen-flags: --modules-per-header -- -Itests/headers
+
+#include "modules-per-header/geometry.h"
+#include "modules-per-header/point.h"
+
+struct Line {
+    struct Point start;
+    struct Point end;
+};
+
+int line_length(struct Line line);
+
+struct point point_scale(struct point p, int factor);
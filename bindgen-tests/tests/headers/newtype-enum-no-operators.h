@@ -0,0 +1,8 @@
+// bindgen-flags: --newtype-enum "Color" --raw-line "const _: () = { trait AmbiguousIfBitOr<A> { fn some_item() {} } impl<T> AmbiguousIfBitOr<()> for T {} impl<T: ::std::ops::BitOr> AmbiguousIfBitOr<u8> for T {} let _ = <Color as AmbiguousIfBitOr<_>>::some_item; };"
+
+// The variants become associated constants of the newtype, which doesn't
+// implement the bitwise operators.
+enum Color {
+    COLOR_RED,
+    COLOR_GREEN,
+};
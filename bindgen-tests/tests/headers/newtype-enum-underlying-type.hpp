@@ -0,0 +1,12 @@
+// bindgen-flags: --newtype-enum "Channel" -- -std=c++11
+
+typedef unsigned short uint16_t;
+
+enum Channel : uint16_t {
+  Left = 1,
+  Right = 2,
+};
+
+struct Mixer {
+  Channel channels[3];
+};
@@ -0,0 +1,6 @@
+// bindgen-flags: --enable-function-attribute-detection
+
+_Noreturn void die(void);
+
+// The declared return type is never returned, so it is replaced by `!` too.
+_Noreturn int die_with_status(int status);
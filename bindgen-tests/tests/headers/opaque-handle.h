@@ -0,0 +1,8 @@
+// bindgen-flags: --opaque-handle "Handle|NotAnInteger" --with-derive-hash --with-derive-partialeq --with-derive-eq
+
+typedef int Handle;
+typedef int Plain;
+typedef void* NotAnInteger;
+
+Handle open_handle(const char* name);
+void close_handle(Handle handle);
@@ -0,0 +1,13 @@
+// bindgen-flags: --opaque-no-construct Handle
+
+/// A handle that is only ever used through a pointer.
+class Handle {
+    int state;
+
+public:
+    Handle();
+    int value() const;
+};
+
+Handle* handle_create();
+void handle_destroy(Handle* handle);
@@ -0,0 +1,13 @@
+// bindgen-flags: --with-opaque-template-instantiations 'Template' -- -std=c++14
+
+template <typename T>
+class Template {
+    T member;
+};
+
+class ContainsInstantiations {
+    // Both instantiations should appear as opaque blobs of bytes, without
+    // layout tests, even though the pattern only names the template.
+    Template<int> ints;
+    Template<double> doubles;
+};
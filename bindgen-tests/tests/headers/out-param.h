@@ -0,0 +1,18 @@
+// bindgen-flags: --out-param "^get_.*=out" --out-param "find_foo=result"
+
+struct Foo {
+    int value;
+};
+
+void get_foo(struct Foo **out);
+
+int get_foo_by_id(int id, struct Foo **out);
+
+int find_foo(const char *name, const struct Foo **result);
+
+// Not a pointer to a pointer, so no wrapper is generated.
+void get_value(int *out);
+
+// The wrapper would be named like this function, so it isn't generated.
+void get_bar(struct Foo **out);
+void get_bar_out(void);
@@ -0,0 +1,7 @@
+// bindgen-flags: --raw-line '#[export_name = "sram_buffer"] static BUFFER: [i32; 16] = [0; 16];' --raw-line '#[export_name = "sram_magic"] static MAGIC: u32 = 0;'
+// bindgen-parse-callbacks: pointer-link-section
+
+// The variables in a section have to be defined for the expectation to link.
+extern int sram_buffer[16];
+extern const unsigned sram_magic;
+extern int counter;
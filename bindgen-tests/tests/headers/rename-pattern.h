@@ -0,0 +1,23 @@
+// bindgen-flags: --rename-pattern "^lib_(.*)$" "$1"
+
+typedef int lib_size;
+
+typedef struct lib_point {
+    lib_size x;
+    lib_size y;
+} lib_point;
+
+struct lib_rect {
+    lib_point origin;
+    lib_size width;
+    lib_size height;
+};
+
+enum lib_mode {
+    LIB_MODE_FILL,
+    LIB_MODE_STROKE,
+};
+
+extern enum lib_mode lib_default_mode;
+
+lib_size lib_rect_area(const struct lib_rect *rect);
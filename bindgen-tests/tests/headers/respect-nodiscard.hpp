@@ -0,0 +1,18 @@
+// bindgen-flags: --respect-nodiscard -- -std=c++17
+
+__attribute__((warn_unused_result))
+int checked(int);
+
+int unchecked(int);
+
+[[nodiscard]] int nodiscard_fn(int);
+
+struct [[nodiscard]] Status {
+    int code;
+};
+
+Status make_status();
+
+struct Plain {
+    int value;
+};
@@ -0,0 +1,12 @@
+// bindgen-flags: --with-derive-partialeq
+// bindgen-parse-callbacks: should-implement
+
+// `PartialEq` isn't derived, as `cache` shouldn't be compared.
+struct Cached {
+    int value;
+    int cache;
+};
+
+struct Plain {
+    int value;
+};
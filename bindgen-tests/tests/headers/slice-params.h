@@ -0,0 +1,19 @@
+// bindgen-parse-callbacks: slice-params
+
+typedef unsigned char uint8_t;
+typedef unsigned long size_t;
+
+int checksum(const uint8_t *data, size_t len);
+void fill(int *values, unsigned values_len, int value);
+void copy_bytes(void *dst, size_t dst_len, const void *src, size_t src_len);
+void unrelated(const char *name, int flags);
+int write_named(const char *name, const uint8_t *data, size_t len);
+
+typedef void (*callback)(int);
+struct buffer {
+    const uint8_t *data;
+    size_t len;
+};
+
+void for_each(const int *values, size_t len, callback cb);
+void append(struct buffer buf, const uint8_t *data, size_t len);
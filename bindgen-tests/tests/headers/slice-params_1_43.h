@@ -0,0 +1,4 @@
+// bindgen-flags: --rust-target 1.43
+// bindgen-parse-callbacks: slice-params
+
+void fill(int *values, unsigned values_len, int value);
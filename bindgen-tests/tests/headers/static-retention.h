@@ -0,0 +1,7 @@
+// bindgen-flags: --raw-line '#[export_name = "keep_registration"] static DEFINITION: i32 = 0;' --raw-line '#[export_name = "export_counter"] static EXPORT_DEFINITION: i32 = 0;'
+// bindgen-parse-callbacks: static-retention
+
+// The retained variables have to be defined for the expectation to link.
+extern const int keep_registration;
+extern const int export_counter;
+extern int counter;
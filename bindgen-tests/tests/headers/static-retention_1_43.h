@@ -0,0 +1,5 @@
+// bindgen-flags: --rust-target 1.43
+// bindgen-parse-callbacks: static-retention
+
+// `addr_of!` isn't available, so no shim is generated.
+extern const int keep_registration;
@@ -0,0 +1,5 @@
+// bindgen-flags: --rust-target nightly --raw-line '#[export_name = "export_counter"] static EXPORT_DEFINITION: i32 = 0;'
+// bindgen-parse-callbacks: static-retention
+
+// The `unsafe(...)` attributes are only emitted for the nightly target.
+extern const int export_counter;
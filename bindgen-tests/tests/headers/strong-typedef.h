@@ -0,0 +1,8 @@
+// bindgen-flags: --strong-typedef "Color|Shade|Name"
+
+typedef unsigned int uint32_t;
+typedef uint32_t Color;
+typedef Color Shade;
+typedef const char* Name;
+
+Color blend(Color a, Shade b);
@@ -0,0 +1,13 @@
+// bindgen-flags: --suppress-clippy --rustified-enum ".*" --errno-wrapper "close=-1"
+
+enum Dense {
+    DENSE_LOW,
+    DENSE_HIGH,
+};
+
+struct Point {
+    int x;
+    int y;
+};
+
+int close(int fd);
@@ -0,0 +1,13 @@
+// bindgen-parse-callbacks: thread-safety
+
+struct Handle {
+    void *ptr;
+};
+
+struct SharedHandle {
+    void *ptr;
+};
+
+struct NotThreadSafe {
+    void *ptr;
+};
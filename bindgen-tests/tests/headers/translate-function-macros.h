@@ -0,0 +1,11 @@
+// bindgen-flags: --translate-function-macros
+
+#define SQUARE(x) ((x) * (x))
+#define AREA(width, height) (width * height + 1)
+#define ALIGN_DOWN(value, align) ((value) & ~((align) - 1))
+
+// Not arithmetic, so these are skipped.
+#define NEXT(x) (x++)
+#define SCALED(x) ((x) * SCALE)
+
+#define ANSWER 42
@@ -0,0 +1,16 @@
+// bindgen-flags: --translate-inline-functions
+
+static inline int add(int a, int b) { return a + b; }
+
+static inline unsigned mask(unsigned value, unsigned bits) {
+    return value & ((1 << bits) - 1);
+}
+
+static inline long scale(long x, long y) { return -(x * 3) / (y % 4); }
+
+static inline int flip(int x) { return ~x ^ 0x0F; }
+
+// These cannot be translated, so no code is generated for them.
+static inline int widen(int a, short b) { return a + b; }
+static inline int max(int a, int b) { return a > b ? a : b; }
+static inline int ratio(int x) { return x / 0; }
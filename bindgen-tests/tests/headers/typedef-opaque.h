@@ -0,0 +1,18 @@
+// bindgen-flags: --typedef-opaque 'Handle|Config'
+
+struct Inner {
+    int a;
+    int b;
+};
+
+typedef struct Inner Handle;
+
+// The typedef isn't generated separately from the struct, which is kept.
+typedef struct Config {
+    int flags;
+} Config;
+
+struct Holder {
+    struct Inner inner;
+    Handle handle;
+};
@@ -0,0 +1,13 @@
+// bindgen-flags: --default-non-copy-union-style manually_drop
+
+class NonTrivial {
+public:
+    ~NonTrivial();
+    int value;
+};
+
+union Storage {
+    NonTrivial non_trivial;
+    int raw;
+    float real;
+};
@@ -0,0 +1,4 @@
+/** Writes the formatted arguments to the log. */
+int log_printf(const char *fmt, ...);
+
+typedef int (*printf_fn)(const char *fmt, ...);
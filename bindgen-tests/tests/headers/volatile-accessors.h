@@ -0,0 +1,15 @@
+// bindgen-flags: --volatile-accessors
+
+typedef unsigned int uint32_t;
+
+struct Uart {
+    volatile uint32_t data;
+    const volatile uint32_t status;
+    uint32_t config;
+};
+
+// Holding a `struct` with volatile accessors doesn't derive `Copy` either.
+struct Board {
+    struct Uart uart;
+    int id;
+};
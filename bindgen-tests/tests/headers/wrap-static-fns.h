@@ -84,3 +84,11 @@ static inline int wrap_as_variadic_fn1(int i, va_list va) {
 
 static inline void wrap_as_variadic_fn2(int i, va_list va) {}
 #endif
+
+static int counter;
+
+static const double scale;
+
+static int history[4];
+
+static int (*callback)(int);
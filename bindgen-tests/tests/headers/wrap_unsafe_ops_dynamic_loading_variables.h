@@ -0,0 +1,5 @@
+// bindgen-flags: --dynamic-loading TestLib --wrap-unsafe-ops --raw-line "#![deny(unsafe_op_in_unsafe_fn)]"
+
+int foo;
+int *baz;
+int bar(int x);
@@ -1,5 +1,6 @@
 use bindgen::callbacks::*;
 use bindgen::FieldVisibilityKind;
+use std::path::Path;
 
 #[derive(Debug)]
 pub struct RemovePrefixParseCallback {
@@ -122,6 +123,165 @@ impl ParseCallbacks for WrapAsVariadicFn {
     }
 }
 
+#[derive(Debug)]
+struct CharMacroAsCChar;
+
+impl ParseCallbacks for CharMacroAsCChar {
+    fn char_macro(&self, name: &str, _value: char) -> Option<IntKind> {
+        name.starts_with("C_")
+            .then_some(IntKind::Char { is_signed: true })
+    }
+
+    fn bytes_macro(
+        &self,
+        _name: &str,
+        _value: &[u8],
+    ) -> Option<BytesMacroKind> {
+        Some(BytesMacroKind::Slice)
+    }
+}
+
+#[derive(Debug)]
+struct ExtraAssertions;
+
+impl ParseCallbacks for ExtraAssertions {
+    fn extra_assertions(
+        &self,
+        info: &ItemInfo<'_>,
+    ) -> Vec<proc_macro2::TokenStream> {
+        let test = format!(
+            "#[test]
+             fn extra_assertions_{0}() {{
+                 assert_eq!(::std::mem::offset_of!({0}, on_event), 0usize);
+             }}",
+            info.name
+        );
+        vec![test.parse().unwrap()]
+    }
+}
+
+#[derive(Debug)]
+struct PointerLinkSection;
+
+impl ParseCallbacks for PointerLinkSection {
+    fn pointer_link_section(&self, var_name: &str) -> Option<String> {
+        var_name.starts_with("sram_").then(|| ".sram".to_owned())
+    }
+}
+
+#[derive(Debug)]
+struct StaticRetention;
+
+impl ParseCallbacks for StaticRetention {
+    fn static_retention(&self, var_name: &str) -> Retention {
+        Retention {
+            used: var_name.starts_with("keep_"),
+            no_mangle: var_name.starts_with("export_"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ShouldImplement;
+
+impl ParseCallbacks for ShouldImplement {
+    fn should_implement(
+        &self,
+        name: &str,
+        derive_trait: DeriveTrait,
+    ) -> Option<bool> {
+        match (name, derive_trait) {
+            ("Cached", DeriveTrait::PartialEqOrPartialOrd) => Some(false),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SliceParams;
+
+impl ParseCallbacks for SliceParams {
+    fn slice_param(
+        &self,
+        _func: &str,
+        ptr_param: &str,
+        len_param: &str,
+    ) -> bool {
+        len_param == "len" || len_param == format!("{}_len", ptr_param)
+    }
+
+    fn safe_slice_wrapper(&self, func: &str) -> bool {
+        func != "copy_bytes"
+    }
+}
+
+#[derive(Debug)]
+struct ExternBlockAttributes;
+
+impl ParseCallbacks for ExternBlockAttributes {
+    fn extern_block_attributes(&self, source_file: &Path) -> Vec<String> {
+        if source_file.ends_with("extern-block-attributes/gpu.h") {
+            // The unbalanced attribute is ignored.
+            vec![r#"cfg(feature = "gpu")"#.to_owned(), "cfg(".to_owned()]
+        } else if source_file
+            .ends_with("extern-block-attributes/gpu_wrappers.h")
+        {
+            vec![r#"cfg(feature = "gpu")"#.to_owned()]
+        } else {
+            vec![]
+        }
+    }
+
+    fn slice_param(
+        &self,
+        _func: &str,
+        _ptr_param: &str,
+        len_param: &str,
+    ) -> bool {
+        len_param == "len"
+    }
+
+    fn static_retention(&self, var_name: &str) -> Retention {
+        Retention {
+            used: var_name == "gpu_generation",
+            no_mangle: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct GeneratedPathOverride;
+
+impl ParseCallbacks for GeneratedPathOverride {
+    fn generated_path_override(&self, item_info: ItemInfo) -> Option<String> {
+        match (item_info.kind, item_info.name) {
+            (ItemKind::Type, "Point") => {
+                Some("self::external::Point".to_owned())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct ThreadSafetyCallback;
+
+impl ParseCallbacks for ThreadSafetyCallback {
+    fn thread_safety(&self, type_name: &str) -> ThreadSafety {
+        match type_name {
+            "Handle" => ThreadSafety {
+                send: true,
+                sync: false,
+            },
+            "SharedHandle" => ThreadSafety {
+                send: true,
+                sync: true,
+            },
+            _ => ThreadSafety::default(),
+        }
+    }
+}
+
 pub fn lookup(cb: &str) -> Box<dyn ParseCallbacks> {
     match cb {
         "enum-variant-rename" => Box::new(EnumVariantRename),
@@ -129,6 +289,15 @@ pub fn lookup(cb: &str) -> Box<dyn ParseCallbacks> {
             Box::new(BlocklistedTypeImplementsTrait)
         }
         "wrap-as-variadic-fn" => Box::new(WrapAsVariadicFn),
+        "char-macro-as-c-char" => Box::new(CharMacroAsCChar),
+        "extra-assertions" => Box::new(ExtraAssertions),
+        "pointer-link-section" => Box::new(PointerLinkSection),
+        "slice-params" => Box::new(SliceParams),
+        "static-retention" => Box::new(StaticRetention),
+        "should-implement" => Box::new(ShouldImplement),
+        "extern-block-attributes" => Box::new(ExternBlockAttributes),
+        "generated-path-override" => Box::new(GeneratedPathOverride),
+        "thread-safety" => Box::new(ThreadSafetyCallback),
         call_back => {
             if let Some(prefix) =
                 call_back.strip_prefix("remove-function-prefix-")
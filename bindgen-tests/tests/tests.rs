@@ -1,11 +1,18 @@
-use bindgen::{clang_version, Builder};
+use bindgen::callbacks::{BytesMacroKind, IntKind, ParseCallbacks, VarInfo};
+use bindgen::{
+    clang_version, Abi, AllowlistDecision, AstCursorKind, BindgenError,
+    Builder, RustEdition, RustTarget, TranslationUnitFlags,
+};
 use owo_colors::{OwoColorize, Style};
 use similar::{ChangeTag, TextDiff};
+use std::cell::Cell;
 use std::env;
 use std::fmt;
 use std::fs;
 use std::io::{BufRead, BufReader, Error, ErrorKind, Read, Write};
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 use crate::options::builder_from_flags;
 
@@ -429,6 +436,98 @@ fn test_header_contents() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn test_custom_formatter() {
+    let actual = builder()
+        .disable_header_comment()
+        .header_contents("test.h", "/** custom-formatter-marker */ int foo;")
+        .clang_arg("--target=x86_64-unknown-linux")
+        .with_formatter(Box::new(|source| {
+            Ok(source
+                .replace("custom-formatter-marker", "CUSTOM-FORMATTER-MARKER"))
+        }))
+        .generate()
+        .unwrap()
+        .to_string();
+
+    assert!(actual.contains("CUSTOM-FORMATTER-MARKER"));
+    assert!(!actual.contains("custom-formatter-marker"));
+}
+
+#[test]
+fn test_structural_anon_type_names() {
+    let generate = |contents: &str| {
+        builder()
+            .disable_header_comment()
+            .structural_anon_type_names(true)
+            .header_contents("test.h", contents)
+            .clang_arg("--target=x86_64-unknown-linux")
+            .generate()
+            .unwrap()
+            .to_string()
+    };
+    let line_of = |bindings: &str, needle: &str| {
+        bindings
+            .lines()
+            .find(|line| line.contains(needle))
+            .unwrap()
+            .to_owned()
+    };
+
+    let first = "enum { A, B }; enum { C, D };";
+    let second = "enum { C, D }; enum { A, B };";
+    let first = generate(first);
+    let second = generate(second);
+
+    assert_eq!(
+        line_of(&first, "pub const A:"),
+        line_of(&second, "pub const A:")
+    );
+    assert_eq!(
+        line_of(&first, "pub const C:"),
+        line_of(&second, "pub const C:")
+    );
+}
+
+#[test]
+fn test_header_banner() {
+    let header = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/headers/char.h");
+    let actual = builder()
+        .disable_header_comment()
+        .emit_header_banner(true)
+        .header(header)
+        .clang_arg("--target=x86_64-unknown-linux")
+        .generate()
+        .unwrap()
+        .to_string();
+
+    let banner: Vec<_> = actual.lines().take_while(|l| !l.is_empty()).collect();
+    assert!(banner[0].starts_with("// Generated by rust-bindgen "));
+    assert_eq!(banner[1], format!("//   {}", header));
+    assert!(banner
+        .last()
+        .unwrap()
+        .starts_with(&format!("//   bindgen {} ", header)));
+    assert!(banner.last().unwrap().contains("--emit-header-banner"));
+}
+
+#[test]
+fn test_command_line_flags() {
+    let flags = builder()
+        .header("input.h")
+        .use_core()
+        .allowlist_function("foo_.*")
+        .command_line_flags();
+
+    assert_eq!(flags[0], "input.h");
+    assert!(flags.iter().any(|flag| flag == "--use-core"));
+    let allowlist = flags
+        .iter()
+        .position(|flag| flag == "--allowlist-function")
+        .unwrap();
+    assert_eq!(flags[allowlist + 1], "foo_.*");
+}
+
 #[test]
 fn test_multiple_header_calls_in_builder() {
     let actual = builder()
@@ -760,3 +859,773 @@ fn test_wrap_static_fns() {
         .unwrap();
     }
 }
+
+#[test]
+fn test_error_on_empty() {
+    let generate = |error_on_empty: bool| {
+        builder()
+            .header_contents("test.h", "int foo(void);")
+            .allowlist_function("bar")
+            .clang_arg("--target=x86_64-unknown-linux")
+            .error_on_empty(error_on_empty)
+            .generate()
+    };
+
+    assert!(generate(false).is_ok());
+    assert_eq!(generate(true).unwrap_err(), BindgenError::EmptyBindings);
+}
+
+#[test]
+fn test_clang_diagnostic_fix_its() {
+    let error = builder()
+        .header_contents("test.h", "struct Point { int x; }\nint foo(void);")
+        .clang_arg("--target=x86_64-unknown-linux")
+        .generate()
+        .unwrap_err();
+
+    // Clang suggests inserting the missing semicolon after the struct.
+    match error {
+        BindgenError::ClangDiagnostic(message) => {
+            assert!(message.contains("help: insert `;` at "), "{}", message);
+        }
+        error => panic!("unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn test_treat_warnings_as_errors() {
+    let generate = |treat_warnings_as_errors: bool| {
+        builder()
+            .header_contents("test.h", "#define WIDE L\"wide\"\nint foo(void);")
+            .clang_arg("--target=x86_64-unknown-linux")
+            .treat_warnings_as_errors(treat_warnings_as_errors)
+            .generate()
+    };
+
+    // The wide string macro is dropped with a warning.
+    assert!(generate(false).is_ok());
+    assert!(matches!(
+        generate(true).unwrap_err(),
+        BindgenError::Warnings(count) if count > 0
+    ));
+
+    // The warnings of clang are counted too.
+    assert!(matches!(
+        builder()
+            .header_contents(
+                "test.h",
+                "#warning \"deprecated\"\nint foo(void);"
+            )
+            .clang_arg("--target=x86_64-unknown-linux")
+            .treat_warnings_as_errors(true)
+            .generate()
+            .unwrap_err(),
+        BindgenError::Warnings(1)
+    ));
+
+    // Redefined macros aren't counted as warnings.
+    assert!(builder()
+        .header_contents("test.h", "#define FOO 1\n#undef FOO\n#define FOO 2")
+        .clang_arg("--target=x86_64-unknown-linux")
+        .treat_warnings_as_errors(true)
+        .generate()
+        .is_ok());
+}
+
+#[test]
+fn test_max_monomorphization_warning() {
+    let generate = |limit: usize| {
+        builder()
+            .header_contents(
+                "test.hpp",
+                "template <typename T> struct Box { T value; };
+                 struct Uses { Box<int> a; Box<char> b; Box<long> c; Box<int> d; };",
+            )
+            .clang_arg("--target=x86_64-unknown-linux")
+            .max_monomorphization_warning(limit)
+            .treat_warnings_as_errors(true)
+            .generate()
+    };
+
+    // `Box` is instantiated with three different template arguments.
+    assert!(generate(3).is_ok());
+    assert_eq!(generate(2).unwrap_err(), BindgenError::Warnings(1));
+}
+
+#[test]
+fn test_response_file_include_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let include_dir = dir.path().join("include dir");
+    std::fs::create_dir(&include_dir).unwrap();
+    std::fs::write(
+        include_dir.join("from_response_file.h"),
+        "int from_response_file(void);",
+    )
+    .unwrap();
+    let response_file = dir.path().join("args.rsp");
+    std::fs::write(&response_file, format!("-I '{}'", include_dir.display()))
+        .unwrap();
+
+    let actual = builder()
+        .disable_header_comment()
+        .header_contents("test.h", "#include \"from_response_file.h\"")
+        .clang_arg(format!("@{}", response_file.display()))
+        .clang_arg("--target=x86_64-unknown-linux")
+        .generate()
+        .unwrap()
+        .to_string();
+
+    assert!(actual.contains("pub fn from_response_file()"));
+}
+
+#[test]
+fn test_replace_header_content() {
+    let dir = tempfile::tempdir().unwrap();
+    let header = dir.path().join("third_party.h");
+    std::fs::write(
+        &header,
+        "#error \"this header can't be parsed\"\nint third_party(void);",
+    )
+    .unwrap();
+
+    let actual = builder()
+        .disable_header_comment()
+        .header_contents(
+            "test.h",
+            &format!("#include \"{}\"", header.display()),
+        )
+        .replace_header_content(&header, "int third_party(void);")
+        .clang_arg("--target=x86_64-unknown-linux")
+        .generate()
+        .unwrap()
+        .to_string();
+
+    assert!(actual.contains("pub fn third_party()"));
+}
+
+#[test]
+fn test_write_by_kind() {
+    let dir = tempfile::tempdir().unwrap();
+    let bindings_dir = dir.path().join("bindings");
+    builder()
+        .header_contents(
+            "test.h",
+            "struct Point { int x; int y; };
+             #define ORIGIN 0
+             extern struct Point last_point;
+             int point_norm(const struct Point *point);",
+        )
+        .clang_arg("--target=x86_64-unknown-linux")
+        .generate()
+        .unwrap()
+        .write_by_kind(&bindings_dir)
+        .unwrap();
+
+    let read =
+        |name: &str| fs::read_to_string(bindings_dir.join(name)).unwrap();
+    assert!(read("types.rs").contains("pub struct Point"));
+    assert!(read("functions.rs").contains("pub fn point_norm("));
+    assert!(read("vars.rs").contains("pub const ORIGIN: u32 = 0;"));
+    assert!(read("vars.rs").contains("pub static mut last_point: Point;"));
+
+    // Check that the files compile together, with the functions and the
+    // variables referring to the types.
+    let lib = dir.path().join("lib.rs");
+    fs::write(
+        &lib,
+        format!(
+            "#![allow(non_snake_case, non_camel_case_types, non_upper_case_globals)]\n\
+             include!({:?});",
+            bindings_dir.join("mod.rs"),
+        ),
+    )
+    .unwrap();
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = std::process::Command::new(rustc)
+        .args(["--crate-type=lib", "--edition=2018", "--emit=metadata"])
+        .arg("--out-dir")
+        .arg(dir.path())
+        .arg(&lib)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_translation_unit_flags() {
+    let generate = |flags: TranslationUnitFlags| {
+        builder()
+            .disable_header_comment()
+            .header_contents("test.h", "#define FOO 42")
+            .clang_arg("--target=x86_64-unknown-linux")
+            .translation_unit_flags(flags)
+            .generate()
+            .unwrap()
+            .to_string()
+    };
+
+    assert!(!generate(TranslationUnitFlags::NONE).contains("FOO"));
+    assert!(generate(
+        TranslationUnitFlags::DETAILED_PREPROCESSING_RECORD |
+            TranslationUnitFlags::KEEP_GOING
+    )
+    .contains("pub const FOO: u32 = 42;"));
+}
+
+#[test]
+fn test_builtin_macros() {
+    let generate = |builtin_macros: bool, builtins: bool| {
+        let mut builder = builder()
+            .disable_header_comment()
+            .header_contents("test.h", "#define USER_MACRO 1")
+            .clang_arg("--target=x86_64-unknown-linux")
+            .generate_builtin_macros(builtin_macros);
+        if builtins {
+            builder = builder.emit_builtins();
+        }
+        builder.generate().unwrap().to_string()
+    };
+
+    let actual = generate(false, false);
+    assert!(actual.contains("pub const USER_MACRO: u32 = 1;"));
+    assert!(!actual.contains("__STDC_VERSION__"));
+
+    let actual = generate(true, false);
+    assert!(actual.contains("pub const USER_MACRO: u32 = 1;"));
+    assert!(actual.contains("pub const __STDC_VERSION__"));
+
+    // `emit_builtins` keeps emitting the built-in macros, as it used to.
+    let actual = generate(false, true);
+    assert!(actual.contains("pub const USER_MACRO: u32 = 1;"));
+    assert!(actual.contains("pub const __STDC_VERSION__"));
+}
+
+#[test]
+fn test_bytes_macro() {
+    type Seen = Arc<Mutex<Vec<(String, Vec<u8>)>>>;
+
+    #[derive(Debug)]
+    struct BytesMacros(Seen);
+
+    impl ParseCallbacks for BytesMacros {
+        fn bytes_macro(
+            &self,
+            name: &str,
+            value: &[u8],
+        ) -> Option<BytesMacroKind> {
+            self.0
+                .lock()
+                .unwrap()
+                .push((name.to_owned(), value.to_owned()));
+            None
+        }
+    }
+
+    let seen = Seen::default();
+    builder()
+        .header_contents(
+            "test.h",
+            "#define VALID \"abc\"\n#define INVALID \"\\xf0\\x28\"",
+        )
+        .clang_arg("--target=x86_64-unknown-linux")
+        .parse_callbacks(Box::new(BytesMacros(seen.clone())))
+        .generate()
+        .unwrap();
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        [("INVALID".to_owned(), vec![0xf0, 0x28])]
+    );
+}
+
+#[test]
+fn test_ast_visitor() {
+    let functions = Rc::new(Cell::new(0));
+    let counter = functions.clone();
+    builder()
+        .header_contents(
+            "test.h",
+            "int foo(void); struct Bar { int x; }; void baz(int, int);",
+        )
+        .clang_arg("--target=x86_64-unknown-linux")
+        .with_ast_visitor(Box::new(move |cursor| {
+            if cursor.kind() == AstCursorKind::Function {
+                counter.set(counter.get() + 1);
+            }
+        }))
+        .generate()
+        .unwrap();
+
+    assert_eq!(functions.get(), 2);
+}
+
+#[test]
+fn test_bitfield_unit_name() {
+    let dir = tempfile::tempdir().unwrap();
+    for (name, header) in [
+        (
+            "FlagsUnit",
+            "struct Flags { unsigned a : 1; unsigned b : 3; };",
+        ),
+        (
+            "ModeUnit",
+            "struct Mode { unsigned read : 1; unsigned write : 1; };",
+        ),
+    ] {
+        builder()
+            .header_contents("test.h", header)
+            .clang_arg("--target=x86_64-unknown-linux")
+            .bitfield_unit_name(name)
+            .generate()
+            .unwrap()
+            .write_to_file(dir.path().join(format!("{}.rs", name)))
+            .unwrap();
+    }
+
+    // Check that both bindings can be included in the same module.
+    let lib = dir.path().join("lib.rs");
+    fs::write(
+        &lib,
+        format!(
+            "include!({:?});\ninclude!({:?});",
+            dir.path().join("FlagsUnit.rs"),
+            dir.path().join("ModeUnit.rs"),
+        ),
+    )
+    .unwrap();
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = std::process::Command::new(rustc)
+        .args(["--crate-type=lib", "--edition=2018", "--emit=metadata"])
+        .arg("--out-dir")
+        .arg(dir.path())
+        .arg(&lib)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_merge_by_pointer_width() {
+    let bindings = |target| {
+        builder()
+            .header_contents(
+                "test.h",
+                "struct Node { struct Node *next; int value; };
+                 struct Point { int x; int y; };",
+            )
+            .clang_arg(format!("--target={}", target))
+            .generate()
+            .unwrap()
+    };
+
+    let merged = bindings("i686-unknown-linux")
+        .merge_by_pointer_width(bindings("x86_64-unknown-linux"))
+        .unwrap()
+        .to_string();
+
+    // `Node` contains a pointer, so it has a definition for each target, but
+    // `Point` is shared.
+    assert_eq!(merged.matches("pub struct Node {").count(), 2);
+    assert_eq!(merged.matches("pub struct Point {").count(), 1);
+    assert!(merged.contains(
+        "#[cfg(target_pointer_width = \"32\")]\n#[repr(C)]\n#[derive(Debug, Copy, Clone)]\npub struct Node {"
+    ));
+    assert!(merged.contains(
+        "#[cfg(target_pointer_width = \"64\")]\n#[repr(C)]\n#[derive(Debug, Copy, Clone)]\npub struct Node {"
+    ));
+    assert!(merged.contains("::std::mem::size_of::<Node>() - 8usize]"));
+    assert!(merged.contains("::std::mem::size_of::<Node>() - 16usize]"));
+
+    assert_eq!(
+        bindings("x86_64-unknown-linux")
+            .merge_by_pointer_width(bindings("aarch64-unknown-linux"))
+            .err(),
+        Some(BindgenError::SamePointerWidth(64))
+    );
+}
+
+#[test]
+fn test_merge_by_feature() {
+    let bindings = |define: Option<&str>| {
+        builder()
+            .header_contents(
+                "test.h",
+                "struct Config {
+                     int flags;
+                 #ifdef WITH_TRACE
+                     int trace_level;
+                 #endif
+                 };",
+            )
+            .clang_arg("--target=x86_64-unknown-linux")
+            .clang_args(define)
+            .generate()
+            .unwrap()
+    };
+
+    let merged = bindings(None)
+        .merge_by_feature(bindings(Some("-DWITH_TRACE")), "trace")
+        .unwrap()
+        .to_string();
+
+    assert_eq!(merged.matches("pub struct Config {").count(), 1);
+    assert!(merged.contains(
+        "    pub flags: ::std::os::raw::c_int,\n    #[cfg(feature = \"trace\")]\n    pub trace_level: ::std::os::raw::c_int,\n"
+    ));
+    assert!(merged.contains(
+        "#[cfg(not(feature = \"trace\"))]\nconst _: () = {\n    [\"Size of Config\"][::std::mem::size_of::<Config>() - 4usize];"
+    ));
+    assert!(merged.contains(
+        "#[cfg(feature = \"trace\")]\nconst _: () = {\n    [\"Size of Config\"][::std::mem::size_of::<Config>() - 8usize];"
+    ));
+}
+
+#[test]
+fn test_bindings_tokens() {
+    let bindings = builder()
+        .header_contents(
+            "test.h",
+            "struct Point { int x; int y; }; int dist(struct Point p);",
+        )
+        .raw_line("use foo::bar;")
+        .generate()
+        .unwrap();
+
+    let file = syn::parse2::<syn::File>(bindings.tokens().clone()).unwrap();
+    assert!(file.items.iter().any(|item| matches!(
+        item,
+        syn::Item::Struct(item) if item.ident == "Point"
+    )));
+    // The raw lines are only added when writing the bindings.
+    assert!(!file
+        .items
+        .iter()
+        .any(|item| matches!(item, syn::Item::Use(_))));
+}
+
+#[test]
+fn test_rust_edition() {
+    let bindings = |edition| {
+        builder()
+            .header_contents(
+                "test.h",
+                "@interface Foo\n@end\n@interface Bar: Foo\n@end",
+            )
+            .clang_args(["-x", "objective-c"])
+            .rust_edition(edition)
+            .generate()
+            .unwrap()
+            .to_string()
+    };
+
+    // `TryFrom` is only in the prelude since Rust 2021.
+    let edition_2015 = bindings(RustEdition::Edition2015);
+    assert!(edition_2015.contains("impl std::convert::TryFrom<Foo> for Bar {"));
+    let edition_2021 = bindings(RustEdition::Edition2021);
+    assert!(edition_2021.contains("impl TryFrom<Foo> for Bar {"));
+
+    assert_eq!(
+        builder()
+            .header_contents("test.h", "")
+            .rust_target(RustTarget::Stable_1_47)
+            .rust_edition(RustEdition::Edition2021)
+            .generate()
+            .err(),
+        Some(BindgenError::UnsupportedEdition(
+            RustEdition::Edition2021,
+            RustTarget::Stable_1_47
+        ))
+    );
+}
+
+#[test]
+fn test_unsupported_option() {
+    let bindings = |target| {
+        builder()
+            .header_contents("test.h", "void may_throw(void);")
+            .rust_target(target)
+            .override_abi(Abi::CUnwind, "may_throw")
+            .generate()
+            .err()
+    };
+
+    // The `C-unwind` ABI is only supported since Rust 1.71.
+    assert_eq!(
+        bindings(RustTarget::Stable_1_40),
+        Some(BindgenError::UnsupportedOption(
+            "--override-abi C-unwind",
+            RustTarget::Stable_1_71,
+            RustTarget::Stable_1_40
+        ))
+    );
+    assert_eq!(
+        bindings(RustTarget::Stable_1_40).unwrap().to_string(),
+        "the `--override-abi C-unwind` option is not supported by the 1.40 \
+         Rust target, it requires 1.71 or newer"
+    );
+}
+
+#[test]
+fn test_can_unwind() {
+    let bindings = |target| {
+        builder()
+            .disable_header_comment()
+            .header_contents(
+                "test.h",
+                "void may_throw(void); void nothrow(void);",
+            )
+            .clang_arg("--target=x86_64-unknown-linux")
+            .rust_target(target)
+            .can_unwind("may_throw")
+            .generate()
+    };
+
+    let actual = bindings(RustTarget::Stable_1_71).unwrap().to_string();
+    assert!(actual.contains("extern \"C-unwind\" {\n    pub fn may_throw();"));
+    assert!(actual.contains("extern \"C\" {\n    pub fn nothrow();"));
+
+    assert_eq!(
+        bindings(RustTarget::Stable_1_68).err(),
+        Some(BindgenError::UnsupportedOption(
+            "--override-abi C-unwind",
+            RustTarget::Stable_1_71,
+            RustTarget::Stable_1_68
+        ))
+    );
+}
+
+#[test]
+fn test_should_allowlist_var() {
+    #[derive(Debug)]
+    struct UnsignedShorts;
+
+    impl ParseCallbacks for UnsignedShorts {
+        fn should_allowlist_var(&self, info: &VarInfo<'_>) -> Option<bool> {
+            Some(info.int_kind == Some(IntKind::UShort))
+        }
+    }
+
+    let actual = builder()
+        .header_contents(
+            "test.h",
+            "const unsigned short PORT = 80;
+             const int COUNT = 3;
+             #define LIMIT 10",
+        )
+        .clang_arg("--target=x86_64-unknown-linux")
+        .parse_callbacks(Box::new(UnsignedShorts))
+        .generate()
+        .unwrap()
+        .to_string();
+
+    assert!(actual.contains("pub const PORT: ::std::os::raw::c_ushort = 80;"));
+    assert!(!actual.contains("COUNT"));
+    assert!(!actual.contains("LIMIT"));
+}
+
+#[test]
+fn test_opaque_no_construct() {
+    let actual = builder()
+        .disable_header_comment()
+        .header_contents(
+            "test.hpp",
+            "class Handle { int state; public: Handle(); static Handle make(); };",
+        )
+        .clang_arg("--target=x86_64-unknown-linux")
+        .derive_default(true)
+        .opaque_no_construct("Handle")
+        .generate()
+        .unwrap()
+        .to_string();
+
+    // Both fields are private, and no derives, constructors or methods that
+    // could produce a `Handle` are generated.
+    assert!(actual.contains("pub struct Handle {\n    _unused: [u8; 0],"));
+    assert!(!actual.contains("derive"));
+    assert!(!actual.contains("impl"));
+    assert!(!actual.contains("-> Handle"));
+}
+
+#[test]
+fn test_trace_allowlist() {
+    let bindings = builder()
+        .header_contents(
+            "test.h",
+            "struct Bar { int x; };
+             struct Unused { int y; };
+             void foo(struct Bar* bar);
+             void other(void);",
+        )
+        .clang_arg("--target=x86_64-unknown-linux")
+        .allowlist_function("foo")
+        .trace_allowlist(true)
+        .generate()
+        .unwrap();
+
+    let decision = |name: &str| {
+        bindings
+            .allowlist_trace()
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(|entry| entry.decision.clone())
+    };
+
+    assert_eq!(
+        decision("foo"),
+        Some(AllowlistDecision::Allowlisted {
+            option: "--allowlist-function",
+            pattern: "foo".to_owned(),
+        })
+    );
+    assert_eq!(
+        decision("Bar"),
+        Some(AllowlistDecision::PulledInBy("foo".to_owned()))
+    );
+    assert_eq!(decision("Unused"), Some(AllowlistDecision::NotAllowlisted));
+    assert_eq!(decision("other"), Some(AllowlistDecision::NotAllowlisted));
+
+    assert_eq!(
+        decision("Bar").unwrap().to_string(),
+        "generated, pulled in transitively by `foo`"
+    );
+}
+
+#[test]
+fn test_write_coverage_report() {
+    let report = tempfile::NamedTempFile::new().unwrap();
+    builder()
+        .header_contents(
+            "test.h",
+            "void foo(void);
+             void skipped(void);
+             void other(void);",
+        )
+        .clang_arg("--target=x86_64-unknown-linux")
+        .allowlist_function("foo|skipped")
+        .blocklist_function("skipped")
+        .write_coverage_report(report.path())
+        .generate()
+        .unwrap();
+
+    let report = fs::read_to_string(report.path()).unwrap();
+    let mut lines = report.lines().collect::<Vec<_>>();
+    lines.sort_unstable();
+    assert_eq!(
+        lines,
+        [
+            "foo\tgenerated\tgenerated, matched --allowlist-function `foo|skipped`",
+            "other\tnot-matched\tnot generated, not allowlisted or used by allowlisted items",
+            "skipped\tblocklisted\tnot generated, matched --blocklist-function `skipped`",
+        ]
+    );
+}
+
+#[test]
+fn test_max_recursion_depth() {
+    // Nest anonymous structs through their fields, deeper than the limit.
+    let depth = 64;
+    let header = format!(
+        "struct Deep {{ {} int value; {} }};",
+        "struct { ".repeat(depth),
+        "} inner; ".repeat(depth)
+    );
+    let actual = builder()
+        .header_contents("test.h", &header)
+        .clang_arg("--target=x86_64-unknown-linux")
+        .max_recursion_depth(32)
+        .generate()
+        .unwrap()
+        .to_string();
+
+    // The type at the limit is generated as an opaque blob, and the types
+    // nested in it aren't generated at all.
+    assert_eq!(actual.matches("pub inner: [u32; 1usize]").count(), 1);
+    assert!(!actual.contains("pub value:"));
+}
+
+#[test]
+fn test_clang_target_separate_from_output_target() {
+    let actual = builder()
+        .header_contents("test.h", "struct Foo { long a; void *b; };")
+        .clang_arg("--target=x86_64-unknown-linux")
+        .clang_target("i686-unknown-linux")
+        .output_target("x86_64-unknown-linux")
+        .generate()
+        .unwrap()
+        .to_string();
+
+    // The layout is the one of the clang target, not the output target.
+    assert!(actual
+        .contains("[\"Size of Foo\"][::std::mem::size_of::<Foo>() - 8usize]"));
+    assert!(actual.contains(
+        "[\"Alignment of Foo\"][::std::mem::align_of::<Foo>() - 4usize]"
+    ));
+    assert!(actual.contains(
+        "[\"Offset of field: Foo::b\"][::std::mem::offset_of!(Foo, b) - 4usize]"
+    ));
+}
+
+#[test]
+fn test_function_attribute_notes_without_comments() {
+    let actual = builder()
+        .header_contents(
+            "test.h",
+            "__attribute__((pure)) int count(const char *s);
+             int log_printf(const char *fmt, ...);",
+        )
+        .clang_arg("--target=x86_64-unknown-linux")
+        .detect_function_attributes(true)
+        .generate_comments(false)
+        .generate()
+        .unwrap()
+        .to_string();
+
+    assert!(actual.contains("pub fn count("));
+    assert!(!actual.contains("This function is"));
+}
+
+#[test]
+fn test_unsafe_ops_wrapped() {
+    let bindings = |wrap_unsafe_ops| {
+        builder()
+            .header_contents(
+                "test.h",
+                "int foo(int x, int y); int bar(void *x); int baz();",
+            )
+            .dynamic_library_name("TestLib")
+            .wrap_unsafe_ops(wrap_unsafe_ops)
+            .generate()
+            .unwrap()
+    };
+
+    // Loading the library and each of its functions, and calling each of
+    // them.
+    let wrapped = bindings(true);
+    assert_eq!(wrapped.unsafe_ops_wrapped(), 8);
+    assert_eq!(wrapped.to_string().matches("unsafe {").count(), 8);
+    assert!(!wrapped.to_string().contains("__bindgen_wrapped_unsafe_ops"));
+
+    assert_eq!(bindings(false).unsafe_ops_wrapped(), 0);
+}
+
+#[test]
+fn test_invalid_map_float() {
+    let actual = builder()
+        .disable_header_comment()
+        .header_contents("test.h", "float half(float x);")
+        .map_float("not a type", "crate::Real64")
+        .generate()
+        .unwrap()
+        .to_string();
+
+    // The invalid types are ignored instead of panicking.
+    assert!(actual.contains("pub fn half(x: f32) -> f32;"));
+}
@@ -4,7 +4,9 @@ pub use crate::ir::analysis::DeriveTrait;
 pub use crate::ir::derive::CanDerive as ImplementsTrait;
 pub use crate::ir::enum_ty::{EnumVariantCustomBehavior, EnumVariantValue};
 pub use crate::ir::int::IntKind;
+use proc_macro2::TokenStream;
 use std::fmt;
+use std::path::Path;
 
 /// An enum to allow ignoring parsing of macros.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
@@ -59,6 +61,25 @@ pub trait ParseCallbacks: fmt::Debug {
     /// treatment of the macro, but may use the value to generate additional code or configuration.
     fn str_macro(&self, _name: &str, _value: &[u8]) {}
 
+    /// The integer kind a character literal macro should have, given a name
+    /// and the value of that macro, or `None` if you want the default, `u8`,
+    /// to be chosen.
+    fn char_macro(&self, _name: &str, _value: char) -> Option<IntKind> {
+        None
+    }
+
+    /// The type a string macro whose value is not well-formed UTF-8 should
+    /// have, given a name and the value of that macro, or `None` if you want
+    /// the default to be chosen. This runs in addition to
+    /// [`ParseCallbacks::str_macro`].
+    fn bytes_macro(
+        &self,
+        _name: &str,
+        _value: &[u8],
+    ) -> Option<BytesMacroKind> {
+        None
+    }
+
     /// This will be run on every function-like macro. The callback cannot
     /// influence the further treatment of the macro, but may use the value to
     /// generate additional code or configuration.
@@ -121,6 +142,25 @@ pub trait ParseCallbacks: fmt::Debug {
         None
     }
 
+    /// Decide whether `_derive_trait` should be derived or implemented for the type named
+    /// `_name`, overriding the patterns of options like [`crate::Builder::no_partialeq`].
+    ///
+    /// * `None`: defer to the options
+    /// * `Some(true)`: the trait is derived or implemented if the type supports it, even if the
+    ///   type matches a pattern like the ones of [`crate::Builder::no_partialeq`]
+    /// * `Some(false)`: the trait is neither derived nor implemented for the type, nor for the
+    ///   types containing it
+    ///
+    /// The traits that aren't generated for any type, e.g. because of
+    /// [`crate::Builder::derive_partialeq`], aren't generated either way.
+    fn should_implement(
+        &self,
+        _name: &str,
+        _derive_trait: DeriveTrait,
+    ) -> Option<bool> {
+        None
+    }
+
     /// Provide a list of custom derive attributes.
     ///
     /// If no additional attributes are wanted, this function should return an
@@ -145,6 +185,128 @@ pub trait ParseCallbacks: fmt::Debug {
         None
     }
 
+    /// Decide whether a variable or constant should be allowlisted, given its name, type, and
+    /// value.
+    ///
+    /// * `None`: defer to the allowlisting options, like [`crate::Builder::allowlist_var`]
+    /// * `Some(true)`: the variable is allowlisted
+    /// * `Some(false)`: the variable is not allowlisted, even if it matches an allowlist pattern
+    fn should_allowlist_var(&self, _info: &VarInfo<'_>) -> Option<bool> {
+        None
+    }
+
+    /// Provide extra assertions to emit along with the layout tests of a `struct` or `union`.
+    ///
+    /// Each returned token stream is emitted as an item right after the layout tests of the
+    /// type, so it is usually a `#[test]` function or a `const _: () = ...;` block checking
+    /// invariants of the generated code that `bindgen` cannot know about. Nothing is emitted if
+    /// layout tests are disabled.
+    fn extra_assertions(&self, _info: &ItemInfo<'_>) -> Vec<TokenStream> {
+        vec![]
+    }
+
+    /// Specify the linker section of a pointer to the variable named `_var_name`.
+    ///
+    /// A `#[link_section = "..."]` attribute can't place the variable itself, as it is declared
+    /// in an `extern` block. If this returns a section, a `#[used]` and `#[link_section]`
+    /// `static mut __bindgen_section_<var_name>: *const T` pointing to the variable is emitted
+    /// instead. This is useful to build tables of pointers to memory-mapped symbols or other
+    /// statics in specific regions of RAM or flash.
+    ///
+    /// The pointer requires the `addr_of!` macro, so it is only emitted for Rust 1.51 or
+    /// newer.
+    fn pointer_link_section(&self, _var_name: &str) -> Option<String> {
+        None
+    }
+
+    /// Specify how the `static` generated for the variable named `_var_name` should be
+    /// retained.
+    ///
+    /// If any of the [`Retention`] flags is set, a `static mut` shim holding a pointer to the
+    /// variable is emitted along with it, named after the variable with a `__bindgen_retain_`
+    /// prefix, with the requested attributes. The attributes can't be emitted on the variable
+    /// itself, as it is declared in an `extern` block. The shim takes the address of the variable
+    /// with `addr_of!`, so it is only emitted if the Rust target is 1.51 or newer.
+    fn static_retention(&self, _var_name: &str) -> Retention {
+        Retention::default()
+    }
+
+    /// Specify whether the pointer parameter `_ptr_param` of the function `_func` and the
+    /// length parameter `_len_param` following it should be taken as a slice.
+    ///
+    /// If this returns `true` for any pair of parameters of a function, a wrapper named after
+    /// the function with a `_slice` suffix is generated. It takes a `&[T]` or a `&mut [T]`,
+    /// depending on the constness of the pointee, instead of each such pair, and splits it into
+    /// a pointer and a length to call the function. Slices of `void` are taken as slices of
+    /// bytes. The wrapper panics if a slice is longer than its length parameter can hold.
+    ///
+    /// The wrapper is an `unsafe` function, unless [`ParseCallbacks::safe_slice_wrapper`]
+    /// returns `true` for the function.
+    ///
+    /// This is only called for adjacent named parameters that are a pointer followed by an
+    /// integer.
+    fn slice_param(
+        &self,
+        _func: &str,
+        _ptr_param: &str,
+        _len_param: &str,
+    ) -> bool {
+        false
+    }
+
+    /// Specify whether the wrapper taking slices generated for the function `_func` because of
+    /// [`ParseCallbacks::slice_param`] should be a safe function.
+    ///
+    /// Returning `true` asserts that the function is safe to call with any such slices. The
+    /// wrapper is still `unsafe` if it takes other parameters through which the function may
+    /// access arbitrary memory, i.e. pointers, function pointers, arrays, structs or unions.
+    fn safe_slice_wrapper(&self, _func: &str) -> bool {
+        false
+    }
+
+    /// Provide a list of attributes to emit on the `extern` blocks declaring the functions and
+    /// variables from the file at `_source_file`.
+    ///
+    /// Each returned string is the content of an attribute, e.g. `cfg(feature = "gpu")` for
+    /// `#[cfg(feature = "gpu")]`. This is most useful along with
+    /// [`Builder::merge_extern_blocks`](crate::Builder::merge_extern_blocks), as only the blocks
+    /// with the same attributes are merged, which keeps one block per source file.
+    ///
+    /// The attributes that can't be parsed are ignored with a warning. The `cfg` attributes
+    /// are also emitted on the stubs generated with
+    /// [`Builder::docsrs_stubs`](crate::Builder::docsrs_stubs) and on the wrappers and shims
+    /// generated for these functions and variables, e.g. the ones generated with
+    /// [`Builder::errno_wrappers`](crate::Builder::errno_wrappers).
+    fn extern_block_attributes(&self, _source_file: &Path) -> Vec<String> {
+        vec![]
+    }
+
+    /// Redirect the type described by `_item_info` to an existing type, e.g. one defined by
+    /// another crate, by returning its path, e.g. `other_crate::Foo`.
+    ///
+    /// Instead of its definition, the type is then emitted as `pub use other_crate::Foo as
+    /// Name;`, so that the generated items referring to it use the existing type. That type
+    /// should have the same layout as the C/C++ type, and implement the traits derived for the
+    /// generated types using it. Unlike [`ParseCallbacks::generated_name_override`], which only
+    /// renames functions and variables, this makes the type disappear from the bindings.
+    fn generated_path_override(
+        &self,
+        _item_info: ItemInfo<'_>,
+    ) -> Option<String> {
+        None
+    }
+
+    /// Specify which of `Send` and `Sync` to implement for the `struct` or `union` named
+    /// `_type_name`, e.g. for a handle type documented as thread-safe whose raw pointer fields
+    /// make it neither `Send` nor `Sync`.
+    ///
+    /// The traits are implemented with an `unsafe impl`, documented with a `SAFETY:` comment
+    /// referring to this callback, so returning them is an assertion that the type is actually
+    /// thread-safe.
+    fn thread_safety(&self, _type_name: &str) -> ThreadSafety {
+        ThreadSafety::default()
+    }
+
     /// Process a function name that as exactly one `va_list` argument
     /// to be wrapped as a variadic function with the wrapped static function
     /// feature.
@@ -156,6 +318,39 @@ pub trait ParseCallbacks: fmt::Debug {
     }
 }
 
+/// The attributes of the shim emitted to retain the `static` generated for a variable, see
+/// [`ParseCallbacks::static_retention`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Retention {
+    /// Emit `#[used]` on the shim, so that it is kept along with the variable it refers to even
+    /// if neither is used, e.g. for plugin registration tables.
+    pub used: bool,
+    /// Emit `#[no_mangle]` on the shim, so that it is exported under its own name. It is emitted
+    /// as `#[unsafe(no_mangle)]`, as required by the 2024 edition, for the nightly Rust target.
+    pub no_mangle: bool,
+}
+
+/// The type to generate a byte string macro with, see [`ParseCallbacks::bytes_macro`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BytesMacroKind {
+    /// A reference to the nul-terminated byte array, e.g. `&[u8; 3]`.
+    Array,
+    /// A reference to the nul-terminated byte slice, `&[u8]`.
+    Slice,
+    /// A reference to a `CStr`. This falls back to [`BytesMacroKind::Array`] if the value contains
+    /// a nul byte or the Rust target doesn't support `CStr` constants.
+    CStr,
+}
+
+/// The thread-safety traits to implement for a type, see [`ParseCallbacks::thread_safety`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadSafety {
+    /// Implement `Send` for the type, so that it can be sent to another thread.
+    pub send: bool,
+    /// Implement `Sync` for the type, so that it can be shared between threads.
+    pub sync: bool,
+}
+
 /// Relevant information about a type to which new derive attributes will be added using
 /// [`ParseCallbacks::add_derives`].
 #[derive(Debug)]
@@ -178,7 +373,8 @@ pub enum TypeKind {
     Union,
 }
 
-/// A struct providing information about the item being passed to [`ParseCallbacks::generated_name_override`].
+/// A struct providing information about the item being passed to [`ParseCallbacks::generated_name_override`]
+/// or [`ParseCallbacks::extra_assertions`].
 #[non_exhaustive]
 pub struct ItemInfo<'a> {
     /// The name of the item
@@ -194,6 +390,24 @@ pub enum ItemKind {
     Function,
     /// A Variable
     Var,
+    /// A Type
+    Type,
+}
+
+/// Relevant information about a variable or constant whose allowlisting can be decided using
+/// [`ParseCallbacks::should_allowlist_var`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct VarInfo<'a> {
+    /// The name of the variable, as matched against the allowlist patterns.
+    pub name: &'a str,
+    /// The name of the type of the variable, if the type is named, e.g. a `typedef` or an
+    /// `enum`.
+    pub type_name: Option<&'a str>,
+    /// The kind of the type of the variable, if it is an integer.
+    pub int_kind: Option<IntKind>,
+    /// The value of the variable, if it is an integer constant.
+    pub value: Option<i64>,
 }
 
 /// Relevant information about a field for which visibility can be determined using
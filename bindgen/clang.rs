@@ -13,6 +13,7 @@ use std::fmt;
 use std::fs::OpenOptions;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::marker::PhantomData;
 use std::os::raw::{c_char, c_int, c_longlong, c_uint, c_ulong, c_ulonglong};
 use std::sync::OnceLock;
 use std::{mem, ptr, slice};
@@ -36,6 +37,14 @@ impl Attribute {
         token_kind: CXToken_Identifier,
     };
 
+    /// A `[[nodiscard]]` attribute, if libclang doesn't expose it as a
+    /// `warn_unused_result` one.
+    pub(crate) const NODISCARD: Self = Self {
+        name: b"nodiscard",
+        kind: None,
+        token_kind: CXToken_Identifier,
+    };
+
     /// A `_Noreturn` attribute.
     pub(crate) const NO_RETURN: Self = Self {
         name: b"_Noreturn",
@@ -49,6 +58,34 @@ impl Attribute {
         kind: None,
         token_kind: CXToken_Identifier,
     };
+
+    /// A `pure` attribute.
+    pub(crate) const PURE: Self = Self {
+        name: b"pure",
+        kind: Some(CXCursor_PureAttr),
+        token_kind: CXToken_Identifier,
+    };
+
+    /// A `const` attribute.
+    pub(crate) const CONST: Self = Self {
+        name: b"const",
+        kind: Some(CXCursor_ConstAttr),
+        token_kind: CXToken_Keyword,
+    };
+
+    /// A `malloc` attribute.
+    pub(crate) const MALLOC: Self = Self {
+        name: b"malloc",
+        kind: None,
+        token_kind: CXToken_Identifier,
+    };
+
+    /// A `returns_nonnull` attribute.
+    pub(crate) const RETURNS_NON_NULL: Self = Self {
+        name: b"returns_nonnull",
+        kind: None,
+        token_kind: CXToken_Identifier,
+    };
 }
 
 /// A cursor into the Clang AST, pointing to an AST node.
@@ -114,6 +151,22 @@ impl Cursor {
         unsafe { cxstring_into_string(clang_Cursor_getMangling(self.x)) }
     }
 
+    /// Get the label given to this cursor's referent with `__asm__("label")`,
+    /// if any.
+    ///
+    /// The label is the exact symbol name the declaration is emitted with.
+    pub(crate) fn asm_label(&self) -> Option<String> {
+        let mut label = None;
+        self.visit(|cur| {
+            if cur.kind() == CXCursor_AsmLabelAttr {
+                label = Some(cur.spelling());
+                return CXChildVisit_Break;
+            }
+            CXChildVisit_Continue
+        });
+        label.filter(|label| !label.is_empty())
+    }
+
     /// Gets the C++ manglings for this cursor, or an error if the manglings
     /// are not available.
     pub(crate) fn cxx_manglings(&self) -> Result<Vec<String>, ()> {
@@ -136,9 +189,16 @@ impl Cursor {
     }
 
     /// Returns whether the cursor refers to a built-in definition.
+    ///
+    /// This includes the definitions from clang's predefines and command-line
+    /// buffers, which depending on the clang version either have no file or a
+    /// pseudo-file like `<built-in>` or `<command line>`.
     pub(crate) fn is_builtin(&self) -> bool {
         let (file, _, _, _) = self.location().location();
-        file.name().is_none()
+        match file.name() {
+            None => true,
+            Some(name) => name.starts_with('<') && name.ends_with('>'),
+        }
     }
 
     /// Get the `Cursor` for this cursor's referent's lexical parent.
@@ -733,6 +793,12 @@ impl Cursor {
         }
     }
 
+    /// Is the referent a scoped enum declaration, that is, an `enum class` or
+    /// `enum struct`?
+    pub(crate) fn is_scoped_enum(&self) -> bool {
+        unsafe { clang_EnumDecl_isScoped(self.x) != 0 }
+    }
+
     /// Get the boolean constant value for this cursor's enum variant referent.
     ///
     /// Returns None if the cursor's referent is not an enum variant.
@@ -811,6 +877,26 @@ impl Cursor {
         found_attrs
     }
 
+    /// Does this cursor have a `warn_unused_result` or `[[nodiscard]]`
+    /// attribute?
+    pub(crate) fn has_must_use_attr(&self) -> bool {
+        let [must_use, nodiscard] =
+            self.has_attrs(&[Attribute::MUST_USE, Attribute::NODISCARD]);
+        must_use || nodiscard
+    }
+
+    /// Is this cursor's referent declared as `constexpr`?
+    pub(crate) fn is_constexpr(&self) -> bool {
+        let name = self.spelling();
+        self.tokens()
+            .iter()
+            .take_while(|token| token.spelling() != name.as_bytes())
+            .any(|token| {
+                token.kind == CXToken_Keyword &&
+                    token.spelling() == b"constexpr"
+            })
+    }
+
     /// Given that this cursor's referent is a `typedef`, get the `Type` that is
     /// being aliased.
     pub(crate) fn typedef_type(&self) -> Option<Type> {
@@ -1061,7 +1147,7 @@ impl ClangToken {
             // expressions, so we strip them down here.
             CXToken_Comment => return None,
             _ => {
-                warn!("Found unexpected token kind: {:?}", self);
+                warning!("Found unexpected token kind: {:?}", self);
                 return None;
             }
         };
@@ -1264,6 +1350,11 @@ impl Type {
         unsafe { clang_isConstQualifiedType(self.x) != 0 }
     }
 
+    /// Is this type volatile qualified?
+    pub(crate) fn is_volatile(&self) -> bool {
+        unsafe { clang_isVolatileQualifiedType(self.x) != 0 }
+    }
+
     #[inline]
     fn is_non_deductible_auto_type(&self) -> bool {
         debug_assert_eq!(self.kind(), CXType_Auto);
@@ -1799,6 +1890,297 @@ impl Drop for Index {
     }
 }
 
+/// A read-only view of a cursor into the Clang AST, handed to the visitors
+/// set up with [`crate::Builder::with_ast_visitor`].
+///
+/// It can't outlive the visitor call it is created for, as the translation
+/// unit it points into is disposed once bindgen is done parsing it.
+pub struct AstCursor<'tu> {
+    cursor: Cursor,
+    _translation_unit: PhantomData<&'tu TranslationUnit>,
+}
+
+impl<'tu> AstCursor<'tu> {
+    /// Wrap the given cursor, for the duration of a visitor call.
+    pub(crate) fn new(cursor: Cursor) -> Self {
+        Self {
+            cursor,
+            _translation_unit: PhantomData,
+        }
+    }
+
+    /// Get the Unified Symbol Resolution of this cursor's referent, if
+    /// available.
+    pub fn usr(&self) -> Option<String> {
+        self.cursor.usr()
+    }
+
+    /// Get the kind of this cursor's referent.
+    pub fn kind(&self) -> AstCursorKind {
+        AstCursorKind::from_raw(self.cursor.kind())
+    }
+
+    /// Is this cursor's referent a declaration?
+    pub fn is_declaration(&self) -> bool {
+        self.cursor.is_declaration()
+    }
+
+    /// Is this cursor's referent a definition?
+    pub fn is_definition(&self) -> bool {
+        self.cursor.is_definition()
+    }
+
+    /// Get the spelling of this cursor's referent.
+    pub fn spelling(&self) -> String {
+        self.cursor.spelling()
+    }
+
+    /// Get the display name of this cursor's referent.
+    ///
+    /// This is not necessarily a valid identifier. It includes extra
+    /// information, such as parameters for a function, etc.
+    pub fn display_name(&self) -> String {
+        self.cursor.display_name()
+    }
+
+    /// Get the raw declaration comment of this cursor's referent, if one
+    /// exists.
+    pub fn raw_comment(&self) -> Option<String> {
+        self.cursor.raw_comment()
+    }
+
+    /// Get the linkage of this cursor's referent, if it is a function or a
+    /// variable.
+    pub fn linkage(&self) -> Option<AstLinkage> {
+        Some(match self.cursor.linkage() {
+            CXLinkage_NoLinkage => AstLinkage::None,
+            CXLinkage_Internal => AstLinkage::Internal,
+            CXLinkage_UniqueExternal => AstLinkage::UniqueExternal,
+            CXLinkage_External => AstLinkage::External,
+            _ => return None,
+        })
+    }
+
+    /// Get the semantic parent of this cursor's referent, if it has one.
+    pub fn semantic_parent(&self) -> Option<AstCursor<'tu>> {
+        self.cursor.fallible_semantic_parent().map(AstCursor::new)
+    }
+
+    /// Get the children of this cursor's referent.
+    pub fn children(&self) -> Vec<AstCursor<'tu>> {
+        self.cursor
+            .collect_children()
+            .into_iter()
+            .map(AstCursor::new)
+            .collect()
+    }
+}
+
+impl fmt::Debug for AstCursor<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        self.cursor.fmt(fmt)
+    }
+}
+
+/// The kind of the referent of an [`AstCursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AstCursorKind {
+    /// A `struct` declaration.
+    Struct,
+    /// A `union` declaration.
+    Union,
+    /// A C++ `class` declaration.
+    Class,
+    /// An `enum` declaration.
+    Enum,
+    /// An enum constant.
+    EnumConstant,
+    /// A field of a `struct`, `union` or `class`.
+    Field,
+    /// A function declaration.
+    Function,
+    /// A variable declaration.
+    Variable,
+    /// A function parameter.
+    Parameter,
+    /// A `typedef` declaration.
+    Typedef,
+    /// A C++ type alias declaration, i.e. `using T = U;`.
+    TypeAlias,
+    /// A C++ namespace.
+    Namespace,
+    /// A C++ method.
+    Method,
+    /// A C++ constructor.
+    Constructor,
+    /// A C++ destructor.
+    Destructor,
+    /// A C++ class template.
+    ClassTemplate,
+    /// A C++ function template.
+    FunctionTemplate,
+    /// Any other kind of referent.
+    Other,
+}
+
+impl AstCursorKind {
+    fn from_raw(kind: CXCursorKind) -> Self {
+        match kind {
+            CXCursor_StructDecl => Self::Struct,
+            CXCursor_UnionDecl => Self::Union,
+            CXCursor_ClassDecl => Self::Class,
+            CXCursor_EnumDecl => Self::Enum,
+            CXCursor_EnumConstantDecl => Self::EnumConstant,
+            CXCursor_FieldDecl => Self::Field,
+            CXCursor_FunctionDecl => Self::Function,
+            CXCursor_VarDecl => Self::Variable,
+            CXCursor_ParmDecl => Self::Parameter,
+            CXCursor_TypedefDecl => Self::Typedef,
+            CXCursor_TypeAliasDecl => Self::TypeAlias,
+            CXCursor_Namespace => Self::Namespace,
+            CXCursor_CXXMethod => Self::Method,
+            CXCursor_Constructor => Self::Constructor,
+            CXCursor_Destructor => Self::Destructor,
+            CXCursor_ClassTemplate => Self::ClassTemplate,
+            CXCursor_FunctionTemplate => Self::FunctionTemplate,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// The linkage of the referent of an [`AstCursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AstLinkage {
+    /// No linkage, e.g. a local variable.
+    None,
+    /// Internal linkage, e.g. a `static` function.
+    Internal,
+    /// External linkage within an anonymous namespace.
+    UniqueExternal,
+    /// External linkage.
+    External,
+}
+
+/// Flags controlling how libclang parses the translation unit.
+///
+/// Flags can be combined using the `|` operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TranslationUnitFlags(CXTranslationUnit_Flags);
+
+impl TranslationUnitFlags {
+    /// No flags are set.
+    pub const NONE: Self = Self(CXTranslationUnit_None);
+    /// Keep a detailed record of the preprocessing, which is needed to see macro definitions.
+    pub const DETAILED_PREPROCESSING_RECORD: Self =
+        Self(CXTranslationUnit_DetailedPreprocessingRecord);
+    /// Allow the translation unit to be incomplete, as it is the case for headers that are
+    /// meant to be included by other files.
+    pub const INCOMPLETE: Self = Self(CXTranslationUnit_Incomplete);
+    /// Skip the bodies of the functions.
+    pub const SKIP_FUNCTION_BODIES: Self =
+        Self(CXTranslationUnit_SkipFunctionBodies);
+    /// Keep going after a fatal error, such as a missing `#include`.
+    pub const KEEP_GOING: Self = Self(CXTranslationUnit_KeepGoing);
+    /// Only parse the main file, without following `#include` directives.
+    pub const SINGLE_FILE_PARSE: Self = Self(CXTranslationUnit_SingleFileParse);
+
+    /// The named flags, along with the name they are parsed from and displayed as.
+    const NAMED: &'static [(&'static str, Self)] = &[
+        (
+            "detailed_preprocessing_record",
+            Self::DETAILED_PREPROCESSING_RECORD,
+        ),
+        ("incomplete", Self::INCOMPLETE),
+        ("skip_function_bodies", Self::SKIP_FUNCTION_BODIES),
+        ("keep_going", Self::KEEP_GOING),
+        ("single_file_parse", Self::SINGLE_FILE_PARSE),
+    ];
+
+    /// Get the raw bits of these flags.
+    pub const fn bits(self) -> c_int {
+        self.0
+    }
+
+    /// Whether all the flags in `other` are set in these flags.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Default for TranslationUnitFlags {
+    fn default() -> Self {
+        Self::DETAILED_PREPROCESSING_RECORD
+    }
+}
+
+impl std::ops::BitOr for TranslationUnitFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for TranslationUnitFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl fmt::Display for TranslationUnitFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = Self::NAMED
+            .iter()
+            .filter(|(_, flag)| self.contains(*flag))
+            .map(|(name, _)| *name)
+            .peekable();
+
+        if names.peek().is_none() {
+            return f.write_str("none");
+        }
+
+        for (i, name) in names.enumerate() {
+            if i != 0 {
+                f.write_str("|")?;
+            }
+            f.write_str(name)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for TranslationUnitFlags {
+    type Err = std::io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut flags = Self::NONE;
+
+        for name in s.split('|').map(str::trim) {
+            if name == "none" {
+                continue;
+            }
+
+            match Self::NAMED.iter().find(|(n, _)| *n == name) {
+                Some((_, flag)) => flags |= *flag,
+                None => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "Got an invalid translation unit flag: '{}'",
+                            name
+                        ),
+                    ))
+                }
+            }
+        }
+
+        Ok(flags)
+    }
+}
+
 /// A translation unit (or "compilation unit").
 pub(crate) struct TranslationUnit {
     x: CXTranslationUnit,
@@ -2009,6 +2391,52 @@ impl Diagnostic {
     pub(crate) fn severity(&self) -> CXDiagnosticSeverity {
         unsafe { clang_getDiagnosticSeverity(self.x) }
     }
+
+    /// Get the fix-it hints clang suggests to address this diagnostic message.
+    pub(crate) fn fix_its(&self) -> Vec<FixIt> {
+        unsafe {
+            let num = clang_getDiagnosticNumFixIts(self.x);
+            (0..num)
+                .map(|i| {
+                    let mut range = mem::zeroed();
+                    let replacement = cxstring_into_string(
+                        clang_getDiagnosticFixIt(self.x, i, &mut range),
+                    );
+                    let start = clang_getRangeStart(range);
+                    let end = clang_getRangeEnd(range);
+                    FixIt {
+                        replacement,
+                        location: SourceLocation { x: start },
+                        is_insertion: clang_equalLocations(start, end) != 0,
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+/// A fix-it hint of a diagnostic message, replacing the code at some location
+/// with some text.
+pub(crate) struct FixIt {
+    replacement: String,
+    location: SourceLocation,
+    is_insertion: bool,
+}
+
+impl fmt::Display for FixIt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_insertion {
+            write!(f, "insert `{}` at {}", self.replacement, self.location)
+        } else if self.replacement.is_empty() {
+            write!(f, "remove the code at {}", self.location)
+        } else {
+            write!(
+                f,
+                "replace the code at {} with `{}`",
+                self.location, self.replacement
+            )
+        }
+    }
 }
 
 impl Drop for Diagnostic {
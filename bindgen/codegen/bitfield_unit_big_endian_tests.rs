@@ -0,0 +1,96 @@
+//! Tests for `__BindgenBitfieldUnit` on big-endian targets.
+//!
+//! Note that bit-fields are allocated left to right (most to least
+//! significant bits) on big-endian targets, so the first bit of the unit is
+//! the most significant bit of its first byte.
+//!
+//! From the PowerPC and s390x ELF ABIs:
+//!
+//! ```c
+//! struct {
+//!     unsigned a : 3;
+//!     unsigned b : 9;
+//!     unsigned c : 4;
+//! };
+//! ```
+//!
+//! ```ignore
+//! +------------------------------------------------------------+
+//! |          |                      |          |               |
+//! |    a     |          b           |    c     |    padding    |
+//! |31      29|28                  20|19      16|15            0|
+//! +------------------------------------------------------------+
+//! ```
+//!
+//! The unit is compiled with its endianness checks as on a big-endian target,
+//! so that these tests run on any host.
+
+use self::big_endian::__BindgenBitfieldUnit;
+
+#[allow(warnings)]
+mod big_endian {
+    // Shadows the built-in `cfg!` used by the unit to check the endianness.
+    macro_rules! cfg {
+        (target_endian = "big") => {
+            true
+        };
+    }
+
+    include!("bitfield_unit.rs");
+}
+
+/// The storage of the fields above, with `a = 0b101`, `b = 0b100110110` and
+/// `c = 0b1001`, without the padding.
+const STORAGE: [u8; 2] = [0b10110011, 0b01101001];
+
+#[test]
+fn bitfield_unit_get_bit() {
+    let unit = __BindgenBitfieldUnit::<[u8; 2]>::new(STORAGE);
+
+    let mut bits = vec![];
+    for i in 0..16 {
+        bits.push(unit.get_bit(i));
+    }
+
+    assert_eq!(
+        bits,
+        &[
+            // 0b10110011
+            true, false, true, true, false, false, true, true,
+            // 0b01101001
+            false, true, true, false, true, false, false, true
+        ]
+    );
+}
+
+#[test]
+fn bitfield_unit_get() {
+    let unit = __BindgenBitfieldUnit::<[u8; 2]>::new(STORAGE);
+
+    assert_eq!(unit.get(0, 3), 0b101);
+    assert_eq!(unit.get(3, 9), 0b100110110);
+    assert_eq!(unit.get(12, 4), 0b1001);
+}
+
+#[test]
+fn bitfield_unit_set() {
+    let mut unit = __BindgenBitfieldUnit::<[u8; 2]>::new([0, 0]);
+    unit.set(0, 3, 0b101);
+    unit.set(3, 9, 0b100110110);
+    unit.set(12, 4, 0b1001);
+
+    assert_eq!(unit, __BindgenBitfieldUnit::new(STORAGE));
+}
+
+#[test]
+fn bitfield_unit_const() {
+    const UNIT: __BindgenBitfieldUnit<[u8; 2]> =
+        __BindgenBitfieldUnit::new([0, 0])
+            .set_const(0, 3, 0b101)
+            .set_const(3, 9, 0b100110110)
+            .set_const(12, 4, 0b1001);
+    const VALUE: u64 = UNIT.get_const(3, 9);
+
+    assert_eq!(UNIT, __BindgenBitfieldUnit::new(STORAGE));
+    assert_eq!(VALUE, 0b100110110);
+}
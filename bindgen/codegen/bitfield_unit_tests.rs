@@ -92,6 +92,7 @@ macro_rules! bitfield_unit_get {
                 println!("actual   = {:064b}", actual);
 
                 assert_eq!(expected, actual);
+                assert_eq!(expected, unit.get_const($start, $len));
             })*
         }
    }
@@ -194,6 +195,10 @@ macro_rules! bitfield_unit_set {
                 println!("actual   = {:064b}", actual);
 
                 assert_eq!($expected, actual);
+
+                let unit = __BindgenBitfieldUnit::<[u8; 4]>::new([0, 0, 0, 0])
+                    .set_const($start, $len, $val);
+                assert_eq!($expected, unit.get_const(0, 32));
             )*
         }
     }
@@ -258,3 +263,34 @@ bitfield_unit_set! {
     set(7, 16, 0b1111111111111111) is 0b00000000011111111111111110000000;
     set(8, 16, 0b1111111111111111) is 0b00000000111111111111111100000000;
 }
+
+#[test]
+fn bitfield_unit_const() {
+    const UNIT: __BindgenBitfieldUnit<[u8; 2]> =
+        __BindgenBitfieldUnit::new([0, 0]).set_const(3, 7, 0b1011001);
+    const VALUE: u64 = UNIT.get_const(3, 7);
+
+    let mut unit = __BindgenBitfieldUnit::<[u8; 2]>::new([0, 0]);
+    unit.set(3, 7, 0b1011001);
+
+    assert_eq!(UNIT, unit);
+    assert_eq!(VALUE, 0b1011001);
+}
+
+#[test]
+fn bitfield_unit_multiple_fields() {
+    // struct { unsigned a : 3; unsigned b : 9; unsigned c : 4; }, with
+    // `a = 0b101`, `b = 0b100110110` and `c = 0b1001`.
+    let storage = [0b10110101, 0b10011001];
+
+    let unit = __BindgenBitfieldUnit::<[u8; 2]>::new(storage);
+    assert_eq!(unit.get(0, 3), 0b101);
+    assert_eq!(unit.get(3, 9), 0b100110110);
+    assert_eq!(unit.get(12, 4), 0b1001);
+
+    let mut unit = __BindgenBitfieldUnit::<[u8; 2]>::new([0, 0]);
+    unit.set(0, 3, 0b101);
+    unit.set(3, 9, 0b100110110);
+    unit.set(12, 4, 0b1001);
+    assert_eq!(unit, __BindgenBitfieldUnit::new(storage));
+}
@@ -0,0 +1,91 @@
+use crate::ir::analysis::HasVtable;
+use crate::ir::comp::{CompInfo, Field, FieldMethods};
+use crate::ir::context::{BindgenContext, TypeId};
+use crate::ir::item::{IsOpaque, Item};
+use crate::ir::ty::TypeKind;
+
+/// The reason why `arbitrary::Arbitrary` can't be derived for the specified
+/// compound type, if any.
+///
+/// Raw pointers are rejected because arbitrary bytes can't make a meaningful
+/// pointer. Other fields are rejected if their generated type, e.g.
+/// `__BindgenBitfieldUnit`, doesn't implement `Arbitrary`.
+pub(crate) fn blocker(
+    ctx: &BindgenContext,
+    comp_info: &CompInfo,
+    item: &Item,
+) -> Option<&'static str> {
+    if item.is_opaque(ctx, &()) {
+        return None;
+    }
+
+    if comp_info.is_union() {
+        return Some("it is a union");
+    }
+
+    if item.has_vtable(ctx) {
+        return Some("it contains a raw pointer");
+    }
+
+    comp_info
+        .base_members()
+        .iter()
+        .filter(|base| base.requires_storage(ctx))
+        .find_map(|base| blocker_ty(ctx, base.ty))
+        .or_else(|| {
+            comp_info.fields().iter().find_map(|field| match *field {
+                Field::DataMember(ref fd) => blocker_ty(ctx, fd.ty()),
+                Field::Bitfields(..) => Some("it contains bitfields"),
+            })
+        })
+}
+
+/// The reason for a field whose type doesn't derive `Arbitrary`.
+const NOT_DERIVED: &str =
+    "it contains a field whose type doesn't derive `Arbitrary`";
+
+/// The reason why the given field type doesn't implement `Arbitrary`, if any.
+fn blocker_ty(ctx: &BindgenContext, ty: TypeId) -> Option<&'static str> {
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+
+    match *item.expect_type().kind() {
+        TypeKind::Int(..) | TypeKind::Float(..) | TypeKind::Opaque => None,
+        // Enums generated as constants are integers, the other ones only
+        // implement `Arbitrary` if they derive it too.
+        TypeKind::Enum(ref enum_) => {
+            if enum_.computed_enum_variation(ctx, item).is_const() ||
+                ctx.derive_arbitrary_by_name(item)
+            {
+                None
+            } else {
+                Some(NOT_DERIVED)
+            }
+        }
+        TypeKind::Array(_, 0) => Some("it contains a flexible array member"),
+        TypeKind::Array(inner, _) | TypeKind::Vector(inner, _) => {
+            blocker_ty(ctx, inner)
+        }
+        TypeKind::Comp(ref info) => {
+            if ctx.derive_arbitrary_by_name(item) {
+                blocker(ctx, info, item)
+            } else {
+                Some(NOT_DERIVED)
+            }
+        }
+        TypeKind::Pointer(..) |
+        TypeKind::Reference(..) |
+        TypeKind::Function(..) |
+        TypeKind::BlockPointer(..) |
+        TypeKind::ObjCId |
+        TypeKind::ObjCSel |
+        TypeKind::ObjCInterface(..) => Some("it contains a raw pointer"),
+        _ if item.is_opaque(ctx, &()) => None,
+        _ => {
+            Some("it contains a field whose type doesn't implement `Arbitrary`")
+        }
+    }
+}
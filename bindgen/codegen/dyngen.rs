@@ -68,11 +68,46 @@ pub(crate) struct DynamicItems {
     init_fields: Vec<proc_macro2::TokenStream>,
 }
 
+/// The number of tokens in each list of a [`DynamicItems`] at some point, see
+/// [`DynamicItems::add_attribute_since`].
+#[derive(Clone, Copy)]
+pub(crate) struct DynamicItemsMark([usize; 4]);
+
 impl DynamicItems {
     pub(crate) fn new() -> Self {
         Self::default()
     }
 
+    /// The current number of tokens in each list.
+    pub(crate) fn mark(&self) -> DynamicItemsMark {
+        DynamicItemsMark([
+            self.struct_members.len(),
+            self.struct_implementation.len(),
+            self.constructor_inits.len(),
+            self.init_fields.len(),
+        ])
+    }
+
+    /// Add the given attribute, e.g. a `#[cfg(...)]`, to the fields, methods
+    /// and initializations of the items pushed since the given mark.
+    pub(crate) fn add_attribute_since(
+        &mut self,
+        DynamicItemsMark(mark): DynamicItemsMark,
+        attribute: &TokenStream,
+    ) {
+        let mut lists = [
+            &mut self.struct_members,
+            &mut self.struct_implementation,
+            &mut self.constructor_inits,
+            &mut self.init_fields,
+        ];
+        for (list, start) in lists.iter_mut().zip(mark) {
+            for tokens in &mut list[start..] {
+                *tokens = quote! { #attribute #tokens };
+            }
+        }
+    }
+
     pub(crate) fn get_tokens(
         &self,
         lib_ident: Ident,
@@ -83,11 +118,10 @@ impl DynamicItems {
         let init_fields = &self.init_fields;
         let struct_implementation = &self.struct_implementation;
 
-        let from_library = if ctx.options().wrap_unsafe_ops {
-            quote!(unsafe { Self::from_library(library) })
-        } else {
-            quote!(Self::from_library(library))
-        };
+        let library_new =
+            ctx.wrap_unsafe_ops(quote!(::libloading::Library::new(path)));
+        let from_library =
+            ctx.wrap_unsafe_ops(quote!(Self::from_library(library)));
 
         quote! {
             pub struct #lib_ident {
@@ -100,7 +134,7 @@ impl DynamicItems {
                     path: P
                 ) -> Result<Self, ::libloading::Error>
                 where P: AsRef<::std::ffi::OsStr> {
-                    let library = ::libloading::Library::new(path)?;
+                    let library = #library_new?;
                     #from_library
                 }
 
@@ -157,11 +191,8 @@ impl DynamicItems {
         } else {
             quote! { self.#ident.as_ref().expect("Expected function, got error.") }
         };
-        let call_body = if ctx.options().wrap_unsafe_ops {
-            quote!(unsafe { (#fn_)(#( #args_identifiers ),*) })
-        } else {
-            quote!((#fn_)(#( #args_identifiers ),*) )
-        };
+        let call_body =
+            ctx.wrap_unsafe_ops(quote!((#fn_)(#( #args_identifiers ),*)));
 
         // We can't implement variadic functions from C easily, so we allow to
         // access the function pointer so that the user can call it just fine.
@@ -176,11 +207,8 @@ impl DynamicItems {
 
         // N.B: Unwrap the signature upon construction if it is required to be resolved.
         let ident_str = codegen::helpers::ast_ty::cstr_expr(ident.to_string());
-        let library_get = if ctx.options().wrap_unsafe_ops {
-            quote!(unsafe { __library.get(#ident_str) })
-        } else {
-            quote!(__library.get(#ident_str))
-        };
+        let library_get =
+            ctx.wrap_unsafe_ops(quote!(__library.get(#ident_str)));
 
         self.constructor_inits.push(if is_required {
             quote! {
@@ -202,6 +230,7 @@ impl DynamicItems {
         ident: Ident,
         ty: TokenStream,
         is_required: bool,
+        ctx: &BindgenContext,
     ) {
         let member = if is_required {
             quote! { *mut #ty }
@@ -225,13 +254,16 @@ impl DynamicItems {
         });
 
         let ident_str = codegen::helpers::ast_ty::cstr_expr(ident.to_string());
+        let library_get =
+            ctx.wrap_unsafe_ops(quote!(__library.get::<*mut #ty>(#ident_str)));
+
         self.constructor_inits.push(if is_required {
             quote! {
-                let #ident = __library.get::<*mut #ty>(#ident_str).map(|sym| *sym)?;
+                let #ident = #library_get.map(|sym| *sym)?;
             }
         } else {
             quote! {
-                let #ident = __library.get::<*mut #ty>(#ident_str).map(|sym| *sym);
+                let #ident = #library_get.map(|sym| *sym);
             }
         });
 
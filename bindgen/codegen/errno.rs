@@ -0,0 +1,87 @@
+use crate::ir::context::BindgenContext;
+use crate::ir::function::FunctionSig;
+use crate::ir::ty::TypeKind;
+
+use super::{helpers, ToRustTyOrOpaque};
+use super::{ErrnoConvention, ErrnoSuccess, ErrnoValue};
+
+use proc_macro2::Ident;
+use quote::ToTokens;
+
+/// Generate a wrapper returning a `std::io::Result` for the function with the
+/// given signature and identifier, which follows the given `errno`
+/// convention.
+///
+/// Returns `None` if the return type of the function cannot hold the error
+/// value of the convention.
+pub(crate) fn gen_errno_wrapper(
+    ctx: &BindgenContext,
+    signature: &FunctionSig,
+    ident: &Ident,
+    convention: ErrnoConvention,
+) -> Option<proc_macro2::TokenStream> {
+    if signature.is_variadic() || signature.is_divergent() {
+        return None;
+    }
+
+    let ret_item = signature
+        .return_type()
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+    let ty = signature.return_type().to_rust_ty_or_opaque(ctx, &());
+
+    let is_error = match (ret_item.expect_type().kind(), convention.error_value)
+    {
+        (&TypeKind::Int(kind), ErrnoValue::Int(value)) => {
+            if kind.is_signed() || value >= 0 {
+                let value = helpers::ast_ty::int_expr(value);
+                quote! { ret == #value }
+            } else {
+                // `(size_t)-1` and alike.
+                let value = proc_macro2::Literal::i64_suffixed(value);
+                quote! { ret == #value as #ty }
+            }
+        }
+        (&TypeKind::Int(kind), ErrnoValue::Negative) if kind.is_signed() => {
+            quote! { ret < 0 }
+        }
+        (&TypeKind::Pointer(..), ErrnoValue::Null)
+            if !signature.returns_non_null() =>
+        {
+            quote! { ret.is_null() }
+        }
+        _ => return None,
+    };
+
+    let (ok_ty, ok) = match convention.success {
+        ErrnoSuccess::Value => (ty.into_token_stream(), quote! { ret }),
+        ErrnoSuccess::Unit => (quote! { () }, quote! { () }),
+    };
+
+    let wrapper = ctx.rust_ident(format!("{}_checked", ident));
+    let args = super::utils::fnsig_arguments(ctx, signature);
+    let arg_names = super::utils::fnsig_argument_identifiers(ctx, signature);
+    let call = ctx.wrap_unsafe_ops(quote! { #ident( #( #arg_names ),* ) });
+    let doc = format!(
+        " Calls [`{}`], returning the error stored in `errno` if it fails.",
+        ident
+    );
+
+    let allow_clippy =
+        super::helpers::allow_clippy(ctx, &["missing_safety_doc"]);
+
+    Some(quote! {
+        #[doc = #doc]
+        #allow_clippy
+        pub unsafe fn #wrapper( #( #args ),* ) -> ::std::io::Result<#ok_ty> {
+            let ret = #call;
+            if #is_error {
+                Err(::std::io::Error::last_os_error())
+            } else {
+                Ok(#ok)
+            }
+        }
+    })
+}
@@ -0,0 +1,273 @@
+use crate::ir::context::{BindgenContext, TypeId};
+use crate::ir::function::{Function, FunctionKind};
+use crate::ir::generic_selection::GenericSelection;
+use crate::ir::int::IntKind;
+use crate::ir::item::{Item, ItemCanonicalName};
+use crate::ir::item_kind::ItemKind;
+use crate::ir::ty::{FloatKind, TypeKind};
+use crate::HashSet;
+
+use super::{helpers, utils, CodegenResult};
+
+/// Generate a trait for the given `_Generic` macro, implemented for the type
+/// of every association that can be translated by calling the function it
+/// selects.
+///
+/// Returns `None` if none of the associations can be translated.
+pub(crate) fn gen_generic_selection_trait(
+    ctx: &BindgenContext,
+    selection: &GenericSelection,
+    result: &CodegenResult<'_>,
+) -> Option<proc_macro2::TokenStream> {
+    let name = selection.name();
+    let controlling_param = selection.controlling_param();
+    let ident = ctx.rust_ident(name);
+
+    if selection.has_default() {
+        skipped_association_diagnostic(
+            name,
+            "default",
+            "it doesn't select the function of a single type",
+        );
+    }
+
+    let params: Vec<_> = selection
+        .params()
+        .iter()
+        .map(|param| ctx.rust_ident(param))
+        .collect();
+    let other_params: Vec<_> = params
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != controlling_param)
+        .map(|(_, param)| param)
+        .collect();
+
+    let mut other_param_types = None;
+    let mut translated_types = HashSet::default();
+    let mut impls = vec![];
+    for (type_name, function_name) in selection.associations() {
+        let skip = |reason| {
+            skipped_association_diagnostic(name, type_name, reason);
+        };
+
+        let (item, function) = match find_function(ctx, function_name) {
+            Some(function) => function,
+            None => {
+                skip("the function it selects isn't generated");
+                continue;
+            }
+        };
+        let canonical_name = item.canonical_name(ctx);
+        if !result
+            .seen_function(function.mangled_name().unwrap_or(&canonical_name))
+        {
+            skip("the function it selects isn't generated");
+            continue;
+        }
+
+        let signature = match *ctx.resolve_type(function.signature()).kind() {
+            TypeKind::Function(ref signature) => signature,
+            _ => panic!("Signature kind is not a Function: {:?}", function),
+        };
+        if signature.is_variadic() || signature.is_divergent() {
+            skip("the function it selects is variadic or doesn't return");
+            continue;
+        }
+        let argument_types: Vec<_> = signature
+            .argument_types()
+            .iter()
+            .map(|&(_, ty)| ty)
+            .collect();
+        if argument_types.len() != params.len() {
+            skip("the function it selects doesn't take the parameters of the macro");
+            continue;
+        }
+
+        let key = match arithmetic_type_key(
+            ctx,
+            argument_types[controlling_param],
+            type_name,
+        ) {
+            Some(key) => key,
+            None => {
+                skip("it isn't the arithmetic type of the controlling parameter of the function it selects");
+                continue;
+            }
+        };
+        if !translated_types.insert(key) {
+            skip("it's the same Rust type as the one of another association");
+            continue;
+        }
+
+        let self_ty =
+            utils::fnsig_argument_type(ctx, &argument_types[controlling_param]);
+        let types: Vec<_> = argument_types
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != controlling_param)
+            .map(|(_, ty)| utils::fnsig_argument_type(ctx, ty))
+            .collect();
+        let types = quote! { #( #other_params: #types ),* };
+        match other_param_types {
+            None => other_param_types = Some(types.clone()),
+            Some(ref other_types)
+                if other_types.to_string() != types.to_string() =>
+            {
+                skip("the function it selects doesn't take the same other parameters as the ones of the other associations");
+                continue;
+            }
+            Some(_) => {}
+        }
+
+        let output = utils::fnsig_return_ty_internal(ctx, signature);
+        let function_ident = ctx.rust_ident(canonical_name);
+        let args = params.iter().enumerate().map(|(i, param)| {
+            if i == controlling_param {
+                quote! { self }
+            } else {
+                quote! { #param }
+            }
+        });
+        let call =
+            ctx.wrap_unsafe_ops(quote! { #function_ident( #( #args ),* ) });
+        impls.push(quote! {
+            impl #ident for #self_ty {
+                type Output = #output;
+                #[inline]
+                unsafe fn #ident(self, #types) -> Self::Output {
+                    #call
+                }
+            }
+        });
+    }
+
+    let other_param_types = other_param_types?;
+    let doc = format!(
+        " The functions selected by the `_Generic` selection of the `{}` macro, by the type of its `{}` parameter.",
+        name,
+        selection.params()[controlling_param],
+    );
+    let allow_clippy = helpers::allow_clippy(ctx, &["missing_safety_doc"]);
+
+    Some(quote! {
+        #[doc = #doc]
+        #allow_clippy
+        pub trait #ident {
+            type Output;
+            unsafe fn #ident(self, #other_param_types) -> Self::Output;
+        }
+        #( #impls )*
+    })
+}
+
+/// Find the plain function with the given name that is generated.
+fn find_function<'a>(
+    ctx: &'a BindgenContext,
+    name: &str,
+) -> Option<(&'a Item, &'a Function)> {
+    ctx.items().find_map(|(id, item)| match *item.kind() {
+        ItemKind::Function(ref function)
+            if function.kind() == FunctionKind::Function &&
+                function.name() == name &&
+                ctx.codegen_items().contains(&id) =>
+        {
+            Some((item, function))
+        }
+        _ => None,
+    })
+}
+
+/// The kind and size of the given type, which distinguish the Rust types of
+/// the arithmetic types, if it's the arithmetic type named by the given C
+/// type name.
+fn arithmetic_type_key(
+    ctx: &BindgenContext,
+    ty: TypeId,
+    type_name: &str,
+) -> Option<(&'static str, usize)> {
+    let ty = ty
+        .into_resolver()
+        .through_type_refs()
+        .resolve(ctx)
+        .expect_type();
+    let canonical = ty.canonical_type(ctx);
+    if ty.name() != Some(type_name) &&
+        !is_builtin_type(canonical.kind(), type_name)
+    {
+        return None;
+    }
+
+    let size = canonical.layout(ctx)?.size;
+    match *canonical.kind() {
+        TypeKind::Int(IntKind::Bool) => Some(("bool", size)),
+        TypeKind::Int(kind) if kind.is_signed() => Some(("signed", size)),
+        TypeKind::Int(_) => Some(("unsigned", size)),
+        TypeKind::Float(FloatKind::Float | FloatKind::Double) => {
+            Some(("float", size))
+        }
+        _ => None,
+    }
+}
+
+/// Whether the given type kind is the one of the builtin arithmetic type
+/// named by the given C type name.
+fn is_builtin_type(kind: &TypeKind, type_name: &str) -> bool {
+    let words: Vec<_> = type_name.split_whitespace().collect();
+    let expected = match *words.as_slice() {
+        ["_Bool"] | ["bool"] => IntKind::Bool,
+        ["char"] => {
+            return matches!(*kind, TypeKind::Int(IntKind::Char { .. }))
+        }
+        ["signed", "char"] => IntKind::SChar,
+        ["unsigned", "char"] => IntKind::UChar,
+        ["float"] => return matches!(*kind, TypeKind::Float(FloatKind::Float)),
+        ["double"] => {
+            return matches!(*kind, TypeKind::Float(FloatKind::Double))
+        }
+        _ => {
+            if words.is_empty() ||
+                !words.iter().all(|word| {
+                    matches!(
+                        *word,
+                        "signed" | "unsigned" | "int" | "short" | "long"
+                    )
+                })
+            {
+                return false;
+            }
+
+            let is_unsigned = words.contains(&"unsigned");
+            let size: Vec<_> = words
+                .iter()
+                .copied()
+                .filter(|word| matches!(*word, "short" | "long"))
+                .collect();
+            match (size.as_slice(), is_unsigned) {
+                ([], false) => IntKind::Int,
+                ([], true) => IntKind::UInt,
+                (["short"], false) => IntKind::Short,
+                (["short"], true) => IntKind::UShort,
+                (["long"], false) => IntKind::Long,
+                (["long"], true) => IntKind::ULong,
+                (["long", "long"], false) => IntKind::LongLong,
+                (["long", "long"], true) => IntKind::ULongLong,
+                _ => return false,
+            }
+        }
+    };
+    matches!(*kind, TypeKind::Int(kind) if kind == expected)
+}
+
+fn skipped_association_diagnostic(
+    macro_name: &str,
+    type_name: &str,
+    reason: &str,
+) {
+    warning!(
+        "Skipping the `{}` association of the `_Generic` macro `{}`, as {}.",
+        type_name,
+        macro_name,
+        reason
+    );
+}
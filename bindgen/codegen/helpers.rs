@@ -36,18 +36,58 @@ pub(crate) mod attributes {
         }
     }
 
+    /// The derive of `arbitrary::Arbitrary`, only enabled if the `arbitrary`
+    /// feature of the crate including the bindings is.
+    pub(crate) fn derive_arbitrary() -> TokenStream {
+        quote! {
+            #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+        }
+    }
+
     pub(crate) fn inline() -> TokenStream {
         quote! {
             #[inline]
         }
     }
 
+    pub(crate) fn doc_hidden() -> TokenStream {
+        quote! {
+            #[doc(hidden)]
+        }
+    }
+
     pub(crate) fn must_use() -> TokenStream {
         quote! {
             #[must_use]
         }
     }
 
+    pub(crate) fn track_caller() -> TokenStream {
+        quote! {
+            #[track_caller]
+        }
+    }
+
+    pub(crate) fn used() -> TokenStream {
+        quote! {
+            #[used]
+        }
+    }
+
+    /// The `#[no_mangle]` attribute, marked as `unsafe` if the Rust target
+    /// supports it, as required by the 2024 edition.
+    pub(crate) fn no_mangle(unsafe_attributes: bool) -> TokenStream {
+        if unsafe_attributes {
+            quote! {
+                #[unsafe(no_mangle)]
+            }
+        } else {
+            quote! {
+                #[no_mangle]
+            }
+        }
+    }
+
     pub(crate) fn non_exhaustive() -> TokenStream {
         quote! {
             #[non_exhaustive]
@@ -75,6 +115,49 @@ pub(crate) mod attributes {
             #[link_name = #name]
         }
     }
+
+    pub(crate) fn link_section(section: &str) -> TokenStream {
+        quote! {
+            #[link_section = #section]
+        }
+    }
+
+    /// A `#[cfg(...)]` with the given predicate, along with the matching
+    /// `#[cfg_attr(docsrs, doc(cfg(...)))]` if `doc_cfg` is set.
+    pub(crate) fn cfg(predicate: TokenStream, doc_cfg: bool) -> TokenStream {
+        if doc_cfg {
+            quote! {
+                #[cfg( #predicate )]
+                #[cfg_attr(docsrs, doc(cfg( #predicate )))]
+            }
+        } else {
+            quote! {
+                #[cfg( #predicate )]
+            }
+        }
+    }
+
+    /// An `#[allow(...)]` of the given clippy lints.
+    pub(crate) fn allow_clippy(lints: &[&str]) -> TokenStream {
+        let lints =
+            lints.iter().map(|lint| Ident::new(lint, Span::call_site()));
+        quote! {
+            #[allow( #( clippy::#lints ),* )]
+        }
+    }
+}
+
+/// The `#[allow(...)]` of the given clippy lints, if
+/// [`crate::Builder::suppress_clippy`] is enabled.
+pub(crate) fn allow_clippy(
+    ctx: &BindgenContext,
+    lints: &[&str],
+) -> Option<proc_macro2::TokenStream> {
+    if ctx.options().suppress_clippy {
+        Some(attributes::allow_clippy(lints))
+    } else {
+        None
+    }
 }
 
 /// Generates a proper type for a field or type with a given `Layout`, that is,
@@ -89,7 +172,7 @@ pub(crate) fn blob(ctx: &BindgenContext, layout: Layout) -> syn::Type {
     let ty = match opaque.known_rust_type_for_array(ctx) {
         Some(ty) => ty,
         None => {
-            warn!("Found unknown alignment on code generation!");
+            warning!("Found unknown alignment on code generation!");
             syn::parse_quote! { u8 }
         }
     };
@@ -113,10 +196,20 @@ pub(crate) fn integer_type(
 
 pub(crate) const BITFIELD_UNIT: &str = "__BindgenBitfieldUnit";
 
+/// The name of the bitfield allocation unit type, see
+/// [`crate::Builder::bitfield_unit_name`].
+pub(crate) fn bitfield_unit_name(ctx: &BindgenContext) -> &str {
+    ctx.options()
+        .bitfield_unit_name
+        .as_deref()
+        .unwrap_or(BITFIELD_UNIT)
+}
+
 /// Generates a bitfield allocation unit type for a type with the given `Layout`.
 pub(crate) fn bitfield_unit(ctx: &BindgenContext, layout: Layout) -> syn::Type {
     let size = layout.size;
-    let bitfield_unit_name = Ident::new(BITFIELD_UNIT, Span::call_site());
+    let bitfield_unit_name =
+        Ident::new(bitfield_unit_name(ctx), Span::call_site());
     let ty = syn::parse_quote! { #bitfield_unit_name<[u8; #size]> };
 
     if ctx.options().enable_cxx_namespaces {
@@ -135,6 +228,16 @@ pub(crate) mod ast_ty {
     use proc_macro2::TokenStream;
     use std::str::FromStr;
 
+    /// The path of the module emitted when the `libc_ctypes_shim` option is
+    /// enabled.
+    fn ctypes_shim(ctx: &BindgenContext) -> TokenStream {
+        if ctx.options().enable_cxx_namespaces {
+            quote! { root::__bindgen_ctypes }
+        } else {
+            quote! { __bindgen_ctypes }
+        }
+    }
+
     pub(crate) fn c_void(ctx: &BindgenContext) -> syn::Type {
         // ctypes_prefix takes precedence
         match ctx.options().ctypes_prefix {
@@ -142,6 +245,10 @@ pub(crate) mod ast_ty {
                 let prefix = TokenStream::from_str(prefix.as_str()).unwrap();
                 syn::parse_quote! { #prefix::c_void }
             }
+            None if ctx.options().libc_ctypes_shim => {
+                let shim = ctypes_shim(ctx);
+                syn::parse_quote! { #shim::c_void }
+            }
             None => {
                 if ctx.options().use_core &&
                     ctx.options().rust_features.core_ffi_c_void
@@ -161,6 +268,10 @@ pub(crate) mod ast_ty {
                 let prefix = TokenStream::from_str(prefix.as_str()).unwrap();
                 syn::parse_quote! { #prefix::#ident }
             }
+            None if ctx.options().libc_ctypes_shim => {
+                let shim = ctypes_shim(ctx);
+                syn::parse_quote! { #shim::#ident }
+            }
             None => {
                 if ctx.options().use_core &&
                     ctx.options().rust_features().core_ffi_c
@@ -228,6 +339,40 @@ pub(crate) mod ast_ty {
         }
     }
 
+    /// Whether the floating point type of the given kind and layout is an IEEE
+    /// 754 binary128 type, which can be represented as `f128`.
+    pub(crate) fn float_kind_is_f128(
+        ctx: &BindgenContext,
+        fk: FloatKind,
+        layout: Option<Layout>,
+    ) -> bool {
+        match fk {
+            FloatKind::Float128 => true,
+            FloatKind::LongDouble => {
+                layout.is_some_and(|layout| layout.size == 16) &&
+                    ctx.is_target_long_double_binary128()
+            }
+            _ => false,
+        }
+    }
+
+    /// The type `float` or `double` was mapped to with the `map_float`
+    /// option, if any.
+    pub(crate) fn mapped_float_type(
+        ctx: &BindgenContext,
+        fk: FloatKind,
+    ) -> Option<syn::Type> {
+        let (ref float, ref double) =
+            *ctx.options().mapped_float_types.as_ref()?;
+        let path = match fk {
+            FloatKind::Float => float,
+            FloatKind::Double => double,
+            _ => return None,
+        };
+        // The paths are checked before generating the bindings.
+        syn::parse_str(path).ok()
+    }
+
     pub(crate) fn float_kind_rust_type(
         ctx: &BindgenContext,
         fk: FloatKind,
@@ -237,6 +382,16 @@ pub(crate) mod ast_ty {
         // often?
         //
         // Also, maybe this one shouldn't be the default?
+        if ctx.options().rust_features().f128 &&
+            float_kind_is_f128(ctx, fk, layout)
+        {
+            return syn::parse_quote! { f128 };
+        }
+
+        if let Some(ty) = mapped_float_type(ctx, fk) {
+            return ty;
+        }
+
         match (fk, ctx.options().convert_floats) {
             (FloatKind::Float16, _) => {
                 // TODO: do f16 when rust lands it
@@ -251,27 +406,21 @@ pub(crate) mod ast_ty {
             (FloatKind::Double, true) => syn::parse_quote! { f64 },
             (FloatKind::Float, false) => raw_type(ctx, "c_float"),
             (FloatKind::Double, false) => raw_type(ctx, "c_double"),
-            (FloatKind::LongDouble, _) => {
-                match layout {
-                    Some(layout) => {
-                        match layout.size {
-                            4 => syn::parse_quote! { f32 },
-                            8 => syn::parse_quote! { f64 },
-                            // TODO(emilio): If rust ever gains f128 we should
-                            // use it here and below.
-                            _ => super::integer_type(ctx, layout)
-                                .unwrap_or(syn::parse_quote! { f64 }),
-                        }
-                    }
-                    None => {
-                        debug_assert!(
-                            false,
-                            "How didn't we know the layout for a primitive type?"
-                        );
-                        syn::parse_quote! { f64 }
-                    }
+            (FloatKind::LongDouble, _) => match layout {
+                Some(layout) => match layout.size {
+                    4 => syn::parse_quote! { f32 },
+                    8 => syn::parse_quote! { f64 },
+                    _ => super::integer_type(ctx, layout)
+                        .unwrap_or(syn::parse_quote! { f64 }),
+                },
+                None => {
+                    debug_assert!(
+                        false,
+                        "How didn't we know the layout for a primitive type?"
+                    );
+                    syn::parse_quote! { f64 }
                 }
-            }
+            },
             (FloatKind::Float128, _) => {
                 if ctx.options().rust_features.i128_and_u128 {
                     syn::parse_quote! { u128 }
@@ -354,7 +503,7 @@ pub(crate) mod ast_ty {
             return Ok(tokens);
         }
 
-        warn!("Unknown non-finite float number: {:?}", f);
+        warning!("Unknown non-finite float number: {:?}", f);
         Err(())
     }
 
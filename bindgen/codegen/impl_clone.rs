@@ -0,0 +1,106 @@
+use crate::ir::analysis::HasVtable;
+use crate::ir::comp::{CompInfo, CompKind, Field, FieldMethods};
+use crate::ir::context::{BindgenContext, TypeId};
+use crate::ir::derive::CanDeriveCopy;
+use crate::ir::item::{IsOpaque, Item};
+use crate::ir::template::TemplateParameters;
+use crate::ir::ty::TypeKind;
+
+/// Whether a manual implementation of `Clone` can be generated for the
+/// specified compound type, which couldn't derive `Copy`.
+///
+/// This is only the case for plain structs whose fields are all `Clone`.
+pub(crate) fn can_gen_clone_impl(
+    ctx: &BindgenContext,
+    comp_info: &CompInfo,
+    item: &Item,
+) -> bool {
+    if comp_info.kind() != CompKind::Struct ||
+        comp_info.is_forward_declaration() ||
+        !item.all_template_params(ctx).is_empty() ||
+        item.has_vtable(ctx) ||
+        ctx.opaque_no_construct_by_name(item) ||
+        ctx.pinned_by_name(item) ||
+        ctx.lookup_has_destructor(item.id().expect_type_id(ctx)) ||
+        comp_info.has_volatile_accessors(
+            ctx,
+            item.expect_type().layout(ctx).as_ref(),
+        )
+    {
+        return false;
+    }
+
+    if ctx.options().flexarray_dst && comp_info.flex_array_member(ctx).is_some()
+    {
+        return false;
+    }
+
+    if item.is_opaque(ctx, &()) {
+        return true;
+    }
+
+    comp_info
+        .base_members()
+        .iter()
+        .all(|base| !base.requires_storage(ctx) || is_clone(ctx, base.ty)) &&
+        comp_info.fields().iter().all(|field| match *field {
+            Field::DataMember(ref fd) => is_clone(ctx, fd.ty()),
+            // Bitfield units are always `Copy`.
+            Field::Bitfields(..) => true,
+        })
+}
+
+/// Whether the given type is `Clone`, either by deriving `Copy` or by having
+/// a manual implementation of `Clone` generated for it.
+fn is_clone(ctx: &BindgenContext, ty: TypeId) -> bool {
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+
+    match *item.expect_type().kind() {
+        TypeKind::Int(..) |
+        TypeKind::Float(..) |
+        TypeKind::Complex(..) |
+        TypeKind::Pointer(..) |
+        TypeKind::Function(..) => true,
+        // Zero-length and flexible arrays are emitted as
+        // `__IncompleteArrayField`, which isn't `Clone`.
+        TypeKind::Array(_, 0) => false,
+        TypeKind::Array(inner, _) => is_clone(ctx, inner),
+        TypeKind::Comp(ref info) => {
+            (item.can_derive_copy(ctx) && !item.annotations().disallow_copy()) ||
+                (ctx.options().impl_clone_fallback &&
+                    can_gen_clone_impl(ctx, info, item))
+        }
+        _ => item.can_derive_copy(ctx) && !item.annotations().disallow_copy(),
+    }
+}
+
+/// Generate a manual implementation of `Clone` trait cloning each one of the
+/// given struct fields.
+pub(crate) fn gen_clone_impl(
+    ctx: &BindgenContext,
+    fields: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let fields = syn::parse2::<syn::FieldsNamed>(quote! {
+        { #( #fields )* }
+    })
+    .expect("generated struct fields should be valid");
+
+    let prefix = ctx.trait_prefix();
+    let names: Vec<_> = fields
+        .named
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+
+    quote! {
+        fn clone(&self) -> Self {
+            Self {
+                #( #names: ::#prefix::clone::Clone::clone(&self.#names) ),*
+            }
+        }
+    }
+}
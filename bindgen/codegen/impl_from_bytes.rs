@@ -0,0 +1,106 @@
+use crate::ir::analysis::HasVtable;
+use crate::ir::comp::{CompInfo, Field, FieldMethods};
+use crate::ir::context::{BindgenContext, TypeId};
+use crate::ir::int::IntKind;
+use crate::ir::item::{IsOpaque, Item};
+use crate::ir::template::TemplateParameters;
+use crate::ir::ty::TypeKind;
+
+/// Whether the specified compound type is plain old data, that is, whether any
+/// sequence of bytes of the right size is a valid value of it.
+///
+/// This excludes types containing `bool`s, `enum`s and pointers, for which
+/// some bit patterns are invalid or meaningless.
+pub(crate) fn is_pod(
+    ctx: &BindgenContext,
+    comp_info: &CompInfo,
+    item: &Item,
+) -> bool {
+    if comp_info.is_forward_declaration() ||
+        !item.all_template_params(ctx).is_empty() ||
+        item.has_vtable(ctx)
+    {
+        return false;
+    }
+
+    if ctx.options().flexarray_dst && comp_info.flex_array_member(ctx).is_some()
+    {
+        return false;
+    }
+
+    if item.is_opaque(ctx, &()) {
+        return true;
+    }
+
+    comp_info
+        .base_members()
+        .iter()
+        .all(|base| !base.requires_storage(ctx) || is_pod_ty(ctx, base.ty)) &&
+        comp_info.fields().iter().all(|field| match *field {
+            Field::DataMember(ref fd) => is_pod_ty(ctx, fd.ty()),
+            Field::Bitfields(ref unit) => unit
+                .bitfields()
+                .iter()
+                .all(|bitfield| is_pod_ty(ctx, bitfield.ty())),
+        })
+}
+
+/// Whether the given type is plain old data.
+fn is_pod_ty(ctx: &BindgenContext, ty: TypeId) -> bool {
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+
+    match *item.expect_type().kind() {
+        TypeKind::Int(IntKind::Bool) => false,
+        TypeKind::Int(..) | TypeKind::Float(..) | TypeKind::Complex(..) => true,
+        TypeKind::Array(inner, _) | TypeKind::Vector(inner, _) => {
+            is_pod_ty(ctx, inner)
+        }
+        TypeKind::Comp(ref info) => is_pod(ctx, info, item),
+        TypeKind::Opaque => true,
+        _ => item.is_opaque(ctx, &()),
+    }
+}
+
+/// Generate the methods reinterpreting or copying a byte slice as the
+/// compound type the methods are implemented for, which must be plain old
+/// data.
+pub(crate) fn gen_from_bytes_methods(
+    ctx: &BindgenContext,
+) -> proc_macro2::TokenStream {
+    let prefix = ctx.trait_prefix();
+
+    quote! {
+        /// Reinterprets the start of `bytes` as a reference to `Self`, without
+        /// copying, using the native byte order.
+        ///
+        /// Returns `None` if `bytes` is too short or is not suitably aligned.
+        #[inline]
+        pub fn from_bytes(bytes: &[u8]) -> ::#prefix::option::Option<&Self> {
+            if bytes.len() < ::#prefix::mem::size_of::<Self>() ||
+                bytes.as_ptr() as usize % ::#prefix::mem::align_of::<Self>() != 0
+            {
+                return ::#prefix::option::Option::None;
+            }
+            ::#prefix::option::Option::Some(unsafe {
+                &*(bytes.as_ptr() as *const Self)
+            })
+        }
+        /// Copies the start of `bytes` into a new `Self`, using the native
+        /// byte order.
+        ///
+        /// Returns `None` if `bytes` is too short.
+        #[inline]
+        pub fn read_from(bytes: &[u8]) -> ::#prefix::option::Option<Self> {
+            if bytes.len() < ::#prefix::mem::size_of::<Self>() {
+                return ::#prefix::option::Option::None;
+            }
+            ::#prefix::option::Option::Some(unsafe {
+                ::#prefix::ptr::read_unaligned(bytes.as_ptr() as *const Self)
+            })
+        }
+    }
+}
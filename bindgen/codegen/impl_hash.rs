@@ -0,0 +1,156 @@
+use crate::ir::analysis::HasVtable;
+use crate::ir::comp::{CompInfo, CompKind, Field, FieldMethods};
+use crate::ir::context::{BindgenContext, TypeId};
+use crate::ir::derive::CanDeriveHash;
+use crate::ir::item::{IsOpaque, Item};
+use crate::ir::template::TemplateParameters;
+use crate::ir::ty::{FloatKind, TypeKind, RUST_DERIVE_IN_ARRAY_LIMIT};
+
+/// Whether a manual implementation of `Hash` can be generated for the
+/// specified compound type, which couldn't derive it.
+///
+/// This is only the case for plain structs. Their fields that aren't `Hash`
+/// are skipped by the implementation.
+pub(crate) fn can_gen_hash_impl(
+    ctx: &BindgenContext,
+    comp_info: &CompInfo,
+    item: &Item,
+) -> bool {
+    if comp_info.kind() != CompKind::Struct ||
+        comp_info.is_forward_declaration() ||
+        !item.all_template_params(ctx).is_empty() ||
+        item.has_vtable(ctx) ||
+        item.is_opaque(ctx, &())
+    {
+        return false;
+    }
+
+    !(ctx.options().flexarray_dst && comp_info.flex_array_member(ctx).is_some())
+}
+
+/// Whether the given type is `Hash`, either by deriving it or by having a
+/// manual implementation of `Hash` generated for it.
+fn is_hash(ctx: &BindgenContext, ty: TypeId) -> bool {
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+
+    match *item.expect_type().kind() {
+        TypeKind::Comp(ref info) => {
+            item.can_derive_hash(ctx) ||
+                (ctx.options().impl_hash &&
+                    can_gen_hash_impl(ctx, info, item) &&
+                    !ctx.no_hash_by_name(item))
+        }
+        TypeKind::Array(inner, len) => {
+            (len <= RUST_DERIVE_IN_ARRAY_LIMIT ||
+                ctx.options().rust_features().larger_arrays) &&
+                is_hash(ctx, inner)
+        }
+        _ => item.can_derive_hash(ctx),
+    }
+}
+
+/// Whether the given type is a `f32` or `f64`, whose bit pattern can be
+/// hashed.
+fn is_float(ctx: &BindgenContext, ty: TypeId) -> bool {
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+
+    matches!(
+        *item.expect_type().kind(),
+        TypeKind::Float(FloatKind::Float | FloatKind::Double)
+    )
+}
+
+/// Generate a manual implementation of `Hash` trait for the specified
+/// compound type, hashing each one of its fields that are `Hash`.
+///
+/// Bitfields are hashed through their backing storage. Floating point fields
+/// are skipped, unless [`crate::Builder::impl_hash_float_bits`] is enabled.
+pub(crate) fn gen_hash_impl(
+    ctx: &BindgenContext,
+    comp_info: &CompInfo,
+) -> proc_macro2::TokenStream {
+    let prefix = ctx.trait_prefix();
+    let mut tokens = vec![];
+
+    for base in comp_info.base_members().iter() {
+        if base.requires_storage(ctx) {
+            tokens.extend(gen_field(ctx, base.ty, &base.field_name));
+        }
+    }
+
+    for field in comp_info.fields() {
+        match *field {
+            Field::DataMember(ref fd) => {
+                tokens.extend(gen_field(ctx, fd.ty(), fd.name().unwrap()));
+            }
+            Field::Bitfields(ref bu) => {
+                let name_ident =
+                    ctx.rust_ident(format!("_bitfield_{}", bu.nth()));
+                tokens.push(quote! {
+                    ::#prefix::hash::Hash::hash(&self.#name_ident, state);
+                });
+            }
+        }
+    }
+
+    let state = if tokens.is_empty() {
+        quote! { _state }
+    } else {
+        quote! { state }
+    };
+
+    quote! {
+        fn hash<H: ::#prefix::hash::Hasher>(&self, #state: &mut H) {
+            #( #tokens )*
+        }
+    }
+}
+
+fn gen_field(
+    ctx: &BindgenContext,
+    ty: TypeId,
+    name: &str,
+) -> Option<proc_macro2::TokenStream> {
+    let prefix = ctx.trait_prefix();
+    let name_ident = ctx.rust_ident(name);
+
+    if is_hash(ctx, ty) {
+        return Some(quote! {
+            ::#prefix::hash::Hash::hash(&self.#name_ident, state);
+        });
+    }
+
+    // Hashing the bit patterns of floats is opt-in because equal floats may
+    // have different bit patterns, like `0.0` and `-0.0`.
+    if !ctx.options().impl_hash_float_bits {
+        return None;
+    }
+
+    if is_float(ctx, ty) {
+        return Some(quote! {
+            ::#prefix::hash::Hash::hash(&self.#name_ident.to_bits(), state);
+        });
+    }
+
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+    match *item.expect_type().kind() {
+        TypeKind::Array(inner, _) if is_float(ctx, inner) => Some(quote! {
+            for value in &self.#name_ident {
+                ::#prefix::hash::Hash::hash(&value.to_bits(), state);
+            }
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,201 @@
+use crate::ir::context::{BindgenContext, TypeId};
+use crate::ir::function::{BinaryOp, FunctionSig, InlineExpr, UnaryOp};
+use crate::ir::int::IntKind;
+use crate::ir::ty::TypeKind;
+
+use super::ToRustTyOrOpaque;
+
+/// The integer kind of the given type, looking through aliases.
+fn int_kind(ctx: &BindgenContext, ty: TypeId) -> Option<IntKind> {
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+
+    match *item.expect_type().kind() {
+        TypeKind::Int(kind) => Some(kind),
+        _ => None,
+    }
+}
+
+/// Generate the body of a Rust function equivalent to a trivial `static
+/// inline` function with the given signature, whose body is a `return` of
+/// `expr`.
+///
+/// Returns `None` if the parameters and the return value of the function
+/// aren't all of the same integer type, or if `expr` would be evaluated
+/// differently in that type than in C.
+pub(crate) fn gen_inline_fn_body(
+    ctx: &BindgenContext,
+    signature: &FunctionSig,
+    expr: &InlineExpr,
+) -> Option<proc_macro2::TokenStream> {
+    if signature.is_variadic() {
+        return None;
+    }
+
+    let kind = int_kind(ctx, signature.return_type())?;
+
+    // Types smaller than `int` would be promoted, so that the operations
+    // would be performed in a different type.
+    if !matches!(
+        kind,
+        IntKind::Int |
+            IntKind::UInt |
+            IntKind::Long |
+            IntKind::ULong |
+            IntKind::LongLong |
+            IntKind::ULongLong |
+            IntKind::I32 |
+            IntKind::U32 |
+            IntKind::I64 |
+            IntKind::U64 |
+            IntKind::I128 |
+            IntKind::U128
+    ) {
+        return None;
+    }
+
+    if signature
+        .argument_types()
+        .iter()
+        .any(|&(_, ty)| int_kind(ctx, ty) != Some(kind))
+    {
+        return None;
+    }
+
+    let translator = Translator {
+        ty: signature.return_type().to_rust_ty_or_opaque(ctx, &()),
+        args: super::utils::fnsig_argument_identifiers(ctx, signature),
+        is_signed: kind.is_signed(),
+    };
+    translator.expr(expr)
+}
+
+struct Translator {
+    ty: syn::Type,
+    args: Vec<proc_macro2::TokenStream>,
+    is_signed: bool,
+}
+
+impl Translator {
+    fn expr(&self, expr: &InlineExpr) -> Option<proc_macro2::TokenStream> {
+        Some(match *expr {
+            InlineExpr::Param(index) => self.args[index].clone(),
+            InlineExpr::Int(value) => {
+                let value = proc_macro2::Literal::u32_unsuffixed(value);
+                quote! { #value }
+            }
+            InlineExpr::Unary(UnaryOp::Neg, ref expr) => {
+                let expr = self.receiver(expr)?;
+                quote! { #expr.wrapping_neg() }
+            }
+            InlineExpr::Unary(UnaryOp::Not, ref expr) => {
+                let expr = self.receiver(expr)?;
+                quote! { !#expr }
+            }
+            InlineExpr::Binary(op, ref lhs, ref rhs) => {
+                // Operations without parameters are performed on `int`s in
+                // C, which only matters for unsigned types if the result
+                // isn't preserved by wrapping around. The same goes for the
+                // left hand side of a shift, which determines its type.
+                if !self.is_signed &&
+                    (matches!(op, BinaryOp::Div | BinaryOp::Rem) &&
+                        !lhs.uses_params() &&
+                        !rhs.uses_params() ||
+                        op == BinaryOp::Shr && !lhs.uses_params())
+                {
+                    return None;
+                }
+
+                // Dividing by zero is undefined behavior in C but panics in
+                // Rust, so only divisors that can't be a constant zero are
+                // translated.
+                if matches!(op, BinaryOp::Div | BinaryOp::Rem) &&
+                    !rhs.uses_params() &&
+                    !matches!(**rhs, InlineExpr::Int(value) if value != 0)
+                {
+                    return None;
+                }
+
+                if op.is_bitwise() {
+                    let lhs = self.bitwise_operand(lhs)?;
+                    let rhs = self.bitwise_operand(rhs)?;
+                    return Some(match op {
+                        BinaryOp::And => quote! { #lhs & #rhs },
+                        BinaryOp::Or => quote! { #lhs | #rhs },
+                        _ => quote! { #lhs ^ #rhs },
+                    });
+                }
+
+                let lhs = self.receiver(lhs)?;
+                let rhs = match op {
+                    BinaryOp::Shl | BinaryOp::Shr => match **rhs {
+                        InlineExpr::Int(value) => {
+                            let value =
+                                proc_macro2::Literal::u32_unsuffixed(value);
+                            quote! { #value }
+                        }
+                        ref rhs => {
+                            let rhs = self.receiver(rhs)?;
+                            quote! { #rhs as u32 }
+                        }
+                    },
+                    _ => self.expr(rhs)?,
+                };
+                let method = match op {
+                    BinaryOp::Add => quote! { wrapping_add },
+                    BinaryOp::Sub => quote! { wrapping_sub },
+                    BinaryOp::Mul => quote! { wrapping_mul },
+                    BinaryOp::Div => quote! { wrapping_div },
+                    BinaryOp::Rem => quote! { wrapping_rem },
+                    BinaryOp::Shl => quote! { wrapping_shl },
+                    _ => quote! { wrapping_shr },
+                };
+                quote! { #lhs.#method(#rhs) }
+            }
+        })
+    }
+
+    /// Translate an expression used as the receiver of a method call or as
+    /// the operand of a unary operator.
+    fn receiver(&self, expr: &InlineExpr) -> Option<proc_macro2::TokenStream> {
+        let ty = &self.ty;
+        Some(match *expr {
+            // The type of a literal cannot be inferred from a method call.
+            InlineExpr::Int(..) => {
+                let expr = self.expr(expr)?;
+                quote! { (#expr as #ty) }
+            }
+            InlineExpr::Binary(op, ..) if op.is_bitwise() => {
+                let uses_params = expr.uses_params();
+                let expr = self.expr(expr)?;
+                if uses_params {
+                    quote! { (#expr) }
+                } else {
+                    quote! { ((#expr) as #ty) }
+                }
+            }
+            InlineExpr::Unary(UnaryOp::Not, ..) => {
+                let expr = self.expr(expr)?;
+                quote! { (#expr) }
+            }
+            _ => self.expr(expr)?,
+        })
+    }
+
+    /// Translate an operand of a bitwise operator.
+    fn bitwise_operand(
+        &self,
+        expr: &InlineExpr,
+    ) -> Option<proc_macro2::TokenStream> {
+        Some(match *expr {
+            InlineExpr::Binary(op, ..) if op.is_bitwise() => {
+                let expr = self.expr(expr)?;
+                quote! { (#expr) }
+            }
+            _ => self.expr(expr)?,
+        })
+    }
+}
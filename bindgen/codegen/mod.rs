@@ -1,16 +1,28 @@
+mod derive_arbitrary;
 mod dyngen;
+mod errno;
 pub(crate) mod error;
+mod generic_selection;
 
 mod helpers;
+mod impl_clone;
 mod impl_debug;
+mod impl_from_bytes;
+mod impl_hash;
 mod impl_partialeq;
-mod postprocessing;
+mod inline_fn;
+mod os_cfg;
+mod out_param;
+pub(crate) mod postprocessing;
 mod serialize;
+mod slice_params;
 pub(crate) mod struct_layout;
 
 #[cfg(test)]
 #[allow(warnings)]
 pub(crate) mod bitfield_unit;
+#[cfg(test)]
+mod bitfield_unit_big_endian_tests;
 #[cfg(all(test, target_endian = "little"))]
 mod bitfield_unit_tests;
 
@@ -20,7 +32,10 @@ use self::struct_layout::StructLayoutTracker;
 
 use super::BindgenOptions;
 
-use crate::callbacks::{DeriveInfo, FieldInfo, TypeKind as DeriveTypeKind};
+use crate::callbacks::{
+    BytesMacroKind, DeriveInfo, FieldInfo, ItemInfo, ItemKind as ItemInfoKind,
+    Retention, ThreadSafety, TypeKind as DeriveTypeKind,
+};
 use crate::codegen::error::Error;
 use crate::ir::analysis::{HasVtable, Sizedness};
 use crate::ir::annotations::{
@@ -30,18 +45,22 @@ use crate::ir::comp::{
     Bitfield, BitfieldUnit, CompInfo, CompKind, Field, FieldData, FieldMethods,
     Method, MethodKind,
 };
-use crate::ir::context::{BindgenContext, ItemId};
+use crate::ir::context::{BindgenContext, ItemId, TypeId};
 use crate::ir::derive::{
     CanDerive, CanDeriveCopy, CanDeriveDebug, CanDeriveDefault, CanDeriveEq,
     CanDeriveHash, CanDeriveOrd, CanDerivePartialEq, CanDerivePartialOrd,
 };
 use crate::ir::dot;
-use crate::ir::enum_ty::{Enum, EnumVariant, EnumVariantValue};
+use crate::ir::enum_ty::{
+    Enum, EnumVariant, EnumVariantValue, UNKNOWN_VARIANT_NAME,
+};
 use crate::ir::function::{
-    ClangAbi, Function, FunctionKind, FunctionSig, Linkage,
+    ClangAbi, Function, FunctionBody, FunctionKind, FunctionSig, Linkage,
 };
 use crate::ir::int::IntKind;
-use crate::ir::item::{IsOpaque, Item, ItemCanonicalName, ItemCanonicalPath};
+use crate::ir::item::{
+    IsOpaque, Item, ItemCanonicalName, ItemCanonicalPath, NameOptions,
+};
 use crate::ir::item_kind::ItemKind;
 use crate::ir::layout::Layout;
 use crate::ir::module::Module;
@@ -49,19 +68,22 @@ use crate::ir::objc::{ObjCInterface, ObjCMethod};
 use crate::ir::template::{
     AsTemplateParam, TemplateInstantiation, TemplateParameters,
 };
-use crate::ir::ty::{Type, TypeKind};
+use crate::ir::ty::{FloatKind, Type, TypeKind};
 use crate::ir::var::Var;
 
 use proc_macro2::{Ident, Span};
 use quote::{ToTokens, TokenStreamExt};
 
-use crate::{Entry, HashMap, HashSet};
+use crate::{
+    AllowlistTraceEntry, BindgenError, Entry, HashMap, HashSet, RustEdition,
+};
 use std::borrow::Cow;
 use std::cell::Cell;
 use std::collections::VecDeque;
 use std::ffi::CStr;
 use std::fmt::{self, Write};
 use std::ops;
+use std::path::Path;
 use std::str::{self, FromStr};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -223,6 +245,24 @@ impl From<DerivableTraits> for Vec<&'static str> {
     }
 }
 
+/// Sort the given derives in the order set with [`crate::Builder::derive_order`].
+///
+/// The derives that aren't part of that order are kept in their relative
+/// order after the ones that are.
+fn sort_derives(ctx: &BindgenContext, derives: &mut [&str]) {
+    let order = &ctx.options().derive_order;
+    if order.is_empty() {
+        return;
+    }
+
+    derives.sort_by_key(|derive| {
+        order
+            .iter()
+            .position(|ordered| **ordered == *derive.trim())
+            .unwrap_or(order.len())
+    });
+}
+
 struct WrapAsVariadic {
     new_name: String,
     idx_of_va_list_arg: usize,
@@ -271,6 +311,10 @@ struct CodegenResult<'a> {
     functions_seen: HashSet<String>,
     vars_seen: HashSet<String>,
 
+    /// The flat constants emitted for the variants of `enum`s, mapped to the
+    /// name of the `enum` they were emitted for, to detect collisions.
+    flat_enum_constants_seen: HashMap<String, String>,
+
     /// Used for making bindings to overloaded functions. Maps from a canonical
     /// function name to the number of overloads we have already codegen'd for
     /// that name. This lets us give each overload a unique suffix.
@@ -279,10 +323,28 @@ struct CodegenResult<'a> {
     /// List of items to serialize. With optionally the argument for the wrap as
     /// variadic transformation to be applied.
     items_to_serialize: Vec<(ItemId, Option<WrapAsVariadic>)>,
+
+    /// The layout checks to emit in the `bindgen_debug_assert_layouts`
+    /// function.
+    layout_debug_asserts: Vec<proc_macro2::TokenStream>,
+
+    /// The layout checks to emit in the `verify_layouts` function.
+    layout_verifications: Vec<proc_macro2::TokenStream>,
+
+    /// The layout tests to emit in the test module of the current module,
+    /// if they should be grouped in one.
+    layout_tests: Vec<proc_macro2::TokenStream>,
+
+    /// The `target_os` each item is specific to, with
+    /// [`crate::Builder::infer_os_cfg`].
+    item_target_oses: &'a HashMap<ItemId, &'static str>,
 }
 
 impl<'a> CodegenResult<'a> {
-    fn new(codegen_id: &'a Cell<usize>) -> Self {
+    fn new(
+        codegen_id: &'a Cell<usize>,
+        item_target_oses: &'a HashMap<ItemId, &'static str>,
+    ) -> Self {
         CodegenResult {
             items: vec![],
             dynamic_items: DynamicItems::new(),
@@ -295,8 +357,13 @@ impl<'a> CodegenResult<'a> {
             items_seen: Default::default(),
             functions_seen: Default::default(),
             vars_seen: Default::default(),
+            flat_enum_constants_seen: Default::default(),
             overload_counters: Default::default(),
             items_to_serialize: Default::default(),
+            layout_debug_asserts: Default::default(),
+            layout_verifications: Default::default(),
+            layout_tests: Default::default(),
+            item_target_oses,
         }
     }
 
@@ -324,6 +391,40 @@ impl<'a> CodegenResult<'a> {
         self.saw_bitfield_unit = true;
     }
 
+    /// Add a layout test, either right away or to the test module of the
+    /// current module.
+    fn push_layout_test(
+        &mut self,
+        ctx: &BindgenContext,
+        test: proc_macro2::TokenStream,
+    ) {
+        if ctx.options().layout_tests_in_module.is_some() {
+            self.layout_tests.push(test);
+        } else {
+            self.push(test);
+        }
+    }
+
+    /// Add the test module of the current module, with the layout tests that
+    /// have been collected so far, if any.
+    fn append_layout_tests_module(&mut self, ctx: &BindgenContext) {
+        let name = match ctx.options().layout_tests_in_module {
+            Some(ref name) if !self.layout_tests.is_empty() => name,
+            _ => return,
+        };
+
+        let ident = ctx.rust_ident(name);
+        let tests = std::mem::take(&mut self.layout_tests);
+        self.push(quote! {
+            #[cfg(test)]
+            mod #ident {
+                use super::*;
+
+                #( #tests )*
+            }
+        });
+    }
+
     fn seen<Id: Into<ItemId>>(&self, item: Id) -> bool {
         self.items_seen.contains(&item.into())
     }
@@ -362,7 +463,7 @@ impl<'a> CodegenResult<'a> {
     where
         F: FnOnce(&mut Self),
     {
-        let mut new = Self::new(self.codegen_id);
+        let mut new = Self::new(self.codegen_id, self.item_target_oses);
 
         cb(&mut new);
 
@@ -371,6 +472,8 @@ impl<'a> CodegenResult<'a> {
         self.saw_block |= new.saw_block;
         self.saw_bitfield_unit |= new.saw_bitfield_unit;
         self.saw_bindgen_union |= new.saw_bindgen_union;
+        self.layout_debug_asserts.extend(new.layout_debug_asserts);
+        self.layout_verifications.extend(new.layout_verifications);
 
         new.items
     }
@@ -507,7 +610,10 @@ impl Item {
             // TODO(emilio, #453): Figure out what to do when this happens
             // legitimately, we could track the opaque stuff and disable the
             // assertion there I guess.
-            warn!("Found non-allowlisted item in code generation: {:?}", self);
+            warning!(
+                "Found non-allowlisted item in code generation: {:?}",
+                self
+            );
         }
 
         result.set_seen(self.id());
@@ -530,6 +636,13 @@ impl CodeGenerator for Item {
             return;
         }
 
+        let target_os = result.item_target_oses.get(&self.id()).copied();
+        let start = result.len();
+        let layout_tests_start = result.layout_tests.len();
+        let layout_debug_asserts_start = result.layout_debug_asserts.len();
+        let layout_verifications_start = result.layout_verifications.len();
+        let dynamic_items_start = result.dynamic_items().mark();
+
         match *self.kind() {
             ItemKind::Module(ref module) => {
                 module.codegen(ctx, result, self);
@@ -544,6 +657,33 @@ impl CodeGenerator for Item {
                 ty.codegen(ctx, result, self);
             }
         }
+
+        if let Some(target_os) = target_os {
+            for tokens in result.split_off(start) {
+                let tokens = os_cfg::with_target_os_cfg(ctx, tokens, target_os);
+                result.push(tokens);
+            }
+            for test in &mut result.layout_tests[layout_tests_start..] {
+                *test =
+                    os_cfg::with_target_os_cfg(ctx, test.clone(), target_os);
+            }
+            // The layout checks of each type are a block in the body of a
+            // function, which can be guarded as a statement.
+            let cfg = quote! { #[cfg(target_os = #target_os)] };
+            for checks in result.layout_debug_asserts
+                [layout_debug_asserts_start..]
+                .iter_mut()
+                .chain(
+                    &mut result.layout_verifications
+                        [layout_verifications_start..],
+                )
+            {
+                *checks = quote! { #cfg #checks };
+            }
+            result
+                .dynamic_items()
+                .add_attribute_since(dynamic_items_start, &cfg);
+        }
     }
 }
 
@@ -561,14 +701,50 @@ impl CodeGenerator for Module {
 
         let codegen_self = |result: &mut CodegenResult,
                             found_any: &mut bool| {
-            for child in self.children() {
-                if ctx.codegen_items().contains(child) {
-                    *found_any = true;
-                    ctx.resolve_item(*child).codegen(ctx, result, &());
+            if item.id() == ctx.root_module() &&
+                ctx.options().modules_per_header &&
+                !ctx.options().enable_cxx_namespaces
+            {
+                self.codegen_modules_per_header(ctx, result, found_any);
+            } else {
+                for child in self.children() {
+                    if ctx.codegen_items().contains(child) {
+                        *found_any = true;
+                        ctx.resolve_item(*child).codegen(ctx, result, &());
+                    }
                 }
             }
 
             if item.id() == ctx.root_module() {
+                for selection in ctx.generic_selections() {
+                    if let Some(tokens) =
+                        generic_selection::gen_generic_selection_trait(
+                            ctx, selection, result,
+                        )
+                    {
+                        result.push(tokens);
+                    }
+                }
+                if ctx.options().layout_tests {
+                    mapped_float_layout_tests(ctx, result);
+                }
+                if !ctx.options().enable_cxx_namespaces {
+                    result.append_layout_tests_module(ctx);
+                }
+                if ctx.options().layout_debug_asserts {
+                    let asserts =
+                        std::mem::take(&mut result.layout_debug_asserts);
+                    utils::append_layout_debug_asserts(&mut *result, asserts);
+                }
+                if ctx.options().layout_verification_fn {
+                    let checks =
+                        std::mem::take(&mut result.layout_verifications);
+                    utils::append_layout_verification_fn(
+                        ctx,
+                        &mut *result,
+                        checks,
+                    );
+                }
                 if result.saw_block {
                     utils::prepend_block_header(ctx, &mut *result);
                 }
@@ -590,6 +766,11 @@ impl CodeGenerator for Module {
                 if result.saw_bitfield_unit {
                     utils::prepend_bitfield_unit_type(ctx, &mut *result);
                 }
+                if ctx.options().libc_ctypes_shim &&
+                    ctx.options().ctypes_prefix.is_none()
+                {
+                    utils::prepend_ctypes_shim(ctx, &mut *result);
+                }
             }
         };
 
@@ -619,6 +800,7 @@ impl CodeGenerator for Module {
             }
 
             codegen_self(result, &mut found_any);
+            result.append_layout_tests_module(ctx);
         });
 
         // Don't bother creating an empty module.
@@ -645,6 +827,83 @@ impl CodeGenerator for Module {
     }
 }
 
+impl Module {
+    /// Generate the children of this module grouped in a module per header
+    /// they are declared in, see [`crate::Builder::modules_per_header`].
+    fn codegen_modules_per_header(
+        &self,
+        ctx: &BindgenContext,
+        result: &mut CodegenResult<'_>,
+        found_any: &mut bool,
+    ) {
+        let mut modules: Vec<(String, Vec<proc_macro2::TokenStream>)> = vec![];
+        for child in self.children() {
+            if !ctx.codegen_items().contains(child) {
+                continue;
+            }
+            *found_any = true;
+
+            // Generate the child in place, so that it is deduplicated along
+            // with the other items, and then move it to its module.
+            let child = ctx.resolve_item(*child);
+            let start = result.len();
+            child.codegen(ctx, result, &());
+
+            // The items without a header, e.g. the template instantiations,
+            // stay in the root module.
+            let name = match header_module_name(child) {
+                Some(name) => name,
+                None => continue,
+            };
+            let items = result.split_off(start);
+            match modules.iter_mut().find(|(module, _)| *module == name) {
+                Some((_, module_items)) => module_items.extend(items),
+                None => modules.push((name, items)),
+            }
+        }
+
+        // A module named like one of the items, e.g. the `point` module of a
+        // `point.h` header declaring `struct point`, would shadow it.
+        let item_names: HashSet<_> = self
+            .children()
+            .iter()
+            .filter(|child| ctx.codegen_items().contains(child))
+            .map(|child| ctx.resolve_item(*child).canonical_name(ctx))
+            .collect();
+        for (mut name, items) in modules {
+            while item_names.contains(&name) {
+                name.push_str("_h");
+            }
+            let ident = ctx.rust_ident(name);
+            result.push(quote! {
+                pub mod #ident {
+                    #[allow(unused_imports)]
+                    use super::*;
+                    #( #items )*
+                }
+                pub use self::#ident::*;
+            });
+        }
+    }
+}
+
+/// The name of the module the given item is put in by
+/// [`crate::Builder::modules_per_header`], that is, the name of the header it
+/// is declared in without its extension, if any.
+fn header_module_name(item: &Item) -> Option<String> {
+    let (file, ..) = item.location()?.location();
+    let file = file.name()?;
+    let stem = Path::new(&file).file_stem()?.to_str()?;
+    let mut name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    Some(name)
+}
+
 impl CodeGenerator for Var {
     type Extra = Item;
     type Return = ();
@@ -679,6 +938,9 @@ impl CodeGenerator for Var {
         if let Some(comment) = item.comment(ctx) {
             attrs.push(attributes::doc(comment));
         }
+        if ctx.is_transitive_dependency(item.id()) {
+            attrs.push(attributes::doc_hidden());
+        }
 
         let var_ty = self.ty();
         let ty = var_ty.to_rust_ty_or_opaque(ctx, &());
@@ -710,7 +972,7 @@ impl CodeGenerator for Var {
                         pub const #canonical_ident : #ty = #val ;
                     });
                 }
-                VarType::String(ref bytes) => {
+                VarType::String(ref bytes, kind) => {
                     let prefix = ctx.trait_prefix();
 
                     let options = ctx.options();
@@ -729,7 +991,13 @@ impl CodeGenerator for Var {
 
                     let bytes = proc_macro2::Literal::byte_string(&cstr_bytes);
 
-                    if options.generate_cstr &&
+                    let kind = kind.unwrap_or(if options.generate_cstr {
+                        BytesMacroKind::CStr
+                    } else {
+                        BytesMacroKind::Array
+                    });
+
+                    if kind == BytesMacroKind::CStr &&
                         rust_features.const_cstr &&
                         CStr::from_bytes_with_nul(&cstr_bytes).is_ok()
                     {
@@ -749,13 +1017,46 @@ impl CodeGenerator for Var {
                         }
                         .into_iter();
 
+                        let ty = if kind == BytesMacroKind::Slice {
+                            quote! { [u8] }
+                        } else {
+                            array_ty
+                        };
+
                         result.push(quote! {
                             #(#attrs)*
-                            pub const #canonical_ident: &#(#lifetime )*#array_ty = #bytes ;
+                            pub const #canonical_ident: &#(#lifetime )*#ty = #bytes ;
                         });
                     }
                 }
                 VarType::Float(f) => {
+                    // Literals can't have the types floats are mapped to.
+                    let float_kind = match *var_ty
+                        .into_resolver()
+                        .through_type_aliases()
+                        .through_type_refs()
+                        .resolve(ctx)
+                        .expect_type()
+                        .kind()
+                    {
+                        TypeKind::Float(kind) => Some(kind),
+                        _ => None,
+                    };
+                    let ty = match float_kind {
+                        Some(kind)
+                            if helpers::ast_ty::mapped_float_type(
+                                ctx, kind,
+                            )
+                            .is_some() =>
+                        {
+                            if kind == FloatKind::Float {
+                                syn::parse_quote! { f32 }
+                            } else {
+                                syn::parse_quote! { f64 }
+                            }
+                        }
+                        _ => ty,
+                    };
                     if let Ok(expr) = helpers::ast_ty::float_expr(ctx, f) {
                         result.push(quote! {
                             #(#attrs)*
@@ -764,12 +1065,87 @@ impl CodeGenerator for Var {
                     }
                 }
                 VarType::Char(c) => {
+                    let int_kind = var_ty
+                        .into_resolver()
+                        .through_type_aliases()
+                        .through_type_refs()
+                        .resolve(ctx)
+                        .expect_type()
+                        .as_integer()
+                        .unwrap();
+                    // Callbacks may pick something other than `u8`, so only
+                    // use a suffixed literal when it's the type we emit.
+                    let val = match int_kind {
+                        IntKind::U8 => quote! { #c },
+                        _ if int_kind.is_signed() => {
+                            helpers::ast_ty::int_expr(c as i8 as i64)
+                        }
+                        _ => helpers::ast_ty::uint_expr(c as u64),
+                    };
                     result.push(quote! {
                         #(#attrs)*
-                        pub const #canonical_ident : #ty = #c ;
+                        pub const #canonical_ident : #ty = #val ;
                     });
                 }
             }
+        } else if matches!(self.linkage(), Linkage::Internal) &&
+            ctx.options().wrap_static_fns
+        {
+            // Variables without external linkage can't be linked against, so
+            // we access them through wrapper functions instead.
+            let is_wrappable = !matches!(
+                var_ty
+                    .into_resolver()
+                    .through_type_refs()
+                    .through_type_aliases()
+                    .resolve(ctx)
+                    .expect_type()
+                    .kind(),
+                TypeKind::Array(..) | TypeKind::Function(..)
+            );
+            if !is_wrappable {
+                unwrappable_static_var_diagnostic(
+                    self.name(),
+                    item.location(),
+                    ctx,
+                );
+                return;
+            }
+
+            let suffix = ctx.wrap_static_fns_suffix();
+
+            let getter = format!("{}_get", canonical_name);
+            let getter_link_name =
+                attributes::link_name::<true>(&format!("{}{}", getter, suffix));
+            let getter = ctx.rust_ident(getter);
+            let block_attributes = utils::extern_block_attributes(ctx, item);
+            result.push(quote! {
+                #( #[#block_attributes] )*
+                extern "C" {
+                    #(#attrs)*
+                    #getter_link_name
+                    pub fn #getter() -> #ty;
+                }
+            });
+
+            if !self.is_const() {
+                let setter = format!("{}_set", canonical_name);
+                let setter_link_name = attributes::link_name::<true>(&format!(
+                    "{}{}",
+                    setter, suffix
+                ));
+                let setter = ctx.rust_ident(setter);
+                result.push(quote! {
+                    #( #[#block_attributes] )*
+                    extern "C" {
+                        #(#attrs)*
+                        #setter_link_name
+                        pub fn #setter(value: #ty);
+                    }
+                });
+            }
+
+            result.items_to_serialize.push((item.id(), None));
         } else {
             // If necessary, apply a `#[link_name]` attribute
             if let Some(link_name) = self.link_name() {
@@ -792,7 +1168,9 @@ impl CodeGenerator for Var {
                 quote! { mut }
             };
 
+            let block_attributes = utils::extern_block_attributes(ctx, item);
             let tokens = quote!(
+                #( #[#block_attributes] )*
                 extern "C" {
                     #(#attrs)*
                     pub static #maybe_mut #canonical_ident: #ty;
@@ -806,9 +1184,86 @@ impl CodeGenerator for Var {
                         .to_rust_ty_or_opaque(ctx, &())
                         .into_token_stream(),
                     ctx.options().dynamic_link_require_all,
+                    ctx,
                 );
             } else {
                 result.push(tokens);
+
+                // The statics pointing to the variable are only emitted where
+                // the `extern` block is.
+                let block_cfgs: Vec<_> = block_attributes
+                    .iter()
+                    .filter(|attr| attr.path().is_ident("cfg"))
+                    .collect();
+                let prefix = ctx.trait_prefix();
+
+                let retention = ctx.options().last_callback(|cb| {
+                    let retention = cb.static_retention(self.name());
+                    (retention != Retention::default()).then_some(retention)
+                });
+                if retention.is_some() && !ctx.options().rust_features().addr_of
+                {
+                    warning!(
+                        "Not generating the retention shim of `{}`, which requires `addr_of!` (Rust 1.51 or newer)",
+                        canonical_name
+                    );
+                } else if let Some(retention) = retention {
+                    let mut shim_attrs = vec![];
+                    if retention.used {
+                        shim_attrs.push(attributes::used());
+                    }
+                    if retention.no_mangle {
+                        shim_attrs.push(attributes::no_mangle(
+                            ctx.options().rust_features().unsafe_attributes,
+                        ));
+                    }
+
+                    // A `static mut` doesn't need to be `Sync`, unlike the raw
+                    // pointer it holds. Taking the address of an `extern`
+                    // static is only safe on newer Rust versions.
+                    let shim = ctx.rust_ident(format!(
+                        "__bindgen_retain_{}",
+                        canonical_name
+                    ));
+                    result.push(quote! {
+                        #( #[#block_cfgs] )*
+                        #(#shim_attrs)*
+                        #[allow(unused_unsafe)]
+                        pub static mut #shim: *const #ty = unsafe {
+                            ::#prefix::ptr::addr_of!(#canonical_ident)
+                        };
+                    });
+                }
+
+                // A `#[link_section]` has no meaning on a static declared in
+                // an `extern` block, so a pointer to it is placed instead.
+                let link_section = ctx
+                    .options()
+                    .last_callback(|cb| cb.pointer_link_section(self.name()));
+                if link_section.is_some() &&
+                    !ctx.options().rust_features().addr_of
+                {
+                    warning!(
+                        "Not generating the pointer to `{}` in a linker section, which requires `addr_of!` (Rust 1.51 or newer)",
+                        canonical_name
+                    );
+                } else if let Some(section) = link_section {
+                    let pointer = ctx.rust_ident(format!(
+                        "__bindgen_section_{}",
+                        canonical_name
+                    ));
+                    let used = attributes::used();
+                    let link_section = attributes::link_section(&section);
+                    result.push(quote! {
+                        #( #[#block_cfgs] )*
+                        #used
+                        #link_section
+                        #[allow(unused_unsafe)]
+                        pub static mut #pointer: *const #ty = unsafe {
+                            ::#prefix::ptr::addr_of!(#canonical_ident)
+                        };
+                    });
+                }
             }
         }
     }
@@ -827,6 +1282,17 @@ impl CodeGenerator for Type {
         debug!("<Type as CodeGenerator>::codegen: item = {:?}", item);
         debug_assert!(item.is_enabled_for_codegen(ctx));
 
+        if matches!(
+            *self.kind(),
+            TypeKind::Comp(..) |
+                TypeKind::Enum(..) |
+                TypeKind::Alias(..) |
+                TypeKind::TemplateAlias(..)
+        ) && codegen_path_override(ctx, result, item)
+        {
+            return;
+        }
+
         match *self.kind() {
             TypeKind::Void |
             TypeKind::NullPtr |
@@ -875,6 +1341,9 @@ impl CodeGenerator for Type {
                 } else {
                     quote! {}
                 };
+                if ctx.is_transitive_dependency(item.id()) {
+                    tokens.append_all(attributes::doc_hidden());
+                }
 
                 tokens.append_all(quote! {
                     pub type #rust_name = #inner_rust_type ;
@@ -969,10 +1438,11 @@ impl CodeGenerator for Type {
                     let inner_canon_type =
                         inner_item.expect_type().canonical_type(ctx);
                     if inner_canon_type.is_invalid_type_param() {
-                        warn!(
+                        warning!(
                             "Item contained invalid named type, skipping: \
                              {:?}, {:?}",
-                            item, inner_item
+                            item,
+                            inner_item
                         );
                         return;
                     }
@@ -996,6 +1466,33 @@ impl CodeGenerator for Type {
                     ctx.options().default_alias_style
                 };
 
+                // Opaque handles are newtypes over integers whose field is
+                // only reachable through explicit conversion methods.
+                let is_opaque_handle =
+                    ctx.options().opaque_handle.matches(&name) &&
+                        outer_params.is_empty() &&
+                        inner_item
+                            .expect_type()
+                            .canonical_type(ctx)
+                            .is_integer();
+                // Strong typedefs are newtypes over primitive types that
+                // dereference to them. The aliased type is canonicalized, so
+                // chains of `typedef`s are matched as well.
+                let is_strong_typedef = !is_opaque_handle &&
+                    ctx.options().strong_typedef.matches(&name) &&
+                    outer_params.is_empty() &&
+                    matches!(
+                        *inner_item.expect_type().canonical_type(ctx).kind(),
+                        TypeKind::Int(..) | TypeKind::Float(..)
+                    );
+                let alias_style = if is_opaque_handle {
+                    AliasVariation::NewType
+                } else if is_strong_typedef {
+                    AliasVariation::NewTypeDeref
+                } else {
+                    alias_style
+                };
+
                 // We prefer using `pub use` over `pub type` because of:
                 // https://github.com/rust-lang/rust/issues/26264
                 if matches!(inner_rust_type, syn::Type::Path(_)) &&
@@ -1045,6 +1542,7 @@ impl CodeGenerator for Type {
                         // In most cases this will be a no-op, since custom_derives will be empty.
                         derives
                             .extend(custom_derives.iter().map(|s| s.as_str()));
+                        sort_derives(ctx, &mut derives);
                         attributes.push(attributes::derives(&derives));
 
                         quote! {
@@ -1062,7 +1560,7 @@ impl CodeGenerator for Type {
                     .iter()
                     .any(|p| ctx.resolve_type(*p).is_invalid_type_param())
                 {
-                    warn!(
+                    warning!(
                         "Item contained invalid template \
                          parameter: {:?}",
                         item
@@ -1090,6 +1588,11 @@ impl CodeGenerator for Type {
                     AliasVariation::TypeAlias => quote! {
                         = #inner_rust_type ;
                     },
+                    AliasVariation::NewType if is_opaque_handle => {
+                        quote! {
+                            (#inner_rust_type) ;
+                        }
+                    }
                     AliasVariation::NewType | AliasVariation::NewTypeDeref => {
                         quote! {
                             (#access_spec #inner_rust_type) ;
@@ -1097,6 +1600,35 @@ impl CodeGenerator for Type {
                     }
                 });
 
+                if is_opaque_handle {
+                    let const_fn = if ctx.options().rust_features().min_const_fn
+                    {
+                        quote! { const fn }
+                    } else {
+                        quote! { fn }
+                    };
+                    // Handles usually stand for an address, so they can be
+                    // printed as pointers with `{:p}`.
+                    let prefix = ctx.trait_prefix();
+                    tokens.append_all(quote! {
+                        impl #rust_name {
+                            #[inline]
+                            pub #const_fn from_raw(raw: #inner_rust_type) -> Self {
+                                #rust_name(raw)
+                            }
+                            #[inline]
+                            pub #const_fn into_raw(self) -> #inner_rust_type {
+                                self.0
+                            }
+                        }
+                        impl ::#prefix::fmt::Pointer for #rust_name {
+                            fn fmt(&self, f: &mut ::#prefix::fmt::Formatter<'_>) -> ::#prefix::fmt::Result {
+                                ::#prefix::fmt::Pointer::fmt(&(self.0 as usize as *const ()), f)
+                            }
+                        }
+                    });
+                }
+
                 if alias_style == AliasVariation::NewTypeDeref {
                     let prefix = ctx.trait_prefix();
                     tokens.append_all(quote! {
@@ -1274,6 +1806,12 @@ impl CodeGenerator for TemplateInstantiation {
             return;
         }
 
+        // Blocklisted types are supplied by the user, so the layout we computed
+        // for them may not match theirs.
+        if self.references_blocklisted(ctx) {
+            return;
+        }
+
         let layout = item.kind().expect_type().layout(ctx);
 
         if let Some(layout) = layout {
@@ -1312,20 +1850,26 @@ impl CodeGenerator for TemplateInstantiation {
                 // If #size_of_expr > #size, this will index OOB, and if
                 // #size_of_expr < #size, the subtraction will overflow, both
                 // of which print enough information to see what has gone wrong.
-                result.push(quote! {
-                    const _: () = {
-                        [#size_of_err][#size_of_expr - #size];
-                        [#align_of_err][#align_of_expr - #align];
-                    };
-                });
+                result.push_layout_test(
+                    ctx,
+                    quote! {
+                        const _: () = {
+                            [#size_of_err][#size_of_expr - #size];
+                            [#align_of_err][#align_of_expr - #align];
+                        };
+                    },
+                );
             } else {
-                result.push(quote! {
-                    #[test]
-                    fn #fn_name() {
-                        assert_eq!(#size_of_expr, #size, #size_of_err);
-                        assert_eq!(#align_of_expr, #align, #align_of_err);
-                    }
-                });
+                result.push_layout_test(
+                    ctx,
+                    quote! {
+                        #[test]
+                        fn #fn_name() {
+                            assert_eq!(#size_of_expr, #size, #size_of_err);
+                            assert_eq!(#align_of_expr, #align, #align_of_err);
+                        }
+                    },
+                );
             }
         }
     }
@@ -1413,10 +1957,13 @@ fn wrap_union_field_if_needed(
     ctx: &BindgenContext,
     struct_layout: &StructLayoutTracker,
     ty: syn::Type,
+    can_copy: bool,
     result: &mut CodegenResult,
 ) -> syn::Type {
     if struct_layout.is_rust_union() {
-        if struct_layout.can_copy_union_fields() {
+        // Only the fields that aren't `Copy` need to be wrapped, the rest can
+        // be used as-is even if the union itself can't be `Copy`.
+        if struct_layout.can_copy_union_fields() || can_copy {
             ty
         } else {
             let prefix = ctx.trait_prefix();
@@ -1466,7 +2013,13 @@ impl<'a> FieldCodegen<'a> for FieldData {
 
         // NB: If supported, we use proper `union` types.
         let ty = if parent.is_union() {
-            wrap_union_field_if_needed(ctx, struct_layout, ty, result)
+            wrap_union_field_if_needed(
+                ctx,
+                struct_layout,
+                ty,
+                self.ty().can_derive_copy(ctx),
+                result,
+            )
         } else if let Some(item) = field_ty.is_incomplete_array(ctx) {
             // Only FAM if its the last field
             if ctx.options().flexarray_dst && last_field {
@@ -1523,41 +2076,145 @@ impl<'a> FieldCodegen<'a> for FieldData {
         let accessor_kind =
             self.annotations().accessor_kind().unwrap_or(accessor_kind);
 
-        match visibility {
-            FieldVisibilityKind::Private => {
-                field.append_all(quote! {
-                    #field_ident : #ty ,
-                });
-            }
-            FieldVisibilityKind::PublicCrate => {
+        let visibility = match visibility {
+            FieldVisibilityKind::Private => quote! {},
+            FieldVisibilityKind::PublicCrate => quote! { pub(crate) },
+            FieldVisibilityKind::Public => quote! { pub },
+        };
+
+        let volatile_accessors = self.has_volatile_accessors(
+            ctx,
+            parent,
+            parent_item.expect_type().layout(ctx).as_ref(),
+        );
+        let (visibility, accessor_visibility) = if volatile_accessors {
+            (quote! {}, Some(visibility))
+        } else {
+            (visibility, None)
+        };
+
+        match ctx.options().nightly_cfg_feature {
+            // Use `f128` if the feature is enabled and fallback to the blob
+            // otherwise. Accessors would need to be duplicated as well, so we
+            // fallback unconditionally for them.
+            Some(ref feature)
+                if !ctx.options().rust_features().f128 &&
+                    accessor_kind == FieldAccessorKind::None &&
+                    !parent.is_union() &&
+                    matches!(
+                        *field_ty.kind(),
+                        TypeKind::Float(fk)
+                            if helpers::ast_ty::float_kind_is_f128(
+                                ctx,
+                                fk,
+                                field_ty.layout(ctx),
+                            )
+                    ) =>
+            {
+                let doc = field.clone();
+                let doc_cfg = ctx.options().emit_doc_cfg;
+                let enabled =
+                    attributes::cfg(quote! { feature = #feature }, doc_cfg);
+                let disabled = attributes::cfg(
+                    quote! { not(feature = #feature) },
+                    doc_cfg,
+                );
                 field.append_all(quote! {
-                    pub(crate) #field_ident : #ty ,
+                    #enabled
+                    #visibility #field_ident : f128 ,
+                    #doc
+                    #disabled
+                    #visibility #field_ident : #ty ,
                 });
             }
-            FieldVisibilityKind::Public => {
+            _ => {
                 field.append_all(quote! {
-                    pub #field_ident : #ty ,
+                    #visibility #field_ident : #ty ,
                 });
             }
         }
 
         fields.extend(Some(field));
 
-        // TODO: Factor the following code out, please!
-        if accessor_kind == FieldAccessorKind::None {
-            return;
-        }
-
-        let getter_name = ctx.rust_ident_raw(format!("get_{}", field_name));
-        let mutable_getter_name =
-            ctx.rust_ident_raw(format!("get_{}_mut", field_name));
+        if let Some(visibility) = accessor_visibility {
+            let prefix = ctx.trait_prefix();
+            methods.extend(Some(quote! {
+                #[inline]
+                #visibility fn #field_ident(&self) -> #ty {
+                    unsafe {
+                        ::#prefix::ptr::read_volatile(
+                            ::#prefix::ptr::addr_of!(self.#field_ident),
+                        )
+                    }
+                }
+            }));
 
-        methods.extend(Some(match accessor_kind {
-            FieldAccessorKind::None => unreachable!(),
-            FieldAccessorKind::Regular => {
-                quote! {
+            if !ctx.resolve_type(self.ty()).is_const() && !field_ty.is_const() {
+                let setter_name =
+                    ctx.rust_ident_raw(format!("set_{}", field_name));
+                methods.extend(Some(quote! {
                     #[inline]
-                    pub fn #getter_name(&self) -> & #ty {
+                    #visibility fn #setter_name(&mut self, val: #ty) {
+                        unsafe {
+                            ::#prefix::ptr::write_volatile(
+                                ::#prefix::ptr::addr_of_mut!(self.#field_ident),
+                                val,
+                            )
+                        }
+                    }
+                }));
+            }
+        }
+
+        if !parent.is_union() &&
+            is_c_char_pointer(ctx, self.ty()) &&
+            ctx.options().cstr_accessors.matches(format!(
+                "{}::{}",
+                parent_item.canonical_name(ctx),
+                self.name().unwrap()
+            ))
+        {
+            let cstr_getter_name =
+                ctx.rust_ident_raw(format!("{}_cstr", field_name));
+            let prefix = ctx.trait_prefix();
+            let cstr = if ctx.options().use_core &&
+                ctx.options().rust_features().core_ffi_c
+            {
+                quote! { ::core::ffi::CStr }
+            } else {
+                quote! { ::std::ffi::CStr }
+            };
+            let from_ptr = ctx.wrap_unsafe_ops(quote! {
+                #cstr::from_ptr(self.#field_ident)
+            });
+
+            methods.extend(Some(quote! {
+                #[inline]
+                pub unsafe fn #cstr_getter_name(&self) -> ::#prefix::option::Option<&#cstr> {
+                    if self.#field_ident.is_null() {
+                        ::#prefix::option::Option::None
+                    } else {
+                        ::#prefix::option::Option::Some(#from_ptr)
+                    }
+                }
+            }));
+        }
+
+        // TODO: Factor the following code out, please!
+        if accessor_kind == FieldAccessorKind::None {
+            return;
+        }
+
+        let getter_name = ctx.rust_ident_raw(format!("get_{}", field_name));
+        let mutable_getter_name =
+            ctx.rust_ident_raw(format!("get_{}_mut", field_name));
+
+        methods.extend(Some(match accessor_kind {
+            FieldAccessorKind::None => unreachable!(),
+            FieldAccessorKind::Regular => {
+                quote! {
+                    #[inline]
+                    pub fn #getter_name(&self) -> & #ty {
                         &self.#field_ident
                     }
 
@@ -1592,6 +2249,28 @@ impl<'a> FieldCodegen<'a> for FieldData {
     }
 }
 
+/// Whether the given type is a pointer to `c_char`, which can be read as a
+/// `CStr`.
+fn is_c_char_pointer(ctx: &BindgenContext, ty: TypeId) -> bool {
+    let resolve = |ty: TypeId| {
+        ty.into_resolver()
+            .through_type_refs()
+            .through_type_aliases()
+            .resolve(ctx)
+            .expect_type()
+    };
+
+    match *resolve(ty).kind() {
+        TypeKind::Pointer(inner) => {
+            matches!(
+                *resolve(inner).kind(),
+                TypeKind::Int(IntKind::Char { .. })
+            )
+        }
+        _ => false,
+    }
+}
+
 impl BitfieldUnit {
     /// Get the constructor name for this bitfield unit.
     fn ctor_name(&self) -> proc_macro2::TokenStream {
@@ -1629,18 +2308,34 @@ impl Bitfield {
         let width = self.width() as u8;
         let prefix = ctx.trait_prefix();
 
-        ctor_impl.append_all(quote! {
-            __bindgen_bitfield_unit.set(
-                #offset,
-                #width,
-                {
-                    let #param_name: #bitfield_int_ty = unsafe {
-                        ::#prefix::mem::transmute(#param_name)
-                    };
-                    #param_name as u64
+        let val = quote! {
+            {
+                let #param_name: #bitfield_int_ty = unsafe {
+                    ::#prefix::mem::transmute(#param_name)
+                };
+                #param_name as u64
+            }
+        };
+
+        ctor_impl.append_all(
+            if ctx.options().rust_features().const_bitfield_unit {
+                quote! {
+                    __bindgen_bitfield_unit = __bindgen_bitfield_unit.set_const(
+                        #offset,
+                        #width,
+                        #val
+                    );
                 }
-            );
-        });
+            } else {
+                quote! {
+                    __bindgen_bitfield_unit.set(
+                        #offset,
+                        #width,
+                        #val
+                    );
+                }
+            },
+        );
 
         ctor_impl
     }
@@ -1722,10 +2417,12 @@ impl<'a> FieldCodegen<'a> for BitfieldUnit {
         let field_ty = {
             let unit_field_ty = unit_field_ty.clone();
             if parent.is_union() {
+                // Bitfield units are always `Copy`.
                 wrap_union_field_if_needed(
                     ctx,
                     struct_layout,
                     unit_field_ty,
+                    true,
                     result,
                 )
             } else {
@@ -1763,7 +2460,10 @@ impl<'a> FieldCodegen<'a> for BitfieldUnit {
         // the 32 items limitation.
         let mut generate_ctor = layout.size <= RUST_DERIVE_IN_ARRAY_LIMIT;
 
-        let mut unit_visibility = visibility_kind;
+        // The unit field and its constructor can't be more visible than the
+        // type of the unit.
+        let mut unit_visibility =
+            visibility_kind.min(ctx.options().bitfield_unit_visibility);
         let bfields = self.bitfields();
         for (idx, bf) in bfields.iter().enumerate() {
             // Codegen not allowed for anonymous bitfields
@@ -1826,14 +2526,39 @@ impl<'a> FieldCodegen<'a> for BitfieldUnit {
         fields.extend(Some(field));
 
         if generate_ctor {
-            methods.extend(Some(quote! {
-                #[inline]
-                #access_spec fn #ctor_name ( #( #ctor_params ),* ) -> #unit_field_ty {
-                    let mut __bindgen_bitfield_unit: #unit_field_ty = Default::default();
-                    #ctor_impl
-                    __bindgen_bitfield_unit
-                }
-            }));
+            let allow_clippy = helpers::allow_clippy(
+                ctx,
+                &[
+                    "too_many_arguments",
+                    "useless_transmute",
+                    "transmute_int_to_bool",
+                    "unnecessary_cast",
+                ],
+            );
+            methods.extend(Some(
+                if ctx.options().rust_features().const_bitfield_unit {
+                    let size = layout.size;
+                    quote! {
+                        #[inline]
+                        #allow_clippy
+                        #access_spec const fn #ctor_name ( #( #ctor_params ),* ) -> #unit_field_ty {
+                            let mut __bindgen_bitfield_unit = <#unit_field_ty>::new([0; #size]);
+                            #ctor_impl
+                            __bindgen_bitfield_unit
+                        }
+                    }
+                } else {
+                    quote! {
+                        #[inline]
+                        #allow_clippy
+                        #access_spec fn #ctor_name ( #( #ctor_params ),* ) -> #unit_field_ty {
+                            let mut __bindgen_bitfield_unit: #unit_field_ty = Default::default();
+                            #ctor_impl
+                            __bindgen_bitfield_unit
+                        }
+                    }
+                },
+            ));
         }
 
         struct_layout.saw_bitfield_unit(layout);
@@ -1928,9 +2653,19 @@ impl<'a> FieldCodegen<'a> for Bitfield {
         );
         let access_spec = access_specifier(*bitfield_visibility);
 
+        let allow_clippy = helpers::allow_clippy(
+            ctx,
+            &[
+                "useless_transmute",
+                "transmute_int_to_bool",
+                "unnecessary_cast",
+            ],
+        );
+
         if parent.is_union() && !struct_layout.is_rust_union() {
             methods.extend(Some(quote! {
                 #[inline]
+                #allow_clippy
                 #access_spec fn #getter_name(&self) -> #bitfield_ty {
                     unsafe {
                         ::#prefix::mem::transmute(
@@ -1941,6 +2676,7 @@ impl<'a> FieldCodegen<'a> for Bitfield {
                 }
 
                 #[inline]
+                #allow_clippy
                 #access_spec fn #setter_name(&mut self, val: #bitfield_ty) {
                     unsafe {
                         let val: #bitfield_int_ty = ::#prefix::mem::transmute(val);
@@ -1955,6 +2691,7 @@ impl<'a> FieldCodegen<'a> for Bitfield {
         } else {
             methods.extend(Some(quote! {
                 #[inline]
+                #allow_clippy
                 #access_spec fn #getter_name(&self) -> #bitfield_ty {
                     unsafe {
                         ::#prefix::mem::transmute(
@@ -1965,6 +2702,7 @@ impl<'a> FieldCodegen<'a> for Bitfield {
                 }
 
                 #[inline]
+                #allow_clippy
                 #access_spec fn #setter_name(&mut self, val: #bitfield_ty) {
                     unsafe {
                         let val: #bitfield_int_ty = ::#prefix::mem::transmute(val);
@@ -1980,6 +2718,282 @@ impl<'a> FieldCodegen<'a> for Bitfield {
     }
 }
 
+/// Emit a `use` declaration redirecting the given type to the path returned
+/// by [`crate::callbacks::ParseCallbacks::generated_path_override`], if any.
+///
+/// Returns whether the type was redirected, in which case its definition must
+/// not be generated.
+fn codegen_path_override(
+    ctx: &BindgenContext,
+    result: &mut CodegenResult<'_>,
+    item: &Item,
+) -> bool {
+    let name = item.canonical_name(ctx);
+    let path = match ctx.options().last_callback(|cb| {
+        cb.generated_path_override(ItemInfo {
+            name: &name,
+            kind: ItemInfoKind::Type,
+        })
+    }) {
+        Some(path) => path,
+        None => return false,
+    };
+
+    let path = match syn::parse_str::<syn::Path>(&path) {
+        Ok(path) => path,
+        Err(..) => {
+            warning!(
+                "Ignoring the invalid path `{}` returned for the type `{}`",
+                path,
+                name
+            );
+            return false;
+        }
+    };
+
+    let ident = ctx.rust_ident(&name);
+    result.push(quote! {
+        pub use #path as #ident;
+    });
+    true
+}
+
+/// Check that the types `float` and `double` are mapped to with the
+/// `map_float` option are 4 and 8 bytes large.
+fn mapped_float_layout_tests(ctx: &BindgenContext, result: &mut CodegenResult) {
+    let prefix = ctx.trait_prefix();
+    let compile_time = ctx.options().rust_features().offset_of;
+    for (kind, size) in [(FloatKind::Float, 4usize), (FloatKind::Double, 8)] {
+        let ty = match helpers::ast_ty::mapped_float_type(ctx, kind) {
+            Some(ty) => ty,
+            None => continue,
+        };
+        let c_name = if kind == FloatKind::Float {
+            "float"
+        } else {
+            "double"
+        };
+        let size_of_err = format!("Size of the type `{}` is mapped to", c_name);
+        let size_of_expr = quote! { ::#prefix::mem::size_of::<#ty>() };
+        let test = if compile_time {
+            quote! {
+                const _: () = {
+                    [#size_of_err][#size_of_expr - #size];
+                };
+            }
+        } else {
+            let fn_name =
+                ctx.rust_ident_raw(format!("bindgen_test_layout_{}", c_name));
+            quote! {
+                #[test]
+                fn #fn_name() {
+                    assert_eq!(#size_of_expr, #size, #size_of_err);
+                }
+            }
+        };
+        result.push_layout_test(ctx, test);
+    }
+}
+
+/// The names and the offsets in bytes of the fields of the given compound
+/// type whose offset is known, in the order they are declared.
+fn field_offsets(comp_info: &CompInfo, is_opaque: bool) -> Vec<(&str, usize)> {
+    if is_opaque {
+        return vec![];
+    }
+    comp_info
+        .fields()
+        .iter()
+        .filter_map(|field| match *field {
+            Field::DataMember(ref f) => Some((f.name()?, f.offset()? / 8)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A check of the layout of a compound type, see [`layout_checks`].
+enum LayoutCheck<'a> {
+    Size,
+    Align,
+    Offset(&'a str),
+}
+
+/// Build the checks of the size, alignment and field offsets of the given
+/// compound type, each turned into a statement by `check` from the kind of
+/// check, the expression computing the actual value and the expected value.
+fn layout_checks(
+    ctx: &BindgenContext,
+    comp_info: &CompInfo,
+    item: &Item,
+    layout: Layout,
+    is_opaque: bool,
+    check: impl Fn(
+        LayoutCheck,
+        proc_macro2::TokenStream,
+        usize,
+    ) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let prefix = ctx.trait_prefix();
+    // Use the full path of the type, as the checks are all emitted in the root
+    // module.
+    let ty = item.to_rust_ty_or_opaque(ctx, &());
+
+    let check_size = check(
+        LayoutCheck::Size,
+        quote! { ::#prefix::mem::size_of::<#ty>() },
+        layout.size,
+    );
+    let check_align = if layout.align > ctx.target_pointer_size() &&
+        !ctx.options().rust_features().repr_align
+    {
+        None
+    } else {
+        Some(check(
+            LayoutCheck::Align,
+            quote! { ::#prefix::mem::align_of::<#ty>() },
+            layout.align,
+        ))
+    };
+
+    let fields = field_offsets(comp_info, is_opaque);
+
+    let offset_of = ctx.options().rust_features().offset_of;
+    let check_fields = fields.iter().map(|(field_name, offset)| {
+        let field_ident = ctx.rust_ident(field_name);
+        let offset_of_expr = if offset_of {
+            quote! { ::#prefix::mem::offset_of!(#ty, #field_ident) }
+        } else {
+            quote! {
+                unsafe {
+                    ::#prefix::ptr::addr_of!((*ptr).#field_ident) as usize - ptr as usize
+                }
+            }
+        };
+        check(
+            LayoutCheck::Offset(&field_ident.to_string()),
+            offset_of_expr,
+            *offset,
+        )
+    });
+
+    let uninit_decl = if fields.is_empty() || offset_of {
+        None
+    } else {
+        Some(quote! {
+            const UNINIT: ::#prefix::mem::MaybeUninit<#ty> = ::#prefix::mem::MaybeUninit::uninit();
+            let ptr = UNINIT.as_ptr();
+        })
+    };
+
+    quote! {
+        {
+            #uninit_decl
+            #check_size
+            #check_align
+            #( #check_fields )*
+        }
+    }
+}
+
+/// Build the `debug_assert_eq!` checks of the layout of the given compound
+/// type.
+fn layout_debug_asserts(
+    ctx: &BindgenContext,
+    comp_info: &CompInfo,
+    item: &Item,
+    layout: Layout,
+    is_opaque: bool,
+) -> proc_macro2::TokenStream {
+    let name = item.canonical_name(ctx);
+    layout_checks(
+        ctx,
+        comp_info,
+        item,
+        layout,
+        is_opaque,
+        |kind, actual, expected| {
+            let err = match kind {
+                LayoutCheck::Size => format!("Size of {name}"),
+                LayoutCheck::Align => format!("Alignment of {name}"),
+                LayoutCheck::Offset(field_name) => {
+                    format!("Offset of field: {name}::{field_name}")
+                }
+            };
+            quote! {
+                debug_assert_eq!(#actual, #expected, #err);
+            }
+        },
+    )
+}
+
+/// Build the checks of the layout of the given compound type, returning a
+/// `LayoutError` from the `verify_layouts` function on the first mismatch.
+fn layout_verifications(
+    ctx: &BindgenContext,
+    comp_info: &CompInfo,
+    item: &Item,
+    layout: Layout,
+    is_opaque: bool,
+) -> proc_macro2::TokenStream {
+    let name = item.canonical_name(ctx);
+    layout_checks(
+        ctx,
+        comp_info,
+        item,
+        layout,
+        is_opaque,
+        |kind, actual, expected| {
+            let error = match kind {
+                LayoutCheck::Size => {
+                    quote! { Size { ty: #name, expected: #expected, actual } }
+                }
+                LayoutCheck::Align => {
+                    quote! { Align { ty: #name, expected: #expected, actual } }
+                }
+                LayoutCheck::Offset(field_name) => quote! {
+                    Offset { ty: #name, field: #field_name, expected: #expected, actual }
+                },
+            };
+            quote! {
+                let actual = #actual;
+                if actual != #expected {
+                    return Err(LayoutError::#error);
+                }
+            }
+        },
+    )
+}
+
+/// Generate a type that can only be used behind a pointer, see
+/// [`Builder::opaque_no_construct`](crate::Builder::opaque_no_construct).
+fn codegen_opaque_no_construct(
+    ctx: &BindgenContext,
+    result: &mut CodegenResult<'_>,
+    item: &Item,
+) {
+    let canonical_ident = ctx.rust_ident(item.canonical_name(ctx));
+    let prefix = ctx.trait_prefix();
+
+    let mut attributes = vec![];
+    if let Some(comment) = item.comment(ctx) {
+        attributes.push(attributes::doc(comment));
+    }
+    attributes.push(attributes::repr("C"));
+
+    // The raw pointer makes the type `!Send` and `!Sync`, and `PhantomPinned`
+    // makes it `!Unpin`, since the C++ side may rely on its address.
+    result.push(quote! {
+        #( #attributes )*
+        pub struct #canonical_ident {
+            _unused: [u8; 0],
+            _marker: ::#prefix::marker::PhantomData<(
+                *mut u8,
+                ::#prefix::marker::PhantomPinned,
+            )>,
+        }
+    });
+}
+
 impl CodeGenerator for CompInfo {
     type Extra = Item;
     type Return = ();
@@ -1999,6 +3013,13 @@ impl CodeGenerator for CompInfo {
             return;
         }
 
+        if ctx.opaque_no_construct_by_name(item) &&
+            item.all_template_params(ctx).is_empty()
+        {
+            codegen_opaque_no_construct(ctx, result, item);
+            return;
+        }
+
         let ty = item.expect_type();
         let layout = ty.layout(ctx);
         let mut packed = self.is_packed(ctx, layout.as_ref());
@@ -2185,7 +3206,7 @@ impl CodeGenerator for CompInfo {
                     });
                 }
                 None => {
-                    warn!("Opaque type without layout! Expect dragons!");
+                    warning!("Opaque type without layout! Expect dragons!");
                 }
             }
         } else if !is_union && !zero_sized {
@@ -2235,6 +3256,21 @@ impl CodeGenerator for CompInfo {
             });
         }
 
+        if ctx.pinned_by_name(item) {
+            let prefix = ctx.trait_prefix();
+            let pin = quote! {
+                _pin: ::#prefix::marker::PhantomPinned,
+            };
+            // A dynamically sized flexible array member has to stay last.
+            if ctx.options().flexarray_dst &&
+                self.flex_array_member(ctx).is_some()
+            {
+                fields.insert(fields.len() - 1, pin);
+            } else {
+                fields.push(pin);
+            }
+        }
+
         let (flex_array_generic, flex_inner_ty) = if ctx.options().flexarray_dst
         {
             match self.flex_array_member(ctx) {
@@ -2288,9 +3324,35 @@ impl CodeGenerator for CompInfo {
         let mut needs_default_impl = false;
         let mut needs_debug_impl = false;
         let mut needs_partialeq_impl = false;
+        let mut needs_hash_impl = false;
         let needs_flexarray_impl = flex_array_generic.is_some();
+        let mut has_doc = false;
         if let Some(comment) = item.comment(ctx) {
             attributes.push(attributes::doc(comment));
+            has_doc = true;
+        }
+
+        if ctx.options().document_layout {
+            if let Some(layout) = layout {
+                if has_doc {
+                    attributes.push(quote!(#[doc = ""]));
+                }
+                attributes.push(attributes::doc(format!(
+                    " Size: {} bytes, alignment: {} bytes.",
+                    layout.size, layout.align
+                )));
+                let fields = field_offsets(self, is_opaque);
+                if !fields.is_empty() {
+                    attributes.push(quote!(#[doc = ""]));
+                    attributes.push(attributes::doc(" Field offsets:".into()));
+                    for (name, offset) in fields {
+                        attributes.push(attributes::doc(format!(
+                            " - `{}`: {} bytes",
+                            name, offset
+                        )));
+                    }
+                }
+            }
         }
 
         // if a type has both a "packed" attribute and an "align(N)" attribute, then check if the
@@ -2346,6 +3408,10 @@ impl CodeGenerator for CompInfo {
             needs_clone_impl = true;
         }
 
+        let needs_clone_fallback_impl = ctx.options().impl_clone_fallback &&
+            !derivable_traits.contains(DerivableTraits::COPY) &&
+            impl_clone::can_gen_clone_impl(ctx, self, item);
+
         if !derivable_traits.contains(DerivableTraits::PARTIAL_EQ) {
             needs_partialeq_impl = ctx.options().derive_partialeq &&
                 ctx.options().impl_partialeq &&
@@ -2353,6 +3419,13 @@ impl CodeGenerator for CompInfo {
                     CanDerive::Manually;
         }
 
+        if !derivable_traits.contains(DerivableTraits::HASH) {
+            needs_hash_impl = ctx.options().derive_hash &&
+                ctx.options().impl_hash &&
+                !ctx.no_hash_by_name(item) &&
+                impl_hash::can_gen_hash_impl(ctx, self, item);
+        }
+
         let mut derives: Vec<_> = derivable_traits.into();
         derives.extend(item.annotations().derives().iter().map(String::as_str));
 
@@ -2372,15 +3445,32 @@ impl CodeGenerator for CompInfo {
         });
         // In most cases this will be a no-op, since custom_derives will be empty.
         derives.extend(custom_derives.iter().map(|s| s.as_str()));
+        sort_derives(ctx, &mut derives);
 
         if !derives.is_empty() {
             attributes.push(attributes::derives(&derives))
         }
 
+        if ctx.derive_arbitrary_by_name(item) {
+            match derive_arbitrary::blocker(ctx, self, item) {
+                None => attributes.push(attributes::derive_arbitrary()),
+                Some(reason) => arbitrary_diagnostic(
+                    &canonical_name,
+                    reason,
+                    item.location(),
+                    ctx,
+                ),
+            }
+        }
+
         if item.must_use(ctx) {
             attributes.push(attributes::must_use());
         }
 
+        if ctx.is_transitive_dependency(item.id()) {
+            attributes.push(attributes::doc_hidden());
+        }
+
         let mut tokens = if is_rust_union {
             quote! {
                 #( #attributes )*
@@ -2414,7 +3504,7 @@ impl CodeGenerator for CompInfo {
         // affect layout, so we're bad and pray to the gods for avoid sending
         // all the tests to shit when parsing things like max_align_t.
         if self.found_unknown_attr() {
-            warn!(
+            warning!(
                 "Type {} has an unknown attribute that may affect layout",
                 canonical_ident
             );
@@ -2522,25 +3612,67 @@ impl CodeGenerator for CompInfo {
                     };
 
                     if compile_time {
-                        result.push(quote! {
-                            const _: () = {
-                                [#size_of_err][#size_of_expr - #size];
-                                #check_struct_align
-                                #( #check_field_offset )*
-                            };
-                        });
+                        // The offset checks of the first fields subtract 0.
+                        let allow_clippy =
+                            helpers::allow_clippy(ctx, &["identity_op"]);
+                        result.push_layout_test(
+                            ctx,
+                            quote! {
+                                #allow_clippy
+                                const _: () = {
+                                    [#size_of_err][#size_of_expr - #size];
+                                    #check_struct_align
+                                    #( #check_field_offset )*
+                                };
+                            },
+                        );
                     } else {
-                        result.push(quote! {
-                            #[test]
-                            fn #fn_name() {
-                                #uninit_decl
-                                assert_eq!(#size_of_expr, #size, #size_of_err);
-                                #check_struct_align
-                                #( #check_field_offset )*
-                            }
-                        });
+                        result.push_layout_test(
+                            ctx,
+                            quote! {
+                                #[test]
+                                fn #fn_name() {
+                                    #uninit_decl
+                                    assert_eq!(#size_of_expr, #size, #size_of_err);
+                                    #check_struct_align
+                                    #( #check_field_offset )*
+                                }
+                            },
+                        );
                     }
                 }
+
+                let extra_assertions = ctx.options().all_callbacks(|cb| {
+                    cb.extra_assertions(&ItemInfo {
+                        name: &canonical_name,
+                        kind: ItemInfoKind::Type,
+                    })
+                });
+                for assertion in extra_assertions {
+                    result.push_layout_test(ctx, assertion);
+                }
+            }
+
+            if ctx.options().layout_debug_asserts &&
+                !self.is_forward_declaration()
+            {
+                if let Some(layout) = layout {
+                    let asserts = layout_debug_asserts(
+                        ctx, self, item, layout, is_opaque,
+                    );
+                    result.layout_debug_asserts.push(asserts);
+                }
+            }
+
+            if ctx.options().layout_verification_fn &&
+                !self.is_forward_declaration()
+            {
+                if let Some(layout) = layout {
+                    let checks = layout_verifications(
+                        ctx, self, item, layout, is_opaque,
+                    );
+                    result.layout_verifications.push(checks);
+                }
             }
 
             let mut method_names = Default::default();
@@ -2603,6 +3735,17 @@ impl CodeGenerator for CompInfo {
             });
         }
 
+        if needs_clone_fallback_impl {
+            let impl_ = impl_clone::gen_clone_impl(ctx, &fields);
+            let prefix = ctx.trait_prefix();
+
+            result.push(quote! {
+                impl #impl_generics_labels ::#prefix::clone::Clone for #ty_for_impl {
+                    #impl_
+                }
+            });
+        }
+
         if needs_flexarray_impl {
             result.push(self.generate_flexarray(
                 ctx,
@@ -2687,10 +3830,73 @@ impl CodeGenerator for CompInfo {
             }
         }
 
-        if !methods.is_empty() {
+        if needs_hash_impl {
+            let impl_ = impl_hash::gen_hash_impl(ctx, self);
+
+            let prefix = ctx.trait_prefix();
             result.push(quote! {
-                impl #impl_generics_labels #ty_for_impl {
-                    #( #methods )*
+                impl #impl_generics_labels ::#prefix::hash::Hash for #ty_for_impl {
+                    #impl_
+                }
+            });
+        }
+
+        if ctx.derive_from_bytes_by_name(item) {
+            if impl_from_bytes::is_pod(ctx, self, item) {
+                methods.push(impl_from_bytes::gen_from_bytes_methods(ctx));
+            } else {
+                not_pod_diagnostic(&canonical_name, item.location(), ctx);
+            }
+        }
+
+        if ctx.first_field_as_ref_by_name(item) {
+            if let Some(impls) = self.first_field_as_ref_impls(
+                ctx,
+                is_opaque,
+                &ty_for_impl,
+                &impl_generics_labels,
+            ) {
+                result.push(impls);
+            } else {
+                warning!(
+                    "Cannot generate `AsRef` for `{}` as its first field is not a struct at offset zero.",
+                    canonical_name,
+                );
+            }
+        }
+
+        let thread_safety = ctx.options().last_callback(|cb| {
+            let thread_safety = cb.thread_safety(&canonical_name);
+            (thread_safety != ThreadSafety::default()).then_some(thread_safety)
+        });
+        if let Some(thread_safety) = thread_safety {
+            let prefix = ctx.trait_prefix();
+            if thread_safety.send {
+                let safety = format!(
+                    " SAFETY: `{}` can be sent between threads, as asserted by `ParseCallbacks::thread_safety`.",
+                    canonical_name
+                );
+                result.push(quote! {
+                    #[doc = #safety]
+                    unsafe impl #impl_generics_labels ::#prefix::marker::Send for #ty_for_impl {}
+                });
+            }
+            if thread_safety.sync {
+                let safety = format!(
+                    " SAFETY: `{}` can be shared between threads, as asserted by `ParseCallbacks::thread_safety`.",
+                    canonical_name
+                );
+                result.push(quote! {
+                    #[doc = #safety]
+                    unsafe impl #impl_generics_labels ::#prefix::marker::Sync for #ty_for_impl {}
+                });
+            }
+        }
+
+        if !methods.is_empty() {
+            result.push(quote! {
+                impl #impl_generics_labels #ty_for_impl {
+                    #( #methods )*
                 }
             });
         }
@@ -2698,6 +3904,53 @@ impl CodeGenerator for CompInfo {
 }
 
 impl CompInfo {
+    /// Generate the `AsRef` and `AsMut` implementations upcasting this struct
+    /// to its first field, see [`crate::Builder::first_field_as_ref`].
+    ///
+    /// Returns `None` if the first field is not a struct at offset zero.
+    fn first_field_as_ref_impls(
+        &self,
+        ctx: &BindgenContext,
+        is_opaque: bool,
+        ty_for_impl: &proc_macro2::TokenStream,
+        impl_generics_labels: &proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        if is_opaque || self.is_union() {
+            return None;
+        }
+
+        let data = match self.fields().first()? {
+            Field::DataMember(data) if data.offset() == Some(0) => data,
+            _ => return None,
+        };
+        let field_item = ctx.resolve_item(data.ty());
+        let is_struct = matches!(
+            *field_item.expect_type().canonical_type(ctx).kind(),
+            TypeKind::Comp(ref comp) if comp.kind() == CompKind::Struct
+        );
+        if !is_struct || field_item.is_opaque(ctx, &()) {
+            return None;
+        }
+
+        let field_ident = ctx.rust_ident_raw(ctx.rust_mangle(data.name()?));
+        let field_ty = data.ty().to_rust_ty_or_opaque(ctx, &());
+        let prefix = ctx.trait_prefix();
+        Some(quote! {
+            impl #impl_generics_labels ::#prefix::convert::AsRef<#field_ty> for #ty_for_impl {
+                #[inline]
+                fn as_ref(&self) -> &#field_ty {
+                    &self.#field_ident
+                }
+            }
+            impl #impl_generics_labels ::#prefix::convert::AsMut<#field_ty> for #ty_for_impl {
+                #[inline]
+                fn as_mut(&mut self) -> &mut #field_ty {
+                    &mut self.#field_ident
+                }
+            }
+        })
+    }
+
     fn generate_flexarray(
         &self,
         ctx: &BindgenContext,
@@ -2999,6 +4252,8 @@ impl Method {
             attrs.push(attributes::must_use());
         }
 
+        attrs.extend(helpers::allow_clippy(ctx, &["missing_safety_doc"]));
+
         let name = ctx.rust_ident(&name);
         methods.push(quote! {
             #(#attrs)*
@@ -3019,7 +4274,8 @@ pub enum EnumVariation {
         /// Indicates whether the generated struct should be `#[non_exhaustive]`
         non_exhaustive: bool,
     },
-    /// The code for this enum will use a newtype
+    /// The code for this enum will use a newtype, with the variants as its
+    /// associated constants unless they are global.
     NewType {
         /// Indicates whether the newtype will have bitwise operators
         is_bitfield: bool,
@@ -3119,6 +4375,11 @@ enum EnumBuilder<'a> {
         ident: Ident,
         tokens: proc_macro2::TokenStream,
         emitted_any_variants: bool,
+        default_variant: Option<&'a str>,
+        repr: proc_macro2::TokenStream,
+        /// The variants and their values, if the enum has an `Unknown`
+        /// variant.
+        known_variants: Option<Vec<(Ident, proc_macro2::TokenStream)>>,
     },
     NewType {
         canonical_name: &'a str,
@@ -3141,11 +4402,37 @@ impl<'a> EnumBuilder<'a> {
         matches!(*self, EnumBuilder::Rust { .. })
     }
 
+    /// Mark the variant with the given name as `#[default]`, if the enum is
+    /// rustified.
+    fn with_default_variant(mut self, name: &'a str) -> Self {
+        if let EnumBuilder::Rust {
+            ref mut default_variant,
+            ..
+        } = self
+        {
+            *default_variant = Some(name);
+        }
+        self
+    }
+
+    /// Give the enum an `Unknown` variant holding the values that don't match
+    /// any other variant, if the enum is rustified.
+    fn with_unknown_variant(mut self) -> Self {
+        if let EnumBuilder::Rust {
+            ref mut known_variants,
+            ..
+        } = self
+        {
+            *known_variants = Some(vec![]);
+        }
+        self
+    }
+
     /// Create a new enum given an item builder, a canonical name, a name for
     /// the representation, and which variation it should be generated as.
     fn new(
         name: &'a str,
-        mut attrs: Vec<proc_macro2::TokenStream>,
+        attrs: Vec<proc_macro2::TokenStream>,
         repr: syn::Type,
         enum_variation: EnumVariation,
         has_typedef: bool,
@@ -3168,13 +4455,15 @@ impl<'a> EnumBuilder<'a> {
 
             EnumVariation::Rust { .. } => {
                 // `repr` is guaranteed to be Rustified in Enum::codegen
-                attrs.insert(0, quote! { #[repr( #repr )] });
                 let tokens = quote!();
                 EnumBuilder::Rust {
                     attrs,
                     ident,
                     tokens,
                     emitted_any_variants: false,
+                    default_variant: None,
+                    repr: quote! { #repr },
+                    known_variants: None,
                 }
             }
 
@@ -3244,17 +4533,38 @@ impl<'a> EnumBuilder<'a> {
                 ident,
                 tokens,
                 emitted_any_variants: _,
+                default_variant,
+                repr,
+                mut known_variants,
             } => {
                 let name = ctx.rust_ident(variant_name);
+                let default = if default_variant == Some(variant.name()) {
+                    quote! { #[default] }
+                } else {
+                    quote! {}
+                };
+                // The `Unknown` variant has a field, so the values are only
+                // part of the conversions.
+                let discriminant = match known_variants {
+                    Some(ref mut known_variants) => {
+                        known_variants.push((name.clone(), expr));
+                        quote! {}
+                    }
+                    None => quote! { = #expr },
+                };
                 EnumBuilder::Rust {
                     attrs,
                     ident,
                     tokens: quote! {
                         #tokens
                         #doc
-                        #name = #expr,
+                        #default
+                        #name #discriminant,
                     },
                     emitted_any_variants: true,
+                    default_variant,
+                    repr,
+                    known_variants,
                 }
             }
 
@@ -3334,11 +4644,57 @@ impl<'a> EnumBuilder<'a> {
         result: &mut CodegenResult<'_>,
     ) -> proc_macro2::TokenStream {
         match self {
+            EnumBuilder::Rust {
+                attrs,
+                ident,
+                tokens,
+                repr,
+                known_variants: Some(known_variants),
+                ..
+            } => {
+                let prefix = ctx.trait_prefix();
+                let unknown =
+                    Ident::new(UNKNOWN_VARIANT_NAME, Span::call_site());
+                let (names, values): (Vec<_>, Vec<_>) =
+                    known_variants.into_iter().unzip();
+
+                result.push(quote! {
+                    impl ::#prefix::convert::From<#repr> for #rust_ty {
+                        #[inline]
+                        fn from(value: #repr) -> Self {
+                            match value {
+                                #( #values => Self::#names, )*
+                                value => Self::#unknown(value),
+                            }
+                        }
+                    }
+                });
+                result.push(quote! {
+                    impl ::#prefix::convert::From<#rust_ty> for #repr {
+                        #[inline]
+                        fn from(value: #rust_ty) -> Self {
+                            match value {
+                                #( #rust_ty::#names => #values, )*
+                                #rust_ty::#unknown(value) => value,
+                            }
+                        }
+                    }
+                });
+
+                quote! {
+                    #( #attrs )*
+                    pub enum #ident {
+                        #tokens
+                        #unknown(#repr),
+                    }
+                }
+            }
             EnumBuilder::Rust {
                 attrs,
                 ident,
                 tokens,
                 emitted_any_variants,
+                repr,
                 ..
             } => {
                 let variants = if !emitted_any_variants {
@@ -3348,6 +4704,7 @@ impl<'a> EnumBuilder<'a> {
                 };
 
                 quote! {
+                    #[repr( #repr )]
                     #( #attrs )*
                     pub enum #ident {
                         #variants
@@ -3426,6 +4783,49 @@ impl<'a> EnumBuilder<'a> {
     }
 }
 
+/// The native Rust integer type of a rustified enum with the given integer
+/// type and layout.
+fn translated_enum_repr(
+    ctx: &BindgenContext,
+    repr: Option<&Type>,
+    layout: Option<Layout>,
+) -> IntKind {
+    let kind = match repr {
+        Some(repr) => match *repr.canonical_type(ctx).kind() {
+            TypeKind::Int(int_kind) => int_kind,
+            _ => panic!("Unexpected type as enum repr"),
+        },
+        None => {
+            warning!(
+                "Guessing type of enum! Forward declarations of enums \
+                     shouldn't be legal!"
+            );
+            IntKind::Int
+        }
+    };
+
+    let signed = kind.is_signed();
+    let size = layout
+        .map(|l| l.size)
+        .or_else(|| kind.known_size())
+        .unwrap_or(0);
+
+    match (signed, size) {
+        (true, 1) => IntKind::I8,
+        (false, 1) => IntKind::U8,
+        (true, 2) => IntKind::I16,
+        (false, 2) => IntKind::U16,
+        (true, 4) => IntKind::I32,
+        (false, 4) => IntKind::U32,
+        (true, 8) => IntKind::I64,
+        (false, 8) => IntKind::U64,
+        _ => {
+            warning!("invalid enum decl: signed: {}, size: {}", signed, size);
+            IntKind::I32
+        }
+    }
+}
+
 impl CodeGenerator for Enum {
     type Extra = Item;
     type Return = ();
@@ -3444,6 +4844,10 @@ impl CodeGenerator for Enum {
         let enum_ty = item.expect_type();
         let layout = enum_ty.layout(ctx);
         let variation = self.computed_enum_variation(ctx, item);
+        let has_unknown_variant = self.has_unknown_variant(ctx, item);
+        if self.wants_unknown_variant(ctx, item) && !has_unknown_variant {
+            unknown_variant_collision_diagnostic(&name, item.location(), ctx);
+        }
 
         let repr_translated;
         let repr = match self.repr().map(|repr| ctx.resolve_type(repr)) {
@@ -3461,44 +4865,7 @@ impl CodeGenerator for Enum {
                 // * the representation couldn't be determined from the C source
                 // * it was explicitly requested as a bindgen option
 
-                let kind = match repr {
-                    Some(repr) => match *repr.canonical_type(ctx).kind() {
-                        TypeKind::Int(int_kind) => int_kind,
-                        _ => panic!("Unexpected type as enum repr"),
-                    },
-                    None => {
-                        warn!(
-                            "Guessing type of enum! Forward declarations of enums \
-                             shouldn't be legal!"
-                        );
-                        IntKind::Int
-                    }
-                };
-
-                let signed = kind.is_signed();
-                let size = layout
-                    .map(|l| l.size)
-                    .or_else(|| kind.known_size())
-                    .unwrap_or(0);
-
-                let translated = match (signed, size) {
-                    (true, 1) => IntKind::I8,
-                    (false, 1) => IntKind::U8,
-                    (true, 2) => IntKind::I16,
-                    (false, 2) => IntKind::U16,
-                    (true, 4) => IntKind::I32,
-                    (false, 4) => IntKind::U32,
-                    (true, 8) => IntKind::I64,
-                    (false, 8) => IntKind::U64,
-                    _ => {
-                        warn!(
-                            "invalid enum decl: signed: {}, size: {}",
-                            signed, size
-                        );
-                        IntKind::I32
-                    }
-                };
-
+                let translated = translated_enum_repr(ctx, repr, layout);
                 repr_translated =
                     Type::new(None, None, TypeKind::Int(translated), false);
                 &repr_translated
@@ -3538,6 +4905,29 @@ impl CodeGenerator for Enum {
             attrs.push(attributes::must_use());
         }
 
+        if ctx.is_transitive_dependency(item.id()) {
+            attrs.push(attributes::doc_hidden());
+        }
+
+        // Rustified enums can derive `Default` by marking one of their
+        // variants as `#[default]`.
+        let default_variant = if variation.is_rust() &&
+            ctx.options().derive_default &&
+            ctx.options().rust_features().default_enum_variant &&
+            !ctx.no_default_by_name(item) &&
+            !item.annotations().disallow_default()
+        {
+            let variant = self.default_variant(ctx);
+            // Empty enums can't derive `Default` either way, so there is
+            // nothing to report for them.
+            if variant.is_none() && !self.variants().is_empty() {
+                no_default_variant_diagnostic(&name, item.location(), ctx);
+            }
+            variant
+        } else {
+            None
+        };
+
         if !variation.is_const() {
             let packed = false; // Enums can't be packed in Rust.
             let mut derives = derives_of_item(item, ctx, packed);
@@ -3550,6 +4940,9 @@ impl CodeGenerator for Enum {
                     DerivableTraits::PARTIAL_EQ |
                     DerivableTraits::EQ,
             );
+            if default_variant.is_some() {
+                derives.insert(DerivableTraits::DEFAULT);
+            }
             let mut derives: Vec<_> = derives.into();
             for derive in item.annotations().derives().iter() {
                 if !derives.contains(&derive.as_str()) {
@@ -3567,8 +4960,13 @@ impl CodeGenerator for Enum {
             });
             // In most cases this will be a no-op, since custom_derives will be empty.
             derives.extend(custom_derives.iter().map(|s| s.as_str()));
+            sort_derives(ctx, &mut derives);
 
             attrs.push(attributes::derives(&derives));
+
+            if ctx.derive_arbitrary_by_name(item) {
+                attrs.push(attributes::derive_arbitrary());
+            }
         }
 
         fn add_constant(
@@ -3606,10 +5004,22 @@ impl CodeGenerator for Enum {
 
         let mut builder =
             EnumBuilder::new(&name, attrs, repr, variation, has_typedef);
+        if let Some(variant) = default_variant {
+            builder = builder.with_default_variant(variant.name());
+        }
+        if has_unknown_variant {
+            builder = builder.with_unknown_variant();
+        }
 
         // A map where we keep a value -> variant relation.
         let mut seen_values = HashMap::<_, Ident>::default();
-        let enum_rust_ty = item.to_rust_ty_or_opaque(ctx, &());
+        // Elsewhere, an enum with an `Unknown` variant is referred to by its
+        // integer type.
+        let enum_rust_ty = if has_unknown_variant {
+            syn::parse_quote! { #ident }
+        } else {
+            item.to_rust_ty_or_opaque(ctx, &())
+        };
         let is_toplevel = item.is_toplevel(ctx);
 
         // Used to mangle the constants we generate in the unnamed-enum case.
@@ -3634,6 +5044,10 @@ impl CodeGenerator for Enum {
         // do).
         let mut constified_variants = VecDeque::new();
 
+        // The variants emitted as variants of a Rust enum, to list them in
+        // `ALL`.
+        let mut rust_variants = HashSet::<&str>::default();
+
         let mut iter = self.variants().iter().peekable();
         while let Some(variant) =
             iter.next().or_else(|| constified_variants.pop_front())
@@ -3710,6 +5124,10 @@ impl CodeGenerator for Enum {
                         enum_ty.name().is_some(),
                     );
 
+                    if variation.is_rust() {
+                        rust_variants.insert(variant.name());
+                    }
+
                     let variant_name = ctx.rust_ident(variant.name());
 
                     // If it's an unnamed enum, or constification is enforced,
@@ -3746,8 +5164,176 @@ impl CodeGenerator for Enum {
             }
         }
 
+        // The variants of the other variations are global constants already.
+        let has_flat_variants = match variation {
+            EnumVariation::NewType { is_global, .. } => is_global,
+            EnumVariation::Consts => true,
+            EnumVariation::Rust { .. } | EnumVariation::ModuleConsts => false,
+        };
+        if ctx.options().flat_enum_constants &&
+            ctx.options().rust_features().associated_const &&
+            enum_ty.name().is_some() &&
+            !has_flat_variants
+        {
+            for variant in self.variants().iter().filter(|v| !v.hidden()) {
+                let variant_name = ctx.rust_mangle(variant.name());
+                match result
+                    .flat_enum_constants_seen
+                    .entry(variant_name.to_string())
+                {
+                    Entry::Occupied(entry) => {
+                        flat_enum_constant_collision_diagnostic(
+                            &variant_name,
+                            entry.get(),
+                            &name,
+                            item.location(),
+                            ctx,
+                        );
+                        continue;
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(name.clone());
+                    }
+                }
+
+                let variant_ident = ctx.rust_ident(variant_name);
+                result.push(quote! {
+                    pub const #variant_ident : #enum_rust_ty = #ident :: #variant_ident ;
+                });
+            }
+        }
+
+        if ctx.options().enum_variants_slice &&
+            ctx.options().rust_features().associated_const &&
+            variation.is_rust()
+        {
+            // Deferred variants are emitted last, so list the variants in
+            // declaration order instead.
+            let variants = self
+                .variants()
+                .iter()
+                .filter(|v| rust_variants.contains(v.name()))
+                .map(|v| ctx.rust_ident(ctx.rust_mangle(v.name())));
+            result.push(quote! {
+                impl #enum_rust_ty {
+                    pub const ALL: &'static [Self] = &[ #( Self :: #variants ),* ];
+                }
+            });
+        }
+
+        if ctx.options().enum_name_fn {
+            let body = match variation {
+                EnumVariation::Rust { .. } => {
+                    let mut arms: Vec<_> = self
+                        .variants()
+                        .iter()
+                        .filter(|v| rust_variants.contains(v.name()))
+                        .map(|v| {
+                            let c_name = v.name();
+                            let variant_ident =
+                                ctx.rust_ident(ctx.rust_mangle(c_name));
+                            quote! { Self :: #variant_ident => #c_name , }
+                        })
+                        .collect();
+                    if has_unknown_variant {
+                        let unknown = ctx.rust_ident(UNKNOWN_VARIANT_NAME);
+                        arms.push(quote! {
+                            Self :: #unknown (_) => "<unknown>" ,
+                        });
+                    }
+                    Some(quote! { match self { #( #arms )* } })
+                }
+                EnumVariation::NewType {
+                    is_bitfield: false, ..
+                } => {
+                    // Variants with the same value as a previous one are
+                    // named after that one.
+                    let mut values = HashSet::default();
+                    let arms = self
+                        .variants()
+                        .iter()
+                        .filter(|v| !v.hidden() && values.insert(v.val()))
+                        .map(|v| {
+                            let c_name = v.name();
+                            let value = match v.val() {
+                                EnumVariantValue::Boolean(v) => quote!(#v),
+                                EnumVariantValue::Signed(v) => {
+                                    helpers::ast_ty::int_expr(v)
+                                }
+                                EnumVariantValue::Unsigned(v) => {
+                                    helpers::ast_ty::uint_expr(v)
+                                }
+                            };
+                            quote! { #value => #c_name , }
+                        });
+                    Some(quote! {
+                        match self.0 {
+                            #( #arms )*
+                            _ => "<unknown>",
+                        }
+                    })
+                }
+                _ => None,
+            };
+            if let Some(body) = body {
+                result.push(quote! {
+                    impl #enum_rust_ty {
+                        /// The name of the C enumerator of this value, or
+                        /// `"<unknown>"` if it doesn't have one.
+                        pub fn name(&self) -> &'static str {
+                            #body
+                        }
+                    }
+                });
+            }
+        }
+
         let item = builder.build(ctx, enum_rust_ty, result);
         result.push(item);
+
+        // The newtype must be exactly as wide as the integer type of the enum
+        // for arrays of it to have the same layout as in C.
+        if ctx.options().layout_tests &&
+            matches!(
+                variation,
+                EnumVariation::NewType {
+                    is_bitfield: false,
+                    ..
+                }
+            )
+        {
+            if let Some(layout) = layout {
+                let prefix = ctx.trait_prefix();
+                let size = layout.size;
+                let size_of_expr = quote! {
+                    ::#prefix::mem::size_of::<#ident>()
+                };
+                let size_of_err = format!("Size of {ident}");
+
+                if ctx.options().rust_features().offset_of {
+                    result.push_layout_test(
+                        ctx,
+                        quote! {
+                            const _: () = {
+                                [#size_of_err][#size_of_expr - #size];
+                            };
+                        },
+                    );
+                } else {
+                    let fn_name = ctx
+                        .rust_ident_raw(format!("bindgen_test_layout_{ident}"));
+                    result.push_layout_test(
+                        ctx,
+                        quote! {
+                            #[test]
+                            fn #fn_name() {
+                                assert_eq!(#size_of_expr, #size, #size_of_err);
+                            }
+                        },
+                    );
+                }
+            }
+        }
     }
 }
 
@@ -3790,6 +5376,46 @@ impl std::str::FromStr for MacroTypeVariation {
     }
 }
 
+/// Enum for which variant of a rustified enum is marked as `#[default]` when
+/// deriving `Default`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum EnumDefaultVariant {
+    /// The first variant whose value is zero, like a zero-initialized C enum
+    #[default]
+    Zero,
+    /// The first variant
+    First,
+}
+
+impl fmt::Display for EnumDefaultVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Zero => "zero",
+            Self::First => "first",
+        };
+        s.fmt(f)
+    }
+}
+
+impl std::str::FromStr for EnumDefaultVariant {
+    type Err = std::io::Error;
+
+    /// Create a `EnumDefaultVariant` from a string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "zero" => Ok(EnumDefaultVariant::Zero),
+            "first" => Ok(EnumDefaultVariant::First),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                concat!(
+                    "Got an invalid EnumDefaultVariant. Accepted values ",
+                    "are 'zero' and 'first'"
+                ),
+            )),
+        }
+    }
+}
+
 /// Enum for how aliases should be translated.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
 pub enum AliasVariation {
@@ -3881,25 +5507,124 @@ impl std::str::FromStr for NonCopyUnionStyle {
     }
 }
 
-/// Fallible conversion to an opaque blob.
-///
-/// Implementors of this trait should provide the `try_get_layout` method to
-/// fallibly get this thing's layout, which the provided `try_to_opaque` trait
-/// method will use to convert the `Layout` into an opaque blob Rust type.
-pub(crate) trait TryToOpaque {
-    type Extra;
+/// The value a C function returns to signal that it failed and set `errno`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ErrnoValue {
+    /// The function returns the given integer on error, e.g. `-1`.
+    ///
+    /// Negative values are converted to the return type of unsigned functions, so that `-1`
+    /// matches e.g. a `(size_t)-1` return value.
+    Int(i64),
+    /// The function returns a negative integer on error.
+    Negative,
+    /// The function returns a null pointer on error.
+    Null,
+}
 
-    /// Get the layout for this thing, if one is available.
-    fn try_get_layout(
-        &self,
-        ctx: &BindgenContext,
-        extra: &Self::Extra,
-    ) -> error::Result<Layout>;
+/// What the wrappers generated for functions following an `errno` convention return on
+/// success.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum ErrnoSuccess {
+    /// Return the value returned by the function.
+    #[default]
+    Value,
+    /// Return `()`, discarding the value returned by the function.
+    Unit,
+}
 
-    /// Do not override this provided trait method.
-    fn try_to_opaque(
-        &self,
-        ctx: &BindgenContext,
+/// How a C function reports errors through `errno`, as used by
+/// [`crate::Builder::errno_wrappers`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ErrnoConvention {
+    /// The value the function returns on error.
+    pub error_value: ErrnoValue,
+    /// What the wrapper returns on success.
+    pub success: ErrnoSuccess,
+}
+
+impl From<ErrnoValue> for ErrnoConvention {
+    fn from(error_value: ErrnoValue) -> Self {
+        Self {
+            error_value,
+            success: ErrnoSuccess::default(),
+        }
+    }
+}
+
+impl fmt::Display for ErrnoConvention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.error_value {
+            ErrnoValue::Int(value) => value.fmt(f)?,
+            ErrnoValue::Negative => "negative".fmt(f)?,
+            ErrnoValue::Null => "null".fmt(f)?,
+        }
+
+        match self.success {
+            ErrnoSuccess::Value => Ok(()),
+            ErrnoSuccess::Unit => ":unit".fmt(f),
+        }
+    }
+}
+
+impl std::str::FromStr for ErrnoConvention {
+    type Err = std::io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (error_value, success) = match s.split_once(':') {
+            Some((error_value, "value")) => (error_value, ErrnoSuccess::Value),
+            Some((error_value, "unit")) => (error_value, ErrnoSuccess::Unit),
+            Some(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    concat!(
+                        "Got an invalid errno success value. Accepted ",
+                        "values are 'value' and 'unit'"
+                    ),
+                ))
+            }
+            None => (s, ErrnoSuccess::Value),
+        };
+
+        let error_value = match error_value {
+            "negative" => ErrnoValue::Negative,
+            "null" => ErrnoValue::Null,
+            value => ErrnoValue::Int(value.parse().map_err(|_| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    concat!(
+                        "Got an invalid errno error value. Accepted values ",
+                        "are integers, 'negative' and 'null'"
+                    ),
+                )
+            })?),
+        };
+
+        Ok(Self {
+            error_value,
+            success,
+        })
+    }
+}
+
+/// Fallible conversion to an opaque blob.
+///
+/// Implementors of this trait should provide the `try_get_layout` method to
+/// fallibly get this thing's layout, which the provided `try_to_opaque` trait
+/// method will use to convert the `Layout` into an opaque blob Rust type.
+pub(crate) trait TryToOpaque {
+    type Extra;
+
+    /// Get the layout for this thing, if one is available.
+    fn try_get_layout(
+        &self,
+        ctx: &BindgenContext,
+        extra: &Self::Extra,
+    ) -> error::Result<Layout>;
+
+    /// Do not override this provided trait method.
+    fn try_to_opaque(
+        &self,
+        ctx: &BindgenContext,
         extra: &Self::Extra,
     ) -> error::Result<syn::Type> {
         self.try_get_layout(ctx, extra)
@@ -4143,6 +5868,16 @@ impl TryToRustTy for Type {
                 let ty = item.try_to_rust_ty(ctx, &())?;
                 Ok(syn::parse_quote! { [ #ty ; #len ] })
             }
+            TypeKind::Enum(ref enum_)
+                if enum_.has_unknown_variant(ctx, item) =>
+            {
+                // The C code may hand over any value, so refer to the enum by
+                // its integer type, which converts to it.
+                let repr = enum_.repr().map(|repr| ctx.resolve_type(repr));
+                let kind = translated_enum_repr(ctx, repr, self.layout(ctx));
+                Type::new(None, None, TypeKind::Int(kind), false)
+                    .try_to_rust_ty(ctx, item)
+            }
             TypeKind::Enum(..) => {
                 let path = item.namespace_aware_canonical_path(ctx);
                 let path = proc_macro2::TokenStream::from_str(&path.join("::"))
@@ -4379,7 +6114,18 @@ impl CodeGenerator for Function {
             _ => panic!("Signature kind is not a Function: {:?}", signature),
         };
 
-        if is_internal {
+        let translated_body = match self.body() {
+            Some(FunctionBody::Trivial(expr)) |
+            Some(FunctionBody::Macro(Some(expr))) => {
+                inline_fn::gen_inline_fn_body(ctx, signature, expr)
+            }
+            _ => None,
+        };
+        let is_macro = matches!(self.body(), Some(FunctionBody::Macro(..)));
+
+        // Functions whose body couldn't be translated are handled once they
+        // are deduplicated, so that they are only reported once.
+        if is_internal && self.body().is_none() {
             if !ctx.options().wrap_static_fns {
                 // We cannot do anything with internal functions if we are not wrapping them so
                 // just avoid generating anything for them.
@@ -4431,6 +6177,25 @@ impl CodeGenerator for Function {
             result.saw_function(seen_symbol_name);
         }
 
+        // Function-like macros have no symbol to fall back to.
+        if is_macro && translated_body.is_none() {
+            untranslatable_fn_macro_diagnostic(name, item.location(), ctx);
+            return None;
+        }
+
+        if self.body().is_some() && translated_body.is_none() {
+            untranslatable_inline_fn_diagnostic(name, item.location(), ctx);
+
+            if !ctx.options().wrap_static_fns {
+                return None;
+            }
+
+            if signature.is_variadic() {
+                variadic_fn_diagnostic(name, item.location(), ctx);
+                return None;
+            }
+        }
+
         let mut attributes = vec![];
 
         if ctx.options().rust_features().must_use_function {
@@ -4448,10 +6213,85 @@ impl CodeGenerator for Function {
             }
         }
 
-        if let Some(comment) = item.comment(ctx) {
+        let comment = item.comment(ctx).filter(|comment| !comment.is_empty());
+        let mut has_doc = comment.is_some();
+        if let Some(comment) = comment {
             attributes.push(attributes::doc(comment));
         }
 
+        // Rust has no equivalent for these attributes, so we document them
+        // instead.
+        let document_attributes = ctx.options().generate_comments;
+        for (has_attr, note) in [
+            (
+                signature.is_pure(),
+                "This function is marked as `pure`: it has no side effects and \
+                 its return value only depends on its arguments and on global \
+                 memory.",
+            ),
+            (
+                signature.is_const(),
+                "This function is marked as `const`: it has no side effects and \
+                 its return value only depends on its arguments.",
+            ),
+            (
+                signature.is_malloc(),
+                "This function is marked as `malloc`: the returned pointer \
+                 doesn't alias any other valid pointer.",
+            ),
+            (
+                signature.returns_non_null(),
+                "This function is marked as `returns_nonnull`: it never \
+                 returns a null pointer.",
+            ),
+            (
+                signature.is_variadic(),
+                "This function is variadic: the variadic arguments are passed \
+                 with the C default argument promotions, e.g. `float` as \
+                 `f64` and integer types smaller than `int` as `c_int`.",
+            ),
+        ] {
+            if document_attributes && has_attr {
+                if has_doc {
+                    attributes.push(quote!(#[doc = ""]));
+                }
+                attributes.push(attributes::doc(format!(" {}", note)));
+                has_doc = true;
+            }
+        }
+
+        if ctx.is_transitive_dependency(item.id()) {
+            attributes.push(attributes::doc_hidden());
+        }
+
+        // Trivial `static inline` functions are translated into Rust, so they
+        // don't need a symbol.
+        if let Some(body) = translated_body {
+            let times_seen = result.overload_number(&canonical_name);
+            if times_seen > 0 {
+                write!(&mut canonical_name, "{}", times_seen).unwrap();
+            }
+
+            let ident = ctx.rust_ident(&canonical_name);
+            let args = utils::fnsig_arguments(ctx, signature);
+            let ret = utils::fnsig_return_ty(ctx, signature);
+            // Macros can be used in constant expressions in C.
+            let const_fn =
+                if is_macro && ctx.options().rust_features().min_const_fn {
+                    quote! { const }
+                } else {
+                    quote! {}
+                };
+            result.push(quote! {
+                #(#attributes)*
+                #[inline]
+                pub #const_fn fn #ident ( #( #args ),* ) #ret {
+                    #body
+                }
+            });
+            return Some(times_seen);
+        }
+
         let abi = match signature.abi(ctx, Some(name)) {
             Err(err) => {
                 if matches!(err, error::Error::UnsupportedAbi(_)) {
@@ -4482,6 +6322,9 @@ impl CodeGenerator for Function {
             write!(&mut canonical_name, "{}", times_seen).unwrap();
         }
 
+        // The link names only apply to the `extern` declarations.
+        let stub_attributes = attributes.clone();
+
         let mut has_link_name_attr = false;
         if let Some(link_name) = self.link_name() {
             attributes.push(attributes::link_name::<false>(link_name));
@@ -4551,13 +6394,26 @@ impl CodeGenerator for Function {
         let ret = utils::fnsig_return_ty(ctx, signature);
 
         let ident = ctx.rust_ident(ident);
+        let has_stub = ctx.options().docsrs_stubs &&
+            !is_dynamic_function &&
+            !signature.is_variadic() &&
+            wrap_as_variadic.is_none();
+        let extern_cfg = if has_stub {
+            attributes::cfg(quote! { not(doc) }, false)
+        } else {
+            quote! {}
+        };
+        let block_attributes = utils::extern_block_attributes(ctx, item);
         let tokens = quote! {
+            #extern_cfg
             #wasm_link_attribute
+            #( #[#block_attributes] )*
             extern #abi {
                 #(#attributes)*
                 pub fn #ident ( #( #args ),* ) #ret;
             }
         };
+        let is_wrapped_as_variadic = wrap_as_variadic.is_some();
 
         // Add the item to the serialization list if necessary
         if should_wrap {
@@ -4585,6 +6441,86 @@ impl CodeGenerator for Function {
             );
         } else {
             result.push(tokens);
+
+            // The stub and the wrappers are only emitted where the `extern`
+            // block would be.
+            let block_cfgs: Vec<_> = block_attributes
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"))
+                .collect();
+            if has_stub {
+                result.push(quote! {
+                    #[cfg(doc)]
+                    #( #[#block_cfgs] )*
+                    #(#stub_attributes)*
+                    #[allow(unused_variables)]
+                    pub unsafe extern #abi fn #ident ( #( #args ),* ) #ret {
+                        unimplemented!()
+                    }
+                });
+            }
+
+            let is_plain_function = !is_wrapped_as_variadic &&
+                matches!(self.kind(), FunctionKind::Function);
+
+            let conventions: Vec<_> = ctx
+                .options()
+                .errno_wrappers
+                .iter()
+                .filter(|(_, set)| set.matches(name))
+                .map(|(&convention, _)| convention)
+                .collect();
+            let convention = match *conventions.as_slice() {
+                [] => None,
+                [convention] => Some(convention),
+                _ => {
+                    // Sort them so that they are listed consistently.
+                    let mut conventions: Vec<_> =
+                        conventions.iter().map(|c| c.to_string()).collect();
+                    conventions.sort();
+                    warning!(
+                        "Not generating an errno wrapper for `{}`, which matches several conventions: {}",
+                        name,
+                        conventions.join(", ")
+                    );
+                    None
+                }
+            };
+            if let Some(convention) = convention {
+                if ctx.options().use_core || !is_plain_function {
+                    warning!(
+                        "Not generating an errno wrapper for `{}`, which is not a plain function",
+                        name
+                    );
+                } else if let Some(wrapper) =
+                    errno::gen_errno_wrapper(ctx, signature, &ident, convention)
+                {
+                    result.push(quote! { #( #[#block_cfgs] )* #wrapper });
+                } else {
+                    warning!(
+                        "Not generating an errno wrapper for `{}`, whose return type cannot hold the error value `{}`",
+                        name, convention
+                    );
+                }
+            }
+
+            let slice_wrapper = if is_plain_function {
+                slice_params::gen_slice_wrapper(ctx, name, signature, &ident)
+            } else {
+                None
+            };
+            if let Some(wrapper) = slice_wrapper {
+                result.push(quote! { #( #[#block_cfgs] )* #wrapper });
+            }
+
+            let out_param_wrapper = if is_plain_function {
+                out_param::gen_out_param_wrapper(ctx, name, signature, &ident)
+            } else {
+                None
+            };
+            if let Some(wrapper) = out_param_wrapper {
+                result.push(quote! { #( #[#block_cfgs] )* #wrapper });
+            }
         }
         Some(times_seen)
     }
@@ -4598,7 +6534,7 @@ fn unsupported_abi_diagnostic(
     ctx: &BindgenContext,
     error: &error::Error,
 ) {
-    warn!(
+    warning!(
         "Skipping {}function `{}` because the {}",
         if variadic { "variadic " } else { "" },
         fn_name,
@@ -4649,12 +6585,119 @@ fn unsupported_abi_diagnostic(
     }
 }
 
+fn untranslatable_inline_fn_diagnostic(
+    fn_name: &str,
+    _location: Option<&crate::clang::SourceLocation>,
+    ctx: &BindgenContext,
+) {
+    let fallback = if ctx.options().wrap_static_fns {
+        "A wrapper will be generated for this function instead."
+    } else {
+        "No code will be generated for this function."
+    };
+
+    warning!(
+        "Cannot translate the body of the inline function `{}`. {}",
+        fn_name,
+        fallback
+    );
+
+    #[cfg(feature = "experimental")]
+    if ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+
+        let mut diag = Diagnostic::default();
+
+        diag.with_title(
+            format!(
+                "Cannot translate the body of the inline function `{}`.",
+                fn_name
+            ),
+            Level::Warn,
+        )
+        .add_annotation(
+            "Only a single `return` of an arithmetic expression over \
+             parameters of the same integer type, which doesn't divide by a \
+             constant zero, can be translated.",
+            Level::Note,
+        )
+        .add_annotation(fallback, Level::Note);
+
+        if let Some(loc) = _location {
+            let (file, line, col, _) = loc.location();
+
+            if let Some(filename) = file.name() {
+                if let Ok(Some(source)) = get_line(&filename, line) {
+                    let mut slice = Slice::default();
+                    slice
+                        .with_source(source)
+                        .with_location(filename, line, col);
+                    diag.add_slice(slice);
+                }
+            }
+        }
+
+        diag.display()
+    }
+}
+
+fn untranslatable_fn_macro_diagnostic(
+    macro_name: &str,
+    _location: Option<&crate::clang::SourceLocation>,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Cannot translate the function-like macro `{}`. No code will be generated for it.",
+        macro_name
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+
+        let mut diag = Diagnostic::default();
+
+        diag.with_title(
+            format!(
+                "Cannot translate the function-like macro `{}`.",
+                macro_name
+            ),
+            Level::Warn,
+        )
+        .add_annotation(
+            "Only macros with named parameters expanding to an arithmetic \
+             expression over them can be translated.",
+            Level::Note,
+        )
+        .add_annotation(
+            "No code will be generated for this macro.",
+            Level::Note,
+        );
+
+        if let Some(loc) = _location {
+            let (file, line, col, _) = loc.location();
+
+            if let Some(filename) = file.name() {
+                if let Ok(Some(source)) = get_line(&filename, line) {
+                    let mut slice = Slice::default();
+                    slice
+                        .with_source(source)
+                        .with_location(filename, line, col);
+                    diag.add_slice(slice);
+                }
+            }
+        }
+
+        diag.display()
+    }
+}
+
 fn variadic_fn_diagnostic(
     fn_name: &str,
     _location: Option<&crate::clang::SourceLocation>,
     _ctx: &BindgenContext,
 ) {
-    warn!(
+    warning!(
         "Cannot generate wrapper for the static variadic function `{}`.",
         fn_name,
     );
@@ -4687,6 +6730,44 @@ fn variadic_fn_diagnostic(
     }
 }
 
+fn unwrappable_static_var_diagnostic(
+    var_name: &str,
+    _location: Option<&crate::clang::SourceLocation>,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Cannot generate wrappers for the static array or function variable `{}`.",
+        var_name,
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+
+        let mut diag = Diagnostic::default();
+
+        diag.with_title(format!("Cannot generate wrappers for the static variable `{}`.", var_name), Level::Warn)
+            .add_annotation("The `--wrap-static-fns` feature does not support array or function variables, as they cannot be returned by value.", Level::Note)
+            .add_annotation("No code will be generated for this variable.", Level::Note);
+
+        if let Some(loc) = _location {
+            let (file, line, col, _) = loc.location();
+
+            if let Some(filename) = file.name() {
+                if let Ok(Some(source)) = get_line(&filename, line) {
+                    let mut slice = Slice::default();
+                    slice
+                        .with_source(source)
+                        .with_location(filename, line, col);
+                    diag.add_slice(slice);
+                }
+            }
+        }
+
+        diag.display()
+    }
+}
+
 fn objc_method_codegen(
     ctx: &BindgenContext,
     method: &ObjCMethod,
@@ -4912,8 +6993,16 @@ impl CodeGenerator for ObjCInterface {
                         "This {} cannot be downcasted to {}",
                         parent_struct_name, child_struct_name
                     );
+                    // `TryFrom` is only in the prelude since Rust 2021.
+                    let try_from = if ctx.options().rust_edition >=
+                        RustEdition::Edition2021
+                    {
+                        quote! { TryFrom }
+                    } else {
+                        quote! { std::convert::TryFrom }
+                    };
                     let try_into_block = quote! {
-                        impl std::convert::TryFrom<#parent_struct> for #class_name {
+                        impl #try_from<#parent_struct> for #class_name {
                             type Error = &'static str;
                             fn try_from(parent: #parent_struct) -> Result<#class_name, Self::Error> {
                                 let is_kind_of : bool = unsafe { msg_send!(parent, isKindOfClass:class!(#class_name))};
@@ -4957,11 +7046,20 @@ impl CodeGenerator for ObjCInterface {
 
 pub(crate) fn codegen(
     context: BindgenContext,
-) -> Result<(proc_macro2::TokenStream, BindgenOptions), CodegenError> {
-    context.gen(|context| {
+) -> Result<
+    (
+        proc_macro2::TokenStream,
+        BindgenOptions,
+        usize,
+        Vec<AllowlistTraceEntry>,
+    ),
+    BindgenError,
+> {
+    let ((module, wrapped_ops, trace), options) = context.gen(|context| {
         let _t = context.timer("codegen");
         let counter = Cell::new(0);
-        let mut result = CodegenResult::new(&counter);
+        let item_target_oses = os_cfg::item_target_oses(context);
+        let mut result = CodegenResult::new(&counter, &item_target_oses);
 
         debug!("codegen: {:?}", context.options());
 
@@ -4980,7 +7078,7 @@ pub(crate) fn codegen(
                     "Your dot file was generated successfully into: {}",
                     path
                 ),
-                Err(e) => warn!("{}", e),
+                Err(e) => warning!("{}", e),
             }
         }
 
@@ -4990,10 +7088,25 @@ pub(crate) fn codegen(
                     "Your depfile was generated successfully into: {}",
                     spec.depfile_path.display()
                 ),
-                Err(e) => warn!("{}", e),
+                Err(e) => warning!("{}", e),
             }
         }
 
+        if !generates_any_item(context) {
+            empty_bindings_diagnostic(context);
+            if context.options().error_on_empty {
+                return Err(BindgenError::EmptyBindings);
+            }
+        }
+
+        if let Some(limit) = context.options().max_monomorphization_warning {
+            check_template_instantiations(context, limit);
+        }
+
+        if context.renames_items() {
+            check_rename_collisions(context);
+        }
+
         context.resolve_item(context.root_module()).codegen(
             context,
             &mut result,
@@ -5007,17 +7120,455 @@ pub(crate) fn codegen(
             result.push(dynamic_items_tokens);
         }
 
-        utils::serialize_items(&result, context)?;
+        utils::serialize_items(&result, context)
+            .map_err(BindgenError::Codegen)?;
 
-        Ok(postprocessing::postprocessing(
-            result.items,
-            context.options(),
-        ))
-    })
+        if let Some(path) = context.options().coverage_report.as_ref() {
+            utils::write_coverage_report(context, path)
+                .map_err(BindgenError::Codegen)?;
+        }
+
+        let (module, wrapped_ops) =
+            postprocessing::postprocessing(result.items, context.options());
+        Ok((module, wrapped_ops, context.allowlist_trace().to_vec()))
+    })?;
+
+    Ok((module, options, wrapped_ops, trace))
+}
+
+/// Whether any of the items that are going to be generated produce actual
+/// bindings, as opposed to modules or builtin types only.
+fn generates_any_item(ctx: &BindgenContext) -> bool {
+    ctx.codegen_items()
+        .iter()
+        .any(|id| match *ctx.resolve_item(*id).kind() {
+            ItemKind::Module(..) => false,
+            ItemKind::Function(..) | ItemKind::Var(..) => true,
+            ItemKind::Type(ref ty) => matches!(
+                *ty.kind(),
+                TypeKind::Comp(..) |
+                    TypeKind::Enum(..) |
+                    TypeKind::Alias(..) |
+                    TypeKind::TemplateAlias(..) |
+                    TypeKind::Opaque |
+                    TypeKind::ObjCInterface(..)
+            ),
+        })
+}
+
+/// Emit a warning for each template instantiated with more than `limit`
+/// different sets of template arguments.
+fn check_template_instantiations(ctx: &BindgenContext, limit: usize) {
+    let mut instantiations = HashMap::<TypeId, HashSet<Vec<ItemId>>>::default();
+    for id in ctx.codegen_items() {
+        let inst = match *ctx.resolve_item(*id).kind() {
+            ItemKind::Type(ref ty) => match *ty.kind() {
+                TypeKind::TemplateInstantiation(ref inst) => inst,
+                _ => continue,
+            },
+            _ => continue,
+        };
+
+        // The same instantiation can be parsed more than once, so they are
+        // told apart by their arguments.
+        let args = inst
+            .template_arguments()
+            .iter()
+            .map(|arg| {
+                arg.into_resolver().through_type_refs().resolve(ctx).id()
+            })
+            .collect();
+        instantiations
+            .entry(inst.template_definition())
+            .or_default()
+            .insert(args);
+    }
+
+    let mut counts = instantiations
+        .into_iter()
+        .filter(|(_, args)| args.len() > limit)
+        .map(|(definition, args)| {
+            (ctx.resolve_item(definition).canonical_name(ctx), args.len())
+        })
+        .collect::<Vec<_>>();
+    counts.sort();
+
+    for (name, count) in counts {
+        monomorphization_diagnostic(&name, count, limit, ctx);
+    }
+}
+
+/// Report the items whose different names are renamed to the same name by
+/// the rename patterns, in the same namespace of the same module.
+fn check_rename_collisions(ctx: &BindgenContext) {
+    let mut names = HashMap::<_, Vec<String>>::default();
+    for id in ctx.codegen_items() {
+        let item = ctx.resolve_item(*id);
+        let is_type = match *item.kind() {
+            ItemKind::Type(ref ty) => match *ty.kind() {
+                TypeKind::Comp(..) |
+                TypeKind::Enum(..) |
+                TypeKind::Alias(..) |
+                TypeKind::TemplateAlias(..) => true,
+                _ => continue,
+            },
+            ItemKind::Function(ref function)
+                if function.kind() == FunctionKind::Function =>
+            {
+                false
+            }
+            ItemKind::Var(..) => false,
+            _ => continue,
+        };
+
+        let original = NameOptions::new(item, ctx).without_renaming().get();
+        let originals = names
+            .entry((item.parent_id(), is_type, item.canonical_name(ctx)))
+            .or_default();
+        if !originals.contains(&original) {
+            originals.push(original);
+        }
+    }
+
+    let mut collisions = names
+        .into_iter()
+        .filter(|(_, originals)| originals.len() > 1)
+        .map(|((_, _, name), mut originals)| {
+            originals.sort();
+            (name, originals)
+        })
+        .collect::<Vec<_>>();
+    collisions.sort();
+
+    for (name, originals) in collisions {
+        rename_collision_diagnostic(&name, &originals, ctx);
+    }
+}
+
+fn rename_collision_diagnostic(
+    name: &str,
+    originals: &[String],
+    _ctx: &BindgenContext,
+) {
+    let originals = originals
+        .iter()
+        .map(|original| format!("`{}`", original))
+        .collect::<Vec<_>>()
+        .join(", ");
+    warning!(
+        "The items {} are all renamed to `{}` by the rename patterns.",
+        originals,
+        name,
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        Diagnostic::default()
+            .with_title(format!("Rename collision on `{}`.", name), Level::Warn)
+            .add_annotation(
+                format!("The items {} are all renamed to it.", originals),
+                Level::Note,
+            )
+            .add_annotation(
+                "Use a more specific pattern to keep their names distinct.",
+                Level::Help,
+            )
+            .display();
+    }
+}
+
+fn monomorphization_diagnostic(
+    name: &str,
+    count: usize,
+    limit: usize,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "The template `{}` is instantiated {} times, more than the limit of {}.",
+        name, count, limit,
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        Diagnostic::default()
+            .with_title(
+                format!(
+                    "The template `{}` is instantiated {} times.",
+                    name, count
+                ),
+                Level::Warn,
+            )
+            .add_annotation(
+                format!("This is more than the limit of {} set with `--max-monomorphization-warning`.", limit),
+                Level::Note,
+            )
+            .add_annotation("Every instantiation adds items to the generated bindings, check that the template isn't instantiated over more types than intended.", Level::Help)
+            .display();
+    }
+}
+
+fn arbitrary_diagnostic(
+    name: &str,
+    reason: &str,
+    _location: Option<&crate::clang::SourceLocation>,
+    _ctx: &BindgenContext,
+) {
+    warning!("Cannot derive `Arbitrary` for `{}` as {}.", name, reason);
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+
+        let mut diag = Diagnostic::default();
+
+        diag.with_title(
+            format!("Cannot derive `Arbitrary` for `{}`.", name),
+            Level::Warn,
+        )
+        .add_annotation(
+            format!("The type can't be derived as {}.", reason),
+            Level::Note,
+        )
+        .add_annotation(
+            "The type is generated without `#[derive(arbitrary::Arbitrary)]`.",
+            Level::Note,
+        );
+
+        if let Some(loc) = _location {
+            let (file, line, col, _) = loc.location();
+
+            if let Some(filename) = file.name() {
+                if let Ok(Some(source)) = get_line(&filename, line) {
+                    let mut slice = Slice::default();
+                    slice
+                        .with_source(source)
+                        .with_location(filename, line, col);
+                    diag.add_slice(slice);
+                }
+            }
+        }
+
+        diag.display()
+    }
+}
+
+fn not_pod_diagnostic(
+    name: &str,
+    _location: Option<&crate::clang::SourceLocation>,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Cannot generate `from_bytes` for `{}` as it is not plain old data.",
+        name,
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+
+        let mut diag = Diagnostic::default();
+
+        diag.with_title(format!("Cannot generate `from_bytes` for `{}`.", name), Level::Warn)
+            .add_annotation("Only types whose fields are all integers, floating point numbers or arrays and structs of them can be parsed from bytes.", Level::Note)
+            .add_annotation("The type is generated without `from_bytes` and `read_from`.", Level::Note);
+
+        if let Some(loc) = _location {
+            let (file, line, col, _) = loc.location();
+
+            if let Some(filename) = file.name() {
+                if let Ok(Some(source)) = get_line(&filename, line) {
+                    let mut slice = Slice::default();
+                    slice
+                        .with_source(source)
+                        .with_location(filename, line, col);
+                    diag.add_slice(slice);
+                }
+            }
+        }
+
+        diag.display()
+    }
+}
+
+fn no_default_variant_diagnostic(
+    name: &str,
+    _location: Option<&crate::clang::SourceLocation>,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Cannot derive `Default` for `{}` as it has no suitable default variant.",
+        name,
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+
+        let mut diag = Diagnostic::default();
+
+        diag.with_title(
+            format!("Cannot derive `Default` for `{}`.", name),
+            Level::Warn,
+        )
+        .add_annotation(
+            format!(
+                "The enum has no {} variant to mark as `#[default]`.",
+                match _ctx.options().enum_default_variant {
+                    EnumDefaultVariant::Zero => "zero-valued",
+                    EnumDefaultVariant::First => "visible",
+                }
+            ),
+            Level::Note,
+        )
+        .add_annotation(
+            "The enum is generated without deriving `Default`.",
+            Level::Note,
+        );
+
+        if let Some(loc) = _location {
+            let (file, line, col, _) = loc.location();
+
+            if let Some(filename) = file.name() {
+                if let Ok(Some(source)) = get_line(&filename, line) {
+                    let mut slice = Slice::default();
+                    slice
+                        .with_source(source)
+                        .with_location(filename, line, col);
+                    diag.add_slice(slice);
+                }
+            }
+        }
+
+        diag.display()
+    }
+}
+
+fn unknown_variant_collision_diagnostic(
+    name: &str,
+    _location: Option<&crate::clang::SourceLocation>,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Cannot give `{}` an `{}` variant as it already has a variant with that name.",
+        name, UNKNOWN_VARIANT_NAME,
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+
+        let mut diag = Diagnostic::default();
+
+        diag.with_title(
+            format!(
+                "Cannot give `{}` an `{}` variant.",
+                name, UNKNOWN_VARIANT_NAME
+            ),
+            Level::Warn,
+        )
+        .add_annotation(
+            format!(
+                "The enum already has a variant named `{}`.",
+                UNKNOWN_VARIANT_NAME
+            ),
+            Level::Note,
+        )
+        .add_annotation(
+            "The enum is generated as a plain rustified enum.",
+            Level::Note,
+        );
+
+        if let Some(loc) = _location {
+            let (file, line, col, _) = loc.location();
+
+            if let Some(filename) = file.name() {
+                if let Ok(Some(source)) = get_line(&filename, line) {
+                    let mut slice = Slice::default();
+                    slice
+                        .with_source(source)
+                        .with_location(filename, line, col);
+                    diag.add_slice(slice);
+                }
+            }
+        }
+
+        diag.display()
+    }
+}
+
+fn flat_enum_constant_collision_diagnostic(
+    name: &str,
+    first_enum: &str,
+    enum_name: &str,
+    _location: Option<&crate::clang::SourceLocation>,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Not emitting the flat constant `{}` for `{}`, as one was already emitted for `{}`.",
+        name, enum_name, first_enum,
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+
+        let mut diag = Diagnostic::default();
+
+        diag.with_title(
+            format!("The flat constant `{}` collides.", name),
+            Level::Warn,
+        )
+        .add_annotation(
+            format!(
+                "Both `{}` and `{}` have a variant named `{}`.",
+                first_enum, enum_name, name
+            ),
+            Level::Note,
+        )
+        .add_annotation(
+            format!("The constant is only emitted for `{}`.", first_enum),
+            Level::Note,
+        );
+
+        if let Some(loc) = _location {
+            let (file, line, col, _) = loc.location();
+
+            if let Some(filename) = file.name() {
+                if let Ok(Some(source)) = get_line(&filename, line) {
+                    let mut slice = Slice::default();
+                    slice
+                        .with_source(source)
+                        .with_location(filename, line, col);
+                    diag.add_slice(slice);
+                }
+            }
+        }
+
+        diag.display()
+    }
+}
+
+fn empty_bindings_diagnostic(_ctx: &BindgenContext) {
+    warning!("No items were generated, the bindings are empty.");
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        Diagnostic::default()
+            .with_title("No items were generated.", Level::Warn)
+            .add_annotation("None of the parsed items matched the allowlist or the configured kinds of items to generate.", Level::Note)
+            .add_annotation("Check that the include paths are correct and that the allowlist patterns match the intended items.", Level::Help)
+            .display();
+    }
 }
 
 pub(crate) mod utils {
-    use super::helpers::BITFIELD_UNIT;
+    use super::helpers::{bitfield_unit_name, BITFIELD_UNIT};
     use super::serialize::CSerialize;
     use super::{error, CodegenError, CodegenResult, ToRustTyOrOpaque};
     use crate::ir::context::BindgenContext;
@@ -5029,9 +7580,30 @@ pub(crate) mod utils {
     use std::borrow::Cow;
     use std::io::Write;
     use std::mem;
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
     use std::str::FromStr;
 
+    /// Write the report requested with `Builder::write_coverage_report` to
+    /// the given path.
+    pub(super) fn write_coverage_report(
+        context: &BindgenContext,
+        path: &Path,
+    ) -> Result<(), CodegenError> {
+        let mut report = Vec::new();
+        for entry in context.allowlist_trace() {
+            writeln!(
+                report,
+                "{}\t{}\t{}",
+                entry.name,
+                entry.decision.coverage_tag(),
+                entry.decision
+            )?;
+        }
+
+        std::fs::write(path, report)?;
+        Ok(())
+    }
+
     pub(super) fn serialize_items(
         result: &CodegenResult,
         context: &BindgenContext,
@@ -5150,22 +7722,73 @@ pub(crate) mod utils {
         })
     }
 
+    /// The attributes requested with the `extern_block_attributes` callback
+    /// for the `extern` block declaring the given item, without the invalid
+    /// ones.
+    pub(super) fn extern_block_attributes(
+        ctx: &BindgenContext,
+        item: &Item,
+    ) -> Vec<syn::Meta> {
+        let file = match item.location() {
+            Some(location) => location.location().0,
+            None => return vec![],
+        };
+        let file = match file.name() {
+            Some(file) => file,
+            None => return vec![],
+        };
+        ctx.options()
+            .all_callbacks(|cb| cb.extern_block_attributes(Path::new(&file)))
+            .into_iter()
+            .filter_map(|attr| match syn::parse_str::<syn::Meta>(&attr) {
+                Ok(attr) => Some(attr),
+                Err(..) => {
+                    warning!(
+                        "Ignoring the invalid attribute `{}` returned for the `extern` blocks of `{}`",
+                        attr, file
+                    );
+                    None
+                }
+            })
+            .collect()
+    }
+
     pub(crate) fn prepend_bitfield_unit_type(
         ctx: &BindgenContext,
         result: &mut Vec<proc_macro2::TokenStream>,
     ) {
-        if ctx.options().blocklisted_items.matches(BITFIELD_UNIT) ||
-            ctx.options().blocklisted_types.matches(BITFIELD_UNIT)
+        let name = bitfield_unit_name(ctx);
+        if ctx.options().blocklisted_items.matches(name) ||
+            ctx.options().blocklisted_types.matches(name)
         {
             return;
         }
 
         let bitfield_unit_src = include_str!("./bitfield_unit.rs");
+        // The `const fn` accessors are the last item of the file, strip them
+        // for targets where they can't be used.
+        let bitfield_unit_src =
+            if ctx.options().rust_features().const_bitfield_unit {
+                bitfield_unit_src
+            } else {
+                let const_impl = bitfield_unit_src
+                    .find("impl<const N: usize>")
+                    .expect("bitfield unit should have const accessors");
+                &bitfield_unit_src[..const_impl]
+            };
         let bitfield_unit_src = if ctx.options().rust_features().min_const_fn {
             Cow::Borrowed(bitfield_unit_src)
         } else {
             Cow::Owned(bitfield_unit_src.replace("const fn ", "fn "))
         };
+        let visibility =
+            super::access_specifier(ctx.options().bitfield_unit_visibility);
+        let bitfield_unit_src = bitfield_unit_src
+            .replace(
+                &format!("pub struct {}", BITFIELD_UNIT),
+                &format!("{} struct {}", visibility, BITFIELD_UNIT),
+            )
+            .replace(BITFIELD_UNIT, name);
         let bitfield_unit_type =
             proc_macro2::TokenStream::from_str(&bitfield_unit_src).unwrap();
         let bitfield_unit_type = quote!(#bitfield_unit_type);
@@ -5407,6 +8030,122 @@ pub(crate) mod utils {
         result.extend(old_items);
     }
 
+    pub(crate) fn append_layout_debug_asserts(
+        result: &mut Vec<proc_macro2::TokenStream>,
+        asserts: Vec<proc_macro2::TokenStream>,
+    ) {
+        result.push(quote! {
+            /// Check the layout of the generated types using `debug_assert_eq!`.
+            ///
+            /// The checks are compiled out when debug assertions are disabled.
+            pub fn bindgen_debug_assert_layouts() {
+                #( #asserts )*
+            }
+        });
+    }
+
+    pub(crate) fn append_layout_verification_fn(
+        ctx: &BindgenContext,
+        result: &mut Vec<proc_macro2::TokenStream>,
+        checks: Vec<proc_macro2::TokenStream>,
+    ) {
+        let prefix = ctx.trait_prefix();
+        result.push(quote! {
+            /// A mismatch between the layout of a generated type and the layout
+            /// it has in C, as found by `verify_layouts`.
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub enum LayoutError {
+                /// The size of the type doesn't match.
+                Size {
+                    /// The name of the type.
+                    ty: &'static str,
+                    /// The value in C.
+                    expected: usize,
+                    /// The value in Rust.
+                    actual: usize,
+                },
+                /// The alignment of the type doesn't match.
+                Align {
+                    /// The name of the type.
+                    ty: &'static str,
+                    /// The value in C.
+                    expected: usize,
+                    /// The value in Rust.
+                    actual: usize,
+                },
+                /// The offset of a field of the type doesn't match.
+                Offset {
+                    /// The name of the type.
+                    ty: &'static str,
+                    /// The name of the field.
+                    field: &'static str,
+                    /// The value in C.
+                    expected: usize,
+                    /// The value in Rust.
+                    actual: usize,
+                },
+            }
+            impl ::#prefix::fmt::Display for LayoutError {
+                fn fmt(&self, f: &mut ::#prefix::fmt::Formatter<'_>) -> ::#prefix::fmt::Result {
+                    match *self {
+                        LayoutError::Size { ty, expected, actual } => write!(
+                            f,
+                            "size of {} is {}, expected {}",
+                            ty, actual, expected
+                        ),
+                        LayoutError::Align { ty, expected, actual } => write!(
+                            f,
+                            "alignment of {} is {}, expected {}",
+                            ty, actual, expected
+                        ),
+                        LayoutError::Offset { ty, field, expected, actual } => write!(
+                            f,
+                            "offset of field {}::{} is {}, expected {}",
+                            ty, field, actual, expected
+                        ),
+                    }
+                }
+            }
+            /// Check the size, alignment and field offsets of the generated
+            /// types, returning the first mismatch.
+            pub fn verify_layouts() -> ::#prefix::result::Result<(), LayoutError> {
+                #( #checks )*
+                Ok(())
+            }
+        });
+    }
+
+    pub(crate) fn prepend_ctypes_shim(
+        ctx: &BindgenContext,
+        result: &mut Vec<proc_macro2::TokenStream>,
+    ) {
+        let fallback = if ctx.options().rust_features().core_ffi_c {
+            quote! { ::core::ffi }
+        } else {
+            quote! { ::std::os::raw }
+        };
+
+        let ctypes = quote! {
+            c_char, c_schar, c_uchar, c_short, c_ushort, c_int, c_uint,
+            c_long, c_ulong, c_longlong, c_ulonglong, c_float, c_double,
+            c_void
+        };
+
+        let shim = quote! {
+            #[allow(unused_imports)]
+            mod __bindgen_ctypes {
+                #[cfg(feature = "libc")]
+                pub use ::libc::{ #ctypes };
+                #[cfg(not(feature = "libc"))]
+                pub use #fallback::{ #ctypes };
+            }
+        };
+
+        let items = vec![shim];
+        let old_items = mem::replace(result, items);
+        result.extend(old_items);
+    }
+
     pub(crate) fn prepend_float16_type(
         result: &mut Vec<proc_macro2::TokenStream>,
     ) {
@@ -5483,7 +8222,7 @@ pub(crate) mod utils {
         })
     }
 
-    fn fnsig_return_ty_internal(
+    pub(crate) fn fnsig_return_ty_internal(
         ctx: &BindgenContext,
         sig: &FunctionSig,
     ) -> syn::Type {
@@ -5503,7 +8242,15 @@ pub(crate) mod utils {
 
         match canonical_type_kind {
             TypeKind::Void => syn::parse_quote! { () },
-            _ => sig.return_type().to_rust_ty_or_opaque(ctx, &()),
+            _ => match sig.return_type().to_rust_ty_or_opaque(ctx, &()) {
+                syn::Type::Ptr(syn::TypePtr { elem, .. })
+                    if sig.returns_non_null() =>
+                {
+                    let prefix = ctx.trait_prefix();
+                    syn::parse_quote! { ::#prefix::ptr::NonNull<#elem> }
+                }
+                ty => ty,
+            },
         }
     }
 
@@ -0,0 +1,313 @@
+use crate::ir::analysis::generate_dependencies;
+use crate::ir::context::{BindgenContext, ItemId};
+use crate::ir::item::{Item, ItemCanonicalName};
+use crate::{HashMap, HashSet};
+
+/// The prefixes of the names of the Apple SDKs, without their version and
+/// `.sdk` extension, and the `target_os` they are specific to.
+const APPLE_SDKS: &[(&str, &str)] = &[
+    ("MacOSX", "macos"),
+    ("iPhoneOS", "ios"),
+    ("iPhoneSimulator", "ios"),
+    ("AppleTVOS", "tvos"),
+    ("AppleTVSimulator", "tvos"),
+    ("WatchOS", "watchos"),
+    ("WatchSimulator", "watchos"),
+];
+
+/// The `target_os` each item is specific to if [`crate::Builder::infer_os_cfg`]
+/// is enabled.
+///
+/// This is the one inferred from the path of the header declaring the item,
+/// or else the one of the items it refers to, e.g. for a struct with a field
+/// whose type is declared in an OS-specific header, as it can't be compiled
+/// for other OSes either.
+pub(crate) fn item_target_oses(
+    ctx: &BindgenContext,
+) -> HashMap<ItemId, &'static str> {
+    let mut target_oses = HashMap::default();
+    if !ctx.options().infer_os_cfg {
+        return target_oses;
+    }
+
+    // Infer the OS once per header, so that it is only diagnosed once.
+    let mut header_target_oses = HashMap::default();
+    let mut worklist = vec![];
+    for &id in ctx.allowlisted_items() {
+        let item = ctx.resolve_item(id);
+        if item.is_module() {
+            continue;
+        }
+        let file = match item.location().and_then(|l| l.location().0.name()) {
+            Some(file) => file,
+            None => continue,
+        };
+        let target_os = *header_target_oses
+            .entry(file)
+            .or_insert_with_key(|file| header_target_os(ctx, file));
+        if let Some(target_os) = target_os {
+            target_oses.insert(id, target_os);
+            worklist.push(id);
+        }
+    }
+
+    // Propagate the OSes to the items referring to these items. Go through
+    // the items in order so that the conflicts are resolved consistently.
+    worklist.sort();
+    worklist.reverse();
+    let dependents = generate_dependencies(ctx, |_| true);
+    let mut conflicting = HashSet::default();
+    while let Some(id) = worklist.pop() {
+        let target_os = target_oses[&id];
+        for &dependent in dependents.get(&id).into_iter().flatten() {
+            if ctx.resolve_item(dependent).is_module() {
+                continue;
+            }
+            match target_oses.get(&dependent) {
+                None => {
+                    target_oses.insert(dependent, target_os);
+                    worklist.push(dependent);
+                }
+                Some(&other) if other != target_os => {
+                    if conflicting.insert(dependent) {
+                        conflicting_target_os_diagnostic(
+                            ctx,
+                            ctx.resolve_item(dependent),
+                            other,
+                            target_os,
+                        );
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    target_oses
+}
+
+/// Guard the given generated items with `#[cfg(target_os = "...")]`.
+pub(crate) fn with_target_os_cfg(
+    ctx: &BindgenContext,
+    tokens: proc_macro2::TokenStream,
+    target_os: &str,
+) -> proc_macro2::TokenStream {
+    let items = match syn::parse2::<syn::File>(tokens.clone()) {
+        Ok(file) => file.items,
+        Err(_) => return tokens,
+    };
+    let items = crate::merge::with_cfg(
+        items,
+        &quote! { target_os = #target_os },
+        ctx.options().emit_doc_cfg,
+    );
+    quote! { #( #items )* }
+}
+
+/// The `target_os` the header at the given path is specific to, if it is
+/// under a known OS-specific system path and the bindings are generated for
+/// that OS or one that is unknown.
+fn header_target_os(ctx: &BindgenContext, path: &str) -> Option<&'static str> {
+    let path_oses = path_target_oses(path);
+    let target_os = match *path_oses.as_slice() {
+        [] => return None,
+        [target_os] => target_os,
+        _ => {
+            ambiguous_target_os_diagnostic(
+                path,
+                &format!(
+                    "Its path is specific to several OSes: {}.",
+                    path_oses.join(", ")
+                ),
+                ctx,
+            );
+            return None;
+        }
+    };
+
+    match triple_target_os(ctx.output_target()) {
+        Some(codegen_os) if codegen_os != target_os => {
+            ambiguous_target_os_diagnostic(
+                path,
+                &format!(
+                    "Its path is specific to `{}`, but the bindings are generated for `{}`.",
+                    target_os, codegen_os
+                ),
+                ctx,
+            );
+            None
+        }
+        _ => Some(target_os),
+    }
+}
+
+/// The `target_os`s the given path is specific to, from its known
+/// OS-specific system paths, e.g. `/usr/include/linux` or the frameworks of
+/// an Apple SDK.
+fn path_target_oses(path: &str) -> Vec<&'static str> {
+    let components: Vec<_> = path.split(['/', '\\']).collect();
+
+    let mut oses = vec![];
+    let mut add = |os| {
+        if !oses.contains(&os) {
+            oses.push(os);
+        }
+    };
+
+    if components.starts_with(&["", "System", "Library", "Frameworks"]) {
+        add("macos");
+    }
+    for (index, &component) in components.iter().enumerate() {
+        if let Some(sdk) = component.strip_suffix(".sdk") {
+            for &(prefix, os) in APPLE_SDKS {
+                if sdk.starts_with(prefix) {
+                    add(os);
+                }
+            }
+        }
+        if component == "Windows Kits" {
+            add("windows");
+        }
+        // The Linux kernel headers, e.g. `/usr/include/linux/input.h`, and
+        // the multiarch ones, e.g. `/usr/include/x86_64-linux-gnu/...`.
+        if components[..index].ends_with(&["usr", "include"]) &&
+            (matches!(component, "linux" | "asm" | "asm-generic") ||
+                component.contains("-linux-"))
+        {
+            add("linux");
+        }
+    }
+    oses
+}
+
+/// The `target_os` of the given target triple, if it is one of the OSes with
+/// known system paths.
+fn triple_target_os(triple: &str) -> Option<&'static str> {
+    // Android triples also contain `linux`, e.g. `aarch64-linux-android`.
+    if triple.contains("android") {
+        return Some("android");
+    }
+    triple.split('-').skip(1).find_map(|part| {
+        Some(match part {
+            "linux" => "linux",
+            "windows" => "windows",
+            "tvos" => "tvos",
+            "watchos" => "watchos",
+            _ if part.starts_with("darwin") || part.starts_with("macos") => {
+                "macos"
+            }
+            _ if part.starts_with("ios") => "ios",
+            _ => return None,
+        })
+    })
+}
+
+fn conflicting_target_os_diagnostic(
+    _ctx: &BindgenContext,
+    item: &Item,
+    target_os: &str,
+    other: &str,
+) {
+    let name = item.canonical_name(_ctx);
+    warning!(
+        "`{}` is guarded by `target_os = \"{}\"`, but also refers to items specific to `{}`",
+        name, target_os, other
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        Diagnostic::default()
+            .with_title(
+                format!("`{}` refers to items specific to several OSes.", name),
+                Level::Warn,
+            )
+            .add_annotation(
+                format!(
+                    "It is guarded by `target_os = \"{}\"`, but refers to items guarded by `target_os = \"{}\"`.",
+                    target_os, other
+                ),
+                Level::Note,
+            )
+            .display();
+    }
+}
+
+fn ambiguous_target_os_diagnostic(
+    path: &str,
+    reason: &str,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Cannot infer the OS of the items declared in `{}`. {}",
+        path,
+        reason
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        Diagnostic::default()
+            .with_title(
+                format!("Cannot infer the OS of the items in `{}`.", path),
+                Level::Warn,
+            )
+            .add_annotation(reason, Level::Note)
+            .add_annotation(
+                "These items are not guarded by a `target_os` cfg.",
+                Level::Note,
+            )
+            .display();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inferring_path_target_oses() {
+        assert_eq!(path_target_oses("/usr/include/linux/input.h"), ["linux"]);
+        assert_eq!(
+            path_target_oses("/usr/include/x86_64-linux-gnu/bits/types.h"),
+            ["linux"]
+        );
+        assert_eq!(
+            path_target_oses(
+                "/Applications/Xcode.app/Contents/Developer/Platforms/MacOSX.platform/Developer/SDKs/MacOSX14.2.sdk/System/Library/Frameworks/CoreFoundation.framework/Headers/CFBase.h"
+            ),
+            ["macos"]
+        );
+        assert_eq!(
+            path_target_oses(
+                "/System/Library/Frameworks/IOKit.framework/Headers/IOKitLib.h"
+            ),
+            ["macos"]
+        );
+        assert_eq!(
+            path_target_oses(
+                r"C:\Program Files (x86)\Windows Kits\10\Include\10.0.22621.0\um\winnt.h"
+            ),
+            ["windows"]
+        );
+        assert_eq!(
+            path_target_oses("/opt/MacOSX.sdk/usr/include/linux/input.h"),
+            ["macos", "linux"]
+        );
+        assert!(path_target_oses("/usr/include/stdio.h").is_empty());
+        assert!(path_target_oses("/home/user/linux/input.h").is_empty());
+    }
+
+    #[test]
+    fn inferring_triple_target_os() {
+        assert_eq!(triple_target_os("x86_64-unknown-linux-gnu"), Some("linux"));
+        assert_eq!(triple_target_os("aarch64-linux-android"), Some("android"));
+        assert_eq!(triple_target_os("x86_64-apple-darwin"), Some("macos"));
+        assert_eq!(triple_target_os("arm64-apple-macosx11.0.0"), Some("macos"));
+        assert_eq!(triple_target_os("aarch64-apple-ios-sim"), Some("ios"));
+        assert_eq!(triple_target_os("x86_64-pc-windows-msvc"), Some("windows"));
+        assert_eq!(triple_target_os("wasm32-unknown-unknown"), None);
+    }
+}
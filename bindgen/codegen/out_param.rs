@@ -0,0 +1,164 @@
+use crate::ir::context::BindgenContext;
+use crate::ir::function::FunctionSig;
+use crate::ir::item::ItemCanonicalName;
+
+use super::utils::{
+    fnsig_argument_identifiers, fnsig_argument_type, fnsig_return_ty_internal,
+};
+
+use proc_macro2::Ident;
+
+/// Generate a wrapper for the function with the given name, signature and
+/// identifier, returning the pointer written through the out parameter set
+/// with [`crate::Builder::out_param`] instead of taking it.
+///
+/// Returns `None` if the function has no such out parameter, or if the
+/// wrapper can't be generated for it.
+pub(crate) fn gen_out_param_wrapper(
+    ctx: &BindgenContext,
+    name: &str,
+    signature: &FunctionSig,
+    ident: &Ident,
+) -> Option<proc_macro2::TokenStream> {
+    let arg_types = signature.argument_types();
+    let out_params: Vec<_> = arg_types
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (arg_name, _))| {
+            let set = ctx.options().out_params.get(arg_name.as_ref()?)?;
+            set.matches(name).then_some(index)
+        })
+        .collect();
+    let out_index = match *out_params.as_slice() {
+        [] => return None,
+        [out_index] => out_index,
+        _ => {
+            skipped_wrapper_diagnostic(
+                name,
+                "it has several out parameters, and only a single one is supported",
+            );
+            return None;
+        }
+    };
+
+    if signature.is_variadic() || signature.is_divergent() {
+        skipped_wrapper_diagnostic(name, "it is variadic or doesn't return");
+        return None;
+    }
+
+    let (elem, is_mut) =
+        match out_pointee(fnsig_argument_type(ctx, &arg_types[out_index].1)) {
+            Some(pointee) => pointee,
+            None => {
+                skipped_wrapper_diagnostic(
+                    name,
+                    "its out parameter isn't a mutable pointer to a pointer",
+                );
+                return None;
+            }
+        };
+
+    let arg_names = fnsig_argument_identifiers(ctx, signature);
+    let out_name = &arg_names[out_index];
+    let mut args = vec![];
+    let mut call_args = vec![];
+    for (index, arg_name) in arg_names.iter().enumerate() {
+        if index == out_index {
+            call_args.push(quote! { &mut #out_name });
+        } else {
+            let ty = fnsig_argument_type(ctx, &arg_types[index].1);
+            args.push(quote! { #arg_name: #ty });
+            call_args.push(quote! { #arg_name });
+        }
+    }
+
+    let wrapper_name = format!("{}_out", ident);
+    let collides = ctx.codegen_items().iter().any(|&id| {
+        let item = ctx.resolve_item(id);
+        item.kind().as_function().is_some() &&
+            item.canonical_name(ctx) == wrapper_name
+    });
+    if collides {
+        skipped_wrapper_diagnostic(
+            name,
+            &format!("a function named `{}` already exists", wrapper_name),
+        );
+        return None;
+    }
+
+    let prefix = ctx.trait_prefix();
+    // `NonNull` can't tell whether the pointee may be mutated through it, so
+    // pointers to `const` pointees are returned as is.
+    let (null, ptr_ty, out_ty, out) = if is_mut {
+        (
+            quote! { null_mut },
+            quote! { *mut #elem },
+            quote! {
+                ::#prefix::option::Option<::#prefix::ptr::NonNull<#elem>>
+            },
+            quote! { ::#prefix::ptr::NonNull::new(#out_name) },
+        )
+    } else {
+        (
+            quote! { null },
+            quote! { *const #elem },
+            quote! { *const #elem },
+            quote! { #out_name },
+        )
+    };
+
+    let call = ctx.wrap_unsafe_ops(quote! { #ident( #( #call_args ),* ) });
+    let (ret_ty, body) = match fnsig_return_ty_internal(ctx, signature) {
+        syn::Type::Tuple(syn::TypeTuple { ref elems, .. })
+            if elems.is_empty() =>
+        {
+            (out_ty, quote! { #call; #out })
+        }
+        ty => (quote! { (#ty, #out_ty) }, quote! { (#call, #out) }),
+    };
+
+    let wrapper = ctx.rust_ident(wrapper_name);
+    let doc = format!(
+        " Calls [`{}`], returning the pointer it writes through `{}`.",
+        ident, out_name
+    );
+
+    let allow_clippy =
+        super::helpers::allow_clippy(ctx, &["missing_safety_doc"]);
+
+    Some(quote! {
+        #[doc = #doc]
+        #allow_clippy
+        pub unsafe fn #wrapper( #( #args ),* ) -> #ret_ty {
+            let mut #out_name: #ptr_ty = ::#prefix::ptr::#null();
+            #body
+        }
+    })
+}
+
+/// The type pointed to by the pointer that the given out parameter type
+/// points to, and whether that pointer is mutable, if the out parameter type
+/// is a mutable pointer to a pointer.
+fn out_pointee(ty: syn::Type) -> Option<(Box<syn::Type>, bool)> {
+    match ty {
+        syn::Type::Ptr(syn::TypePtr {
+            mutability: Some(_),
+            elem,
+            ..
+        }) => match *elem {
+            syn::Type::Ptr(inner) => {
+                Some((inner.elem, inner.mutability.is_some()))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn skipped_wrapper_diagnostic(name: &str, reason: &str) {
+    warning!(
+        "Not generating an out parameter wrapper for `{}`, as {}.",
+        name,
+        reason
+    );
+}
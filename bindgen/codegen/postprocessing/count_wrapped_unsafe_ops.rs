@@ -0,0 +1,96 @@
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+
+/// The name of the attribute marking the `unsafe` blocks emitted by
+/// `BindgenContext::wrap_unsafe_ops`, so that only the blocks that end up in
+/// the bindings are counted.
+pub(crate) const MARKER: &str = "__bindgen_wrapped_unsafe_ops";
+
+/// Remove the markers of the wrapped `unsafe` blocks from the given tokens,
+/// returning them along with the number of markers removed.
+pub(crate) fn count_wrapped_unsafe_ops(
+    tokens: TokenStream,
+) -> (TokenStream, usize) {
+    let mut count = 0;
+    let tokens = strip_markers(tokens, &mut count);
+    (tokens, count)
+}
+
+fn strip_markers(tokens: TokenStream, count: &mut usize) -> TokenStream {
+    let mut stripped = vec![];
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct)
+                if punct.as_char() == '#' &&
+                    tokens.peek().is_some_and(is_marker) =>
+            {
+                tokens.next();
+                *count += 1;
+            }
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    strip_markers(group.stream(), count),
+                );
+                new_group.set_span(group.span());
+                stripped.push(TokenTree::Group(new_group));
+            }
+            token => stripped.push(token),
+        }
+    }
+    stripped.into_iter().collect()
+}
+
+/// Whether the given token is the bracketed body of the marker attribute.
+fn is_marker(token: &TokenTree) -> bool {
+    let TokenTree::Group(group) = token else {
+        return false;
+    };
+    let mut inner = group.stream().into_iter();
+    group.delimiter() == Delimiter::Bracket &&
+        matches!(
+            (inner.next(), inner.next()),
+            (Some(TokenTree::Ident(ident)), None) if ident == MARKER
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::{Ident, Span};
+    use quote::quote;
+
+    #[test]
+    fn counting_wrapped_unsafe_ops() {
+        let marker = Ident::new(MARKER, Span::call_site());
+        let tokens = quote! {
+            pub unsafe fn first(&self) -> i32 {
+                #[#marker] unsafe { (self.first)() }
+            }
+            pub unsafe fn second(&self) -> Option<i32> {
+                Some(#[#marker] unsafe { (self.second)() })
+            }
+            #[inline]
+            pub fn unwrapped() -> i32 {
+                unsafe { ::std::mem::zeroed() }
+            }
+        };
+
+        let expected = quote! {
+            pub unsafe fn first(&self) -> i32 {
+                unsafe { (self.first)() }
+            }
+            pub unsafe fn second(&self) -> Option<i32> {
+                Some(unsafe { (self.second)() })
+            }
+            #[inline]
+            pub fn unwrapped() -> i32 {
+                unsafe { ::std::mem::zeroed() }
+            }
+        };
+
+        let (stripped, count) = count_wrapped_unsafe_ops(tokens);
+        assert_eq!(stripped.to_string(), expected.to_string());
+        assert_eq!(count, 2);
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Group, Ident, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{File, Item};
+
+use crate::merge::{group_entities, Entity};
+
+/// The name replacing the one of an anonymous type when comparing it with the
+/// other anonymous types.
+const PLACEHOLDER: &str = "__bindgen_anon_type";
+
+/// Keep a single definition of each set of structurally identical anonymous
+/// structs and unions, and make all the use sites of the others refer to it.
+///
+/// Two anonymous types are identical if their definitions and
+/// implementations are identical once their names are ignored. Their layout
+/// tests are ignored, as they only depend on the definitions. Types containing
+/// anonymous types are compared once those are deduplicated, so that nested
+/// anonymous types don't prevent their parents from being deduplicated.
+pub(super) fn dedup_identical_anonymous_types(file: &mut File) {
+    loop {
+        let mut renames = HashMap::new();
+        let items = dedup_items(std::mem::take(&mut file.items), &mut renames);
+        if renames.is_empty() {
+            file.items = items;
+            break;
+        }
+
+        file.items = items
+            .into_iter()
+            .map(|item| {
+                syn::parse2(rename_idents(item.into_token_stream(), &renames))
+                    .expect("renamed item should be valid")
+            })
+            .collect();
+    }
+}
+
+/// Remove the duplicated anonymous types from the given items, recording the
+/// name of the type each of them duplicates in `renames`.
+fn dedup_items(
+    items: Vec<Item>,
+    renames: &mut HashMap<String, String>,
+) -> Vec<Item> {
+    let entities = group_entities(items);
+
+    let mut names_by_shape = HashMap::<String, String>::new();
+    let mut duplicates = vec![];
+    for entity in &entities {
+        if let Some(shape) = anonymous_type_shape(entity) {
+            match names_by_shape.get(&shape) {
+                Some(name) => {
+                    renames.insert(entity.key.clone(), name.clone());
+                    duplicates.push(entity.key.clone());
+                }
+                None => {
+                    names_by_shape.insert(shape, entity.key.clone());
+                }
+            }
+        }
+    }
+
+    let is_duplicate = |entity: &Entity| {
+        duplicates.iter().any(|name| {
+            entity.key == *name ||
+                entity.key == format!("bindgen_test_layout_{}", name)
+        })
+    };
+
+    entities
+        .into_iter()
+        .filter(|entity| !is_duplicate(entity))
+        .flat_map(|entity| entity.items)
+        .map(|item| match item {
+            Item::Mod(mut module) => {
+                if let Some((_, items)) = module.content.as_mut() {
+                    *items = dedup_items(std::mem::take(items), renames);
+                }
+                Item::Mod(module)
+            }
+            item => item,
+        })
+        .collect()
+}
+
+/// The tokens of the given entity with its name replaced, if it is an
+/// anonymous struct or union.
+fn anonymous_type_shape(entity: &Entity) -> Option<String> {
+    if !entity.key.contains("_bindgen_ty_") {
+        return None;
+    }
+
+    let is_definition = |item: &Item| match item {
+        Item::Struct(item) => item.ident == entity.key,
+        Item::Union(item) => item.ident == entity.key,
+        _ => false,
+    };
+    if !entity.items.iter().any(is_definition) {
+        return None;
+    }
+
+    let renames = HashMap::from([(entity.key.clone(), PLACEHOLDER.to_owned())]);
+    let shape = entity
+        .items
+        .iter()
+        .filter(|item| !matches!(item, Item::Const(item) if item.ident == "_"))
+        .map(|item| rename_idents(item.to_token_stream(), &renames))
+        .collect::<TokenStream>();
+
+    Some(shape.to_string())
+}
+
+/// Replace the identifiers in the given tokens according to `renames`.
+fn rename_idents(
+    tokens: TokenStream,
+    renames: &HashMap<String, String>,
+) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) => match renames.get(&ident.to_string()) {
+                Some(name) => TokenTree::Ident(Ident::new(name, ident.span())),
+                None => TokenTree::Ident(ident),
+            },
+            TokenTree::Group(group) => {
+                let mut renamed = Group::new(
+                    group.delimiter(),
+                    rename_idents(group.stream(), renames),
+                );
+                renamed.set_span(group.span());
+                TokenTree::Group(renamed)
+            }
+            token => token,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn deduplicating_anonymous_types() {
+        let anonymous_type = |name: &str| {
+            let ident = Ident::new(name, proc_macro2::Span::call_site());
+            let size = format!("Size of {}", name);
+            quote! {
+                #[repr(C)]
+                pub struct #ident {
+                    pub x: ::std::os::raw::c_int,
+                    pub y: ::std::os::raw::c_int,
+                }
+                const _: () = {
+                    [#size][::std::mem::size_of::<#ident>() - 8usize];
+                };
+            }
+        };
+        let first = anonymous_type("Shapes__bindgen_ty_1");
+        let second = anonymous_type("Shapes__bindgen_ty_2");
+        let third = anonymous_type("Shapes__bindgen_ty_3");
+        let module = quote! {
+            #first
+            #second
+            #third
+            #[repr(C)]
+            pub struct Shapes {
+                pub a: Shapes__bindgen_ty_1,
+                pub b: Shapes__bindgen_ty_2,
+                pub c: Shapes__bindgen_ty_3,
+            }
+        };
+
+        let expected = quote! {
+            #first
+            #[repr(C)]
+            pub struct Shapes {
+                pub a: Shapes__bindgen_ty_1,
+                pub b: Shapes__bindgen_ty_1,
+                pub c: Shapes__bindgen_ty_1,
+            }
+        };
+
+        let mut file = syn::parse2(module).unwrap();
+        dedup_identical_anonymous_types(&mut file);
+        assert_eq!(file.into_token_stream().to_string(), expected.to_string());
+    }
+}
@@ -4,9 +4,15 @@ use syn::{parse2, File};
 
 use crate::BindgenOptions;
 
+mod count_wrapped_unsafe_ops;
+mod dedup_identical_anonymous_types;
 mod merge_extern_blocks;
 mod sort_semantically;
 
+pub(crate) use count_wrapped_unsafe_ops::MARKER as WRAPPED_UNSAFE_OPS_MARKER;
+
+use count_wrapped_unsafe_ops::count_wrapped_unsafe_ops;
+use dedup_identical_anonymous_types::dedup_identical_anonymous_types;
 use merge_extern_blocks::merge_extern_blocks;
 use sort_semantically::sort_semantically;
 
@@ -26,12 +32,25 @@ macro_rules! pass {
     };
 }
 
-const PASSES: &[PostProcessingPass] =
-    &[pass!(merge_extern_blocks), pass!(sort_semantically)];
+const PASSES: &[PostProcessingPass] = &[
+    pass!(dedup_identical_anonymous_types),
+    pass!(merge_extern_blocks),
+    pass!(sort_semantically),
+];
 
+/// Run the enabled postprocessing passes on the given items, returning the
+/// resulting tokens along with the number of `unsafe` blocks wrapping unsafe
+/// operations among them.
 pub(crate) fn postprocessing(
     items: Vec<TokenStream>,
     options: &BindgenOptions,
+) -> (TokenStream, usize) {
+    count_wrapped_unsafe_ops(run_passes(items, options))
+}
+
+fn run_passes(
+    items: Vec<TokenStream>,
+    options: &BindgenOptions,
 ) -> TokenStream {
     let items = items.into_iter().collect();
     let require_syn = PASSES.iter().any(|pass| (pass.should_run)(options));
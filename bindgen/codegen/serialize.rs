@@ -9,6 +9,7 @@ use crate::ir::item::Item;
 use crate::ir::item::ItemCanonicalName;
 use crate::ir::item_kind::ItemKind;
 use crate::ir::ty::{FloatKind, Type, TypeKind};
+use crate::ir::var::Var;
 
 use super::{CodegenError, WrapAsVariadic};
 
@@ -44,6 +45,7 @@ impl<'a> CSerialize<'a> for Item {
             ItemKind::Function(func) => {
                 func.serialize(ctx, (self, extra), stack, writer)
             }
+            ItemKind::Var(var) => var.serialize(ctx, (), stack, writer),
             kind => Err(CodegenError::Serialize {
                 msg: format!("Cannot serialize item kind {:?}", kind),
                 loc: get_loc(self),
@@ -198,6 +200,37 @@ impl<'a> CSerialize<'a> for Function {
     }
 }
 
+impl<'a> CSerialize<'a> for Var {
+    type Extra = ();
+
+    fn serialize<W: Write>(
+        &self,
+        ctx: &BindgenContext,
+        (): Self::Extra,
+        stack: &mut Vec<String>,
+        writer: &mut W,
+    ) -> Result<(), CodegenError> {
+        let name = self.name();
+        let suffix = ctx.wrap_static_fns_suffix();
+
+        // Write `ty name_get__extern(void) { return name; }`, with the getter
+        // as the declarator of the type, so that function pointers are
+        // returned as such.
+        stack.push(format!("{}_get{}(void)", name, suffix));
+        self.ty().serialize(ctx, (), stack, writer)?;
+        writeln!(writer, " {{ return {}; }}", name)?;
+
+        if !self.is_const() {
+            // Write `void name_set__extern(ty value) { name = value; }`.
+            write!(writer, "void {}_set{}(", name, suffix)?;
+            serialize_args(&[("value".to_owned(), self.ty())], ctx, writer)?;
+            writeln!(writer, ") {{ {} = value; }}", name)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<'a> CSerialize<'a> for TypeId {
     type Extra = ();
 
@@ -0,0 +1,275 @@
+use crate::ir::context::{BindgenContext, TypeId};
+use crate::ir::function::FunctionSig;
+use crate::ir::int::IntKind;
+use crate::ir::ty::TypeKind;
+
+use super::utils::{fnsig_argument_type, fnsig_return_ty};
+
+use proc_macro2::Ident;
+use quote::ToTokens;
+
+/// A parameter of a wrapper generated for a function with slice parameters.
+enum Param {
+    /// The parameter is passed through as is.
+    Plain,
+    /// The parameter is the pointer of a pointer and length pair, taken as a
+    /// slice of the given element type.
+    SlicePtr {
+        elem: proc_macro2::TokenStream,
+        is_mut: bool,
+        is_void: bool,
+    },
+    /// The parameter is the length of the slice of the parameter at the
+    /// given index.
+    SliceLen {
+        ptr: usize,
+        ty: proc_macro2::TokenStream,
+    },
+}
+
+/// The type pointed to by the given pointer type, looking through aliases.
+fn pointee(ctx: &BindgenContext, ty: TypeId) -> Option<TypeId> {
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+
+    match *item.expect_type().kind() {
+        TypeKind::Pointer(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Whether the given type is an integer that can hold the length of a slice.
+fn is_len(ctx: &BindgenContext, ty: TypeId) -> bool {
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+
+    match *item.expect_type().kind() {
+        TypeKind::Int(kind) => !matches!(kind, IntKind::Bool),
+        _ => false,
+    }
+}
+
+/// Whether a value of the given type can be passed to a foreign function
+/// without letting it dereference memory the caller doesn't control.
+///
+/// Pointers, function pointers and aggregates, which may contain either, are
+/// not.
+fn is_safe_param(ctx: &BindgenContext, ty: TypeId) -> bool {
+    let item = ty
+        .into_resolver()
+        .through_type_refs()
+        .through_type_aliases()
+        .resolve(ctx);
+
+    matches!(
+        *item.expect_type().canonical_type(ctx).kind(),
+        TypeKind::Int(..) |
+            TypeKind::Float(..) |
+            TypeKind::Complex(..) |
+            TypeKind::Enum(..)
+    )
+}
+
+/// The parameters to use for the given pointer and length parameters of the
+/// function with the given name, if they should be taken as a slice.
+fn slice_pair(
+    ctx: &BindgenContext,
+    name: &str,
+    (ptr_name, ptr_ty): &(Option<String>, TypeId),
+    (len_name, len_ty): &(Option<String>, TypeId),
+    ptr_index: usize,
+) -> Option<(Param, Param)> {
+    let pointee = pointee(ctx, *ptr_ty)?;
+    if !is_len(ctx, *len_ty) {
+        return None;
+    }
+
+    let (ptr_name, len_name) = (ptr_name.as_ref()?, len_name.as_ref()?);
+    ctx.options().last_callback(|cb| {
+        cb.slice_param(name, ptr_name, len_name).then_some(())
+    })?;
+
+    let ptr = match fnsig_argument_type(ctx, ptr_ty) {
+        syn::Type::Ptr(ptr) => ptr,
+        _ => return None,
+    };
+
+    // `void` pointers are taken as byte slices.
+    let is_void = matches!(
+        *ctx.resolve_type(pointee).canonical_type(ctx).kind(),
+        TypeKind::Void
+    );
+    let elem = if is_void {
+        quote! { u8 }
+    } else {
+        ptr.elem.into_token_stream()
+    };
+
+    Some((
+        Param::SlicePtr {
+            elem,
+            is_mut: ptr.mutability.is_some(),
+            is_void,
+        },
+        Param::SliceLen {
+            ptr: ptr_index,
+            ty: fnsig_argument_type(ctx, len_ty).into_token_stream(),
+        },
+    ))
+}
+
+/// Generate a wrapper for the function with the given name, signature and
+/// identifier, taking a slice instead of each pointer and length parameter
+/// pair reported by [`crate::callbacks::ParseCallbacks::slice_param`].
+///
+/// Returns `None` if no parameters are taken as slices.
+pub(crate) fn gen_slice_wrapper(
+    ctx: &BindgenContext,
+    name: &str,
+    signature: &FunctionSig,
+    ident: &Ident,
+) -> Option<proc_macro2::TokenStream> {
+    if signature.is_variadic() {
+        return None;
+    }
+
+    let arg_types = signature.argument_types();
+    let mut params = arg_types.iter().map(|_| Param::Plain).collect::<Vec<_>>();
+    let mut index = 0;
+    while index + 1 < arg_types.len() {
+        match slice_pair(
+            ctx,
+            name,
+            &arg_types[index],
+            &arg_types[index + 1],
+            index,
+        ) {
+            Some((ptr, len)) => {
+                params[index] = ptr;
+                params[index + 1] = len;
+                index += 2;
+            }
+            None => index += 1,
+        }
+    }
+
+    if params.iter().all(|param| matches!(param, Param::Plain)) {
+        return None;
+    }
+
+    let prefix = ctx.trait_prefix();
+    let arg_names = super::utils::fnsig_argument_identifiers(ctx, signature);
+    // The wrapper is only safe if asked for, and can't be if the function
+    // may dereference other pointers.
+    let mut is_unsafe = ctx
+        .options()
+        .last_callback(|cb| cb.safe_slice_wrapper(name).then_some(()))
+        .is_none();
+    let mut args = vec![];
+    let mut call_args = vec![];
+    for (index, param) in params.iter().enumerate() {
+        let arg_name = &arg_names[index];
+        match *param {
+            Param::Plain => {
+                let ty = fnsig_argument_type(ctx, &arg_types[index].1);
+                is_unsafe |= !is_safe_param(ctx, arg_types[index].1);
+                args.push(quote! { #arg_name: #ty });
+                call_args.push(quote! { #arg_name });
+            }
+            Param::SlicePtr {
+                ref elem,
+                is_mut,
+                is_void,
+            } => {
+                let ptr = if is_mut {
+                    args.push(quote! { #arg_name: &mut [#elem] });
+                    quote! { #arg_name.as_mut_ptr() }
+                } else {
+                    args.push(quote! { #arg_name: &[#elem] });
+                    quote! { #arg_name.as_ptr() }
+                };
+                call_args.push(if is_void {
+                    quote! { #ptr.cast() }
+                } else {
+                    ptr
+                });
+            }
+            Param::SliceLen { ptr, ref ty } => {
+                let ptr_name = &arg_names[ptr];
+                call_args.push(if ty.to_string() == "usize" {
+                    quote! { #ptr_name.len() }
+                } else {
+                    let msg = format!(
+                        "the length of `{}` should fit in `{}`",
+                        ptr_name, arg_name
+                    );
+                    quote! {
+                        ::#prefix::convert::TryInto::try_into(#ptr_name.len())
+                            .expect(#msg)
+                    }
+                });
+            }
+        }
+    }
+
+    let wrapper = ctx.rust_ident(format!("{}_slice", ident));
+    let ret = fnsig_return_ty(ctx, signature);
+    let call = quote! { #ident( #( #call_args ),* ) };
+    let doc = format!(
+        " Calls [`{}`], taking slices instead of pointer and length pairs.",
+        ident
+    );
+    // Only the conversion of the lengths to narrower types can panic.
+    let can_panic = params.iter().any(|param| match *param {
+        Param::SliceLen { ref ty, .. } => ty.to_string() != "usize",
+        _ => false,
+    });
+    let panics_doc = if can_panic {
+        quote! {
+            #[doc = ""]
+            #[doc = " # Panics"]
+            #[doc = ""]
+            #[doc = " Panics if a slice is longer than its length parameter can hold."]
+        }
+    } else {
+        quote! {}
+    };
+
+    // Make the panics in the wrapper point at its caller.
+    let track_caller =
+        if can_panic && ctx.options().rust_features().track_caller {
+            Some(super::helpers::attributes::track_caller())
+        } else {
+            None
+        };
+
+    Some(if is_unsafe {
+        let call = ctx.wrap_unsafe_ops(call);
+        let allow_clippy =
+            super::helpers::allow_clippy(ctx, &["missing_safety_doc"]);
+        quote! {
+            #[doc = #doc]
+            #panics_doc
+            #track_caller
+            #allow_clippy
+            pub unsafe fn #wrapper( #( #args ),* ) #ret {
+                #call
+            }
+        }
+    } else {
+        quote! {
+            #[doc = #doc]
+            #panics_doc
+            #track_caller
+            pub fn #wrapper( #( #args ),* ) #ret {
+                unsafe { #call }
+            }
+        }
+    })
+}
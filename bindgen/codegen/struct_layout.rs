@@ -331,7 +331,7 @@ impl<'a> StructLayoutTracker<'a> {
         );
 
         if layout.size < self.latest_offset {
-            warn!(
+            warning!(
                 "Calculated wrong layout for {}, too more {} bytes",
                 self.name,
                 self.latest_offset - layout.size
@@ -98,14 +98,24 @@ define_rust_targets! {
         vectorcall_abi,
         ptr_metadata: #81513,
         layout_for_ptr: #69835,
+        f128: #116909,
+        // Stable since Rust 1.82, but only emitted for the nightly target so
+        // that supporting it doesn't raise the default target.
+        unsafe_attributes: #128771,
     },
     Stable_1_77(77) => { offset_of: #106655 },
     Stable_1_73(73) => { thiscall_abi: #42202 },
     Stable_1_71(71) => { c_unwind_abi: #106075 },
     Stable_1_68(68) => { abi_efiapi: #105795 },
     Stable_1_64(64) => { core_ffi_c: #94503 },
-    Stable_1_59(59) => { const_cstr: #54745 },
+    Stable_1_62(62) => { default_enum_variant: #94457 },
+    Stable_1_59(59) => {
+        const_cstr: #54745,
+        const_bitfield_unit,
+    },
+    Stable_1_51(51) => { addr_of: #80886 },
     Stable_1_47(47) => { larger_arrays: #74060 },
+    Stable_1_46(46) => { track_caller: #72445 },
     Stable_1_43(43) => { associated_constants: #68952 },
     Stable_1_40(40) => { non_exhaustive: #44109 },
     Stable_1_36(36) => { maybe_uninit: #60445 },
@@ -192,6 +202,21 @@ impl Ord for RustTarget {
     }
 }
 
+impl RustTarget {
+    /// The oldest Rust target supporting the given feature.
+    pub(crate) fn oldest_supporting(
+        feature: fn(&RustFeatures) -> bool,
+    ) -> Self {
+        let mut targets = Self::stable_releases();
+        targets.sort_by_key(|&(_, minor)| minor);
+        targets
+            .iter()
+            .map(|&(target, _)| target)
+            .find(|&target| feature(&target.into()))
+            .unwrap_or(Self::Nightly)
+    }
+}
+
 impl FromStr for RustTarget {
     type Err = io::Error;
 
@@ -226,6 +251,67 @@ impl std::fmt::Display for RustTarget {
     }
 }
 
+/// Represents the edition of the crate the bindings are used in.
+///
+/// The default edition is Rust 2015, which the bindings generated for any other
+/// edition are also compatible with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RustEdition {
+    /// Rust 2015
+    #[default]
+    Edition2015,
+    /// Rust 2018
+    Edition2018,
+    /// Rust 2021
+    Edition2021,
+}
+
+impl RustEdition {
+    /// The minor version of the first Rust release supporting this edition.
+    const fn min_minor(self) -> u64 {
+        match self {
+            Self::Edition2015 => 0,
+            Self::Edition2018 => 31,
+            Self::Edition2021 => 56,
+        }
+    }
+
+    /// Whether the given Rust target supports this edition.
+    pub(crate) fn is_available(self, target: RustTarget) -> bool {
+        target
+            .minor()
+            .map_or(true, |minor| minor >= self.min_minor())
+    }
+}
+
+impl FromStr for RustEdition {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2015" => Ok(Self::Edition2015),
+            "2018" => Ok(Self::Edition2018),
+            "2021" => Ok(Self::Edition2021),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Got an invalid Rust edition. Accepted values are \"2015\", \"2018\" and \"2021\".",
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for RustEdition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Edition2015 => "2015",
+            Self::Edition2018 => "2018",
+            Self::Edition2021 => "2021",
+        };
+
+        s.fmt(f)
+    }
+}
+
 impl Default for RustFeatures {
     fn default() -> Self {
         RustTarget::default().into()
@@ -260,6 +346,8 @@ mod test {
                 !f_1_21.thiscall_abi &&
                 !f_1_21.vectorcall_abi
         );
+        let f_1_46 = RustFeatures::from(RustTarget::Stable_1_46);
+        assert!(f_1_46.track_caller && !f_1_46.larger_arrays);
         let features = RustFeatures::from(RustTarget::Stable_1_71);
         assert!(
             features.c_unwind_abi &&
@@ -276,7 +364,8 @@ mod test {
                 f_nightly.maybe_uninit &&
                 f_nightly.repr_align &&
                 f_nightly.thiscall_abi &&
-                f_nightly.vectorcall_abi
+                f_nightly.vectorcall_abi &&
+                f_nightly.f128
         );
     }
 
@@ -296,4 +385,18 @@ mod test {
         test_target("1.71", RustTarget::Stable_1_71);
         test_target("nightly", RustTarget::Nightly);
     }
+
+    #[test]
+    fn edition_availability() {
+        assert!(RustEdition::Edition2015.is_available(RustTarget::Stable_1_0));
+        assert!(!RustEdition::Edition2018.is_available(RustTarget::Stable_1_30));
+        assert!(RustEdition::Edition2018.is_available(RustTarget::Stable_1_33));
+        assert!(!RustEdition::Edition2021.is_available(RustTarget::Stable_1_47));
+        assert!(RustEdition::Edition2021.is_available(RustTarget::Stable_1_59));
+        assert!(RustEdition::Edition2021.is_available(RustTarget::Nightly));
+        assert_eq!(
+            RustEdition::from_str("2021").unwrap(),
+            RustEdition::Edition2021
+        );
+    }
 }
@@ -168,6 +168,14 @@ impl<'ctx> CannotDerive<'ctx> {
             return CanDerive::No;
         }
 
+        if self.ctx.opaque_no_construct_by_name(item) {
+            trace!(
+                "    cannot derive {} for opaque type without constructor",
+                self.derive_trait
+            );
+            return CanDerive::No;
+        }
+
         trace!("ty: {:?}", ty);
         if item.is_opaque(self.ctx, &()) {
             if !self.derive_trait.can_derive_union() &&
@@ -320,6 +328,21 @@ impl<'ctx> CannotDerive<'ctx> {
                     return CanDerive::No;
                 }
 
+                // Except for `Default`, the derived implementations would read
+                // the fields hidden behind volatile accessors non-volatilely.
+                if !matches!(self.derive_trait, DeriveTrait::Default) &&
+                    info.has_volatile_accessors(
+                        self.ctx,
+                        ty.layout(self.ctx).as_ref(),
+                    )
+                {
+                    trace!(
+                        "    comp has volatile accessors which cannot derive {}",
+                        self.derive_trait
+                    );
+                    return CanDerive::No;
+                }
+
                 if info.kind() == CompKind::Union {
                     if self.derive_trait.can_derive_union() {
                         if self.ctx.options().untagged_union &&
@@ -458,7 +481,10 @@ impl<'ctx> CannotDerive<'ctx> {
 impl DeriveTrait {
     fn not_by_name(&self, ctx: &BindgenContext, item: &Item) -> bool {
         match self {
-            DeriveTrait::Copy => ctx.no_copy_by_name(item),
+            // Copying a pinned type would move it out of its address.
+            DeriveTrait::Copy => {
+                ctx.no_copy_by_name(item) || ctx.pinned_by_name(item)
+            }
             DeriveTrait::Debug => ctx.no_debug_by_name(item),
             DeriveTrait::Default => ctx.no_default_by_name(item),
             DeriveTrait::Hash => ctx.no_hash_by_name(item),
@@ -1,5 +1,49 @@
 //! Utilities for manipulating C/C++ comments.
 
+use std::fmt;
+use std::str::FromStr;
+
+/// How Doxygen commands in documentation comments are translated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DoxygenStyle {
+    /// Keep Doxygen commands verbatim.
+    #[default]
+    Verbatim,
+    /// Translate common Doxygen commands into idiomatic rustdoc: `@param` into an `# Arguments`
+    /// section, `@return` into a `# Returns` section, `@note` and `@warning` into blockquotes,
+    /// and `@code`/`@endcode` into fenced code blocks.
+    Rustdoc,
+}
+
+impl fmt::Display for DoxygenStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Verbatim => "verbatim",
+            Self::Rustdoc => "rustdoc",
+        };
+
+        s.fmt(f)
+    }
+}
+
+impl FromStr for DoxygenStyle {
+    type Err = std::io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "verbatim" => Ok(Self::Verbatim),
+            "rustdoc" => Ok(Self::Rustdoc),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                concat!(
+                    "Got an invalid DoxygenStyle. Accepted values ",
+                    "are 'verbatim' and 'rustdoc'"
+                ),
+            )),
+        }
+    }
+}
+
 /// The type of a comment.
 #[derive(Debug, PartialEq, Eq)]
 enum Kind {
@@ -65,6 +109,158 @@ fn preprocess_multi_line(comment: &str) -> String {
     lines.join("\n")
 }
 
+/// What the lines of a comment that don't start with a command continue.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Continuation {
+    Description,
+    Param,
+    Returns,
+    Quote,
+}
+
+/// Splits a line starting with a Doxygen command, like `@param x` or
+/// `\return`, into the name of the command and the rest of the line.
+fn split_command(line: &str) -> Option<(&str, &str)> {
+    let line = line.strip_prefix(['@', '\\'])?;
+    let end = line
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(line.len());
+    if end == 0 {
+        return None;
+    }
+    Some((&line[..end], &line[end..]))
+}
+
+/// Translates the common Doxygen commands of a preprocessed comment into
+/// rustdoc, as done for [`DoxygenStyle::Rustdoc`].
+pub(crate) fn translate_doxygen(comment: &str) -> String {
+    let mut lines = vec![];
+    let mut params: Vec<String> = vec![];
+    let mut returns: Vec<String> = vec![];
+    let mut continuation = Continuation::Description;
+    let mut in_code = false;
+
+    for line in comment.lines() {
+        let trimmed = line.trim();
+        if in_code {
+            if matches!(split_command(trimmed), Some(("endcode", _))) {
+                lines.push(" ```".to_owned());
+                in_code = false;
+            } else {
+                lines.push(line.to_owned());
+            }
+            continue;
+        }
+
+        let (command, rest) = match split_command(trimmed) {
+            Some(command) => command,
+            None => {
+                if trimmed.is_empty() {
+                    continuation = Continuation::Description;
+                    // Don't leave several empty lines where the commands
+                    // that were moved to a section used to be.
+                    if lines
+                        .last()
+                        .is_some_and(|line: &String| line.trim().is_empty())
+                    {
+                        continue;
+                    }
+                }
+                match continuation {
+                    Continuation::Description => lines.push(line.to_owned()),
+                    Continuation::Param => {
+                        let param = params.last_mut().unwrap();
+                        param.push(' ');
+                        param.push_str(trimmed);
+                    }
+                    Continuation::Returns => {
+                        let ret = returns.last_mut().unwrap();
+                        ret.push(' ');
+                        ret.push_str(trimmed);
+                    }
+                    Continuation::Quote => {
+                        lines.push(format!(" > {}", trimmed))
+                    }
+                }
+                continue;
+            }
+        };
+
+        let rest = rest.trim();
+        match command {
+            "param" => {
+                // Skip the direction, like in `@param[in]`.
+                let rest = match rest.strip_prefix('[') {
+                    Some(rest) => rest
+                        .split_once(']')
+                        .map_or(rest, |(_, rest)| rest)
+                        .trim_start(),
+                    None => rest,
+                };
+                let (name, desc) =
+                    rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                let desc = desc.trim();
+                params.push(if desc.is_empty() {
+                    format!(" * `{}`", name)
+                } else {
+                    format!(" * `{}` - {}", name, desc)
+                });
+                continuation = Continuation::Param;
+            }
+            "return" | "returns" => {
+                returns.push(format!(" {}", rest));
+                continuation = Continuation::Returns;
+            }
+            "note" | "warning" => {
+                let title = if command == "note" { "Note" } else { "Warning" };
+                lines.push(format!(" > **{}:** {}", title, rest));
+                continuation = Continuation::Quote;
+            }
+            "code" => {
+                // Keep the language of e.g. `@code{.cpp}`, and don't let
+                // rustdoc run the code as a Rust doctest otherwise.
+                let lang = rest
+                    .strip_prefix("{.")
+                    .and_then(|rest| rest.strip_suffix('}'))
+                    .unwrap_or("c");
+                lines.push(format!(" ```{}", lang));
+                in_code = true;
+            }
+            "brief" => {
+                lines.push(format!(" {}", rest));
+                continuation = Continuation::Description;
+            }
+            _ => {
+                // The lines following an unknown command belong to it, so
+                // keep them verbatim along with it.
+                lines.push(line.to_owned());
+                continuation = Continuation::Description;
+            }
+        }
+    }
+
+    if in_code {
+        lines.push(" ```".to_owned());
+    }
+
+    for (title, entries) in [("Arguments", params), ("Returns", returns)] {
+        if entries.is_empty() {
+            continue;
+        }
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!(" # {}", title));
+        lines.push(String::new());
+        lines.extend(entries);
+    }
+
+    lines.join("\n")
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -97,4 +293,28 @@ mod test {
             "hello\nworld\nfoo"
         );
     }
+
+    #[test]
+    fn translates_doxygen_commands() {
+        assert_eq!(
+            translate_doxygen(
+                " @brief Adds two numbers.\n\n @param[in] a The first\n   number.\n \\param b The second number.\n @return The sum.\n @note Wraps around."
+            ),
+            " Adds two numbers.\n\n > **Note:** Wraps around.\n\n # Arguments\n\n * `a` - The first number.\n * `b` - The second number.\n\n # Returns\n\n The sum."
+        );
+
+        assert_eq!(
+            translate_doxygen(
+                " Example:\n @code{.cpp}\n auto x = add(1, 2);\n @endcode"
+            ),
+            " Example:\n ```cpp\n auto x = add(1, 2);\n ```"
+        );
+
+        assert_eq!(
+            translate_doxygen(
+                " @param a The first number.\n @see add\n   for the sum."
+            ),
+            " @see add\n   for the sum.\n\n # Arguments\n\n * `a` - The first number."
+        );
+    }
 }
@@ -421,6 +421,7 @@ struct RawField(FieldData);
 
 impl RawField {
     /// Construct a new `RawField`.
+    #[allow(clippy::too_many_arguments)]
     fn new(
         name: Option<String>,
         ty: TypeId,
@@ -428,6 +429,7 @@ impl RawField {
         annotations: Option<Annotations>,
         bitfield_width: Option<u32>,
         public: bool,
+        volatile: bool,
         offset: Option<usize>,
     ) -> RawField {
         RawField(FieldData {
@@ -437,6 +439,7 @@ impl RawField {
             annotations: annotations.unwrap_or_default(),
             bitfield_width,
             public,
+            volatile,
             offset,
         })
     }
@@ -888,10 +891,32 @@ pub(crate) struct FieldData {
     /// If the C++ field is declared `public`
     public: bool,
 
+    /// If the field is `volatile` qualified.
+    volatile: bool,
+
     /// The offset of the field (in bits)
     offset: Option<usize>,
 }
 
+impl FieldData {
+    /// Is this field hidden behind accessors performing volatile reads and
+    /// writes, see [`crate::Builder::volatile_accessors`]?
+    ///
+    /// The accessors need the field to be aligned.
+    pub(crate) fn has_volatile_accessors(
+        &self,
+        ctx: &BindgenContext,
+        parent: &CompInfo,
+        parent_layout: Option<&Layout>,
+    ) -> bool {
+        ctx.options().volatile_accessors &&
+            self.volatile &&
+            !parent.is_union() &&
+            ctx.resolve_type(self.ty).is_incomplete_array(ctx).is_none() &&
+            !parent.is_packed(ctx, parent_layout)
+    }
+}
+
 impl FieldMethods for FieldData {
     fn name(&self) -> Option<&str> {
         self.name.as_deref()
@@ -1055,6 +1080,10 @@ pub(crate) struct CompInfo {
     /// Whether we saw `__attribute__((packed))` on or within this type.
     packed_attr: bool,
 
+    /// Whether this type is marked as `warn_unused_result` or `[[nodiscard]]`,
+    /// if [`crate::Builder::respect_nodiscard`] is enabled.
+    must_use_attr: bool,
+
     /// Used to know if we've found an opaque attribute that could cause us to
     /// generate a type with invalid layout. This is explicitly used to avoid us
     /// generating bad alignments when parsing types like max_align_t.
@@ -1087,6 +1116,7 @@ impl CompInfo {
             has_non_type_template_params: false,
             has_unevaluable_bit_field_width: false,
             packed_attr: false,
+            must_use_attr: false,
             found_unknown_attr: false,
             is_forward_declaration: false,
         }
@@ -1305,7 +1335,7 @@ impl CompInfo {
                         // nothing.
                     } else {
                         let field = RawField::new(
-                            None, ty, None, None, None, public, offset,
+                            None, ty, None, None, None, public, false, offset,
                         );
                         ci.fields.append_raw_field(field);
                     }
@@ -1327,7 +1357,8 @@ impl CompInfo {
 
                         if !used {
                             let field = RawField::new(
-                                None, ty, None, None, None, public, offset,
+                                None, ty, None, None, None, public, false,
+                                offset,
                             );
                             ci.fields.append_raw_field(field);
                         }
@@ -1359,6 +1390,8 @@ impl CompInfo {
                     let annotations = Annotations::new(&cur);
                     let name = cur.spelling();
                     let is_public = cur.public_accessible();
+                    let is_volatile = cur.cur_type().is_volatile() ||
+                        cur.cur_type().canonical_type().is_volatile();
                     let offset = cur.offset_of_field().ok();
 
                     // Name can be empty if there are bitfields, for example,
@@ -1377,6 +1410,7 @@ impl CompInfo {
                         annotations,
                         bit_width,
                         is_public,
+                        is_volatile,
                         offset,
                     );
                     ci.fields.append_raw_field(field);
@@ -1579,7 +1613,7 @@ impl CompInfo {
                 CXCursor_FunctionTemplate |
                 CXCursor_ConversionFunction => {}
                 _ => {
-                    warn!(
+                    warning!(
                         "unhandled comp member `{}` (kind {:?}) in `{}` ({})",
                         cur.spelling(),
                         clang::kind_to_str(cur.kind()),
@@ -1592,11 +1626,15 @@ impl CompInfo {
         });
 
         if let Some((ty, _, public, offset)) = maybe_anonymous_struct_field {
-            let field =
-                RawField::new(None, ty, None, None, None, public, offset);
+            let field = RawField::new(
+                None, ty, None, None, None, public, false, offset,
+            );
             ci.fields.append_raw_field(field);
         }
 
+        ci.must_use_attr =
+            ctx.options().respect_nodiscard && cursor.has_must_use_attr();
+
         Ok(ci)
     }
 
@@ -1614,7 +1652,7 @@ impl CompInfo {
                 _ => CompKind::Struct,
             },
             _ => {
-                warn!("Unknown kind for comp type: {:?}", cursor);
+                warning!("Unknown kind for comp type: {:?}", cursor);
                 return Err(ParseError::Continue);
             }
         })
@@ -1637,6 +1675,21 @@ impl CompInfo {
         self.found_unknown_attr
     }
 
+    /// Are any of the fields of this compound type hidden behind volatile
+    /// accessors?
+    pub(crate) fn has_volatile_accessors(
+        &self,
+        ctx: &BindgenContext,
+        layout: Option<&Layout>,
+    ) -> bool {
+        self.fields().iter().any(|field| match *field {
+            Field::DataMember(ref data) => {
+                data.has_volatile_accessors(ctx, self, layout)
+            }
+            Field::Bitfields(..) => false,
+        })
+    }
+
     /// Is this compound type packed?
     pub(crate) fn is_packed(
         &self,
@@ -1692,6 +1745,11 @@ impl CompInfo {
         self.is_forward_declaration
     }
 
+    /// Is this type marked as `warn_unused_result` or `[[nodiscard]]`?
+    pub(crate) fn has_must_use_attr(&self) -> bool {
+        self.must_use_attr
+    }
+
     /// Compute this compound structure's bitfield allocation units.
     pub(crate) fn compute_bitfield_units(
         &mut self,
@@ -1857,7 +1915,7 @@ impl IsOpaque for CompInfo {
             if self.is_packed(ctx, layout.as_ref()) &&
                 layout.map_or(false, |l| l.align > 1)
             {
-                warn!("Found a type that is both packed and aligned to greater than \
+                warning!("Found a type that is both packed and aligned to greater than \
                        1; Rust before version 1.33 doesn't have `#[repr(packed(N))]`, so we \
                        are treating it as opaque. You may wish to set bindgen's rust target \
                        version to 1.33 or later to enable `#[repr(packed(N))]` support.");
@@ -11,24 +11,30 @@ use super::derive::{
     CanDerive, CanDeriveCopy, CanDeriveDebug, CanDeriveDefault, CanDeriveEq,
     CanDeriveHash, CanDeriveOrd, CanDerivePartialEq, CanDerivePartialOrd,
 };
+use super::enum_ty::{Enum, GroupedConstant};
 use super::function::Function;
+use super::generic_selection::GenericSelection;
 use super::int::IntKind;
 use super::item::{IsOpaque, Item, ItemAncestors, ItemSet};
 use super::item_kind::ItemKind;
+use super::layout::Layout;
 use super::module::{Module, ModuleKind};
 use super::template::{TemplateInstantiation, TemplateParameters};
-use super::traversal::{self, Edge, ItemTraversal};
+use super::traversal::{self, Edge, ItemTraversal, Trace};
 use super::ty::{FloatKind, Type, TypeKind};
+use super::var::VarType;
+use crate::callbacks::VarInfo;
 use crate::clang::{self, ABIKind, Cursor};
-use crate::codegen::CodegenError;
-use crate::BindgenOptions;
+use crate::codegen::postprocessing::WRAPPED_UNSAFE_OPS_MARKER;
+use crate::regex_set::RegexSet;
+use crate::{AllowlistDecision, AllowlistTraceEntry, BindgenOptions};
 use crate::{Entry, HashMap, HashSet};
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
 use std::borrow::Cow;
 use std::cell::{Cell, RefCell};
-use std::collections::{BTreeSet, HashMap as StdHashMap};
+use std::collections::{BTreeSet, HashMap as StdHashMap, VecDeque};
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::mem;
@@ -358,6 +364,18 @@ pub(crate) struct BindgenContext {
     /// This needs to be an std::HashMap because the cexpr API requires it.
     parsed_macros: StdHashMap<Vec<u8>, cexpr::expr::EvalResult>,
 
+    /// The integer macro constants that are grouped into enums, by enum name,
+    /// in the order in which their groups were first seen.
+    constant_groups: Vec<(String, Vec<GroupedConstant>)>,
+
+    /// The function-like macros built on `_Generic` selections, which are
+    /// translated into traits.
+    generic_selections: Vec<GenericSelection>,
+
+    /// The compiled regular expressions of `BindgenOptions::rename_patterns`,
+    /// and their replacements.
+    rename_patterns: Vec<(regex::Regex, Box<str>)>,
+
     /// A map with all include locations.
     ///
     /// This is needed so that items are created in the order they are defined in.
@@ -408,6 +426,19 @@ pub(crate) struct BindgenContext {
     /// It's computed right after computing the allowlisted items.
     codegen_items: Option<ItemSet>,
 
+    /// Why each function, variable and named type was or wasn't generated.
+    ///
+    /// Only populated with `--trace-allowlist`, right after computing the
+    /// codegen items.
+    allowlist_trace: Vec<AllowlistTraceEntry>,
+
+    /// The items that were allowlisted directly, rather than as dependencies
+    /// of other allowlisted items, along with their inner types.
+    ///
+    /// Only populated with `--hide-transitive-deps`, right after computing
+    /// the codegen items.
+    directly_allowlisted: Option<ItemSet>,
+
     /// Map from an item's ID to the set of template parameter items that it
     /// uses. See `ir::named` for more details. Always `Some` during the codegen
     /// phase.
@@ -495,6 +526,27 @@ pub(crate) struct BindgenContext {
     has_float: Option<HashSet<ItemId>>,
 }
 
+/// Whether the given item shows up in the allowlist trace, that is, whether
+/// it's a function, a variable or a named type that gets its own definition.
+fn is_traced(item: &Item) -> bool {
+    match *item.kind() {
+        ItemKind::Module(..) => false,
+        ItemKind::Function(..) | ItemKind::Var(..) => true,
+        ItemKind::Type(ref ty) => {
+            ty.name().is_some() &&
+                matches!(
+                    *ty.kind(),
+                    TypeKind::Comp(..) |
+                        TypeKind::Enum(..) |
+                        TypeKind::Alias(..) |
+                        TypeKind::TemplateAlias(..) |
+                        TypeKind::Opaque |
+                        TypeKind::ObjCInterface(..)
+                )
+        }
+    }
+}
+
 /// A traversal of allowlisted items.
 struct AllowlistedItemsTraversal<'ctx> {
     ctx: &'ctx BindgenContext,
@@ -545,9 +597,6 @@ impl BindgenContext {
         // see: https://reviews.llvm.org/D32389
         let index = clang::Index::new(false, true);
 
-        let parse_options =
-            clang_sys::CXTranslationUnit_DetailedPreprocessingRecord;
-
         let translation_unit = {
             let _t =
                 Timer::new("translation_unit").with_output(options.time_phases);
@@ -555,9 +604,9 @@ impl BindgenContext {
             clang::TranslationUnit::parse(
                 &index,
                 "",
-                &options.clang_args,
+                &options.clang_args_with_target(),
                 input_unsaved_files,
-                parse_options,
+                options.translation_unit_flags.bits(),
             ).expect("libclang error; possible causes include:
 - Invalid flag syntax
 - Unrecognized flags
@@ -574,6 +623,22 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         // depfiles need to include the explicitly listed headers too
         let deps = options.input_headers.iter().cloned().collect();
 
+        let rename_patterns = options
+            .rename_patterns
+            .iter()
+            .filter_map(|(regex, replacement)| match regex::Regex::new(regex) {
+                Ok(regex) => Some((regex, replacement.clone())),
+                Err(e) => {
+                    warning!(
+                        "Invalid regex in rename pattern {:?}: {:?}",
+                        regex,
+                        e
+                    );
+                    None
+                }
+            })
+            .collect();
+
         BindgenContext {
             items: vec![Some(root_module)],
             includes: Default::default(),
@@ -586,6 +651,9 @@ If you encounter an error missing from this list, please file an issue or a PR!"
             semantic_parents: Default::default(),
             currently_parsed_types: vec![],
             parsed_macros: Default::default(),
+            constant_groups: vec![],
+            generic_selections: vec![],
+            rename_patterns,
             replacements: Default::default(),
             collected_typerefs: false,
             in_codegen: false,
@@ -598,6 +666,8 @@ If you encounter an error missing from this list, please file an issue or a PR!"
             allowlisted: None,
             blocklisted_types_implement_traits: Default::default(),
             codegen_items: None,
+            allowlist_trace: vec![],
+            directly_allowlisted: None,
             used_template_parameters: None,
             need_bitfield_allocation: Default::default(),
             enum_typedef_combos: None,
@@ -632,6 +702,27 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         self.target_info.pointer_width / 8
     }
 
+    /// Returns the target triple set by [`Builder::output_target`], which
+    /// defaults to the one Clang parsed the input headers for.
+    ///
+    /// [`Builder::output_target`]: crate::Builder::output_target
+    pub(crate) fn output_target(&self) -> &str {
+        self.options
+            .output_target
+            .as_deref()
+            .unwrap_or(&self.target_info.triple)
+    }
+
+    /// Whether `long double` is an IEEE 754 binary128 type on the output
+    /// target when it takes 16 bytes.
+    pub(crate) fn is_target_long_double_binary128(&self) -> bool {
+        // x86 targets use the x87 extended precision format and PowerPC
+        // targets use a double-double format instead.
+        ["aarch64-", "riscv", "s390x-", "loongarch64-"]
+            .iter()
+            .any(|arch| self.output_target().starts_with(arch))
+    }
+
     /// Returns the ABI, which is mostly useful for determining the mangling kind.
     pub(crate) fn abi_kind(&self) -> ABIKind {
         self.target_info.abi
@@ -768,9 +859,10 @@ If you encounter an error missing from this list, please file an issue or a PR!"
             } else if let Some(usr) = declaration.usr() {
                 TypeKey::Usr(usr)
             } else {
-                warn!(
+                warning!(
                     "Valid declaration with no USR: {:?}, {:?}",
-                    declaration, location
+                    declaration,
+                    location
                 );
                 TypeKey::Declaration(declaration)
             };
@@ -901,6 +993,25 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         Cow::Borrowed(name)
     }
 
+    /// Apply the rename patterns to the given item name, see
+    /// [`crate::Builder::rename_pattern`].
+    pub(crate) fn rename_item<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        let mut name = Cow::Borrowed(name);
+        for (regex, replacement) in &self.rename_patterns {
+            if let Cow::Owned(renamed) =
+                regex.replace_all(&name, &**replacement)
+            {
+                name = Cow::Owned(renamed);
+            }
+        }
+        name
+    }
+
+    /// Whether any rename pattern is applied to the item names.
+    pub(crate) fn renames_items(&self) -> bool {
+        !self.rename_patterns.is_empty()
+    }
+
     /// Returns a mangled name as a rust identifier.
     pub(crate) fn rust_ident<S>(&self, name: S) -> Ident
     where
@@ -961,20 +1072,21 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         let typerefs = self.collect_typerefs();
 
         for (id, ty, loc, parent_id) in typerefs {
-            let _resolved =
-                {
-                    let resolved = Item::from_ty(&ty, loc, parent_id, self)
+            let _resolved = {
+                let resolved = Item::from_ty(&ty, loc, parent_id, self)
                     .unwrap_or_else(|_| {
-                        warn!("Could not resolve type reference, falling back \
-                               to opaque blob");
+                        warning!(
+                            "Could not resolve type reference, falling back \
+                               to opaque blob"
+                        );
                         Item::new_opaque_type(self.next_item_id(), &ty, self)
                     });
 
-                    let item = self.items[id.0].as_mut().unwrap();
-                    *item.kind_mut().as_type_mut().unwrap().kind_mut() =
-                        TypeKind::ResolvedTypeRef(resolved);
-                    resolved
-                };
+                let item = self.items[id.0].as_mut().unwrap();
+                *item.kind_mut().as_type_mut().unwrap().kind_mut() =
+                    TypeKind::ResolvedTypeRef(resolved);
+                resolved
+            };
 
             // Something in the STL is trolling me. I don't need this assertion
             // right now, but worth investigating properly once this lands.
@@ -1190,12 +1302,12 @@ If you encounter an error missing from this list, please file an issue or a PR!"
 
     /// Enter the code generation phase, invoke the given callback `cb`, and
     /// leave the code generation phase.
-    pub(crate) fn gen<F, Out>(
+    pub(crate) fn gen<F, Out, E>(
         mut self,
         cb: F,
-    ) -> Result<(Out, BindgenOptions), CodegenError>
+    ) -> Result<(Out, BindgenOptions), E>
     where
-        F: FnOnce(&Self) -> Result<Out, CodegenError>,
+        F: FnOnce(&Self) -> Result<Out, E>,
     {
         self.in_codegen = true;
 
@@ -1654,7 +1766,7 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         let num_expected_args =
             self.resolve_type(template).num_self_template_params(self);
         if num_expected_args == 0 {
-            warn!(
+            warning!(
                 "Tried to instantiate a template for which we could not \
                  determine any template parameters"
             );
@@ -1739,7 +1851,7 @@ If you encounter an error missing from this list, please file an issue or a PR!"
                         // instantiation :(
                         let args_len = args.len();
                         if args_len < num_expected_template_args {
-                            warn!(
+                            warning!(
                                 "Found a template instantiation without \
                                  enough template arguments"
                             );
@@ -1792,7 +1904,7 @@ If you encounter an error missing from this list, please file an issue or a PR!"
                     }
                 }
                 _ => {
-                    warn!(
+                    warning!(
                         "Found template arg cursor we can't handle: {:?}",
                         child
                     );
@@ -1808,7 +1920,7 @@ If you encounter an error missing from this list, please file an issue or a PR!"
             // arguments. For example, `Foo<true, 5>` versus `Bar<bool, int>`.
             // We can't handle these instantiations, so just punt in this
             // situation...
-            warn!(
+            warning!(
                 "Found template instantiated with a const value; \
                  bindgen can't handle this kind of template instantiation!"
             );
@@ -1816,7 +1928,7 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         }
 
         if args.len() != num_expected_args {
-            warn!(
+            warning!(
                 "Found a template with an unexpected number of template \
                  arguments"
             );
@@ -2048,6 +2160,13 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         let is_const = ty.is_const();
         let layout = ty.fallible_layout(self).ok();
         let location = ty.declaration().location();
+
+        if matches!(type_kind, TypeKind::Int(IntKind::I128 | IntKind::U128)) &&
+            !self.options().rust_features().i128_and_u128
+        {
+            int128_fallback_diagnostic(&spelling, layout, self);
+        }
+
         let ty = Type::new(Some(spelling), layout, type_kind, is_const);
         let id = self.next_item_id();
         let item = Item::new(
@@ -2131,7 +2250,7 @@ If you encounter an error missing from this list, please file an issue or a PR!"
             }
             c_args.extend(
                 self.options
-                    .clang_args
+                    .clang_args_with_target()
                     .iter()
                     .filter(|next| {
                         !self.options.input_headers.contains(next) &&
@@ -2154,8 +2273,7 @@ If you encounter an error missing from this list, please file an issue or a PR!"
             ];
             c_args.extend(
                 self.options
-                    .clang_args
-                    .clone()
+                    .clang_args_with_target()
                     .iter()
                     .filter(|next| {
                         !self.options.input_headers.contains(next) &&
@@ -2196,6 +2314,106 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         self.parsed_macros.insert(id, value);
     }
 
+    /// Add the integer macro constant `name` to the enum its name matches, if
+    /// any.
+    ///
+    /// Returns whether the constant was grouped, in which case it shouldn't
+    /// be generated on its own.
+    pub(crate) fn group_macro_constant(
+        &mut self,
+        cursor: Cursor,
+        name: &str,
+        value: i64,
+    ) -> bool {
+        let enum_name = match self
+            .options
+            .constant_groups
+            .iter()
+            .find(|(_, regex_set)| regex_set.matches(name))
+        {
+            Some((enum_name, _)) => enum_name,
+            None => return false,
+        };
+
+        let constant = (cursor, name.to_owned(), value);
+        match self
+            .constant_groups
+            .iter_mut()
+            .find(|(group_name, _)| group_name == enum_name)
+        {
+            Some((_, constants)) => constants.push(constant),
+            None => self
+                .constant_groups
+                .push((enum_name.clone(), vec![constant])),
+        }
+        true
+    }
+
+    /// Note a function-like macro built on a `_Generic` selection, to
+    /// translate it into a trait.
+    pub(crate) fn add_generic_selection(
+        &mut self,
+        selection: GenericSelection,
+    ) {
+        self.generic_selections.push(selection);
+    }
+
+    /// The function-like macros built on `_Generic` selections.
+    pub(crate) fn generic_selections(&self) -> &[GenericSelection] {
+        &self.generic_selections
+    }
+
+    /// Add a function equivalent to the given function-like macro definition,
+    /// to translate it into Rust.
+    pub(crate) fn add_function_macro(&mut self, cursor: Cursor) {
+        let function = Function::from_macro(&cursor, self);
+        let id = self.next_item_id();
+        let module = self.root_module.into();
+        self.add_item(
+            Item::new(
+                id,
+                None,
+                None,
+                module,
+                ItemKind::Function(function),
+                Some(cursor.location()),
+            ),
+            Some(cursor),
+            None,
+        );
+    }
+
+    /// Add an enum for every group of macro constants that was found while
+    /// parsing.
+    pub(crate) fn add_constant_group_enums(&mut self) {
+        for (name, constants) in mem::take(&mut self.constant_groups) {
+            // The enum is declared where its first constant is.
+            let declaration = constants[0].0;
+            let (enum_ty, layout) =
+                Enum::from_constant_group(&name, &constants, self);
+            let ty = Type::new(
+                Some(name),
+                Some(layout),
+                TypeKind::Enum(enum_ty),
+                false,
+            );
+            let id = self.next_item_id();
+            let module = self.root_module.into();
+            self.add_item(
+                Item::new(
+                    id,
+                    None,
+                    None,
+                    module,
+                    ItemKind::Type(ty),
+                    Some(declaration.location()),
+                ),
+                Some(declaration),
+                None,
+            );
+        }
+    }
+
     /// Are we in the codegen phase?
     pub(crate) fn in_codegen_phase(&self) -> bool {
         self.in_codegen
@@ -2216,7 +2434,7 @@ If you encounter an error missing from this list, please file an issue or a PR!"
                 entry.insert(potential_ty);
             }
             Entry::Occupied(occupied) => {
-                warn!(
+                warning!(
                     "Replacement for {:?} already defined as {:?}; \
                      ignoring duplicate replacement definition as {:?}",
                     name,
@@ -2247,6 +2465,36 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         self.options.opaque_types.matches(path[1..].join("::"))
     }
 
+    /// Is the given item a typedef marked as opaque with
+    /// [`crate::Builder::typedef_opaque`]?
+    pub(crate) fn opaque_typedef_by_name(&self, item: &Item) -> bool {
+        let is_typedef = item.as_type().is_some_and(|ty| {
+            matches!(
+                *ty.kind(),
+                TypeKind::Alias(..) | TypeKind::TemplateAlias(..)
+            )
+        });
+        is_typedef &&
+            self.options
+                .opaque_typedefs
+                .matches(item.path_for_allowlisting(self)[1..].join("::"))
+    }
+
+    /// Are all the instantiations of the template with the given `name` marked
+    /// as opaque?
+    pub(crate) fn opaque_template_instantiations_by_name(
+        &self,
+        path: &[String],
+    ) -> bool {
+        debug_assert!(
+            self.in_codegen_phase(),
+            "You're not supposed to call this yet"
+        );
+        self.options
+            .opaque_template_instantiations
+            .matches(path[1..].join("::"))
+    }
+
     /// Get the options used to configure this bindgen context.
     pub(crate) fn options(&self) -> &BindgenOptions {
         &self.options
@@ -2317,7 +2565,7 @@ If you encounter an error missing from this list, please file an issue or a PR!"
                         // and then move on.
                         //
                         // See also https://github.com/rust-lang/rust-bindgen/issues/1676.
-                        warn!(
+                        warning!(
                             "Ignored unknown namespace prefix '{}' at {:?} in {:?}",
                             String::from_utf8_lossy(name),
                             token,
@@ -2462,6 +2710,14 @@ If you encounter an error missing from this list, please file an issue or a PR!"
                 // Only consider roots that are enabled for codegen.
                 .filter(|&(_, item)| item.is_enabled_for_codegen(self))
                 .filter(|&(_, item)| {
+                    // The parse callbacks take precedence over the
+                    // allowlisting options.
+                    if let Some(allowlisted) =
+                        self.allowlist_var_by_callbacks(item)
+                    {
+                        return allowlisted;
+                    }
+
                     // If nothing is explicitly allowlisted, then everything is fair
                     // game.
                     if self.options().allowlisted_types.is_empty() &&
@@ -2605,13 +2861,33 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         )
         .collect::<ItemSet>();
 
+        let codegen_items_predicate = if self.options().allowlist_recursively {
+            traversal::codegen_edges
+        } else {
+            allowlisted_items_predicate
+        };
+
+        if self.options().trace_allowlist ||
+            self.options().coverage_report.is_some()
+        {
+            self.allowlist_trace =
+                self.trace_allowlist(&roots, codegen_items_predicate);
+        }
+
+        if self.options().hide_transitive_deps {
+            self.directly_allowlisted = Some(
+                AllowlistedItemsTraversal::new(
+                    self,
+                    roots.clone(),
+                    traversal::only_inner_type_edges,
+                )
+                .collect(),
+            );
+        }
+
         let codegen_items = if self.options().allowlist_recursively {
-            AllowlistedItemsTraversal::new(
-                self,
-                roots,
-                traversal::codegen_edges,
-            )
-            .collect::<ItemSet>()
+            AllowlistedItemsTraversal::new(self, roots, codegen_items_predicate)
+                .collect::<ItemSet>()
         } else {
             allowlisted.clone()
         };
@@ -2636,6 +2912,211 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         }
     }
 
+    /// Whether the given item is only generated as a dependency of the
+    /// allowlisted items and should be hidden from the documentation, see
+    /// `--hide-transitive-deps`.
+    pub(crate) fn is_transitive_dependency(&self, id: ItemId) -> bool {
+        self.directly_allowlisted
+            .as_ref()
+            .is_some_and(|directly_allowlisted| {
+                !directly_allowlisted.contains(&id)
+            })
+    }
+
+    /// Get why each function, variable and named type was or wasn't generated.
+    pub(crate) fn allowlist_trace(&self) -> &[AllowlistTraceEntry] {
+        &self.allowlist_trace
+    }
+
+    /// Record why each traced item was or wasn't generated, given the roots of
+    /// the allowlisted items traversal and the predicate used to compute the
+    /// codegen items.
+    fn trace_allowlist(
+        &self,
+        roots: &[ItemId],
+        predicate: traversal::TraversalPredicate,
+    ) -> Vec<AllowlistTraceEntry> {
+        // Find the item each item was first reached from, the same way the
+        // codegen items traversal does.
+        let mut referrers = StdHashMap::new();
+        let mut seen = roots.iter().copied().collect::<ItemSet>();
+        let mut queue = roots.iter().copied().collect::<VecDeque<_>>();
+        while let Some(id) = queue.pop_front() {
+            self.resolve_item(id).trace(
+                self,
+                &mut |sub_id: ItemId, kind| {
+                    if predicate(self, Edge::new(sub_id, kind)) &&
+                        seen.insert(sub_id)
+                    {
+                        referrers.insert(sub_id, id);
+                        queue.push_back(sub_id);
+                    }
+                },
+                &(),
+            );
+        }
+
+        let name_of =
+            |item: &Item| item.path_for_allowlisting(self)[1..].join("::");
+
+        self.items()
+            .filter(|&(_, item)| {
+                item.is_enabled_for_codegen(self) && is_traced(item)
+            })
+            .map(|(id, item)| {
+                let decision =
+                    if let Some(decision) = self.blocklist_decision(item) {
+                        decision
+                    } else if !seen.contains(&id) {
+                        AllowlistDecision::NotAllowlisted
+                    } else if !referrers.contains_key(&id) {
+                        self.allowlist_root_decision(item)
+                    } else {
+                        // Report the closest named item this one was reached
+                        // from, rather than e.g. a pointer type.
+                        let mut referrer = referrers[&id];
+                        while !is_traced(self.resolve_item(referrer)) {
+                            match referrers.get(&referrer) {
+                                Some(&next) => referrer = next,
+                                None => break,
+                            }
+                        }
+                        AllowlistDecision::PulledInBy(name_of(
+                            self.resolve_item(referrer),
+                        ))
+                    };
+                AllowlistTraceEntry {
+                    name: name_of(item),
+                    decision,
+                }
+            })
+            .collect()
+    }
+
+    /// Why the given item, that is a root of the allowlisted items traversal,
+    /// was allowlisted.
+    fn allowlist_root_decision(&self, item: &Item) -> AllowlistDecision {
+        if self.allowlist_var_by_callbacks(item).is_some() {
+            return AllowlistDecision::Callback;
+        }
+
+        let options = self.options();
+        if options.allowlisted_types.is_empty() &&
+            options.allowlisted_functions.is_empty() &&
+            options.allowlisted_vars.is_empty() &&
+            options.allowlisted_files.is_empty() &&
+            options.allowlisted_items.is_empty()
+        {
+            return AllowlistDecision::NothingAllowlisted;
+        }
+
+        if item.annotations().use_instead_of().is_some() {
+            return AllowlistDecision::Replacement;
+        }
+
+        let allowlisted = |option, pattern: Option<&str>| {
+            pattern.map(|pattern| AllowlistDecision::Allowlisted {
+                option,
+                pattern: pattern.to_owned(),
+            })
+        };
+
+        let filename = item
+            .location()
+            .and_then(|location| location.location().0.name());
+        if let Some(decision) = filename.and_then(|filename| {
+            allowlisted(
+                "--allowlist-file",
+                options.allowlisted_files.matching_item(filename),
+            )
+        }) {
+            return decision;
+        }
+
+        let name = item.path_for_allowlisting(self)[1..].join("::");
+        let (option, set) = match *item.kind() {
+            ItemKind::Function(..) => {
+                ("--allowlist-function", &options.allowlisted_functions)
+            }
+            ItemKind::Var(..) => ("--allowlist-var", &options.allowlisted_vars),
+            _ => ("--allowlist-type", &options.allowlisted_types),
+        };
+        allowlisted(
+            "--allowlist-item",
+            options.allowlisted_items.matching_item(&name),
+        )
+        .or_else(|| allowlisted(option, set.matching_item(&name)))
+        .unwrap_or(AllowlistDecision::Implicit)
+    }
+
+    /// Whether the parse callbacks allowlist the given item, if it is a
+    /// variable and they decide.
+    fn allowlist_var_by_callbacks(&self, item: &Item) -> Option<bool> {
+        let var = item.kind().as_var()?;
+        let name = item.path_for_allowlisting(self)[1..].join("::");
+        let ty = var.ty().into_resolver().through_type_refs().resolve(self);
+        let int_kind = match *ty.expect_type().canonical_type(self).kind() {
+            TypeKind::Int(kind) => Some(kind),
+            _ => None,
+        };
+        let value = match var.val() {
+            Some(&VarType::Int(value)) => Some(value),
+            _ => None,
+        };
+
+        self.options().last_callback(|cb| {
+            cb.should_allowlist_var(&VarInfo {
+                name: &name,
+                type_name: ty.expect_type().name(),
+                int_kind,
+                value,
+            })
+        })
+    }
+
+    /// Why the given item was blocklisted, if it was.
+    fn blocklist_decision(&self, item: &Item) -> Option<AllowlistDecision> {
+        if !item.is_blocklisted(self) {
+            return None;
+        }
+
+        let options = self.options();
+        let blocklisted = |option, pattern: Option<&str>| {
+            pattern.map(|pattern| AllowlistDecision::Blocklisted {
+                option,
+                pattern: pattern.to_owned(),
+            })
+        };
+
+        let filename = item
+            .location()
+            .and_then(|location| location.location().0.name());
+        let name = item.path_for_allowlisting(self)[1..].join("::");
+        let (option, set) = match *item.kind() {
+            ItemKind::Function(..) => {
+                ("--blocklist-function", &options.blocklisted_functions)
+            }
+            ItemKind::Var(..) => ("--blocklist-var", &options.blocklisted_vars),
+            _ => ("--blocklist-type", &options.blocklisted_types),
+        };
+        let decision = filename
+            .and_then(|filename| {
+                blocklisted(
+                    "--blocklist-file",
+                    options.blocklisted_files.matching_item(filename),
+                )
+            })
+            .or_else(|| {
+                blocklisted(
+                    "--blocklist-item",
+                    options.blocklisted_items.matching_item(&name),
+                )
+            })
+            .or_else(|| blocklisted(option, set.matching_item(&name)))
+            .unwrap_or(AllowlistDecision::Hidden);
+        Some(decision)
+    }
+
     /// Convenient method for getting the prefix to use for most traits in
     /// codegen depending on the `use_core` option.
     pub(crate) fn trait_prefix(&self) -> Ident {
@@ -2935,34 +3416,100 @@ If you encounter an error missing from this list, please file an issue or a PR!"
         self.has_float.as_ref().unwrap().contains(&id.into())
     }
 
+    /// Whether the given trait shouldn't be derived or implemented for this
+    /// item, according to [`ParseCallbacks::should_implement`] or else to the
+    /// patterns of the corresponding `--no-*` flag.
+    ///
+    /// [`ParseCallbacks::should_implement`]: crate::callbacks::ParseCallbacks::should_implement
+    fn not_implemented_by_name(
+        &self,
+        item: &Item,
+        derive_trait: DeriveTrait,
+        patterns: &RegexSet,
+    ) -> bool {
+        let name = item.path_for_allowlisting(self)[1..].join("::");
+        match self
+            .options()
+            .last_callback(|cb| cb.should_implement(&name, derive_trait))
+        {
+            Some(implement) => !implement,
+            None => patterns.matches(name),
+        }
+    }
+
     /// Check if `--no-partialeq` flag is enabled for this item.
     pub(crate) fn no_partialeq_by_name(&self, item: &Item) -> bool {
-        let name = item.path_for_allowlisting(self)[1..].join("::");
-        self.options().no_partialeq_types.matches(name)
+        self.not_implemented_by_name(
+            item,
+            DeriveTrait::PartialEqOrPartialOrd,
+            &self.options().no_partialeq_types,
+        )
     }
 
     /// Check if `--no-copy` flag is enabled for this item.
     pub(crate) fn no_copy_by_name(&self, item: &Item) -> bool {
+        self.not_implemented_by_name(
+            item,
+            DeriveTrait::Copy,
+            &self.options().no_copy_types,
+        )
+    }
+
+    /// Check if `--opaque-no-construct` flag is enabled for this item.
+    pub(crate) fn opaque_no_construct_by_name(&self, item: &Item) -> bool {
         let name = item.path_for_allowlisting(self)[1..].join("::");
-        self.options().no_copy_types.matches(name)
+        self.options().opaque_no_construct_types.matches(name)
+    }
+
+    /// Check if `--derive-from-bytes` flag is enabled for this item.
+    pub(crate) fn derive_from_bytes_by_name(&self, item: &Item) -> bool {
+        let name = item.path_for_allowlisting(self)[1..].join("::");
+        self.options().from_bytes_types.matches(name)
+    }
+
+    /// Check if `--derive-arbitrary` flag is enabled for this item.
+    pub(crate) fn derive_arbitrary_by_name(&self, item: &Item) -> bool {
+        let name = item.path_for_allowlisting(self)[1..].join("::");
+        self.options().arbitrary_types.matches(name)
+    }
+
+    /// Check if `--first-field-as-ref` flag is enabled for this item.
+    pub(crate) fn first_field_as_ref_by_name(&self, item: &Item) -> bool {
+        let name = item.path_for_allowlisting(self)[1..].join("::");
+        self.options().first_field_as_ref_types.matches(name)
+    }
+
+    /// Check if `--mark-pinned` flag is enabled for this item.
+    pub(crate) fn pinned_by_name(&self, item: &Item) -> bool {
+        let name = item.path_for_allowlisting(self)[1..].join("::");
+        self.options().pinned_types.matches(name)
     }
 
     /// Check if `--no-debug` flag is enabled for this item.
     pub(crate) fn no_debug_by_name(&self, item: &Item) -> bool {
-        let name = item.path_for_allowlisting(self)[1..].join("::");
-        self.options().no_debug_types.matches(name)
+        self.not_implemented_by_name(
+            item,
+            DeriveTrait::Debug,
+            &self.options().no_debug_types,
+        )
     }
 
     /// Check if `--no-default` flag is enabled for this item.
     pub(crate) fn no_default_by_name(&self, item: &Item) -> bool {
-        let name = item.path_for_allowlisting(self)[1..].join("::");
-        self.options().no_default_types.matches(name)
+        self.not_implemented_by_name(
+            item,
+            DeriveTrait::Default,
+            &self.options().no_default_types,
+        )
     }
 
     /// Check if `--no-hash` flag is enabled for this item.
     pub(crate) fn no_hash_by_name(&self, item: &Item) -> bool {
-        let name = item.path_for_allowlisting(self)[1..].join("::");
-        self.options().no_hash_types.matches(name)
+        self.not_implemented_by_name(
+            item,
+            DeriveTrait::Hash,
+            &self.options().no_hash_types,
+        )
     }
 
     /// Check if `--must-use-type` flag is enabled for this item.
@@ -2974,7 +3521,11 @@ If you encounter an error missing from this list, please file an issue or a PR!"
     /// Wrap some tokens in an `unsafe` block if the `--wrap-unsafe-ops` option is enabled.
     pub(crate) fn wrap_unsafe_ops(&self, tokens: impl ToTokens) -> TokenStream {
         if self.options.wrap_unsafe_ops {
-            quote!(unsafe { #tokens })
+            // The marker is removed once the bindings are complete, counting the
+            // blocks that were emitted.
+            let marker =
+                Ident::new(WRAPPED_UNSAFE_OPS_MARKER, Span::call_site());
+            quote!(#[#marker] unsafe { #tokens })
         } else {
             tokens.into_token_stream()
         }
@@ -3135,7 +3686,7 @@ impl TemplateParameters for PartialType {
 }
 
 fn unused_regex_diagnostic(item: &str, name: &str, _ctx: &BindgenContext) {
-    warn!("unused option: {} {}", name, item);
+    warning!("unused option: {} {}", name, item);
 
     #[cfg(feature = "experimental")]
     if _ctx.options().emit_diagnostics {
@@ -3153,3 +3704,41 @@ fn unused_regex_diagnostic(item: &str, name: &str, _ctx: &BindgenContext) {
             .display();
     }
 }
+
+fn int128_fallback_diagnostic(
+    spelling: &str,
+    _layout: Option<Layout>,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "The Rust target doesn't support 128-bit integers, `{}` will be represented as `[u64; 2]`.",
+        spelling
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        let mut diag = Diagnostic::default();
+        diag.with_title(
+            format!("Unsupported 128-bit integer type `{}`.", spelling),
+            Level::Warn,
+        )
+        .add_annotation(
+            "`i128` and `u128` require a Rust target of 1.26 or later, a `[u64; 2]` blob is used instead.",
+            Level::Note,
+        );
+
+        if let Some(layout) = _layout.filter(|layout| layout.align != 8) {
+            diag.add_annotation(
+                format!(
+                    "The blob is 8-byte aligned while this type is {}-byte aligned on the current target.",
+                    layout.align
+                ),
+                Level::Note,
+            );
+        }
+
+        diag.display();
+    }
+}
@@ -1,8 +1,10 @@
 //! Intermediate representation for C/C++ enumerations.
 
-use super::super::codegen::EnumVariation;
+use super::super::codegen::{EnumDefaultVariant, EnumVariation};
 use super::context::{BindgenContext, TypeId};
+use super::int::IntKind;
 use super::item::Item;
+use super::layout::Layout;
 use super::ty::{Type, TypeKind};
 use crate::clang;
 use crate::ir::annotations::Annotations;
@@ -20,6 +22,14 @@ pub enum EnumVariantCustomBehavior {
     Hide,
 }
 
+/// The name of the variant holding the values of an enum that don't match any
+/// other variant.
+pub(crate) const UNKNOWN_VARIANT_NAME: &str = "Unknown";
+
+/// An integer macro constant grouped into an enum: its definition, name and
+/// value.
+pub(crate) type GroupedConstant = (clang::Cursor, String, i64);
+
 /// A C/C++ enumeration.
 #[derive(Debug)]
 pub(crate) struct Enum {
@@ -32,6 +42,10 @@ pub(crate) struct Enum {
 
     /// The different variants, with explicit values.
     variants: Vec<EnumVariant>,
+
+    /// Whether this enum is marked as `warn_unused_result` or `[[nodiscard]]`,
+    /// if [`crate::Builder::respect_nodiscard`] is enabled.
+    must_use_attr: bool,
 }
 
 impl Enum {
@@ -40,7 +54,11 @@ impl Enum {
         repr: Option<TypeId>,
         variants: Vec<EnumVariant>,
     ) -> Self {
-        Enum { repr, variants }
+        Enum {
+            repr,
+            variants,
+            must_use_attr: false,
+        }
     }
 
     /// Get this enumeration's representation.
@@ -53,6 +71,11 @@ impl Enum {
         &self.variants
     }
 
+    /// Is this enumeration marked as `warn_unused_result` or `[[nodiscard]]`?
+    pub(crate) fn has_must_use_attr(&self) -> bool {
+        self.must_use_attr
+    }
+
     /// Construct an enumeration from the given Clang type.
     pub(crate) fn from_ty(
         ty: &clang::Type,
@@ -68,7 +91,14 @@ impl Enum {
         let declaration = ty.declaration().canonical();
         let repr = declaration
             .enum_type()
-            .and_then(|et| Item::from_ty(&et, declaration, None, ctx).ok());
+            .and_then(|et| Item::from_ty(&et, declaration, None, ctx).ok())
+            .or_else(|| {
+                // Scoped enums without an explicit underlying type always use
+                // `int`, so we don't need to guess their representation later.
+                declaration.is_scoped_enum().then(|| {
+                    Item::builtin_type(TypeKind::Int(IntKind::Int), false, ctx)
+                })
+            });
         let mut variants = vec![];
 
         let variant_ty =
@@ -147,7 +177,79 @@ impl Enum {
             }
             CXChildVisit_Continue
         });
-        Ok(Enum::new(repr, variants))
+
+        let mut enum_ = Enum::new(repr, variants);
+        enum_.must_use_attr =
+            ctx.options().respect_nodiscard && declaration.has_must_use_attr();
+        Ok(enum_)
+    }
+
+    /// Construct an enumeration from a group of integer macro constants, as
+    /// done by [`Builder::constant_group_as_enum`][crate::Builder::constant_group_as_enum].
+    ///
+    /// Returns the enumeration together with its layout, as there's no Clang
+    /// type to compute it from.
+    pub(crate) fn from_constant_group(
+        name: &str,
+        constants: &[GroupedConstant],
+        ctx: &mut BindgenContext,
+    ) -> (Self, Layout) {
+        // Use the same representation a C enum with these values would have.
+        let is_signed = constants.iter().any(|&(_, _, value)| value < 0);
+        let fits_in_int = constants.iter().all(|&(_, _, value)| {
+            if is_signed {
+                value >= i32::MIN as i64 && value <= i32::MAX as i64
+            } else {
+                value <= u32::MAX as i64
+            }
+        });
+        let kind = match (is_signed, fits_in_int) {
+            (true, true) => IntKind::Int,
+            (true, false) => IntKind::LongLong,
+            (false, true) => IntKind::UInt,
+            (false, false) => IntKind::ULongLong,
+        };
+        let size = if fits_in_int { 4 } else { 8 };
+        let repr = Item::builtin_type(TypeKind::Int(kind), false, ctx);
+
+        let variants = constants
+            .iter()
+            .map(|(cursor, constant_name, value)| {
+                let val = if is_signed {
+                    EnumVariantValue::Signed(*value)
+                } else {
+                    EnumVariantValue::Unsigned(*value as u64)
+                };
+                let custom_behavior =
+                    ctx.options().last_callback(|callbacks| {
+                        callbacks.enum_variant_behavior(
+                            Some(name),
+                            constant_name,
+                            val,
+                        )
+                    });
+                let new_name = ctx
+                    .options()
+                    .last_callback(|callbacks| {
+                        callbacks.enum_variant_name(
+                            Some(name),
+                            constant_name,
+                            val,
+                        )
+                    })
+                    .unwrap_or_else(|| constant_name.clone());
+
+                EnumVariant::new(
+                    new_name,
+                    constant_name.clone(),
+                    cursor.raw_comment(),
+                    val,
+                    custom_behavior,
+                )
+            })
+            .collect();
+
+        (Enum::new(Some(repr), variants), Layout::new(size, size))
     }
 
     fn is_matching_enum(
@@ -235,6 +337,67 @@ impl Enum {
             ctx.options().default_enum_style
         }
     }
+
+    /// Whether the enum is generated as a Rust `enum` with an `Unknown` variant
+    /// holding the values that don't match any other variant, see
+    /// [`crate::Builder::with_enum_unknown_variant`].
+    pub(crate) fn has_unknown_variant(
+        &self,
+        ctx: &BindgenContext,
+        item: &Item,
+    ) -> bool {
+        self.wants_unknown_variant(ctx, item) &&
+            !self.has_unknown_variant_name()
+    }
+
+    /// Whether the enum is a rustified enum that was asked for an `Unknown`
+    /// variant, regardless of whether it can have one.
+    pub(crate) fn wants_unknown_variant(
+        &self,
+        ctx: &BindgenContext,
+        item: &Item,
+    ) -> bool {
+        matches!(
+            self.computed_enum_variation(ctx, item),
+            EnumVariation::Rust { .. }
+        ) && self.is_matching_enum(
+            ctx,
+            &ctx.options().enum_unknown_variants,
+            item,
+        )
+    }
+
+    /// Whether one of the variants is already named like the `Unknown`
+    /// variant.
+    pub(crate) fn has_unknown_variant_name(&self) -> bool {
+        self.variants()
+            .iter()
+            .any(|v| !v.hidden() && v.name() == UNKNOWN_VARIANT_NAME)
+    }
+
+    /// Returns the variant to mark as `#[default]` when deriving `Default` for
+    /// the rustified enum, if there's a suitable one.
+    pub(crate) fn default_variant(
+        &self,
+        ctx: &BindgenContext,
+    ) -> Option<&EnumVariant> {
+        // Constified variants are emitted last, so order them accordingly.
+        let mut variants: Vec<_> =
+            self.variants().iter().filter(|v| !v.hidden()).collect();
+        variants.sort_by_key(|v| v.force_constification());
+
+        match ctx.options().enum_default_variant {
+            EnumDefaultVariant::Zero => variants.into_iter().find(|v| {
+                matches!(
+                    v.val(),
+                    EnumVariantValue::Boolean(false) |
+                        EnumVariantValue::Signed(0) |
+                        EnumVariantValue::Unsigned(0)
+                )
+            }),
+            EnumDefaultVariant::First => variants.into_iter().next(),
+        }
+    }
 }
 
 /// A single enum variant, to be contained only in an enum.
@@ -3,9 +3,10 @@
 use super::comp::MethodKind;
 use super::context::{BindgenContext, TypeId};
 use super::dot::DotAttributes;
+use super::int::IntKind;
 use super::item::Item;
 use super::traversal::{EdgeKind, Trace, Tracer};
-use super::ty::TypeKind;
+use super::ty::{Type, TypeKind};
 use crate::callbacks::{ItemInfo, ItemKind};
 use crate::clang::{self, ABIKind, Attribute};
 use crate::parse::{ClangSubItemParser, ParseError, ParseResult};
@@ -17,6 +18,45 @@ use std::str::FromStr;
 
 const RUST_DERIVE_FUNPTR_LIMIT: usize = 12;
 
+/// Whether the given token is the given punctuation.
+pub(crate) fn is_punct(token: &cexpr::token::Token, punct: &[u8]) -> bool {
+    token.kind == cexpr::token::Kind::Punctuation && &*token.raw == punct
+}
+
+/// Split the tokens of a function-like macro definition, following its name,
+/// into the names of its parameters and its expansion.
+pub(crate) fn split_macro_params(
+    tokens: &[cexpr::token::Token],
+) -> Option<(Vec<String>, &[cexpr::token::Token])> {
+    let (open, mut rest) = tokens.split_first()?;
+    if !is_punct(open, b"(") {
+        return None;
+    }
+
+    let mut params = vec![];
+    loop {
+        let (token, tail) = rest.split_first()?;
+        rest = tail;
+        if params.is_empty() && is_punct(token, b")") {
+            break;
+        }
+        if token.kind != cexpr::token::Kind::Identifier {
+            return None;
+        }
+        params.push(String::from_utf8(token.raw.to_vec()).ok()?);
+
+        let (token, tail) = rest.split_first()?;
+        rest = tail;
+        if is_punct(token, b")") {
+            break;
+        }
+        if !is_punct(token, b",") {
+            return None;
+        }
+    }
+    Some((params, rest))
+}
+
 /// What kind of a function are we looking at?
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) enum FunctionKind {
@@ -70,6 +110,255 @@ pub(crate) enum Linkage {
     Internal,
 }
 
+/// The body of a `static inline` function, as seen when translating trivial
+/// ones into Rust.
+#[derive(Debug)]
+pub(crate) enum FunctionBody {
+    /// The body is a single `return` of an arithmetic expression over the
+    /// parameters.
+    Trivial(InlineExpr),
+    /// Any other body, which cannot be translated.
+    Other,
+    /// The expansion of a function-like macro, if it is an arithmetic
+    /// expression over the parameters.
+    Macro(Option<InlineExpr>),
+}
+
+impl FunctionBody {
+    /// Parse the body of the given function definition.
+    fn parse(definition: &clang::Cursor) -> Self {
+        let body = definition
+            .collect_children()
+            .into_iter()
+            .find(|child| child.kind() == clang_sys::CXCursor_CompoundStmt);
+        let params: Vec<_> = definition
+            .args()
+            .unwrap_or_default()
+            .iter()
+            .map(|param| param.spelling())
+            .collect();
+
+        body.and_then(|body| {
+            let tokens: Vec<_> = body
+                .cexpr_tokens()
+                .into_iter()
+                .filter(|token| token.kind != cexpr::token::Kind::Comment)
+                .collect();
+            InlineExpr::parse_body(&tokens, &params)
+        })
+        .map_or(FunctionBody::Other, FunctionBody::Trivial)
+    }
+}
+
+/// An arithmetic expression over the parameters of a function.
+#[derive(Debug)]
+pub(crate) enum InlineExpr {
+    /// The parameter at the given index.
+    Param(usize),
+    /// An integer literal, which always fits in an `int`.
+    Int(u32),
+    /// A unary operation.
+    Unary(UnaryOp, Box<InlineExpr>),
+    /// A binary operation.
+    Binary(BinaryOp, Box<InlineExpr>, Box<InlineExpr>),
+}
+
+/// A unary arithmetic operator.
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum UnaryOp {
+    /// `-`
+    Neg,
+    /// `~`
+    Not,
+}
+
+/// A binary arithmetic operator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum BinaryOp {
+    /// `+`
+    Add,
+    /// `-`
+    Sub,
+    /// `*`
+    Mul,
+    /// `/`
+    Div,
+    /// `%`
+    Rem,
+    /// `<<`
+    Shl,
+    /// `>>`
+    Shr,
+    /// `&`
+    And,
+    /// `|`
+    Or,
+    /// `^`
+    Xor,
+}
+
+impl BinaryOp {
+    /// The operator spelled by the given token, along with its precedence.
+    fn from_token(token: &cexpr::token::Token) -> Option<(Self, u8)> {
+        if token.kind != cexpr::token::Kind::Punctuation {
+            return None;
+        }
+
+        Some(match &*token.raw {
+            b"*" => (BinaryOp::Mul, 5),
+            b"/" => (BinaryOp::Div, 5),
+            b"%" => (BinaryOp::Rem, 5),
+            b"+" => (BinaryOp::Add, 4),
+            b"-" => (BinaryOp::Sub, 4),
+            b"<<" => (BinaryOp::Shl, 3),
+            b">>" => (BinaryOp::Shr, 3),
+            b"&" => (BinaryOp::And, 2),
+            b"^" => (BinaryOp::Xor, 1),
+            b"|" => (BinaryOp::Or, 0),
+            _ => return None,
+        })
+    }
+
+    /// Whether this is a bitwise operator, which is written as an operator
+    /// rather than a method call in Rust.
+    pub(crate) fn is_bitwise(self) -> bool {
+        matches!(self, BinaryOp::And | BinaryOp::Or | BinaryOp::Xor)
+    }
+}
+
+impl InlineExpr {
+    /// Parse an arithmetic expression, where `params` are the names of the
+    /// parameters it can refer to.
+    fn parse_expr(
+        tokens: &[cexpr::token::Token],
+        params: &[String],
+    ) -> Option<Self> {
+        let mut parser = InlineExprParser { tokens, params };
+        let expr = parser.binary(0)?;
+        parser.tokens.is_empty().then_some(expr)
+    }
+
+    /// Parse a function body of the form `{ return <expr>; }`, where `params`
+    /// are the names of the parameters of the function.
+    fn parse_body(
+        tokens: &[cexpr::token::Token],
+        params: &[String],
+    ) -> Option<Self> {
+        let is_punct = |token: &cexpr::token::Token, punct: &[u8]| {
+            token.kind == cexpr::token::Kind::Punctuation &&
+                &*token.raw == punct
+        };
+
+        let tokens = match tokens {
+            [open, ret, tokens @ .., semi, close]
+                if is_punct(open, b"{") &&
+                    ret.kind == cexpr::token::Kind::Keyword &&
+                    &*ret.raw == b"return" &&
+                    is_punct(semi, b";") &&
+                    is_punct(close, b"}") =>
+            {
+                tokens
+            }
+            _ => return None,
+        };
+
+        Self::parse_expr(tokens, params)
+    }
+
+    /// Whether this expression refers to any of the parameters.
+    pub(crate) fn uses_params(&self) -> bool {
+        match *self {
+            InlineExpr::Param(..) => true,
+            InlineExpr::Int(..) => false,
+            InlineExpr::Unary(_, ref expr) => expr.uses_params(),
+            InlineExpr::Binary(_, ref lhs, ref rhs) => {
+                lhs.uses_params() || rhs.uses_params()
+            }
+        }
+    }
+}
+
+/// A precedence climbing parser for [`InlineExpr`]s.
+struct InlineExprParser<'a> {
+    tokens: &'a [cexpr::token::Token],
+    params: &'a [String],
+}
+
+impl<'a> InlineExprParser<'a> {
+    fn binary(&mut self, min_precedence: u8) -> Option<InlineExpr> {
+        let mut lhs = self.unary()?;
+        while let Some((op, precedence)) =
+            self.tokens.first().and_then(BinaryOp::from_token)
+        {
+            if precedence < min_precedence {
+                break;
+            }
+            self.tokens = &self.tokens[1..];
+            let rhs = self.binary(precedence + 1)?;
+            lhs = InlineExpr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Some(lhs)
+    }
+
+    fn unary(&mut self) -> Option<InlineExpr> {
+        use cexpr::token::Kind;
+
+        let (token, rest) = self.tokens.split_first()?;
+        self.tokens = rest;
+
+        match (token.kind, &*token.raw) {
+            (Kind::Punctuation, b"-") => {
+                Some(InlineExpr::Unary(UnaryOp::Neg, Box::new(self.unary()?)))
+            }
+            (Kind::Punctuation, b"~") => {
+                Some(InlineExpr::Unary(UnaryOp::Not, Box::new(self.unary()?)))
+            }
+            (Kind::Punctuation, b"+") => self.unary(),
+            (Kind::Punctuation, b"(") => {
+                let expr = self.binary(0)?;
+                let (close, rest) = self.tokens.split_first()?;
+                if close.kind != Kind::Punctuation || &*close.raw != b")" {
+                    return None;
+                }
+                self.tokens = rest;
+                Some(expr)
+            }
+            (Kind::Identifier, name) => self
+                .params
+                .iter()
+                .position(|param| param.as_bytes() == name)
+                .map(InlineExpr::Param),
+            (Kind::Literal, literal) => parse_int_literal(literal),
+            _ => None,
+        }
+    }
+}
+
+/// Parse an integer literal without suffix whose value fits in an `int`, so
+/// that it has the same value once converted to the type of the expression.
+fn parse_int_literal(literal: &[u8]) -> Option<InlineExpr> {
+    let literal = std::str::from_utf8(literal).ok()?;
+    let (digits, radix) = if let Some(hex) = literal
+        .strip_prefix("0x")
+        .or_else(|| literal.strip_prefix("0X"))
+    {
+        (hex, 16)
+    } else if literal.len() > 1 && literal.starts_with('0') {
+        (&literal[1..], 8)
+    } else {
+        (literal, 10)
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+
+    u32::from_str_radix(digits, radix)
+        .ok()
+        .filter(|value| *value <= i32::MAX as u32)
+        .map(InlineExpr::Int)
+}
+
 /// A function declaration, with a signature, arguments, and argument names.
 ///
 /// The argument names vector must be the same length as the ones in the
@@ -93,6 +382,10 @@ pub(crate) struct Function {
 
     /// The linkage of the function.
     linkage: Linkage,
+
+    /// The body of the function, if it is a `static inline` function or a
+    /// function-like macro that should be translated into Rust.
+    body: Option<FunctionBody>,
 }
 
 impl Function {
@@ -104,6 +397,7 @@ impl Function {
         signature: TypeId,
         kind: FunctionKind,
         linkage: Linkage,
+        body: Option<FunctionBody>,
     ) -> Self {
         Function {
             name,
@@ -112,6 +406,78 @@ impl Function {
             signature,
             kind,
             linkage,
+            body,
+        }
+    }
+
+    /// Construct a function equivalent to the given function-like macro
+    /// definition, whose parameters and return value are all `i64`s.
+    ///
+    /// The body of the function is only known if the parameters of the macro
+    /// are all named and it expands to an arithmetic expression over them.
+    pub(crate) fn from_macro(
+        cursor: &clang::Cursor,
+        ctx: &mut BindgenContext,
+    ) -> Self {
+        use cexpr::token::Kind;
+
+        let name = cursor.spelling();
+        let tokens: Vec<_> = cursor
+            .cexpr_tokens()
+            .into_iter()
+            .filter(|token| token.kind != Kind::Comment)
+            .collect();
+        let (params, expr) =
+            match split_macro_params(tokens.get(1..).unwrap_or(&[])) {
+                Some((params, expansion)) => {
+                    let expr = InlineExpr::parse_expr(expansion, &params);
+                    (params, expr)
+                }
+                None => (vec![], None),
+            };
+
+        let int = Item::builtin_type(TypeKind::Int(IntKind::I64), false, ctx);
+        let signature = FunctionSig {
+            name: name.clone(),
+            return_type: int,
+            argument_types: params
+                .into_iter()
+                .map(|param| (Some(param), int))
+                .collect(),
+            is_variadic: false,
+            is_divergent: false,
+            must_use: false,
+            is_pure: false,
+            is_const: false,
+            is_malloc: false,
+            returns_non_null: false,
+            abi: ClangAbi::Known(Abi::C),
+        };
+
+        let ty = Type::new(None, None, TypeKind::Function(signature), false);
+        let id = ctx.next_item_id();
+        let module = ctx.root_module().into();
+        ctx.add_item(
+            Item::new(
+                id,
+                None,
+                None,
+                module,
+                super::item_kind::ItemKind::Type(ty),
+                None,
+            ),
+            None,
+            None,
+        );
+
+        Function {
+            name,
+            mangled_name: None,
+            link_name: None,
+            signature: id.as_type_id_unchecked(),
+            kind: FunctionKind::Function,
+            linkage: Linkage::Internal,
+            body: Some(FunctionBody::Macro(expr)),
         }
     }
 
@@ -144,6 +510,11 @@ impl Function {
     pub(crate) fn linkage(&self) -> Linkage {
         self.linkage
     }
+
+    /// Get this function's body, if it should be translated into Rust.
+    pub(crate) fn body(&self) -> Option<&FunctionBody> {
+        self.body.as_ref()
+    }
 }
 
 impl DotAttributes for Function {
@@ -288,6 +659,18 @@ pub(crate) struct FunctionSig {
     /// Whether this function's return value must be used.
     must_use: bool,
 
+    /// Whether this function is marked as `pure`.
+    is_pure: bool,
+
+    /// Whether this function is marked as `const`.
+    is_const: bool,
+
+    /// Whether this function is marked as `malloc`.
+    is_malloc: bool,
+
+    /// Whether this function is marked as `returns_nonnull`.
+    returns_non_null: bool,
+
     /// The ABI of this function.
     abi: ClangAbi,
 }
@@ -313,6 +696,14 @@ pub(crate) fn cursor_mangling(
     ctx: &BindgenContext,
     cursor: &clang::Cursor,
 ) -> Option<String> {
+    // An asm label names the symbol exactly, so it is used even when we don't
+    // trust clang's manglings. Clang's mangling of such a declaration may also
+    // carry a leading `\x01`, which we'd otherwise prefix a second time when
+    // emitting `#[link_name]`.
+    if let Some(label) = cursor.asm_label() {
+        return Some(label);
+    }
+
     if !ctx.options().enable_mangling {
         return None;
     }
@@ -501,6 +892,20 @@ impl FunctionSig {
                     Attribute::NO_RETURN_CPP,
                 ]);
                 (must_use, no_return || no_return_cpp)
+            } else if ctx.options().respect_nodiscard {
+                (cursor.has_must_use_attr(), false)
+            } else {
+                Default::default()
+            };
+
+        let [is_pure, is_const, is_malloc, returns_non_null] =
+            if ctx.options().detect_function_attributes {
+                cursor.has_attrs(&[
+                    Attribute::PURE,
+                    Attribute::CONST,
+                    Attribute::MALLOC,
+                    Attribute::RETURNS_NON_NULL,
+                ])
             } else {
                 Default::default()
             };
@@ -601,7 +1006,7 @@ impl FunctionSig {
         let abi = get_abi(call_conv);
 
         if abi.is_unknown() {
-            warn!("Unknown calling convention: {:?}", call_conv);
+            warning!("Unknown calling convention: {:?}", call_conv);
         }
 
         Ok(Self {
@@ -611,6 +1016,10 @@ impl FunctionSig {
             is_variadic: ty.is_variadic(),
             is_divergent,
             must_use,
+            is_pure,
+            is_const,
+            is_malloc,
+            returns_non_null,
             abi,
         })
     }
@@ -696,6 +1105,26 @@ impl FunctionSig {
         self.must_use
     }
 
+    /// Is this function marked as `pure`?
+    pub(crate) fn is_pure(&self) -> bool {
+        self.is_pure
+    }
+
+    /// Is this function marked as `const`?
+    pub(crate) fn is_const(&self) -> bool {
+        self.is_const
+    }
+
+    /// Is this function marked as `malloc`?
+    pub(crate) fn is_malloc(&self) -> bool {
+        self.is_malloc
+    }
+
+    /// Is this function marked as never returning a null pointer?
+    pub(crate) fn returns_non_null(&self) -> bool {
+        self.returns_non_null
+    }
+
     /// Are function pointers with this signature able to derive Rust traits?
     /// Rust only supports deriving traits for function pointers with a limited
     /// number of parameters and a couple ABIs.
@@ -753,8 +1182,11 @@ impl ClangSubItemParser for Function {
                 .definition()
                 .map_or(false, |x| x.is_inlined_function())
         {
+            let translate = context.options().translate_inline_functions &&
+                matches!(linkage, Linkage::Internal);
             if !context.options().generate_inline_functions &&
-                !context.options().wrap_static_fns
+                !context.options().wrap_static_fns &&
+                !translate
             {
                 return Err(ParseError::Continue);
             }
@@ -810,6 +1242,18 @@ impl ClangSubItemParser for Function {
             })
         });
 
+        let body = if context.options().translate_inline_functions &&
+            kind == FunctionKind::Function &&
+            matches!(linkage, Linkage::Internal)
+        {
+            cursor
+                .definition()
+                .filter(|definition| definition.is_inlined_function())
+                .map(|definition| FunctionBody::parse(&definition))
+        } else {
+            None
+        };
+
         let function = Self::new(
             name.clone(),
             mangled_name,
@@ -817,6 +1261,7 @@ impl ClangSubItemParser for Function {
             sig,
             kind,
             linkage,
+            body,
         );
 
         Ok(ParseResult::New(function, Some(cursor)))
@@ -0,0 +1,212 @@
+//! Intermediate representation for the function-like macros dispatching on
+//! the type of one of their parameters with a C11 `_Generic` selection.
+
+use super::function::{is_punct, split_macro_params};
+use crate::clang;
+
+use cexpr::token::{Kind, Token};
+
+/// A function-like macro calling the function selected by a `_Generic`
+/// selection on the type of one of its parameters, e.g.
+/// `#define cbrt(x) _Generic((x), float: cbrtf, default: cbrt)(x)`.
+#[derive(Debug)]
+pub(crate) struct GenericSelection {
+    /// The name of the macro.
+    name: String,
+    /// The names of the parameters of the macro, which are passed in the
+    /// same order to the selected function.
+    params: Vec<String>,
+    /// The index of the parameter whose type selects the function.
+    controlling_param: usize,
+    /// The names of the types of the associations and the names of the
+    /// functions they select, without the `default` association.
+    associations: Vec<(String, String)>,
+    /// Whether the selection has a `default` association.
+    has_default: bool,
+}
+
+impl GenericSelection {
+    /// Parse the given function-like macro definition.
+    ///
+    /// Returns `None` if the macro doesn't use a `_Generic` selection, and
+    /// the reason why it can't be translated if it's not of the supported
+    /// form.
+    pub(crate) fn parse(
+        cursor: &clang::Cursor,
+    ) -> Option<Result<Self, &'static str>> {
+        let tokens: Vec<_> = cursor
+            .cexpr_tokens()
+            .into_iter()
+            .filter(|token| token.kind != Kind::Comment)
+            .collect();
+        if !tokens.iter().any(|token| &*token.raw == b"_Generic") {
+            return None;
+        }
+
+        Some(Self::parse_tokens(cursor, &tokens))
+    }
+
+    /// Parse the tokens of the given macro definition, which uses a
+    /// `_Generic` selection.
+    fn parse_tokens(
+        cursor: &clang::Cursor,
+        tokens: &[Token],
+    ) -> Result<Self, &'static str> {
+        let (params, expansion) =
+            split_macro_params(tokens.get(1..).unwrap_or(&[]))
+                .ok_or("its parameters can't be parsed")?;
+        // Allow for the whole expansion to be parenthesized.
+        let expansion = strip_parens(expansion);
+
+        let not_a_call = "it doesn't call the function selected by a `_Generic` selection with its parameters";
+        let (keyword, rest) = expansion.split_first().ok_or(not_a_call)?;
+        if &*keyword.raw != b"_Generic" {
+            return Err(not_a_call);
+        }
+        let end = closing_paren(rest).ok_or(not_a_call)?;
+        let (selection, call) = (&rest[1..end], &rest[end + 1..]);
+
+        // The call must pass the parameters in order.
+        let args = match call {
+            [open, args @ .., close]
+                if is_punct(open, b"(") && is_punct(close, b")") =>
+            {
+                args
+            }
+            _ => return Err(not_a_call),
+        };
+        let args: Vec<_> = split_top_level(args, b",")
+            .into_iter()
+            .filter(|arg| !arg.is_empty())
+            .collect();
+        let passes_params = args.len() == params.len() &&
+            args.iter().zip(&params).all(|(arg, param)| {
+                matches!(arg, [token] if token.kind == Kind::Identifier && &*token.raw == param.as_bytes())
+            });
+        if !passes_params {
+            return Err(not_a_call);
+        }
+
+        let mut parts = split_top_level(selection, b",").into_iter();
+        let controlling_param = match strip_parens(parts.next().unwrap_or(&[]))
+        {
+            [token] if token.kind == Kind::Identifier => params
+                .iter()
+                .position(|param| param.as_bytes() == &*token.raw),
+            _ => None,
+        }
+        .ok_or("its controlling expression isn't one of its parameters")?;
+
+        let mut associations = vec![];
+        let mut has_default = false;
+        for association in parts {
+            let colon = association
+                .iter()
+                .position(|token| is_punct(token, b":"))
+                .ok_or("its associations can't be parsed")?;
+            let function = match &association[colon + 1..] {
+                [token] if token.kind == Kind::Identifier => {
+                    String::from_utf8_lossy(&token.raw).into_owned()
+                }
+                _ => {
+                    return Err("its associations don't all select a function")
+                }
+            };
+
+            let type_name = association[..colon]
+                .iter()
+                .map(|token| String::from_utf8_lossy(&token.raw))
+                .collect::<Vec<_>>()
+                .join(" ");
+            if type_name == "default" {
+                has_default = true;
+            } else {
+                associations.push((type_name, function));
+            }
+        }
+
+        Ok(GenericSelection {
+            name: cursor.spelling(),
+            params,
+            controlling_param,
+            associations,
+            has_default,
+        })
+    }
+
+    /// The name of the macro.
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The names of the parameters of the macro.
+    pub(crate) fn params(&self) -> &[String] {
+        &self.params
+    }
+
+    /// The index of the parameter whose type selects the function.
+    pub(crate) fn controlling_param(&self) -> usize {
+        self.controlling_param
+    }
+
+    /// The names of the types of the associations and the names of the
+    /// functions they select, without the `default` association.
+    pub(crate) fn associations(&self) -> &[(String, String)] {
+        &self.associations
+    }
+
+    /// Whether the selection has a `default` association.
+    pub(crate) fn has_default(&self) -> bool {
+        self.has_default
+    }
+}
+
+/// The index of the parenthesis closing the one the given tokens start with,
+/// if they start with one.
+fn closing_paren(tokens: &[Token]) -> Option<usize> {
+    if !is_punct(tokens.first()?, b"(") {
+        return None;
+    }
+
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if is_punct(token, b"(") {
+            depth += 1;
+        } else if is_punct(token, b")") {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// The given tokens without the parentheses they are enclosed in, if any.
+fn strip_parens(mut tokens: &[Token]) -> &[Token] {
+    while matches!(closing_paren(tokens), Some(end) if end + 1 == tokens.len())
+    {
+        tokens = &tokens[1..tokens.len() - 1];
+    }
+    tokens
+}
+
+/// Split the given tokens at the given punctuation, outside of any
+/// parentheses.
+fn split_top_level<'a>(tokens: &'a [Token], punct: &[u8]) -> Vec<&'a [Token]> {
+    let mut parts = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if is_punct(token, b"(") || is_punct(token, b"[") {
+            depth += 1;
+        } else if is_punct(token, b")") || is_punct(token, b"]") {
+            depth = depth.saturating_sub(1);
+        } else if depth == 0 && is_punct(token, punct) {
+            parts.push(&tokens[start..i]);
+            start = i + 1;
+        }
+    }
+    parts.push(&tokens[start..]);
+    parts
+}
@@ -3,7 +3,7 @@
 use super::super::codegen::{EnumVariation, CONSTIFIED_ENUM_MODULE_REPR_NAME};
 use super::analysis::{HasVtable, HasVtableResult, Sizedness, SizednessResult};
 use super::annotations::Annotations;
-use super::comp::{CompKind, MethodKind};
+use super::comp::{CompKind, Field, FieldMethods, MethodKind};
 use super::context::{BindgenContext, ItemId, PartialType, TypeId};
 use super::derive::{
     CanDeriveCopy, CanDeriveDebug, CanDeriveDefault, CanDeriveEq,
@@ -384,6 +384,16 @@ pub(crate) struct Item {
     /// The next local ID to use for a child or template instantiation.
     next_child_local_id: Cell<usize>,
 
+    /// The item's structural ID, derived from the structure of an anonymous
+    /// type. Only used for anonymous items when the
+    /// `structural_anon_type_names` option is enabled.
+    ///
+    /// Lazily initialized in structural_id().
+    structural_id: OnceCell<String>,
+
+    /// A hash of the structure of this type, used to compute `structural_id`.
+    structural_hash: OnceCell<u64>,
+
     /// A cached copy of the canonical name, as returned by `canonical_name`.
     ///
     /// This is a fairly used operation during codegen so this makes bindgen
@@ -432,6 +442,8 @@ impl Item {
             id,
             local_id: OnceCell::new(),
             next_child_local_id: Cell::new(1),
+            structural_id: OnceCell::new(),
+            structural_hash: OnceCell::new(),
             canonical_name: OnceCell::new(),
             path_for_allowlisting: OnceCell::new(),
             parent_id,
@@ -540,6 +552,66 @@ impl Item {
         })
     }
 
+    /// Get an identifier for this anonymous type that only depends on its
+    /// structure, and not on the order in which clang traverses declarations.
+    ///
+    /// Structurally identical siblings are told apart by their position in
+    /// the source.
+    fn structural_id(&self, ctx: &BindgenContext) -> String {
+        self.structural_id
+            .get_or_init(|| {
+                let hash = self.structural_hash(ctx);
+                let mut twins: Vec<_> = ctx
+                    .items()
+                    .map(|(_, item)| item)
+                    .filter(|item| {
+                        item.parent_id == self.parent_id &&
+                            item.is_anon() &&
+                            item.kind().as_type().is_some_and(|ty| {
+                                matches!(
+                                    *ty.kind(),
+                                    TypeKind::Comp(..) | TypeKind::Enum(..)
+                                )
+                            }) &&
+                            item.structural_hash(ctx) == hash
+                    })
+                    .collect();
+
+                twins.sort_by_key(|item| {
+                    item.location().map(|location| {
+                        let (file, line, col, _) = location.location();
+                        (file.name(), line, col)
+                    })
+                });
+
+                // Fold the hash so the names stay reasonably short.
+                let id = format!("{:08x}", (hash ^ (hash >> 32)) as u32);
+                match twins.iter().position(|item| item.id == self.id) {
+                    Some(index) if index > 0 => format!("{}_{}", id, index),
+                    _ => id,
+                }
+            })
+            .clone()
+    }
+
+    /// Get a hash of the structure of this type, see `structural_id`.
+    fn structural_hash(&self, ctx: &BindgenContext) -> u64 {
+        *self.structural_hash.get_or_init(|| {
+            let mut signature = String::new();
+            write_structural_signature(
+                ctx,
+                self.id.expect_type_id(ctx),
+                &mut signature,
+            );
+
+            // FNV-1a, which unlike the standard library hashers is stable
+            // across Rust versions and platforms.
+            signature.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+            })
+        })
+    }
+
     /// Get an identifier that differentiates a child of this item of other
     /// related items.
     ///
@@ -924,9 +996,15 @@ impl Item {
         let name = names.join("_");
 
         let name = if opt.user_mangled == UserMangled::Yes {
-            ctx.options()
+            let name = ctx
+                .options()
                 .last_callback(|callbacks| callbacks.item_name(&name))
-                .unwrap_or(name)
+                .unwrap_or(name);
+            if opt.renamed {
+                ctx.rename_item(&name).into_owned()
+            } else {
+                name
+            }
         } else {
             name
         };
@@ -942,6 +1020,11 @@ impl Item {
         let ty_kind = self.kind().as_type().map(|t| t.kind());
         if let Some(ty_kind) = ty_kind {
             match *ty_kind {
+                TypeKind::Comp(..) | TypeKind::Enum(..)
+                    if ctx.options().structural_anon_type_names =>
+                {
+                    return self.structural_id(ctx)
+                }
                 TypeKind::Comp(..) |
                 TypeKind::TemplateInstantiation(..) |
                 TypeKind::Enum(..) => return self.local_id(ctx).to_string(),
@@ -1094,7 +1177,15 @@ impl Item {
 
     /// Whether this is a `#[must_use]` type.
     pub(crate) fn must_use(&self, ctx: &BindgenContext) -> bool {
-        self.annotations().must_use_type() || ctx.must_use_type_by_name(self)
+        let has_must_use_attr =
+            self.as_type().is_some_and(|ty| match *ty.kind() {
+                TypeKind::Comp(ref ci) => ci.has_must_use_attr(),
+                TypeKind::Enum(ref enum_ty) => enum_ty.has_must_use_attr(),
+                _ => false,
+            });
+        self.annotations().must_use_type() ||
+            ctx.must_use_type_by_name(self) ||
+            has_must_use_attr
     }
 }
 
@@ -1123,7 +1214,8 @@ impl IsOpaque for Item {
         );
         self.annotations.opaque() ||
             self.as_type().map_or(false, |ty| ty.is_opaque(ctx, self)) ||
-            ctx.opaque_by_name(self.path_for_allowlisting(ctx))
+            ctx.opaque_by_name(self.path_for_allowlisting(ctx)) ||
+            ctx.opaque_typedef_by_name(self)
     }
 }
 
@@ -1254,6 +1346,95 @@ impl TemplateParameters for ItemKind {
     }
 }
 
+/// Write a description of the structure of the given type, which doesn't
+/// depend on any traversal order, to `out`.
+fn write_structural_signature(
+    ctx: &BindgenContext,
+    id: TypeId,
+    out: &mut String,
+) {
+    let item = ctx.resolve_item(id);
+    let ty = item.expect_type();
+
+    if ty.is_const() {
+        out.push_str("const ");
+    }
+
+    if let Some(name) = ty.name() {
+        out.push_str(name);
+        return;
+    }
+
+    match *ty.kind() {
+        TypeKind::Comp(ref info) => {
+            out.push_str(match info.kind() {
+                CompKind::Struct => "struct{",
+                CompKind::Union => "union{",
+            });
+            for field in info.fields() {
+                let mut write_field = |field: &dyn FieldMethods| {
+                    out.push_str(field.name().unwrap_or("_"));
+                    out.push(':');
+                    write_structural_signature(ctx, field.ty(), out);
+                    if let Some(width) = field.bitfield_width() {
+                        let _ = write!(out, ":{}", width);
+                    }
+                    out.push(';');
+                };
+                match *field {
+                    Field::DataMember(ref data) => write_field(data),
+                    Field::Bitfields(ref unit) => {
+                        for bitfield in unit.bitfields() {
+                            write_field(bitfield);
+                        }
+                    }
+                }
+            }
+            out.push('}');
+        }
+        TypeKind::Enum(ref enum_ty) => {
+            out.push_str("enum{");
+            for variant in enum_ty.variants() {
+                let _ = write!(out, "{}={:?};", variant.name(), variant.val());
+            }
+            out.push('}');
+        }
+        TypeKind::Pointer(inner) => {
+            out.push('*');
+            write_structural_signature(ctx, inner, out);
+        }
+        TypeKind::Reference(inner) => {
+            out.push('&');
+            write_structural_signature(ctx, inner, out);
+        }
+        TypeKind::Array(inner, len) | TypeKind::Vector(inner, len) => {
+            out.push('[');
+            write_structural_signature(ctx, inner, out);
+            let _ = write!(out, ";{}]", len);
+        }
+        TypeKind::Alias(inner) | TypeKind::ResolvedTypeRef(inner) => {
+            write_structural_signature(ctx, inner, out);
+        }
+        TypeKind::Function(ref sig) => {
+            out.push_str("fn(");
+            for &(_, arg) in sig.argument_types() {
+                write_structural_signature(ctx, arg, out);
+                out.push(',');
+            }
+            out.push_str(")->");
+            write_structural_signature(ctx, sig.return_type(), out);
+        }
+        TypeKind::Void |
+        TypeKind::NullPtr |
+        TypeKind::Int(..) |
+        TypeKind::Float(..) |
+        TypeKind::Complex(..) => {
+            let _ = write!(out, "{:?}", ty.kind());
+        }
+        _ => out.push_str(&item.canonical_name(ctx)),
+    }
+}
+
 // An utility function to handle recursing inside nested types.
 fn visit_child(
     cur: clang::Cursor,
@@ -1280,6 +1461,58 @@ fn visit_child(
     }
 }
 
+fn max_recursion_depth_diagnostic(
+    ty: &clang::Type,
+    _location: &clang::Cursor,
+    depth: usize,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Maximum recursion depth of {} exceeded while parsing type: {}",
+        depth,
+        ty.spelling()
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+        use std::borrow::Cow;
+
+        let mut slice = Slice::default();
+        let mut source = Cow::from(ty.spelling());
+
+        let (file, line, col, _) = _location.location().location();
+        if let Some(filename) = file.name() {
+            if let Ok(Some(code)) = get_line(&filename, line) {
+                source = code.into();
+            }
+            slice.with_location(filename, line, col);
+        }
+
+        slice.with_source(source);
+
+        Diagnostic::default()
+            .with_title(
+                format!(
+                    "Maximum recursion depth of {} exceeded while parsing `{}`.",
+                    depth,
+                    ty.spelling()
+                ),
+                Level::Error,
+            )
+            .add_slice(slice)
+            .add_annotation(
+                "This type is generated as an opaque blob.",
+                Level::Note,
+            )
+            .add_annotation(
+                "Use `--max-recursion-depth` to raise the limit.",
+                Level::Help,
+            )
+            .display();
+    }
+}
+
 impl Item {
     /// Create a builtin type.
     pub(crate) fn builtin_type(
@@ -1432,7 +1665,10 @@ impl Item {
                 let file = cursor.get_included_file_name();
                 match file {
                     None => {
-                        warn!("Inclusion of a nameless file in {:?}", cursor);
+                        warning!(
+                            "Inclusion of a nameless file in {:?}",
+                            cursor
+                        );
                     }
                     Some(included_file) => {
                         for cb in &ctx.options().parse_callbacks {
@@ -1449,7 +1685,7 @@ impl Item {
                 // ignore toplevel operator overloads
                 let spelling = cursor.spelling();
                 if !spelling.starts_with("operator") {
-                    warn!(
+                    warning!(
                         "Unhandled cursor kind {:?}: {:?}",
                         cursor.kind(),
                         cursor
@@ -1646,6 +1882,30 @@ impl Item {
             }
         }
 
+        let depth = ctx.currently_parsed_types().len();
+        if valid_decl && depth >= ctx.options().max_recursion_depth {
+            max_recursion_depth_diagnostic(ty, &location, depth, ctx);
+            // Register the opaque type for the declaration, so that the
+            // references to it don't parse it in full once the enclosing
+            // types are done.
+            let opaque = Opaque::from_clang_ty(ty, ctx);
+            let parent_id =
+                parent_id.unwrap_or_else(|| ctx.current_module().into());
+            ctx.add_item(
+                Item::new(
+                    id,
+                    comment,
+                    annotations,
+                    parent_id,
+                    ItemKind::Type(opaque),
+                    Some(location.location()),
+                ),
+                Some(declaration_to_look_for),
+                Some(location),
+            );
+            return Ok(id.as_type_id_unchecked());
+        }
+
         let current_module = ctx.current_module().into();
         let partial_ty = PartialType::new(declaration_to_look_for, id);
         if valid_decl {
@@ -1704,7 +1964,7 @@ impl Item {
                 //
                 // This is what happens with some template members, for example.
                 if let Err(ParseError::Recurse) = result {
-                    warn!(
+                    warning!(
                         "Unknown type, assuming named template type: \
                          id = {:?}; spelling = {}",
                         id,
@@ -1972,6 +2232,7 @@ pub(crate) struct NameOptions<'a> {
     ctx: &'a BindgenContext,
     within_namespaces: bool,
     user_mangled: UserMangled,
+    renamed: bool,
 }
 
 impl<'a> NameOptions<'a> {
@@ -1982,9 +2243,17 @@ impl<'a> NameOptions<'a> {
             ctx,
             within_namespaces: false,
             user_mangled: UserMangled::Yes,
+            renamed: true,
         }
     }
 
+    /// Construct the name without applying the rename patterns of
+    /// [`crate::Builder::rename_pattern`] to it.
+    pub(crate) fn without_renaming(&mut self) -> &mut Self {
+        self.renamed = false;
+        self
+    }
+
     /// Construct the name without the item's containing C++ namespaces mangled
     /// into it. In other words, the item's name within the item's namespace.
     pub(crate) fn within_namespaces(&mut self) -> &mut Self {
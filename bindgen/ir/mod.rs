@@ -13,6 +13,7 @@ pub(crate) mod derive;
 pub(crate) mod dot;
 pub(crate) mod enum_ty;
 pub(crate) mod function;
+pub(crate) mod generic_selection;
 pub(crate) mod int;
 pub(crate) mod item;
 pub(crate) mod item_kind;
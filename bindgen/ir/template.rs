@@ -30,6 +30,7 @@
 use super::context::{BindgenContext, ItemId, TypeId};
 use super::item::{IsOpaque, Item, ItemAncestors};
 use super::traversal::{EdgeKind, Trace, Tracer};
+use super::ty::TypeKind;
 use crate::clang;
 
 /// Template declaration (and such declaration's template parameters) related
@@ -216,6 +217,28 @@ impl TemplateInstantiation {
         &self.args[..]
     }
 
+    /// Whether the template definition or any of the template arguments of
+    /// this instantiation is blocklisted, looking through aliases and nested
+    /// instantiations.
+    pub(crate) fn references_blocklisted(&self, ctx: &BindgenContext) -> bool {
+        std::iter::once(self.template_definition())
+            .chain(self.template_arguments().iter().copied())
+            .any(|id| {
+                let item = id
+                    .into_resolver()
+                    .through_type_refs()
+                    .through_type_aliases()
+                    .resolve(ctx);
+                item.is_blocklisted(ctx) ||
+                    match *item.expect_type().kind() {
+                        TypeKind::TemplateInstantiation(ref inst) => {
+                            inst.references_blocklisted(ctx)
+                        }
+                        _ => false,
+                    }
+            })
+    }
+
     /// Parse a `TemplateInstantiation` from a clang `Type`.
     pub(crate) fn from_ty(
         ty: &clang::Type,
@@ -270,7 +293,7 @@ impl TemplateInstantiation {
             Some(def) => def,
             None => {
                 if !ty.declaration().is_builtin() {
-                    warn!(
+                    warning!(
                         "Could not find template definition for template \
                          instantiation"
                     );
@@ -306,6 +329,10 @@ impl IsOpaque for TemplateInstantiation {
         // arguments properly.
 
         let mut path = item.path_for_allowlisting(ctx).clone();
+        if ctx.opaque_template_instantiations_by_name(&path) {
+            return true;
+        }
+
         let args: Vec<_> = self
             .template_arguments()
             .iter()
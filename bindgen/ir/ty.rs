@@ -737,7 +737,7 @@ impl Type {
 
         if location.kind() == CXCursor_ClassTemplatePartialSpecialization {
             // Sorry! (Not sorry)
-            warn!(
+            warning!(
                 "Found a partial template specialization; bindgen does not \
                  support partial template specialization! Constructing \
                  opaque type instead."
@@ -872,7 +872,7 @@ impl Type {
                                 match complex {
                                     Ok(complex) => TypeKind::Comp(complex),
                                     Err(_) => {
-                                        warn!(
+                                        warning!(
                                             "Could not create complex type \
                                              from class template or base \
                                              specifier, using opaque blob"
@@ -933,7 +933,7 @@ impl Type {
                                 let inner_type = match inner {
                                     Ok(inner) => inner,
                                     Err(..) => {
-                                        warn!(
+                                        warning!(
                                             "Failed to parse template alias \
                                              {:?}",
                                             location
@@ -991,7 +991,7 @@ impl Type {
                             }
                             _ => {
                                 if ty.kind() == CXType_Unexposed {
-                                    warn!(
+                                    warning!(
                                         "Unexposed type {:?}, recursing inside, \
                                           loc: {:?}",
                                         ty,
@@ -1000,7 +1000,7 @@ impl Type {
                                     return Err(ParseError::Recurse);
                                 }
 
-                                warn!("invalid type {:?}", ty);
+                                warning!("invalid type {:?}", ty);
                                 return Err(ParseError::Continue);
                             }
                         }
@@ -1091,7 +1091,7 @@ impl Type {
                     let inner_id =
                         Item::from_ty_or_ref(inner, location, None, ctx);
                     if inner_id == potential_id {
-                        warn!(
+                        warning!(
                             "Generating opaque type instead of self-referential \
                             typedef");
                         // This can happen if we bail out of recursive situations
@@ -1190,7 +1190,7 @@ impl Type {
                     return Err(ParseError::Continue);
                 }
                 _ => {
-                    warn!(
+                    warning!(
                         "unsupported type: kind = {:?}; ty = {:?}; at {:?}",
                         ty.kind(),
                         ty,
@@ -3,11 +3,14 @@
 use super::super::codegen::MacroTypeVariation;
 use super::context::{BindgenContext, TypeId};
 use super::dot::DotAttributes;
-use super::function::cursor_mangling;
+use super::function::{cursor_mangling, Linkage};
+use super::generic_selection::GenericSelection;
 use super::int::IntKind;
 use super::item::Item;
 use super::ty::{FloatKind, TypeKind};
-use crate::callbacks::{ItemInfo, ItemKind, MacroParsingBehavior};
+use crate::callbacks::{
+    BytesMacroKind, ItemInfo, ItemKind, MacroParsingBehavior,
+};
 use crate::clang;
 use crate::clang::ClangToken;
 use crate::parse::{ClangSubItemParser, ParseError, ParseResult};
@@ -26,8 +29,11 @@ pub(crate) enum VarType {
     Float(f64),
     /// A character.
     Char(u8),
-    /// A string, not necessarily well-formed utf-8.
-    String(Vec<u8>),
+    /// A string, not necessarily well-formed utf-8, and the type picked for
+    /// it by [`ParseCallbacks::bytes_macro`], if any.
+    ///
+    /// [`ParseCallbacks::bytes_macro`]: crate::callbacks::ParseCallbacks::bytes_macro
+    String(Vec<u8>, Option<BytesMacroKind>),
 }
 
 /// A `Var` is our intermediate representation of a variable.
@@ -45,6 +51,8 @@ pub(crate) struct Var {
     val: Option<VarType>,
     /// Whether this variable is const.
     is_const: bool,
+    /// The linkage of this variable.
+    linkage: Linkage,
 }
 
 impl Var {
@@ -56,6 +64,7 @@ impl Var {
         ty: TypeId,
         val: Option<VarType>,
         is_const: bool,
+        linkage: Linkage,
     ) -> Var {
         assert!(!name.is_empty());
         Var {
@@ -65,6 +74,7 @@ impl Var {
             ty,
             val,
             is_const,
+            linkage,
         }
     }
 
@@ -73,6 +83,11 @@ impl Var {
         self.is_const
     }
 
+    /// Get this variable's linkage.
+    pub(crate) fn linkage(&self) -> Linkage {
+        self.linkage
+    }
+
     /// The value of this constant variable, if any.
     pub(crate) fn val(&self) -> Option<&VarType> {
         self.val.as_ref()
@@ -185,6 +200,7 @@ impl ClangSubItemParser for Var {
         use clang_sys::*;
         match cursor.kind() {
             CXCursor_MacroDefinition => {
+                let is_function_like = cursor.is_macro_function_like();
                 for callbacks in &ctx.options().parse_callbacks {
                     match callbacks.will_parse_macro(&cursor.spelling()) {
                         MacroParsingBehavior::Ignore => {
@@ -193,13 +209,42 @@ impl ClangSubItemParser for Var {
                         MacroParsingBehavior::Default => {}
                     }
 
-                    if cursor.is_macro_function_like() {
+                    if is_function_like {
                         handle_function_macro(&cursor, callbacks.as_ref());
-                        // We handled the macro, skip macro processing below.
-                        return Err(ParseError::Continue);
+                        break;
                     }
                 }
 
+                if is_function_like && ctx.options().translate_generic_macros {
+                    match GenericSelection::parse(&cursor) {
+                        Some(Ok(selection)) => {
+                            ctx.add_generic_selection(selection);
+                            return Err(ParseError::Continue);
+                        }
+                        Some(Err(reason)) => {
+                            untranslatable_generic_macro_diagnostic(
+                                &cursor.spelling(),
+                                reason,
+                                cursor.location(),
+                                ctx,
+                            );
+                            return Err(ParseError::Continue);
+                        }
+                        None => {}
+                    }
+                }
+
+                if is_function_like &&
+                    (ctx.options().translate_function_macros ||
+                        !ctx.options().parse_callbacks.is_empty())
+                {
+                    if ctx.options().translate_function_macros {
+                        ctx.add_function_macro(cursor);
+                    }
+                    // We handled the macro, skip macro processing below.
+                    return Err(ParseError::Continue);
+                }
+
                 let value = parse_macro(ctx, &cursor);
 
                 let (id, value) = match value {
@@ -209,6 +254,18 @@ impl ClangSubItemParser for Var {
 
                 assert!(!id.is_empty(), "Empty macro name?");
 
+                // `cexpr` ignores width prefixes, so a wide string would be
+                // silently turned into a byte string of the wrong element
+                // type. Skip it instead, and don't note it either so that
+                // macros built on top of it are skipped as well.
+                if matches!(value, EvalResult::Str(_)) &&
+                    has_wide_string_literal(&cursor)
+                {
+                    let name = String::from_utf8(id).unwrap();
+                    wide_string_macro_diagnostic(&name, cursor.location(), ctx);
+                    return Err(ParseError::Continue);
+                }
+
                 let previously_defined = ctx.parsed_macro(&id);
 
                 // NB: It's important to "note" the macro even if the result is
@@ -244,7 +301,12 @@ impl ClangSubItemParser for Var {
                             }
                         };
 
-                        (TypeKind::Int(IntKind::U8), VarType::Char(c))
+                        let kind = ctx
+                            .options()
+                            .last_callback(|cb| cb.char_macro(&name, c.into()))
+                            .unwrap_or(IntKind::U8);
+
+                        (TypeKind::Int(kind), VarType::Char(c))
                     }
                     EvalResult::Str(val) => {
                         let char_ty = Item::builtin_type(
@@ -255,9 +317,21 @@ impl ClangSubItemParser for Var {
                         for callbacks in &ctx.options().parse_callbacks {
                             callbacks.str_macro(&name, &val);
                         }
-                        (TypeKind::Pointer(char_ty), VarType::String(val))
+                        let kind = if std::str::from_utf8(&val).is_ok() {
+                            None
+                        } else {
+                            ctx.options()
+                                .last_callback(|cb| cb.bytes_macro(&name, &val))
+                        };
+                        (TypeKind::Pointer(char_ty), VarType::String(val, kind))
                     }
                     EvalResult::Int(Wrapping(value)) => {
+                        // Grouped constants are generated as enum variants
+                        // instead.
+                        if ctx.group_macro_constant(cursor, &name, value) {
+                            return Err(ParseError::Continue);
+                        }
+
                         let kind = ctx
                             .options()
                             .last_callback(|c| c.int_macro(&name, value))
@@ -272,7 +346,15 @@ impl ClangSubItemParser for Var {
                 let ty = Item::builtin_type(type_kind, true, ctx);
 
                 Ok(ParseResult::New(
-                    Var::new(name, None, None, ty, Some(val), true),
+                    Var::new(
+                        name,
+                        None,
+                        None,
+                        ty,
+                        Some(val),
+                        true,
+                        Linkage::External,
+                    ),
                     Some(cursor),
                 ))
             }
@@ -292,7 +374,7 @@ impl ClangSubItemParser for Var {
                 let name = name;
 
                 if name.is_empty() {
-                    warn!("Empty constant name?");
+                    warning!("Empty constant name?");
                     return Err(ParseError::Continue);
                 }
 
@@ -368,12 +450,36 @@ impl ClangSubItemParser for Var {
                     cursor
                         .evaluate()
                         .and_then(|v| v.as_literal_string())
-                        .map(VarType::String)
+                        .map(|val| VarType::String(val, None))
+                };
+
+                // A `constexpr` variable may not have a symbol to link to, so
+                // only its value can be used.
+                if value.is_none() && cursor.is_constexpr() {
+                    let reason = if is_integer || is_float {
+                        "its value can't be evaluated"
+                    } else {
+                        "it isn't of an integer, floating point or string type"
+                    };
+                    unevaluable_constexpr_diagnostic(
+                        &name,
+                        reason,
+                        cursor.location(),
+                        ctx,
+                    );
+                    return Err(ParseError::Continue);
+                }
+
+                let linkage = if cursor.linkage() == CXLinkage_Internal {
+                    Linkage::Internal
+                } else {
+                    Linkage::External
                 };
 
                 let mangling = cursor_mangling(ctx, &cursor);
-                let var =
-                    Var::new(name, mangling, link_name, ty, value, is_const);
+                let var = Var::new(
+                    name, mangling, link_name, ty, value, is_const, linkage,
+                );
 
                 Ok(ParseResult::New(var, Some(cursor)))
             }
@@ -479,11 +585,159 @@ fn get_integer_literal_from_cursor(cursor: &clang::Cursor) -> Option<i64> {
     value
 }
 
+/// Whether the definition of a macro contains a wide, UTF-16 or UTF-32 string
+/// literal.
+fn has_wide_string_literal(cursor: &clang::Cursor) -> bool {
+    use cexpr::token::Kind;
+
+    cursor.cexpr_tokens().iter().any(|token| {
+        token.kind == Kind::Literal &&
+            [&b"L\""[..], b"u\"", b"U\""]
+                .iter()
+                .any(|prefix| token.raw.starts_with(prefix))
+    })
+}
+
+fn wide_string_macro_diagnostic(
+    macro_name: &str,
+    _location: crate::clang::SourceLocation,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Unsupported wide string in macro definition: {}",
+        macro_name
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+        use std::borrow::Cow;
+
+        let mut slice = Slice::default();
+        let mut source = Cow::from(macro_name);
+
+        let (file, line, col, _) = _location.location();
+        if let Some(filename) = file.name() {
+            if let Ok(Some(code)) = get_line(&filename, line) {
+                source = code.into();
+            }
+            slice.with_location(filename, line, col);
+        }
+
+        slice.with_source(source);
+
+        Diagnostic::default()
+            .with_title("Unsupported wide string in macro.", Level::Warn)
+            .add_slice(slice)
+            .add_annotation(
+                "Only narrow and UTF-8 string literals can be translated, this macro was skipped.",
+                Level::Note,
+            )
+            .display();
+    }
+}
+
+fn untranslatable_generic_macro_diagnostic(
+    macro_name: &str,
+    reason: &str,
+    _location: crate::clang::SourceLocation,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Cannot translate the `_Generic` macro `{}`, as {}.",
+        macro_name,
+        reason
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+        use std::borrow::Cow;
+
+        let mut slice = Slice::default();
+        let mut source = Cow::from(macro_name);
+
+        let (file, line, col, _) = _location.location();
+        if let Some(filename) = file.name() {
+            if let Ok(Some(code)) = get_line(&filename, line) {
+                source = code.into();
+            }
+            slice.with_location(filename, line, col);
+        }
+
+        slice.with_source(source);
+
+        Diagnostic::default()
+            .with_title(
+                format!(
+                    "Cannot translate the `_Generic` macro `{}`.",
+                    macro_name
+                ),
+                Level::Warn,
+            )
+            .add_slice(slice)
+            .add_annotation(
+                format!("The macro was skipped, as {}.", reason),
+                Level::Note,
+            )
+            .display();
+    }
+}
+
+fn unevaluable_constexpr_diagnostic(
+    name: &str,
+    reason: &str,
+    _location: crate::clang::SourceLocation,
+    _ctx: &BindgenContext,
+) {
+    warning!(
+        "Skipping the `constexpr` variable `{}`, as {}.",
+        name,
+        reason
+    );
+
+    #[cfg(feature = "experimental")]
+    if _ctx.options().emit_diagnostics {
+        use crate::diagnostics::{get_line, Diagnostic, Level, Slice};
+        use std::borrow::Cow;
+
+        let mut slice = Slice::default();
+        let mut source = Cow::from(name);
+
+        let (file, line, col, _) = _location.location();
+        if let Some(filename) = file.name() {
+            if let Ok(Some(code)) = get_line(&filename, line) {
+                source = code.into();
+            }
+            slice.with_location(filename, line, col);
+        }
+
+        slice.with_source(source);
+
+        Diagnostic::default()
+            .with_title(
+                format!("Cannot translate the `constexpr` variable `{}`.", name),
+                Level::Warn,
+            )
+            .add_slice(slice)
+            .add_annotation(
+                format!(
+                    "The variable was skipped, as {} and it may not have a symbol to link to.",
+                    reason
+                ),
+                Level::Note,
+            )
+            .display();
+    }
+}
+
 fn duplicated_macro_diagnostic(
     macro_name: &str,
     _location: crate::clang::SourceLocation,
     _ctx: &BindgenContext,
 ) {
+    // This isn't counted as a warning for `--treat-warnings-as-errors`, as
+    // it is also emitted for macros that are redefined after an `#undef`.
     warn!("Duplicated macro definition: {}", macro_name);
 
     #[cfg(feature = "experimental")]
@@ -29,13 +29,25 @@ extern crate log;
 #[macro_use]
 mod log_stubs;
 
+/// Log a warning and record that it was emitted, see
+/// [`Builder::treat_warnings_as_errors`].
+macro_rules! warning {
+    ($($arg:tt)*) => {{
+        warn!($($arg)*);
+        $crate::warning_emitted();
+    }};
+}
+
 #[macro_use]
 mod extra_assertions;
 
 mod codegen;
 mod deps;
+mod merge;
 mod options;
+mod split;
 mod time;
+mod versions;
 
 pub mod callbacks;
 
@@ -47,13 +59,16 @@ mod ir;
 mod parse;
 mod regex_set;
 
+pub use clang::{AstCursor, AstCursorKind, AstLinkage, TranslationUnitFlags};
 pub use codegen::{
-    AliasVariation, EnumVariation, MacroTypeVariation, NonCopyUnionStyle,
+    AliasVariation, EnumDefaultVariant, EnumVariation, ErrnoConvention,
+    ErrnoSuccess, ErrnoValue, MacroTypeVariation, NonCopyUnionStyle,
 };
 #[cfg(feature = "__cli")]
 pub use features::RUST_TARGET_STRINGS;
-pub use features::{RustTarget, LATEST_STABLE_RUST};
+pub use features::{RustEdition, RustTarget, LATEST_STABLE_RUST};
 pub use ir::annotations::FieldVisibilityKind;
+pub use ir::comment::DoxygenStyle;
 pub use ir::function::Abi;
 pub use regex_set::RegexSet;
 
@@ -66,10 +81,11 @@ use options::BindgenOptions;
 use parse::ParseError;
 
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::collections::hash_map::Entry;
 use std::env;
 use std::ffi::OsStr;
-use std::fs::{File, OpenOptions};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
@@ -211,6 +227,26 @@ impl std::fmt::Display for Formatter {
     }
 }
 
+/// A user-provided formatter for the bindings, set up with [`Builder::with_formatter`].
+#[derive(Clone)]
+pub(crate) struct CustomFormatter(Rc<dyn Fn(String) -> io::Result<String>>);
+
+impl std::fmt::Debug for CustomFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CustomFormatter")
+    }
+}
+
+/// A user-provided visitor of the Clang AST, set up with [`Builder::with_ast_visitor`].
+#[derive(Clone)]
+pub(crate) struct AstVisitor(Rc<dyn Fn(&AstCursor<'_>)>);
+
+impl std::fmt::Debug for AstVisitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AstVisitor")
+    }
+}
+
 /// Configure and generate Rust bindings for a C/C++ header.
 ///
 /// This is the main entry point to the library.
@@ -318,6 +354,13 @@ fn get_extra_clang_args(
 impl Builder {
     /// Generate the Rust bindings using the options built up thus far.
     pub fn generate(mut self) -> Result<Bindings, BindgenError> {
+        // Build the banner before the options are modified below.
+        let header_banner = if self.options.emit_header_banner {
+            self.header_banner()
+        } else {
+            vec![]
+        };
+
         // Add any extra arguments from the environment to the clang command line.
         self.options.clang_args.extend(
             get_extra_clang_args(&self.options.parse_callbacks)
@@ -346,7 +389,51 @@ impl Builder {
                 })
                 .collect::<Vec<_>>();
 
-        Bindings::generate(self.options, input_unsaved_files)
+        Bindings::generate(self.options, input_unsaved_files, header_banner)
+    }
+
+    /// Build the lines of the banner emitted by [`Builder::emit_header_banner`].
+    fn header_banner(&self) -> Vec<String> {
+        /// Quote the flag so it can be pasted in a shell, if needed.
+        fn quote(flag: &str) -> Cow<'_, str> {
+            let is_safe = !flag.is_empty() &&
+                flag.chars().all(|c| {
+                    c.is_ascii_alphanumeric() || "-_./=:,+@%^".contains(c)
+                });
+            if is_safe {
+                return flag.into();
+            }
+            // The banner is made of line comments, so newlines are escaped
+            // as well.
+            format!("'{}'", flag.replace('\'', "'\\''").replace('\n', "\\n"))
+                .into()
+        }
+
+        let version =
+            option_env!("CARGO_PKG_VERSION").unwrap_or("(unknown version)");
+        let mut banner =
+            vec![format!("// Generated by rust-bindgen {version} from:")];
+        banner.extend(
+            self.options
+                .input_headers
+                .iter()
+                .chain(
+                    self.options
+                        .input_header_contents
+                        .iter()
+                        .map(|(name, _)| name),
+                )
+                .map(|header| format!("//   {}", header)),
+        );
+        banner.push("//".to_owned());
+        banner.push("// Equivalent command line:".to_owned());
+        banner.push(
+            std::iter::once("//   bindgen".into())
+                .chain(self.command_line_flags().iter().map(|flag| quote(flag)))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        banner
     }
 
     /// Preprocess and dump the input header files to disk.
@@ -440,7 +527,7 @@ impl Builder {
 
 impl BindgenOptions {
     fn build(&mut self) {
-        const REGEX_SETS_LEN: usize = 29;
+        const REGEX_SETS_LEN: usize = 40;
 
         let regex_sets: [_; REGEX_SETS_LEN] = [
             &mut self.blocklisted_types,
@@ -449,6 +536,8 @@ impl BindgenOptions {
             &mut self.blocklisted_files,
             &mut self.blocklisted_vars,
             &mut self.opaque_types,
+            &mut self.opaque_typedefs,
+            &mut self.opaque_template_instantiations,
             &mut self.allowlisted_vars,
             &mut self.allowlisted_types,
             &mut self.allowlisted_functions,
@@ -461,9 +550,12 @@ impl BindgenOptions {
             &mut self.newtype_global_enums,
             &mut self.rustified_enums,
             &mut self.rustified_non_exhaustive_enums,
+            &mut self.enum_unknown_variants,
             &mut self.type_alias,
             &mut self.new_type_alias,
             &mut self.new_type_alias_deref,
+            &mut self.opaque_handle,
+            &mut self.strong_typedef,
             &mut self.bindgen_wrapper_union,
             &mut self.manually_drop_union,
             &mut self.no_partialeq_types,
@@ -472,12 +564,22 @@ impl BindgenOptions {
             &mut self.no_default_types,
             &mut self.no_hash_types,
             &mut self.must_use_types,
+            &mut self.cstr_accessors,
+            &mut self.opaque_no_construct_types,
+            &mut self.pinned_types,
+            &mut self.from_bytes_types,
+            &mut self.first_field_as_ref_types,
+            &mut self.arbitrary_types,
         ];
 
         let record_matches = self.record_matches;
         #[cfg(feature = "experimental")]
         {
-            let sets_len = REGEX_SETS_LEN + self.abi_overrides.len();
+            let sets_len = REGEX_SETS_LEN +
+                self.abi_overrides.len() +
+                self.constant_groups.len() +
+                self.errno_wrappers.len() +
+                self.out_params.len();
             let names = if self.emit_diagnostics {
                 <[&str; REGEX_SETS_LEN]>::into_iter([
                     "--blocklist-type",
@@ -486,6 +588,8 @@ impl BindgenOptions {
                     "--blocklist-file",
                     "--blocklist-var",
                     "--opaque-type",
+                    "--typedef-opaque",
+                    "--with-opaque-template-instantiations",
                     "--allowlist-type",
                     "--allowlist-function",
                     "--allowlist-var",
@@ -496,11 +600,14 @@ impl BindgenOptions {
                     "--newtype-global-enum",
                     "--rustified-enum",
                     "--rustified-enum-non-exhaustive",
+                    "--with-enum-unknown-variant",
                     "--constified-enum-module",
                     "--constified-enum",
                     "--type-alias",
                     "--new-type-alias",
                     "--new-type-alias-deref",
+                    "--opaque-handle",
+                    "--strong-typedef",
                     "--bindgen-wrapper-union",
                     "--manually-drop-union",
                     "--no-partialeq",
@@ -509,22 +616,49 @@ impl BindgenOptions {
                     "--no-default",
                     "--no-hash",
                     "--must-use",
+                    "--cstr-accessor",
+                    "--opaque-no-construct",
+                    "--mark-pinned",
+                    "--derive-from-bytes",
+                    "--first-field-as-ref",
+                    "--derive-arbitrary",
                 ])
                 .chain((0..self.abi_overrides.len()).map(|_| "--override-abi"))
+                .chain(
+                    (0..self.constant_groups.len())
+                        .map(|_| "--constant-group-as-enum"),
+                )
+                .chain(
+                    (0..self.errno_wrappers.len()).map(|_| "--errno-wrapper"),
+                )
+                .chain((0..self.out_params.len()).map(|_| "--out-param"))
                 .map(Some)
                 .collect()
             } else {
                 vec![None; sets_len]
             };
 
-            for (regex_set, name) in
-                self.abi_overrides.values_mut().chain(regex_sets).zip(names)
+            for (regex_set, name) in self
+                .abi_overrides
+                .values_mut()
+                .chain(self.constant_groups.values_mut())
+                .chain(self.errno_wrappers.values_mut())
+                .chain(self.out_params.values_mut())
+                .chain(regex_sets)
+                .zip(names)
             {
                 regex_set.build_with_diagnostics(record_matches, name);
             }
         }
         #[cfg(not(feature = "experimental"))]
-        for regex_set in self.abi_overrides.values_mut().chain(regex_sets) {
+        for regex_set in self
+            .abi_overrides
+            .values_mut()
+            .chain(self.constant_groups.values_mut())
+            .chain(self.errno_wrappers.values_mut())
+            .chain(self.out_params.values_mut())
+            .chain(regex_sets)
+        {
             regex_set.build(record_matches);
         }
 
@@ -540,6 +674,80 @@ impl BindgenOptions {
         }
     }
 
+    /// The first enabled option that the Rust target doesn't support, along
+    /// with the oldest Rust target supporting it.
+    ///
+    /// Options whose output falls back to something every target supports,
+    /// like [`Builder::wrap_unsafe_ops`], aren't checked.
+    fn unsupported_option(&self) -> Option<(&'static str, RustTarget)> {
+        let overrides_abi = |abi| {
+            self.abi_overrides
+                .get(&abi)
+                .is_some_and(|set| !set.is_empty())
+        };
+
+        /// Whether an option is enabled, its name and whether the Rust
+        /// features support it.
+        type FeatureGatedOption =
+            (bool, &'static str, fn(&RustFeatures) -> bool);
+
+        let options: [FeatureGatedOption; 8] = [
+            (
+                overrides_abi(Abi::ThisCall),
+                "--override-abi thiscall",
+                |features| features.thiscall_abi,
+            ),
+            (
+                overrides_abi(Abi::CUnwind),
+                "--override-abi C-unwind",
+                |features| features.c_unwind_abi,
+            ),
+            (
+                overrides_abi(Abi::EfiApi),
+                "--override-abi efiapi",
+                |features| features.abi_efiapi,
+            ),
+            (
+                overrides_abi(Abi::Vectorcall),
+                "--override-abi vectorcall",
+                |features| features.vectorcall_abi,
+            ),
+            (
+                !self.rustified_non_exhaustive_enums.is_empty(),
+                "--rustified-non-exhaustive-enum",
+                |features| features.non_exhaustive,
+            ),
+            (
+                self.default_enum_style ==
+                    (EnumVariation::Rust {
+                        non_exhaustive: true,
+                    }),
+                "--default-enum-style rust_non_exhaustive",
+                |features| features.non_exhaustive,
+            ),
+            // Associated constants are needed to emit these constants at all.
+            (
+                self.flat_enum_constants,
+                "--flat-enum-constants",
+                |features| features.associated_const,
+            ),
+            (
+                self.enum_variants_slice,
+                "--enum-variants-slice",
+                |features| features.associated_const,
+            ),
+        ];
+
+        options
+            .iter()
+            .find(|&&(enabled, _, supported)| {
+                enabled && !supported(&self.rust_features)
+            })
+            .map(|&(_, option, supported)| {
+                (option, RustTarget::oldest_supporting(supported))
+            })
+    }
+
     /// Update rust target version
     pub fn set_rust_target(&mut self, rust_target: RustTarget) {
         self.rust_target = rust_target;
@@ -577,8 +785,26 @@ impl BindgenOptions {
         self.parse_callbacks.iter().for_each(|cb| f(cb.as_ref()));
     }
 
+    /// The arguments passed to Clang, followed by the target set by
+    /// [`Builder::clang_target`], if any. Clang uses the last target it's
+    /// given, so it overrides any target in the other arguments.
+    fn clang_args_with_target(&self) -> Cow<'_, [Box<str>]> {
+        match self.clang_target {
+            Some(ref triple) => {
+                let mut args = self.clang_args.clone();
+                args.push(format!("--target={}", triple).into_boxed_str());
+                args.into()
+            }
+            None => Cow::Borrowed(&self.clang_args),
+        }
+    }
+
     fn process_comment(&self, comment: &str) -> String {
         let comment = comment::preprocess(comment);
+        let comment = match self.doxygen_style {
+            DoxygenStyle::Verbatim => comment,
+            DoxygenStyle::Rustdoc => comment::translate_doxygen(&comment),
+        };
         self.parse_callbacks
             .last()
             .and_then(|cb| cb.process_comment(&comment))
@@ -586,8 +812,224 @@ impl BindgenOptions {
     }
 }
 
+impl BindgenOptions {
+    /// Ignore the Clang arguments that require a newer version of `libclang`
+    /// than `version`.
+    ///
+    /// Returns the arguments that were ignored.
+    fn disable_unsupported_by_clang(
+        &mut self,
+        version: (u32, u32),
+    ) -> Vec<Box<str>> {
+        // The C++ standards that older versions of `libclang` reject, along
+        // with the first version accepting them.
+        const STANDARDS: [(&str, (u32, u32)); 8] = [
+            ("c++20", (10, 0)),
+            ("gnu++20", (10, 0)),
+            ("c++2b", (13, 0)),
+            ("gnu++2b", (13, 0)),
+            ("c++23", (17, 0)),
+            ("gnu++23", (17, 0)),
+            ("c++2c", (17, 0)),
+            ("gnu++2c", (17, 0)),
+        ];
+
+        let mut disabled = vec![];
+        self.clang_args.retain(|arg| {
+            let unsupported = arg
+                .strip_prefix("-std=")
+                .or_else(|| arg.strip_prefix("--std="))
+                .is_some_and(|standard| {
+                    STANDARDS.iter().any(|&(name, required)| {
+                        name == standard && version < required
+                    })
+                });
+            if unsupported {
+                disabled.push(arg.clone());
+            }
+            !unsupported
+        });
+
+        for arg in &disabled {
+            unsupported_clang_version_diagnostic(arg, version, self);
+        }
+
+        disabled
+    }
+}
+
+std::thread_local! {
+    /// The number of warnings emitted on this thread since the generation of
+    /// the current bindings started.
+    static WARNINGS_EMITTED: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Record that a warning was emitted, see [`Builder::treat_warnings_as_errors`].
+pub(crate) fn warning_emitted() {
+    WARNINGS_EMITTED.with(|count| count.set(count.get() + 1));
+}
+
+fn unsupported_clang_version_diagnostic(
+    arg: &str,
+    version: (u32, u32),
+    _options: &BindgenOptions,
+) {
+    warning!(
+        "The `{}` argument is not supported by libclang {}.{} and was ignored",
+        arg,
+        version.0,
+        version.1
+    );
+
+    #[cfg(feature = "experimental")]
+    if _options.emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        let mut diagnostic = Diagnostic::default();
+        diagnostic.with_title(
+            format!(
+                "The `{}` argument is not supported by libclang {}.{}.",
+                arg, version.0, version.1
+            ),
+            Level::Warn,
+        );
+        diagnostic.add_annotation(
+            "This argument was ignored because of `--clang-version-fallback`",
+            Level::Info,
+        );
+        diagnostic.add_annotation(
+            "Use a newer version of libclang to enable it",
+            Level::Help,
+        );
+        diagnostic.display();
+    }
+}
+
+/// Detects the system include search paths for the given clang arguments and
+/// whether the inputs are C++, or describes why they couldn't be detected.
+type IncludePathDetector = fn(&[String], bool) -> Result<Vec<PathBuf>, String>;
+
+/// Adds the system include search paths reported by `detector` to the clang
+/// arguments as `-isystem` arguments.
+fn detect_include_paths(
+    options: &mut BindgenOptions,
+    detector: IncludePathDetector,
+) {
+    if !options.detect_include_paths {
+        return;
+    }
+
+    // Filter out include paths and similar stuff, so we don't incorrectly
+    // promote them to `-isystem`.
+    let clang_args_for_clang_sys = {
+        let mut last_was_include_prefix = false;
+        options
+            .clang_args_with_target()
+            .iter()
+            .filter(|arg| {
+                if last_was_include_prefix {
+                    last_was_include_prefix = false;
+                    return false;
+                }
+
+                let arg = arg.as_ref();
+
+                // https://clang.llvm.org/docs/ClangCommandLineReference.html
+                // -isystem and -isystem-after are harmless.
+                if arg == "-I" || arg == "--include-directory" {
+                    last_was_include_prefix = true;
+                    return false;
+                }
+
+                if arg.starts_with("-I") ||
+                    arg.starts_with("--include-directory=")
+                {
+                    return false;
+                }
+
+                true
+            })
+            .map(|arg| arg.clone().into())
+            .collect::<Vec<_>>()
+    };
+
+    // Whether we are working with C or C++ inputs.
+    let is_cpp = args_are_cpp(&options.clang_args) ||
+        options.input_headers.iter().any(|h| file_is_cpp(h));
+
+    match detector(&clang_args_for_clang_sys, is_cpp) {
+        Ok(search_paths) => {
+            for path in search_paths.into_iter() {
+                if let Ok(path) = path.into_os_string().into_string() {
+                    options.clang_args.push("-isystem".into());
+                    options.clang_args.push(path.into_boxed_str());
+                }
+            }
+        }
+        Err(reason) => {
+            debug!("Could not detect the system include paths: {}", reason);
+            if options.warn_on_include_path_detection_failure {
+                include_path_detection_diagnostic(&reason, options);
+            }
+        }
+    }
+}
+
+/// Detects the system include search paths using the clang executable found by
+/// `clang_sys`.
+fn clang_sys_include_paths(
+    clang_args: &[String],
+    is_cpp: bool,
+) -> Result<Vec<PathBuf>, String> {
+    debug!("Trying to find clang with flags: {:?}", clang_args);
+
+    let clang = clang_sys::support::Clang::find(None, clang_args)
+        .ok_or_else(|| "no clang executable was found".to_owned())?;
+
+    debug!("Found clang: {:?}", clang);
+
+    let clang_path = clang.path;
+    let search_paths = if is_cpp {
+        clang.cpp_search_paths
+    } else {
+        clang.c_search_paths
+    };
+
+    search_paths.ok_or_else(|| {
+        format!(
+            "`{}` didn't report any include search paths",
+            clang_path.display()
+        )
+    })
+}
+
+fn include_path_detection_diagnostic(reason: &str, _options: &BindgenOptions) {
+    warning!("Could not detect the system include paths: {}", reason);
+
+    #[cfg(feature = "experimental")]
+    if _options.emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        let mut diagnostic = Diagnostic::default();
+        diagnostic.with_title(
+            format!("Could not detect the system include paths: {}.", reason),
+            Level::Warn,
+        );
+        diagnostic.add_annotation(
+            "System headers like `stdio.h` may not be found",
+            Level::Info,
+        );
+        diagnostic.add_annotation(
+            "Set `CLANG_PATH` to a clang executable for the target, or pass \
+             `--sysroot` or `-isystem` in the clang arguments",
+            Level::Help,
+        );
+        diagnostic.display();
+    }
+}
+
 fn deprecated_target_diagnostic(target: RustTarget, _options: &BindgenOptions) {
-    warn!("The {} Rust target is deprecated. If you have a need to use this target please report it at https://github.com/rust-lang/rust-bindgen/issues", target);
+    warning!("The {} Rust target is deprecated. If you have a need to use this target please report it at https://github.com/rust-lang/rust-bindgen/issues", target);
 
     #[cfg(feature = "experimental")]
     if _options.emit_diagnostics {
@@ -607,6 +1049,66 @@ fn deprecated_target_diagnostic(target: RustTarget, _options: &BindgenOptions) {
     }
 }
 
+fn unsupported_option_diagnostic(
+    option: &str,
+    required: RustTarget,
+    _options: &BindgenOptions,
+) {
+    error!(
+        "The `{}` option requires the {} Rust target or a newer one, but the {} Rust target was set",
+        option, required, _options.rust_target
+    );
+
+    #[cfg(feature = "experimental")]
+    if _options.emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        let mut diagnostic = Diagnostic::default();
+        diagnostic.with_title(
+            format!(
+                "The `{}` option is not supported by the {} Rust target.",
+                option, _options.rust_target
+            ),
+            Level::Error,
+        );
+        diagnostic.add_annotation(
+            format!(
+                "This option requires the {} Rust target or a newer one.",
+                required
+            ),
+            Level::Note,
+        );
+        diagnostic.add_annotation(
+            "Raise the target passed to `--rust-target` or disable this option",
+            Level::Help,
+        );
+        diagnostic.display();
+    }
+}
+
+fn invalid_mapped_float_diagnostic(path: &str, _options: &BindgenOptions) {
+    warning!(
+        "Ignoring the `map_float` option, `{}` is not a valid Rust type",
+        path
+    );
+
+    #[cfg(feature = "experimental")]
+    if _options.emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        Diagnostic::default()
+            .with_title(
+                format!("`{}` is not a valid Rust type.", path),
+                Level::Warn,
+            )
+            .add_annotation(
+                "`float` and `double` are translated to `f32` and `f64` instead.",
+                Level::Note,
+            )
+            .display();
+    }
+}
+
 #[cfg(feature = "runtime")]
 fn ensure_libclang_is_loaded() {
     if clang_sys::is_loaded() {
@@ -644,6 +1146,28 @@ pub enum BindgenError {
     ClangDiagnostic(String),
     /// Code generation reported an error.
     Codegen(CodegenError),
+    /// No items were generated and [`Builder::error_on_empty`] was enabled.
+    EmptyBindings,
+    /// The bindings passed to [`Bindings::merge_by_pointer_width`] were generated for
+    /// targets with the same pointer width, in bits.
+    SamePointerWidth(usize),
+    /// The Rust edition set with [`Builder::rust_edition`] is not supported by the Rust
+    /// target.
+    UnsupportedEdition(RustEdition, RustTarget),
+    /// The named option requires the first Rust target or a newer one, but the second one was
+    /// set with [`Builder::rust_target`].
+    UnsupportedOption(&'static str, RustTarget, RustTarget),
+    /// The given number of warnings were emitted while generating the bindings and
+    /// [`Builder::treat_warnings_as_errors`] was enabled.
+    Warnings(usize),
+    /// No bindings were passed to [`Bindings::merge_versions`].
+    NoVersions,
+    /// The name of the trait or a version passed to [`Bindings::merge_versions`] is not a
+    /// valid Rust identifier.
+    InvalidIdentifier(String),
+    /// The bindings to combine could not be parsed as Rust items, e.g. because of invalid
+    /// raw tokens, with the given parse error.
+    UnparsableBindings(String),
 }
 
 impl std::fmt::Display for BindgenError {
@@ -664,17 +1188,155 @@ impl std::fmt::Display for BindgenError {
             BindgenError::Codegen(err) => {
                 write!(f, "codegen error: {}", err)
             }
+            BindgenError::EmptyBindings => {
+                write!(f, "no items were generated")
+            }
+            BindgenError::SamePointerWidth(width) => {
+                write!(
+                    f,
+                    "cannot merge bindings for targets with the same {}-bit pointer width",
+                    width
+                )
+            }
+            BindgenError::UnsupportedEdition(edition, target) => {
+                write!(
+                    f,
+                    "the {} Rust edition is not supported by the {} Rust target",
+                    edition, target
+                )
+            }
+            BindgenError::UnsupportedOption(option, required, target) => {
+                write!(
+                    f,
+                    "the `{}` option is not supported by the {} Rust target, it requires {} or newer",
+                    option, target, required
+                )
+            }
+            BindgenError::NoVersions => {
+                write!(f, "no versions of the bindings were given")
+            }
+            BindgenError::InvalidIdentifier(name) => {
+                write!(f, "`{}` is not a valid Rust identifier", name)
+            }
+            BindgenError::UnparsableBindings(err) => {
+                write!(f, "the bindings could not be parsed: {}", err)
+            }
+            BindgenError::Warnings(1) => {
+                write!(f, "1 warning was emitted and treated as an error")
+            }
+            BindgenError::Warnings(count) => {
+                write!(
+                    f,
+                    "{} warnings were emitted and treated as errors",
+                    count
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for BindgenError {}
 
+/// An entry of the trace recorded when [`Builder::trace_allowlist`] is enabled.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AllowlistTraceEntry {
+    /// The name of the item, as matched against the allowlist and blocklist patterns.
+    pub name: String,
+    /// Why the item was or wasn't generated.
+    pub decision: AllowlistDecision,
+}
+
+/// Why an item was or wasn't generated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AllowlistDecision {
+    /// Nothing was allowlisted, so every item is generated.
+    NothingAllowlisted,
+    /// The item matched an allowlist pattern.
+    Allowlisted {
+        /// The option the pattern was given with, e.g. `--allowlist-function`.
+        option: &'static str,
+        /// The matching pattern.
+        pattern: String,
+    },
+    /// The item was allowlisted by [`callbacks::ParseCallbacks::should_allowlist_var`].
+    Callback,
+    /// The item replaces another type, so it's always generated.
+    Replacement,
+    /// The item is always generated along with the allowlisted items.
+    Implicit,
+    /// The item was pulled in transitively by the named item.
+    PulledInBy(String),
+    /// The item matched a blocklist pattern.
+    Blocklisted {
+        /// The option the pattern was given with, e.g. `--blocklist-type`.
+        option: &'static str,
+        /// The matching pattern.
+        pattern: String,
+    },
+    /// The item was hidden by an annotation or replaced by another type.
+    Hidden,
+    /// The item matched no allowlist pattern and wasn't used by any allowlisted item.
+    NotAllowlisted,
+}
+
+impl AllowlistDecision {
+    /// The tag of this decision in the report written by
+    /// [`Builder::write_coverage_report`].
+    pub(crate) fn coverage_tag(&self) -> &'static str {
+        match self {
+            AllowlistDecision::Blocklisted { .. } |
+            AllowlistDecision::Hidden => "blocklisted",
+            AllowlistDecision::NotAllowlisted => "not-matched",
+            _ => "generated",
+        }
+    }
+}
+
+impl std::fmt::Display for AllowlistDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AllowlistDecision::NothingAllowlisted => {
+                "generated, nothing was allowlisted".fmt(f)
+            }
+            AllowlistDecision::Allowlisted { option, pattern } => {
+                write!(f, "generated, matched {} `{}`", option, pattern)
+            }
+            AllowlistDecision::Callback => {
+                "generated, allowlisted by a parse callback".fmt(f)
+            }
+            AllowlistDecision::Replacement => {
+                "generated, replaces another type".fmt(f)
+            }
+            AllowlistDecision::Implicit => {
+                "generated along with the allowlisted items".fmt(f)
+            }
+            AllowlistDecision::PulledInBy(name) => {
+                write!(f, "generated, pulled in transitively by `{}`", name)
+            }
+            AllowlistDecision::Blocklisted { option, pattern } => {
+                write!(f, "not generated, matched {} `{}`", option, pattern)
+            }
+            AllowlistDecision::Hidden => {
+                "not generated, hidden or replaced by another type".fmt(f)
+            }
+            AllowlistDecision::NotAllowlisted => {
+                "not generated, not allowlisted or used by allowlisted items"
+                    .fmt(f)
+            }
+        }
+    }
+}
+
 /// Generated Rust bindings.
 #[derive(Debug)]
 pub struct Bindings {
     options: BindgenOptions,
     module: proc_macro2::TokenStream,
+    unsafe_ops_wrapped: usize,
+    allowlist_trace: Vec<AllowlistTraceEntry>,
+    header_banner: Vec<String>,
+    target_pointer_size: usize,
 }
 
 pub(crate) const HOST_TARGET: &str =
@@ -710,6 +1372,54 @@ fn rust_to_clang_target(rust_target: &str) -> Box<str> {
     clang_target.into()
 }
 
+/// Expand the `@file` response files in the given clang arguments, so that the
+/// arguments they contain, e.g. include paths or the target, are seen by
+/// `bindgen` and not only by clang.
+///
+/// The contents of a response file are split with shell-like quoting rules.
+/// Response files referenced from a response file are expanded as well, and
+/// their relative paths are resolved from the directory of the referencing
+/// file, like clang does. Arguments naming a file that can't be read or
+/// split, or a file that is already being expanded, are kept as is.
+fn expand_response_files(clang_args: Vec<Box<str>>) -> Vec<Box<str>> {
+    fn expand(
+        arg: Box<str>,
+        dir: Option<&Path>,
+        expanding: &mut Vec<PathBuf>,
+        expanded: &mut Vec<Box<str>>,
+    ) {
+        let path = match arg.strip_prefix('@') {
+            Some(path) if !path.is_empty() => Path::new(path),
+            _ => return expanded.push(arg),
+        };
+        let path = match dir {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path.to_owned(),
+        };
+
+        let args = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| shlex::split(&contents));
+        let args = match args {
+            Some(args) if !expanding.contains(&path) => args,
+            _ => return expanded.push(arg),
+        };
+
+        expanding.push(path);
+        let dir = expanding.last().unwrap().parent().map(Path::to_owned);
+        for arg in args {
+            expand(arg.into(), dir.as_deref(), expanding, expanded);
+        }
+        expanding.pop();
+    }
+
+    let mut expanded = Vec::with_capacity(clang_args.len());
+    for arg in clang_args {
+        expand(arg, None, &mut vec![], &mut expanded);
+    }
+    expanded
+}
+
 /// Returns the effective target, and whether it was explicitly specified on the
 /// clang flags.
 fn find_effective_target(clang_args: &[Box<str>]) -> (Box<str>, bool) {
@@ -740,8 +1450,37 @@ impl Bindings {
     /// Generate bindings for the given options.
     pub(crate) fn generate(
         mut options: BindgenOptions,
-        input_unsaved_files: Vec<clang::UnsavedFile>,
+        mut input_unsaved_files: Vec<clang::UnsavedFile>,
+        header_banner: Vec<String>,
     ) -> Result<Bindings, BindgenError> {
+        WARNINGS_EMITTED.with(|count| count.set(0));
+
+        if !options.rust_edition.is_available(options.rust_target) {
+            return Err(BindgenError::UnsupportedEdition(
+                options.rust_edition,
+                options.rust_target,
+            ));
+        }
+
+        if let Some((option, required)) = options.unsupported_option() {
+            unsupported_option_diagnostic(option, required, &options);
+            return Err(BindgenError::UnsupportedOption(
+                option,
+                required,
+                options.rust_target,
+            ));
+        }
+
+        if let Some((ref float, ref double)) = options.mapped_float_types {
+            if let Some(path) = [float, double]
+                .iter()
+                .find(|path| syn::parse_str::<syn::Type>(path).is_err())
+            {
+                invalid_mapped_float_diagnostic(path, &options);
+                options.mapped_float_types = None;
+            }
+        }
+
         ensure_libclang_is_loaded();
 
         #[cfg(feature = "runtime")]
@@ -754,8 +1493,19 @@ impl Bindings {
 
         options.build();
 
-        let (effective_target, explicit_target) =
-            find_effective_target(&options.clang_args);
+        options.clang_args =
+            expand_response_files(std::mem::take(&mut options.clang_args));
+
+        if options.clang_version_fallback {
+            if let Some(version) = clang_version().parsed {
+                options.disable_unsupported_by_clang(version);
+            }
+        }
+
+        let (effective_target, explicit_target) = match options.clang_target {
+            Some(ref triple) => (triple.as_str().into(), true),
+            None => find_effective_target(&options.clang_args),
+        };
 
         let is_host_build =
             rust_to_clang_target(HOST_TARGET) == effective_target;
@@ -772,81 +1522,7 @@ impl Bindings {
             );
         };
 
-        fn detect_include_paths(options: &mut BindgenOptions) {
-            if !options.detect_include_paths {
-                return;
-            }
-
-            // Filter out include paths and similar stuff, so we don't incorrectly
-            // promote them to `-isystem`.
-            let clang_args_for_clang_sys = {
-                let mut last_was_include_prefix = false;
-                options
-                    .clang_args
-                    .iter()
-                    .filter(|arg| {
-                        if last_was_include_prefix {
-                            last_was_include_prefix = false;
-                            return false;
-                        }
-
-                        let arg = arg.as_ref();
-
-                        // https://clang.llvm.org/docs/ClangCommandLineReference.html
-                        // -isystem and -isystem-after are harmless.
-                        if arg == "-I" || arg == "--include-directory" {
-                            last_was_include_prefix = true;
-                            return false;
-                        }
-
-                        if arg.starts_with("-I") ||
-                            arg.starts_with("--include-directory=")
-                        {
-                            return false;
-                        }
-
-                        true
-                    })
-                    .map(|arg| arg.clone().into())
-                    .collect::<Vec<_>>()
-            };
-
-            debug!(
-                "Trying to find clang with flags: {:?}",
-                clang_args_for_clang_sys
-            );
-
-            let clang = match clang_sys::support::Clang::find(
-                None,
-                &clang_args_for_clang_sys,
-            ) {
-                None => return,
-                Some(clang) => clang,
-            };
-
-            debug!("Found clang: {:?}", clang);
-
-            // Whether we are working with C or C++ inputs.
-            let is_cpp = args_are_cpp(&options.clang_args) ||
-                options.input_headers.iter().any(|h| file_is_cpp(h));
-
-            let search_paths = if is_cpp {
-                clang.cpp_search_paths
-            } else {
-                clang.c_search_paths
-            };
-
-            if let Some(search_paths) = search_paths {
-                for path in search_paths.into_iter() {
-                    if let Ok(path) = path.into_os_string().into_string() {
-                        options.clang_args.push("-isystem".into());
-                        options.clang_args.push(path.into_boxed_str());
-                    }
-                }
-            }
-        }
-
-        detect_include_paths(&mut options);
+        detect_include_paths(&mut options, clang_sys_include_paths);
 
         #[cfg(unix)]
         fn can_read(perms: &std::fs::Permissions) -> bool {
@@ -883,10 +1559,18 @@ impl Bindings {
             options.clang_args.push(f.name.to_str().unwrap().into())
         }
 
+        // The replaced headers are only parsed where they are included.
+        input_unsaved_files.extend(options.header_replacements.iter().map(
+            |(path, contents)| {
+                clang::UnsavedFile::new(path.as_ref(), contents.as_ref())
+            },
+        ));
+
         debug!("Fixed-up options: {:?}", options);
 
         let time_phases = options.time_phases;
         let mut context = BindgenContext::new(options, &input_unsaved_files);
+        let target_pointer_size = context.target_pointer_size();
 
         if is_host_build {
             debug_assert_eq!(
@@ -903,10 +1587,162 @@ impl Bindings {
             parse(&mut context)?;
         }
 
-        let (module, options) =
-            codegen::codegen(context).map_err(BindgenError::Codegen)?;
+        let (module, options, unsafe_ops_wrapped, allowlist_trace) =
+            codegen::codegen(context)?;
+
+        let warnings = WARNINGS_EMITTED.with(Cell::get);
+        if options.treat_warnings_as_errors && warnings > 0 {
+            return Err(BindgenError::Warnings(warnings));
+        }
+
+        Ok(Bindings {
+            options,
+            module,
+            unsafe_ops_wrapped,
+            allowlist_trace,
+            header_banner,
+            target_pointer_size,
+        })
+    }
+
+    /// Merge these bindings with `other`, generated from the same input for
+    /// a target with a different pointer width, e.g. with `-m32` and `-m64`.
+    ///
+    /// The items generated identically for both targets are emitted once. The
+    /// items whose layout or any other part differs between the targets are
+    /// emitted once per target, guarded by the corresponding
+    /// `#[cfg(target_pointer_width = "...")]`. Items generated for only one
+    /// target are guarded the same way. See [`Builder::emit_doc_cfg`] to also
+    /// show these guards in the documentation.
+    ///
+    /// The options of these bindings, e.g. the raw lines and formatter, are
+    /// the ones used to write the merged bindings.
+    pub fn merge_by_pointer_width(
+        self,
+        other: Bindings,
+    ) -> Result<Bindings, BindgenError> {
+        if self.target_pointer_size == other.target_pointer_size {
+            return Err(BindgenError::SamePointerWidth(
+                self.target_pointer_size * 8,
+            ));
+        }
 
-        Ok(Bindings { options, module })
+        let module = merge::merge_by_pointer_width(
+            self.module,
+            self.target_pointer_size * 8,
+            other.module,
+            other.target_pointer_size * 8,
+            self.options.emit_doc_cfg,
+        )?;
+
+        Ok(Bindings {
+            module,
+            unsafe_ops_wrapped: self.unsafe_ops_wrapped +
+                other.unsafe_ops_wrapped,
+            ..self
+        })
+    }
+
+    /// Merge these bindings, generated without a feature of the input, with
+    /// `with`, generated from the same input with that feature enabled, e.g.
+    /// with a `-D` define guarding some of its code with `#ifdef`.
+    ///
+    /// The items generated identically in both cases are emitted once. The
+    /// items that differ or are generated in only one case are guarded by
+    /// `#[cfg(not(feature = "..."))]` or `#[cfg(feature = "...")]`, with the
+    /// name of the given Cargo feature. A struct that only has additional
+    /// trailing fields with the feature is emitted once, with these fields
+    /// guarded by `#[cfg(feature = "...")]`, and its layout tests are guarded
+    /// if they differ. See [`Builder::emit_doc_cfg`] to also show these guards
+    /// in the documentation.
+    ///
+    /// The options of these bindings, e.g. the raw lines and formatter, are
+    /// the ones used to write the merged bindings.
+    pub fn merge_by_feature(
+        self,
+        with: Bindings,
+        feature: &str,
+    ) -> Result<Bindings, BindgenError> {
+        let module = merge::merge_by_feature(
+            self.module,
+            with.module,
+            feature,
+            self.options.emit_doc_cfg,
+        )?;
+
+        Ok(Bindings {
+            module,
+            unsafe_ops_wrapped: self.unsafe_ops_wrapped +
+                with.unsafe_ops_wrapped,
+            ..self
+        })
+    }
+
+    /// Combine bindings generated for different versions of a library, along
+    /// with the name of each version, e.g. `v1`.
+    ///
+    /// The items of each version are emitted in a module named after it and
+    /// guarded by `#[cfg(feature = "...")]` with the same name, so that the
+    /// crate including the bindings selects the version with a feature. A trait
+    /// named `trait_name` is emitted along with them, with an associated
+    /// `unsafe fn` for every function declared with the same signature by all
+    /// the versions. Each module implements the trait for a `Backend` unit
+    /// struct, so that code can be written once against the trait.
+    ///
+    /// Only the functions whose signatures don't use any type defined in the
+    /// bindings, e.g. functions taking and returning integers or pointers to
+    /// them, are part of the trait. Variadic functions and the functions of
+    /// [`Builder::dynamic_library_name`] aren't either.
+    ///
+    /// The names of the trait and the versions must be valid Rust identifiers, or
+    /// [`BindgenError::InvalidIdentifier`] is returned. The options of
+    /// the first bindings, e.g. the raw lines and formatter, are the ones used
+    /// to write the combined bindings.
+    pub fn merge_versions(
+        trait_name: &str,
+        versions: Vec<(&str, Bindings)>,
+    ) -> Result<Bindings, BindgenError> {
+        let mut versions = versions.into_iter();
+        let (first_version, first) =
+            versions.next().ok_or(BindgenError::NoVersions)?;
+
+        let mut unsafe_ops_wrapped = first.unsafe_ops_wrapped;
+        let mut modules = vec![(first_version, first.module)];
+        for (version, bindings) in versions {
+            unsafe_ops_wrapped += bindings.unsafe_ops_wrapped;
+            modules.push((version, bindings.module));
+        }
+
+        Ok(Bindings {
+            module: versions::merge_versions(trait_name, modules)?,
+            unsafe_ops_wrapped,
+            options: first.options,
+            allowlist_trace: first.allowlist_trace,
+            header_banner: first.header_banner,
+            target_pointer_size: first.target_pointer_size,
+        })
+    }
+
+    /// Get the number of `unsafe` blocks wrapping unsafe operations in these bindings.
+    ///
+    /// This is always zero unless the [`Builder::wrap_unsafe_ops`] option is enabled.
+    pub fn unsafe_ops_wrapped(&self) -> usize {
+        self.unsafe_ops_wrapped
+    }
+
+    /// Get the unformatted tokens of these bindings, e.g. to emit them from a procedural macro.
+    ///
+    /// The header comment, the header banner and the raw lines are not part of these tokens, as
+    /// they are only added when the bindings are written as source text.
+    pub fn tokens(&self) -> &proc_macro2::TokenStream {
+        &self.module
+    }
+
+    /// Get the reason each function, variable and named type was or wasn't generated.
+    ///
+    /// This is always empty unless the [`Builder::trace_allowlist`] option is enabled.
+    pub fn allowlist_trace(&self) -> &[AllowlistTraceEntry] {
+        &self.allowlist_trace
     }
 
     /// Write these bindings as source text to a file.
@@ -920,8 +1756,47 @@ impl Bindings {
         Ok(())
     }
 
+    /// Write these bindings as source text to the given directory, in a file
+    /// per kind of item: `types.rs`, `functions.rs` and `vars.rs`, see
+    /// [`CodegenConfig`].
+    ///
+    /// A `mod.rs` file is written along with them, with the header banner and
+    /// the raw lines, declaring a module per file and re-exporting the items of
+    /// all of them. Each file imports the items of `mod.rs`, so that its items
+    /// can refer to the items of the other files, and their private items are
+    /// made `pub` for that purpose. The files are included relatively to
+    /// `mod.rs`, so it can be used with either `include!` or `#[path]`.
+    ///
+    /// Everything that isn't a function or a variable, e.g. the implementations
+    /// of the types, their layout tests and the modules of
+    /// [`Builder::enable_cxx_namespaces`], is written to `types.rs`.
+    pub fn write_by_kind<P: AsRef<Path>>(&self, dir: P) -> io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let parts = split::split_by_kind(self.module.clone());
+        for (kind, part) in split::KINDS.iter().zip(parts) {
+            let mut file = File::create(dir.join(format!("{}.rs", kind)))?;
+            self.write_header_comment(&mut file)?;
+            self.write_tokens(&part, &mut file)?;
+        }
+
+        let mut root = File::create(dir.join("mod.rs"))?;
+        self.write_header_comment(&mut root)?;
+        self.write_banner_and_raw_lines(&mut root)?;
+        self.write_tokens(&split::root_module(), &mut root)
+    }
+
     /// Write these bindings as source text to the given `Write`able.
     pub fn write<'a>(&self, mut writer: Box<dyn Write + 'a>) -> io::Result<()> {
+        self.write_header_comment(&mut writer)?;
+        self.write_banner_and_raw_lines(&mut writer)?;
+        self.write_tokens(&self.module, &mut writer)
+    }
+
+    /// Write the comment generated at the top of the bindings, unless it is
+    /// disabled.
+    fn write_header_comment(&self, writer: &mut impl Write) -> io::Result<()> {
         const NL: &str = if cfg!(windows) { "\r\n" } else { "\n" };
 
         if !self.options.disable_header_comment {
@@ -932,6 +1807,23 @@ impl Bindings {
                 "/* automatically generated by rust-bindgen {version} */{NL}",
             )?;
         }
+        Ok(())
+    }
+
+    /// Write the header banner and the raw lines, if any.
+    fn write_banner_and_raw_lines(
+        &self,
+        writer: &mut impl Write,
+    ) -> io::Result<()> {
+        const NL: &str = if cfg!(windows) { "\r\n" } else { "\n" };
+
+        if !self.header_banner.is_empty() {
+            for line in &self.header_banner {
+                writer.write_all(line.as_bytes())?;
+                writer.write_all(NL.as_bytes())?;
+            }
+            writer.write_all(NL.as_bytes())?;
+        }
 
         for line in self.options.raw_lines.iter() {
             writer.write_all(line.as_bytes())?;
@@ -941,17 +1833,29 @@ impl Bindings {
         if !self.options.raw_lines.is_empty() {
             writer.write_all(NL.as_bytes())?;
         }
+        Ok(())
+    }
 
-        match self.format_tokens(&self.module) {
+    /// Write the given tokens formatted with the formatter set up in
+    /// `BindgenOptions`, or unformatted if that fails.
+    fn write_tokens(
+        &self,
+        tokens: &proc_macro2::TokenStream,
+        writer: &mut impl Write,
+    ) -> io::Result<()> {
+        match self.format_tokens(tokens) {
             Ok(formatted_bindings) => {
                 writer.write_all(formatted_bindings.as_bytes())?;
             }
+            Err(err) if self.options.treat_warnings_as_errors => {
+                return Err(err)
+            }
             Err(err) => {
                 eprintln!(
                     "Failed to run rustfmt: {} (non-fatal, continuing)",
                     err
                 );
-                writer.write_all(self.module.to_string().as_bytes())?;
+                writer.write_all(tokens.to_string().as_bytes())?;
             }
         }
         Ok(())
@@ -979,6 +1883,12 @@ impl Bindings {
         let _t = time::Timer::new("rustfmt_generated_string")
             .with_output(self.options.time_phases);
 
+        if let Some(CustomFormatter(ref formatter)) =
+            self.options.custom_formatter
+        {
+            return formatter(tokens.to_string());
+        }
+
         match self.options.formatter {
             Formatter::None => return Ok(tokens.to_string()),
             #[cfg(feature = "prettyplease")]
@@ -1032,6 +1942,12 @@ impl Bindings {
                     io::ErrorKind::Other,
                     "Rustfmt parsing errors.".to_string(),
                 )),
+                Some(3) if self.options.treat_warnings_as_errors => {
+                    Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        "Rustfmt could not format some lines".to_string(),
+                    ))
+                }
                 Some(3) => {
                     rustfmt_non_fatal_error_diagnostic(
                         "Rustfmt could not format some lines",
@@ -1049,8 +1965,33 @@ impl Bindings {
     }
 }
 
+/// Display a clang diagnostic if `--emit-diagnostics` is enabled, returning
+/// whether it was displayed.
+fn clang_diagnostic(
+    _msg: &str,
+    _is_err: bool,
+    _fix_its: &[String],
+    _options: &BindgenOptions,
+) -> bool {
+    #[cfg(feature = "experimental")]
+    if _options.emit_diagnostics {
+        use crate::diagnostics::{Diagnostic, Level};
+
+        let level = if _is_err { Level::Error } else { Level::Warn };
+        let mut diagnostic = Diagnostic::default();
+        diagnostic.with_title(_msg, level);
+        for fix_it in _fix_its {
+            diagnostic.add_annotation(fix_it.as_str(), Level::Help);
+        }
+        diagnostic.display();
+        return true;
+    }
+
+    false
+}
+
 fn rustfmt_non_fatal_error_diagnostic(msg: &str, _options: &BindgenOptions) {
-    warn!("{}", msg);
+    warning!("{}", msg);
 
     #[cfg(feature = "experimental")]
     if _options.emit_diagnostics {
@@ -1081,7 +2022,15 @@ impl std::fmt::Display for Bindings {
 /// Determines whether the given cursor is in any of the files matched by the
 /// options.
 fn filter_builtins(ctx: &BindgenContext, cursor: &clang::Cursor) -> bool {
-    ctx.options().builtins || !cursor.is_builtin()
+    if !cursor.is_builtin() {
+        return true;
+    }
+
+    if cursor.kind() == clang_sys::CXCursor_MacroDefinition {
+        ctx.options().builtin_macros || ctx.options().builtins
+    } else {
+        ctx.options().builtins
+    }
 }
 
 /// Parse one `Item` from the Clang cursor.
@@ -1094,6 +2043,12 @@ fn parse_one(
         return;
     }
 
+    if cursor.is_declaration() {
+        for AstVisitor(visitor) in &ctx.options().ast_visitors {
+            visitor(&AstCursor::new(cursor));
+        }
+    }
+
     match Item::parse(cursor, parent, ctx) {
         Ok(..) => {}
         Err(ParseError::Continue) => {}
@@ -1110,13 +2065,27 @@ fn parse(context: &mut BindgenContext) -> Result<(), BindgenError> {
 
     let mut error = None;
     for d in context.translation_unit().diags().iter() {
-        let msg = d.format();
+        let mut msg = d.format();
         let is_err = d.severity() >= CXDiagnostic_Error;
+        let fix_its: Vec<_> = d
+            .fix_its()
+            .iter()
+            .map(|fix_it| fix_it.to_string())
+            .collect();
+        if d.severity() == CXDiagnostic_Warning {
+            crate::warning_emitted();
+        }
+        let displayed =
+            clang_diagnostic(&msg, is_err, &fix_its, context.options());
+        for fix_it in &fix_its {
+            msg.push_str("\nhelp: ");
+            msg.push_str(fix_it);
+        }
         if is_err {
             let error = error.get_or_insert_with(String::new);
             error.push_str(&msg);
             error.push('\n');
-        } else {
+        } else if !displayed {
             eprintln!("clang diag: {}", msg);
         }
     }
@@ -1143,6 +2112,8 @@ fn parse(context: &mut BindgenContext) -> Result<(), BindgenError> {
         cursor.visit_sorted(ctx, |ctx, child| parse_one(ctx, child, None))
     });
 
+    context.add_constant_group_enums();
+
     assert!(
         context.current_module() == context.root_module(),
         "How did this happen?"
@@ -1328,6 +2299,156 @@ fn commandline_flag_unit_test_function() {
     assert!(test_cases.iter().all(|x| command_line_flags.contains(x)));
 }
 
+#[test]
+fn clang_target_is_kept_out_of_clang_args() {
+    let builder = builder()
+        .clang_arg("--target=x86_64-unknown-linux")
+        .clang_target("i686-unknown-linux");
+
+    let command_line_flags = builder.command_line_flags();
+    assert_eq!(
+        command_line_flags
+            .iter()
+            .filter(|flag| flag.contains("i686-unknown-linux"))
+            .count(),
+        1
+    );
+
+    assert_eq!(
+        *builder.options.clang_args_with_target(),
+        [
+            "--target=x86_64-unknown-linux".into(),
+            "--target=i686-unknown-linux".into()
+        ]
+    );
+    assert_eq!(builder.options.clang_args.len(), 1);
+}
+
+#[test]
+fn clang_version_fallback_disables_unsupported_options() {
+    let mut options = builder().clang_args(["-std=c++23", "-Wall"]).options;
+    assert!(options.disable_unsupported_by_clang((17, 0)).is_empty());
+    assert_eq!(options.clang_args.len(), 2);
+
+    assert_eq!(
+        options.disable_unsupported_by_clang((16, 0)),
+        ["-std=c++23".into()]
+    );
+    assert_eq!(options.clang_args, ["-Wall".into()]);
+}
+
+#[test]
+fn test_detect_include_paths() {
+    fn detector(
+        clang_args: &[String],
+        is_cpp: bool,
+    ) -> Result<Vec<PathBuf>, String> {
+        assert_eq!(clang_args, ["--target=x86_64-unknown-linux"]);
+        assert!(!is_cpp);
+        Ok(vec!["/mock/include".into(), "/mock/usr/include".into()])
+    }
+
+    let mut options = builder()
+        .warn_on_include_path_detection_failure(true)
+        .clang_args([
+            "-I",
+            "/local",
+            "-I/other",
+            "--target=x86_64-unknown-linux",
+        ])
+        .options;
+    detect_include_paths(&mut options, detector);
+    assert_eq!(
+        &options.clang_args[4..],
+        [
+            "-isystem".into(),
+            "/mock/include".into(),
+            "-isystem".into(),
+            "/mock/usr/include".into(),
+        ] as [Box<str>; 4]
+    );
+
+    let mut options = builder().detect_include_paths(false).options;
+    detect_include_paths(&mut options, |_, _| unreachable!());
+    assert!(options.clang_args.is_empty());
+
+    let mut options = builder().options;
+    detect_include_paths(&mut options, |_, _| Err("mocked".to_owned()));
+    assert!(options.clang_args.is_empty());
+}
+
+#[test]
+fn unsupported_options() {
+    let unsupported = |builder: Builder| {
+        builder
+            .rust_target(RustTarget::Stable_1_40)
+            .options
+            .unsupported_option()
+    };
+
+    assert_eq!(unsupported(builder()), None);
+    assert_eq!(
+        unsupported(builder().rustified_non_exhaustive_enum("e")),
+        None
+    );
+    assert_eq!(
+        unsupported(builder().override_abi(Abi::Vectorcall, "f")),
+        Some(("--override-abi vectorcall", RustTarget::Nightly))
+    );
+    // Unsafe blocks can be emitted for any target.
+    assert_eq!(unsupported(builder().wrap_unsafe_ops(true)), None);
+
+    assert_eq!(
+        builder()
+            .rust_target(RustTarget::Stable_1_33)
+            .rustified_non_exhaustive_enum("e")
+            .options
+            .unsupported_option(),
+        Some(("--rustified-non-exhaustive-enum", RustTarget::Stable_1_40))
+    );
+}
+
+#[test]
+fn test_expand_response_files() {
+    let dir = env::temp_dir().join(format!(
+        "bindgen-test-expand-response-files-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("args.rsp"),
+        "-I \"include dir\" '-DNAME=a b'\n@nested.rsp @args.rsp",
+    )
+    .unwrap();
+    std::fs::write(dir.join("nested.rsp"), "--target=x86_64-unknown-linux")
+        .unwrap();
+
+    let args = [
+        "-DFOO",
+        &format!("@{}", dir.join("args.rsp").display()),
+        "@",
+    ]
+    .iter()
+    .map(|&arg| arg.into())
+    .collect();
+    let expanded = expand_response_files(args);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(
+        expanded.iter().map(AsRef::as_ref).collect::<Vec<&str>>(),
+        [
+            "-DFOO",
+            "-I",
+            "include dir",
+            "-DNAME=a b",
+            "--target=x86_64-unknown-linux",
+            // The response file being expanded isn't expanded again.
+            "@args.rsp",
+            "@",
+        ]
+    );
+}
+
 #[test]
 fn test_rust_to_clang_target() {
     assert_eq!(
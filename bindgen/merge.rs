@@ -0,0 +1,605 @@
+//! Merging bindings generated for targets with different pointer widths or
+//! with different features enabled.
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+
+use crate::BindgenError;
+
+/// The items generated for a single C/C++ entity, e.g. a struct along with its
+/// implementations and layout tests.
+pub(crate) struct Entity {
+    pub(crate) key: String,
+    pub(crate) items: Vec<syn::Item>,
+}
+
+/// Merge the items of `first` and `second`, which were generated for targets
+/// with `first_width` and `second_width` bits wide pointers respectively.
+///
+/// Entities generated identically for both targets are emitted once, while
+/// the items of the others are emitted for each target, guarded by
+/// `#[cfg(target_pointer_width = "...")]`, along with the matching
+/// `#[cfg_attr(docsrs, doc(cfg(...)))]` if `doc_cfg` is set.
+pub(crate) fn merge_by_pointer_width(
+    first: TokenStream,
+    first_width: usize,
+    second: TokenStream,
+    second_width: usize,
+    doc_cfg: bool,
+) -> Result<TokenStream, BindgenError> {
+    let first_width = first_width.to_string();
+    let second_width = second_width.to_string();
+    let merge = Merge {
+        first_cfg: quote! { target_pointer_width = #first_width },
+        second_cfg: quote! { target_pointer_width = #second_width },
+        doc_cfg,
+        trailing_fields: false,
+    };
+
+    let items = merge.items(parse(first)?, parse(second)?);
+    Ok(quote! { #( #items )* })
+}
+
+/// Merge the items of `without` and `with`, which were generated without and
+/// with the C/C++ code enabled by the given feature, e.g. with a `-D` define.
+///
+/// Entities generated identically in both cases are emitted once, while the
+/// items of the others are guarded by `#[cfg(not(feature = "..."))]` and
+/// `#[cfg(feature = "...")]` respectively, along with the matching
+/// `#[cfg_attr(docsrs, doc(cfg(...)))]` if `doc_cfg` is set.
+///
+/// A struct that only has additional trailing fields with the feature is
+/// emitted once, with those fields guarded by `#[cfg(feature = "...")]`.
+/// Its other items, e.g. its layout tests, are emitted once if they
+/// are identical in both cases, and guarded otherwise.
+pub(crate) fn merge_by_feature(
+    without: TokenStream,
+    with: TokenStream,
+    feature: &str,
+    doc_cfg: bool,
+) -> Result<TokenStream, BindgenError> {
+    let merge = Merge {
+        first_cfg: quote! { not(feature = #feature) },
+        second_cfg: quote! { feature = #feature },
+        doc_cfg,
+        trailing_fields: true,
+    };
+
+    let items = merge.items(parse(without)?, parse(with)?);
+    Ok(quote! { #( #items )* })
+}
+
+/// Parse the items of the given bindings, which may contain invalid tokens,
+/// e.g. from raw lines or parse callbacks.
+pub(crate) fn parse(
+    module: TokenStream,
+) -> Result<Vec<syn::Item>, BindgenError> {
+    syn::parse2::<syn::File>(module)
+        .map(|file| file.items)
+        .map_err(|err| BindgenError::UnparsableBindings(err.to_string()))
+}
+
+/// How to merge two sets of bindings.
+struct Merge {
+    /// The `#[cfg]` predicate guarding the items only in the first bindings.
+    first_cfg: TokenStream,
+    /// The `#[cfg]` predicate guarding the items only in the second bindings.
+    second_cfg: TokenStream,
+    /// Whether to emit the matching `#[cfg_attr(docsrs, doc(cfg(...)))]`.
+    doc_cfg: bool,
+    /// Whether to merge the structs that only have additional trailing fields
+    /// in the second bindings.
+    trailing_fields: bool,
+}
+
+impl Merge {
+    fn items(
+        &self,
+        first: Vec<syn::Item>,
+        second: Vec<syn::Item>,
+    ) -> Vec<syn::Item> {
+        let first = group_entities(first);
+        let mut second = group_entities(second);
+
+        let mut merged = vec![];
+        for entity in first {
+            let other = second
+                .iter()
+                .position(|other| other.key == entity.key)
+                .map(|index| second.remove(index));
+
+            match other {
+                Some(other) => {
+                    self.entity(&mut merged, entity.items, other.items)
+                }
+                None => merged.extend(with_cfg(
+                    entity.items,
+                    &self.first_cfg,
+                    self.doc_cfg,
+                )),
+            }
+        }
+        for entity in second {
+            merged.extend(with_cfg(
+                entity.items,
+                &self.second_cfg,
+                self.doc_cfg,
+            ));
+        }
+
+        merged
+    }
+
+    fn entity(
+        &self,
+        merged: &mut Vec<syn::Item>,
+        first: Vec<syn::Item>,
+        second: Vec<syn::Item>,
+    ) {
+        // Modules are merged item by item, so that the items they have in
+        // common aren't duplicated.
+        if let ([syn::Item::Mod(first_mod)], [syn::Item::Mod(second_mod)]) =
+            (&first[..], &second[..])
+        {
+            if let (Some((_, first_items)), Some((_, second_items))) =
+                (&first_mod.content, &second_mod.content)
+            {
+                if first_mod.attrs == second_mod.attrs {
+                    let mut module = first_mod.clone();
+                    let items =
+                        self.items(first_items.clone(), second_items.clone());
+                    module.content.as_mut().unwrap().1 = items;
+                    merged.push(syn::Item::Mod(module));
+                    return;
+                }
+            }
+        }
+
+        if first == second {
+            merged.extend(first);
+            return;
+        }
+
+        if self.trailing_fields {
+            if let Some(items) = self.trailing_fields(&first, &second) {
+                merged.extend(items);
+                return;
+            }
+        }
+
+        merged.extend(with_cfg(first, &self.first_cfg, self.doc_cfg));
+        merged.extend(with_cfg(second, &self.second_cfg, self.doc_cfg));
+    }
+
+    /// Merge the items of a struct that only has additional trailing fields in
+    /// the second bindings, if that's the case.
+    fn trailing_fields(
+        &self,
+        first: &[syn::Item],
+        second: &[syn::Item],
+    ) -> Option<Vec<syn::Item>> {
+        let (first_index, first_struct) = find_struct(first)?;
+        let (second_index, second_struct) = find_struct(second)?;
+
+        let (first_fields, second_fields) =
+            match (&first_struct.fields, &second_struct.fields) {
+                (syn::Fields::Named(first), syn::Fields::Named(second)) => {
+                    (&first.named, &second.named)
+                }
+                _ => return None,
+            };
+        let shared = first_fields.len();
+        if second_fields.len() <= shared ||
+            !second_fields.iter().zip(first_fields).all(|(a, b)| a == b)
+        {
+            return None;
+        }
+        let mut without_fields = second_struct.clone();
+        if let syn::Fields::Named(ref mut fields) = without_fields.fields {
+            fields.named = first_fields.clone();
+        }
+        if without_fields != *first_struct {
+            return None;
+        }
+
+        let mut merged_struct = second_struct.clone();
+        if let syn::Fields::Named(ref mut fields) = merged_struct.fields {
+            for field in fields.named.iter_mut().skip(shared) {
+                field
+                    .attrs
+                    .splice(0..0, cfg_attrs(&self.second_cfg, self.doc_cfg));
+            }
+        }
+
+        let mut merged = vec![syn::Item::Struct(merged_struct)];
+        let mut second: Vec<_> = second
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| index != second_index)
+            .map(|(_, item)| item.clone())
+            .collect();
+        for (index, item) in first.iter().enumerate() {
+            if index == first_index {
+                continue;
+            }
+            match second.iter().position(|other| other == item) {
+                Some(other) => merged.push(second.remove(other)),
+                None => merged.extend(with_cfg(
+                    vec![item.clone()],
+                    &self.first_cfg,
+                    self.doc_cfg,
+                )),
+            }
+        }
+        merged.extend(with_cfg(second, &self.second_cfg, self.doc_cfg));
+
+        Some(merged)
+    }
+}
+
+/// The index of the only struct among the given items, along with the struct,
+/// if there is exactly one.
+fn find_struct(items: &[syn::Item]) -> Option<(usize, &syn::ItemStruct)> {
+    let mut structs =
+        items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| match item {
+                syn::Item::Struct(item) => Some((index, item)),
+                _ => None,
+            });
+    match (structs.next(), structs.next()) {
+        (Some(found), None) => Some(found),
+        _ => None,
+    }
+}
+
+/// The `#[cfg(...)]` attribute with the given predicate, along with the
+/// matching `#[cfg_attr(docsrs, doc(cfg(...)))]` if `doc_cfg` is set.
+fn cfg_attrs(predicate: &TokenStream, doc_cfg: bool) -> Vec<syn::Attribute> {
+    let mut attrs: Vec<syn::Attribute> =
+        vec![syn::parse_quote! { #[cfg(#predicate)] }];
+    if doc_cfg {
+        attrs.push(syn::parse_quote! {
+            #[cfg_attr(docsrs, doc(cfg(#predicate)))]
+        });
+    }
+    attrs
+}
+
+/// Guard the given items with `#[cfg(...)]` with the given predicate, along
+/// with the matching `#[cfg_attr(docsrs, doc(cfg(...)))]` if `doc_cfg` is set.
+pub(crate) fn with_cfg(
+    mut items: Vec<syn::Item>,
+    predicate: &TokenStream,
+    doc_cfg: bool,
+) -> Vec<syn::Item> {
+    let new_attrs = cfg_attrs(predicate, doc_cfg);
+    for item in &mut items {
+        if let Some(attrs) = item_attrs(item) {
+            attrs.splice(0..0, new_attrs.iter().cloned());
+        }
+    }
+
+    items
+}
+
+fn item_attrs(item: &mut syn::Item) -> Option<&mut Vec<syn::Attribute>> {
+    Some(match item {
+        syn::Item::Const(item) => &mut item.attrs,
+        syn::Item::Enum(item) => &mut item.attrs,
+        syn::Item::ExternCrate(item) => &mut item.attrs,
+        syn::Item::Fn(item) => &mut item.attrs,
+        syn::Item::ForeignMod(item) => &mut item.attrs,
+        syn::Item::Impl(item) => &mut item.attrs,
+        syn::Item::Macro(item) => &mut item.attrs,
+        syn::Item::Mod(item) => &mut item.attrs,
+        syn::Item::Static(item) => &mut item.attrs,
+        syn::Item::Struct(item) => &mut item.attrs,
+        syn::Item::Trait(item) => &mut item.attrs,
+        syn::Item::TraitAlias(item) => &mut item.attrs,
+        syn::Item::Type(item) => &mut item.attrs,
+        syn::Item::Union(item) => &mut item.attrs,
+        syn::Item::Use(item) => &mut item.attrs,
+        _ => return None,
+    })
+}
+
+/// Group the given items by the entity they were generated for, keeping the
+/// order in which each entity first appears.
+pub(crate) fn group_entities(items: Vec<syn::Item>) -> Vec<Entity> {
+    let mut entities: Vec<Entity> = vec![];
+    for item in items {
+        let key = entity_key(&item);
+        match entities.iter_mut().find(|entity| entity.key == key) {
+            Some(entity) => entity.items.push(item),
+            None => entities.push(Entity {
+                key,
+                items: vec![item],
+            }),
+        }
+    }
+    entities
+}
+
+/// The key identifying the entity an item was generated for.
+///
+/// Implementations belong to their self type and layout tests to the type
+/// they check, while items that can't be attributed to a named entity are
+/// identified by their tokens.
+fn entity_key(item: &syn::Item) -> String {
+    let name = match item {
+        syn::Item::Const(item) if item.ident == "_" => {
+            checked_type(item.expr.to_token_stream())
+        }
+        syn::Item::Const(item) => Some(item.ident.to_string()),
+        syn::Item::Enum(item) => Some(item.ident.to_string()),
+        syn::Item::Fn(item) => Some(item.sig.ident.to_string()),
+        syn::Item::ForeignMod(item) => match &item.items[..] {
+            [syn::ForeignItem::Fn(item)] => Some(item.sig.ident.to_string()),
+            [syn::ForeignItem::Static(item)] => Some(item.ident.to_string()),
+            [syn::ForeignItem::Type(item)] => Some(item.ident.to_string()),
+            _ => None,
+        },
+        syn::Item::Impl(item) => match &*item.self_ty {
+            syn::Type::Path(path) => {
+                path.path.segments.last().map(|seg| seg.ident.to_string())
+            }
+            _ => None,
+        },
+        syn::Item::Mod(item) => Some(format!("mod {}", item.ident)),
+        syn::Item::Static(item) => Some(item.ident.to_string()),
+        syn::Item::Struct(item) => Some(item.ident.to_string()),
+        syn::Item::Type(item) => Some(item.ident.to_string()),
+        syn::Item::Union(item) => Some(item.ident.to_string()),
+        _ => None,
+    };
+
+    name.unwrap_or_else(|| item.to_token_stream().to_string())
+}
+
+/// The name of the type whose size is checked by a layout test, that is, the
+/// last segment of the type in the first `size_of::<...>` it contains.
+fn checked_type(tokens: TokenStream) -> Option<String> {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Ident(ident) if ident == "size_of" => {
+                let mut name = None;
+                for token in tokens[index + 1..].iter().skip(3) {
+                    match token {
+                        TokenTree::Ident(ident) => {
+                            name = Some(ident.to_string())
+                        }
+                        TokenTree::Punct(punct) if punct.as_char() == '>' => {
+                            return name
+                        }
+                        TokenTree::Punct(_) => {}
+                        _ => return None,
+                    }
+                }
+            }
+            TokenTree::Group(group) => {
+                if let Some(name) = checked_type(group.stream()) {
+                    return Some(name);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merging_by_pointer_width() {
+        let shared = quote! {
+            #[repr(C)]
+            pub struct Point {
+                pub x: ::std::os::raw::c_int,
+                pub y: ::std::os::raw::c_int,
+            }
+            const _: () = {
+                ["Size of Point"][::std::mem::size_of::<Point>() - 8usize];
+            };
+        };
+        let first = quote! {
+            #shared
+            #[repr(C)]
+            pub struct Node {
+                pub next: *mut Node,
+            }
+            const _: () = {
+                ["Size of Node"][::std::mem::size_of::<Node>() - 4usize];
+            };
+        };
+        let second = quote! {
+            #shared
+            #[repr(C)]
+            pub struct Node {
+                pub next: *mut Node,
+            }
+            const _: () = {
+                ["Size of Node"][::std::mem::size_of::<Node>() - 8usize];
+            };
+        };
+
+        let expected = quote! {
+            #shared
+            #[cfg(target_pointer_width = "32")]
+            #[repr(C)]
+            pub struct Node {
+                pub next: *mut Node,
+            }
+            #[cfg(target_pointer_width = "32")]
+            const _: () = {
+                ["Size of Node"][::std::mem::size_of::<Node>() - 4usize];
+            };
+            #[cfg(target_pointer_width = "64")]
+            #[repr(C)]
+            pub struct Node {
+                pub next: *mut Node,
+            }
+            #[cfg(target_pointer_width = "64")]
+            const _: () = {
+                ["Size of Node"][::std::mem::size_of::<Node>() - 8usize];
+            };
+        };
+
+        assert_eq!(
+            merge_by_pointer_width(first, 32, second, 64, false)
+                .unwrap()
+                .to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn merging_by_pointer_width_with_doc_cfg() {
+        let first = quote! {
+            pub type Word = u32;
+        };
+        let second = quote! {
+            pub type Word = u64;
+        };
+
+        let expected = quote! {
+            #[cfg(target_pointer_width = "32")]
+            #[cfg_attr(docsrs, doc(cfg(target_pointer_width = "32")))]
+            pub type Word = u32;
+            #[cfg(target_pointer_width = "64")]
+            #[cfg_attr(docsrs, doc(cfg(target_pointer_width = "64")))]
+            pub type Word = u64;
+        };
+
+        assert_eq!(
+            merge_by_pointer_width(first, 32, second, 64, true)
+                .unwrap()
+                .to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn merging_by_feature() {
+        let without = quote! {
+            #[repr(C)]
+            #[derive(Debug, Default, Copy, Clone)]
+            pub struct Config {
+                pub flags: ::std::os::raw::c_int,
+            }
+            const _: () = {
+                ["Size of Config"][::std::mem::size_of::<Config>() - 4usize];
+                ["Offset of field: Config::flags"][::std::mem::offset_of!(Config, flags) - 0usize];
+            };
+            extern "C" {
+                pub fn configure(config: *mut Config);
+            }
+        };
+        let with = quote! {
+            #[repr(C)]
+            #[derive(Debug, Default, Copy, Clone)]
+            pub struct Config {
+                pub flags: ::std::os::raw::c_int,
+                pub trace_level: ::std::os::raw::c_int,
+            }
+            const _: () = {
+                ["Size of Config"][::std::mem::size_of::<Config>() - 8usize];
+                ["Offset of field: Config::flags"][::std::mem::offset_of!(Config, flags) - 0usize];
+                ["Offset of field: Config::trace_level"][::std::mem::offset_of!(Config, trace_level) - 4usize];
+            };
+            extern "C" {
+                pub fn configure(config: *mut Config);
+            }
+            extern "C" {
+                pub fn set_trace_level(level: ::std::os::raw::c_int);
+            }
+        };
+
+        let expected = quote! {
+            #[repr(C)]
+            #[derive(Debug, Default, Copy, Clone)]
+            pub struct Config {
+                pub flags: ::std::os::raw::c_int,
+                #[cfg(feature = "trace")]
+                pub trace_level: ::std::os::raw::c_int,
+            }
+            #[cfg(not(feature = "trace"))]
+            const _: () = {
+                ["Size of Config"][::std::mem::size_of::<Config>() - 4usize];
+                ["Offset of field: Config::flags"][::std::mem::offset_of!(Config, flags) - 0usize];
+            };
+            #[cfg(feature = "trace")]
+            const _: () = {
+                ["Size of Config"][::std::mem::size_of::<Config>() - 8usize];
+                ["Offset of field: Config::flags"][::std::mem::offset_of!(Config, flags) - 0usize];
+                ["Offset of field: Config::trace_level"][::std::mem::offset_of!(Config, trace_level) - 4usize];
+            };
+            extern "C" {
+                pub fn configure(config: *mut Config);
+            }
+            #[cfg(feature = "trace")]
+            extern "C" {
+                pub fn set_trace_level(level: ::std::os::raw::c_int);
+            }
+        };
+
+        assert_eq!(
+            merge_by_feature(without, with, "trace", false)
+                .unwrap()
+                .to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn merging_by_feature_with_reordered_fields() {
+        let without = quote! {
+            #[repr(C)]
+            pub struct Pair {
+                pub a: u8,
+                pub b: u8,
+            }
+        };
+        let with = quote! {
+            #[repr(C)]
+            pub struct Pair {
+                pub b: u8,
+                pub a: u8,
+            }
+        };
+
+        let expected = quote! {
+            #[cfg(not(feature = "swapped"))]
+            #[repr(C)]
+            pub struct Pair {
+                pub a: u8,
+                pub b: u8,
+            }
+            #[cfg(feature = "swapped")]
+            #[repr(C)]
+            pub struct Pair {
+                pub b: u8,
+                pub a: u8,
+            }
+        };
+
+        assert_eq!(
+            merge_by_feature(without, with, "swapped", false)
+                .unwrap()
+                .to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn merging_unparsable_bindings() {
+        assert!(matches!(
+            merge_by_feature(quote! { pub fn }, quote! {}, "broken", false),
+            Err(BindgenError::UnparsableBindings(_))
+        ));
+    }
+}
@@ -7,20 +7,27 @@ mod as_args;
 
 use crate::callbacks::ParseCallbacks;
 use crate::codegen::{
-    AliasVariation, EnumVariation, MacroTypeVariation, NonCopyUnionStyle,
+    AliasVariation, EnumDefaultVariant, EnumVariation, ErrnoConvention,
+    MacroTypeVariation, NonCopyUnionStyle,
 };
 use crate::deps::DepfileSpec;
-use crate::features::{RustFeatures, RustTarget};
+use crate::features::{RustEdition, RustFeatures, RustTarget};
+use crate::ir::comment::DoxygenStyle;
 use crate::regex_set::RegexSet;
 use crate::Abi;
+use crate::AstCursor;
+use crate::AstVisitor;
 use crate::Builder;
 use crate::CodegenConfig;
+use crate::CustomFormatter;
 use crate::FieldVisibilityKind;
 use crate::Formatter;
 use crate::HashMap;
+use crate::TranslationUnitFlags;
 use crate::DEFAULT_ANON_FIELDS_PREFIX;
 
 use std::env;
+use std::io;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
@@ -106,6 +113,10 @@ macro_rules! options {
 
         impl Builder {
             /// Generates the command line flags used to create this [`Builder`].
+            ///
+            /// Passing these flags to the `bindgen` executable generates the same bindings. See
+            /// [`Builder::emit_header_banner`] to also write them as a comment at the top of the
+            /// generated bindings.
             pub fn command_line_flags(&self) -> Vec<String> {
                 let mut args = vec![];
 
@@ -253,6 +264,70 @@ options! {
         },
         as_args: "--opaque-type",
     },
+    /// Typedefs that should be treated as opaque in the generated code, without the types they
+    /// alias.
+    opaque_typedefs: RegexSet {
+        methods: {
+            regex_option! {
+                /// Treat the given typedef as opaque in the generated bindings, without affecting
+                /// the type it aliases.
+                ///
+                /// Unlike [`Builder::opaque_type`], this only matches typedefs, so the typedef
+                /// becomes an alias of a chunk of bytes with the alignment and size of the aliased
+                /// type, while the aliased type keeps its full definition for the other references
+                /// to it. A typedef that has the same name as the type it aliases, e.g.
+                /// `typedef struct foo { ... } foo;`, is not generated separately from the type, so
+                /// it is not made opaque.
+                pub fn typedef_opaque<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.opaque_typedefs.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--typedef-opaque",
+    },
+    /// Templates whose instantiations should all be treated as opaque structures in the generated
+    /// code.
+    opaque_template_instantiations: RegexSet {
+        methods: {
+            regex_option! {
+                /// Treat all the instantiations of the given template as opaque in the generated
+                /// bindings, whatever their template arguments.
+                ///
+                /// Unlike [`Builder::opaque_type`], which is matched against the full spelling of
+                /// an instantiation, e.g. `std::vector<int>`, this is matched against the bare name
+                /// of the template, e.g. `std::vector`, so a single pattern makes
+                /// `std::vector<int>`, `std::vector<float>`, etc. all opaque. The template itself
+                /// is not made opaque.
+                pub fn with_opaque_template_instantiations<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.opaque_template_instantiations.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--with-opaque-template-instantiations",
+    },
+    /// Types that should be treated as opaque structures that can only be used through pointers.
+    opaque_no_construct_types: RegexSet {
+        methods: {
+            regex_option! {
+                /// Generate the given type as an opaque type that can only be used through
+                /// pointers.
+                ///
+                /// The type is represented as a zero-sized struct with a private field, which
+                /// implements none of the derivable traits and is neither `Send`, `Sync` nor
+                /// `Unpin`. No methods or constructors are generated for it, so there is no way
+                /// to create a value of the type on the Rust side.
+                ///
+                /// Template classes matching this pattern are generated as usual.
+                pub fn opaque_no_construct<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.opaque_no_construct_types.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--opaque-no-construct",
+    },
     /// The explicit `rustfmt` path.
     rustfmt_path: Option<PathBuf> {
         methods: {
@@ -428,6 +503,10 @@ options! {
                 /// This means that an integer newtype will be declared to represent the `enum`
                 /// type and its variants will be represented as constants inside of this type's
                 /// `impl` block.
+                ///
+                /// Unlike the [`Builder::bitfield_enum`] style, no operators are implemented for
+                /// the newtype, which makes this style suitable to get associated constants on a
+                /// struct without the bitwise operations.
                 pub fn newtype_enum<T: AsRef<str>>(mut self, arg: T) -> Builder {
                     self.options.newtype_enums.insert(arg);
                     self
@@ -489,6 +568,29 @@ options! {
         },
         as_args: "--rustified-non-exhaustive-enums",
     },
+    /// Rustified `enum`s with an `Unknown` variant.
+    enum_unknown_variants: RegexSet {
+        methods: {
+            regex_option! {
+                /// Give the given rustified `enum` an `Unknown` variant holding the values that
+                /// don't match any other variant.
+                ///
+                /// Such an `enum` has neither a `repr` nor discriminants, and the integer type of
+                /// the `enum` is used instead of it in the foreign functions, the fields and the
+                /// type aliases, so that C can't hand over an invalid value of it. Conversions
+                /// between both are generated as `From` implementations, which map the values
+                /// that aren't those of any variant to the `Unknown` variant and back.
+                ///
+                /// This has no effect on the `enum`s which aren't rustified, nor on the ones
+                /// with a variant already named `Unknown`.
+                pub fn with_enum_unknown_variant<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.enum_unknown_variants.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--with-enum-unknown-variant",
+    },
     /// `enum`s marked as modules of constants.
     constified_enum_modules: RegexSet {
         methods: {
@@ -540,6 +642,27 @@ options! {
             }
         },
     },
+    /// The variant of rustified enums marked as `#[default]` when deriving `Default`.
+    enum_default_variant: EnumDefaultVariant {
+        methods: {
+            /// Set which variant of rustified enums is marked as `#[default]` when deriving
+            /// `Default` for them with [`Builder::derive_default`].
+            ///
+            /// If this method is not called, [`EnumDefaultVariant::Zero`] is used by default.
+            /// Enums without a suitable variant don't derive `Default`. Deriving `Default` for
+            /// enums requires Rust 1.62 or later.
+            pub fn enum_default_variant(mut self, arg: EnumDefaultVariant) -> Builder {
+                self.options.enum_default_variant = arg;
+                self
+            }
+        },
+        as_args: |variant, args| {
+            if *variant != Default::default() {
+                args.push("--enum-default-variant".to_owned());
+                args.push(variant.to_string());
+            }
+        },
+    },
     /// The default style of code generation for `typedef`s.
     default_alias_style: AliasVariation {
         methods: {
@@ -614,6 +737,50 @@ options! {
         },
         as_args: "--new-type-alias-deref",
     },
+    /// `typedef` patterns that will be wrapped in an opaque handle newtype.
+    opaque_handle: RegexSet {
+        methods: {
+            regex_option! {
+                /// Mark the given integer `typedef` as an opaque handle.
+                ///
+                /// The handle is generated as a `#[repr(transparent)]` newtype with a private
+                /// field. Converting between the handle and the underlying integer is done
+                /// explicitly with the generated `from_raw` and `into_raw` methods. The handle
+                /// also implements `fmt::Pointer`, so that it can be printed as an address with
+                /// `{:p}`.
+                ///
+                /// `typedef`s matching this pattern whose aliased type is not an integer are
+                /// generated as usual.
+                pub fn opaque_handle<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.opaque_handle.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--opaque-handle",
+    },
+    /// `typedef` patterns that will be wrapped in a newtype if they alias a primitive type.
+    strong_typedef: RegexSet {
+        methods: {
+            regex_option! {
+                /// Mark the given `typedef` of a primitive type as a strong `typedef`.
+                ///
+                /// The `typedef` is generated as a `#[repr(transparent)]` newtype implementing
+                /// `Deref` and `DerefMut` to the aliased type, like the ones generated with
+                /// [`Builder::new_type_alias_deref`], so that it can't be mixed up with other
+                /// `typedef`s of the same type.
+                ///
+                /// Only `typedef`s whose aliased type is an integer or floating-point type, possibly
+                /// through other `typedef`s, are affected. Any other `typedef` matching this pattern
+                /// is generated as usual.
+                pub fn strong_typedef<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.strong_typedef.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--strong-typedef",
+    },
     /// The default style of code to generate for `union`s containing non-`Copy` members.
     default_non_copy_union_style: NonCopyUnionStyle {
         methods: {
@@ -687,6 +854,21 @@ options! {
         },
         as_args: "--builtins",
     },
+    /// Whether we should generate constants for built-in macros.
+    builtin_macros: bool {
+        methods: {
+            /// Set whether Rust constants should be generated for built-in macros (for example
+            /// `__STDC_VERSION__` or `__GNUC__`).
+            ///
+            /// Macros defined by clang itself or on the command line are not emitted by default.
+            /// They are also emitted if [`Builder::emit_builtins`] is used.
+            pub fn generate_builtin_macros(mut self, doit: bool) -> Self {
+                self.options.builtin_macros = doit;
+                self
+            }
+        },
+        as_args: "--builtin-macros",
+    },
     /// Whether we should dump the Clang AST for debugging purposes.
     emit_ast: bool {
         methods: {
@@ -765,6 +947,50 @@ options! {
         },
         as_args: "--enable-function-attribute-detection",
     },
+    /// Whether we should detect the `pure`, `const`, `malloc` and `returns_nonnull` attributes
+    /// in functions.
+    detect_function_attributes: bool {
+        methods: {
+            /// Detect the `pure`, `const`, `malloc` and `returns_nonnull` attributes on C
+            /// functions.
+            ///
+            /// This enables the following features:
+            /// - Add a note to the documentation of Rust functions whose C counterparts are marked
+            /// with any of these attributes, as Rust has no equivalent for them.
+            /// These notes are only added if [`Builder::generate_comments`] is enabled.
+            /// - Use `NonNull<T>` as the return type for Rust functions returning pointers whose C
+            /// counterparts are marked as `returns_nonnull`.
+            ///
+            /// As this changes the signature of the generated functions, it is independent of
+            /// [`Builder::enable_function_attribute_detection`] and disabled by default.
+            pub fn detect_function_attributes(mut self, doit: bool) -> Self {
+                self.options.detect_function_attributes = doit;
+                self
+            }
+        },
+        as_args: "--detect-function-attributes",
+    },
+    /// Whether we should add `#[must_use]` to the items marked as `warn_unused_result` or
+    /// `[[nodiscard]]`.
+    respect_nodiscard: bool {
+        methods: {
+            /// Set whether to add `#[must_use]` to the Rust items whose C or C++ counterparts are
+            /// marked as `__attribute__((warn_unused_result))` or `[[nodiscard]]`.
+            ///
+            /// This applies to functions, as with
+            /// [`Builder::enable_function_attribute_detection`] but without the detection of the
+            /// other attributes, and to types, as with [`Builder::must_use_type`]. Functions
+            /// returning such a type are marked as `#[must_use]` as well. The attribute on
+            /// functions also requires that the Rust target version supports it.
+            ///
+            /// This option is disabled by default.
+            pub fn respect_nodiscard(mut self, doit: bool) -> Self {
+                self.options.respect_nodiscard = doit;
+                self
+            }
+        },
+        as_args: "--respect-nodiscard",
+    },
     /// Whether we should avoid mangling names with namespaces.
     disable_name_namespacing: bool {
         methods: {
@@ -810,6 +1036,27 @@ options! {
         },
         as_args: "--disable-nested-struct-naming",
     },
+    /// Whether to name anonymous types after their structure.
+    structural_anon_type_names: bool {
+        methods: {
+            /// Set whether anonymous types should be named after their structure.
+            ///
+            /// Anonymous `struct`s, `union`s and `enum`s are named `_bindgen_ty_N` by default,
+            /// where `N` is a counter that depends on the order in which declarations are
+            /// traversed, which may change across `libclang` versions.
+            ///
+            /// When this option is enabled, `N` is replaced by a hash of the fields of the type
+            /// and their types instead, so the same header produces the same names regardless of
+            /// the traversal order.
+            ///
+            /// This option is disabled by default.
+            pub fn structural_anon_type_names(mut self, doit: bool) -> Self {
+                self.options.structural_anon_type_names = doit;
+                self
+            }
+        },
+        as_args: "--structural-anon-type-names",
+    },
     /// Whether we should avoid embedding version identifiers into source code.
     disable_header_comment: bool {
         methods: {
@@ -824,6 +1071,23 @@ options! {
         },
         as_args: "--disable-header-comment",
     },
+    /// Whether we should emit a banner listing the input headers and the equivalent command line.
+    emit_header_banner: bool {
+        methods: {
+            /// Set whether a banner should be inserted at the top of the generated bindings.
+            ///
+            /// The banner is a comment listing the `bindgen` version, the input headers and the
+            /// command line equivalent to the options used to generate the bindings. It doesn't
+            /// contain anything that changes between runs with the same options, like timestamps.
+            ///
+            /// This banner is not inserted by default.
+            pub fn emit_header_banner(mut self, doit: bool) -> Self {
+                self.options.emit_header_banner = doit;
+                self
+            }
+        },
+        as_args: "--emit-header-banner",
+    },
     /// Whether we should generate layout tests for generated `struct`s.
     layout_tests: bool {
         default: true,
@@ -838,6 +1102,128 @@ options! {
         },
         as_args: |value, args| (!value).as_args(args, "--no-layout-tests"),
     },
+    /// The name of the module the layout tests are grouped in, if any.
+    layout_tests_in_module: Option<String> {
+        methods: {
+            /// Set the name of the `#[cfg(test)]` module the layout tests are grouped in.
+            ///
+            /// The layout tests of every generated module are emitted in a private child module
+            /// with this name, which refers to the tested types through `use super::*;`, so they
+            /// are only compiled in test builds.
+            ///
+            /// The layout tests are emitted alongside the types they check if `None` is passed to
+            /// this method or if this method is not called at all.
+            pub fn layout_tests_in_module(mut self, name: Option<String>) -> Self {
+                self.options.layout_tests_in_module = name;
+                self
+            }
+        },
+        as_args: "--layout-tests-in-module",
+    },
+    /// Whether we should generate a function checking the layout of the generated types with
+    /// `debug_assert_eq!`.
+    layout_debug_asserts: bool {
+        methods: {
+            /// Set whether a `bindgen_debug_assert_layouts` function should be generated.
+            ///
+            /// This function checks the size, alignment and field offsets of the generated types
+            /// using `debug_assert_eq!`, so the checks can be run at startup in debug builds
+            /// without a separate test run. They are compiled out when debug assertions are
+            /// disabled.
+            ///
+            /// This option is disabled by default.
+            pub fn layout_debug_asserts(mut self, doit: bool) -> Self {
+                self.options.layout_debug_asserts = doit;
+                self
+            }
+        },
+        as_args: "--layout-debug-asserts",
+    },
+    /// Whether we should generate a function returning the first mismatch in the layout of the
+    /// generated types.
+    layout_verification_fn: bool {
+        methods: {
+            /// Set whether a `verify_layouts` function should be generated.
+            ///
+            /// This function checks the size, alignment and field offsets of the generated types
+            /// and returns a `LayoutError` describing the first mismatch instead of panicking, so
+            /// a program can check the layouts at startup and report a mismatch gracefully.
+            ///
+            /// This option is disabled by default.
+            pub fn layout_verification_fn(mut self, doit: bool) -> Self {
+                self.options.layout_verification_fn = doit;
+                self
+            }
+        },
+        as_args: "--layout-verification-fn",
+    },
+    /// Whether to group the generated items in a module per header.
+    modules_per_header: bool {
+        methods: {
+            /// Set whether to group the generated items in a module per header they are
+            /// declared in, named after the header without its extension, e.g. `foo` for
+            /// `include/foo.h`. The name gets a `_h` suffix if an item has the same name, e.g.
+            /// `stat_h` for `sys/stat.h`, which declares `struct stat`.
+            ///
+            /// This gives a browsable module tree for bindings generated from many headers. All
+            /// the items are re-exported at the root as well, so they can still be used as if
+            /// they were not grouped. The items without a header, e.g. the template
+            /// instantiations, are not grouped.
+            ///
+            /// This option has no effect when [`Builder::enable_cxx_namespaces`] is enabled.
+            ///
+            /// The generated items are not grouped by default.
+            pub fn modules_per_header(mut self, doit: bool) -> Self {
+                self.options.modules_per_header = doit;
+                self
+            }
+        },
+        as_args: "--modules-per-header",
+    },
+    /// Whether to document the layout of the generated types.
+    document_layout: bool {
+        methods: {
+            /// Set whether to append the layout of each generated `struct` and `union` to its
+            /// documentation, that is, its size, its alignment and the offset of each of its
+            /// fields.
+            ///
+            /// The layout is the one checked by the layout tests, which can be useful when
+            /// auditing or reverse-engineering the bindings.
+            ///
+            /// The layout is not documented by default.
+            pub fn document_layout(mut self, doit: bool) -> Self {
+                self.options.document_layout = doit;
+                self
+            }
+        },
+        as_args: "--document-layout",
+    },
+    /// Whether to generate accessors performing volatile reads and writes for `volatile` fields.
+    volatile_accessors: bool {
+        methods: {
+            /// Set whether accessors performing volatile reads and writes should be generated for
+            /// `volatile` fields.
+            ///
+            /// This option is disabled by default.
+            ///
+            /// When enabled, the `volatile` fields of `struct`s are made private, and a getter
+            /// reading the field with [`std::ptr::read_volatile`] is generated for each one of
+            /// them, along with a `set_<field>` setter writing the field with
+            /// [`std::ptr::write_volatile`] unless the field is also `const`. This is useful for
+            /// memory mapped hardware registers, whose accesses must not be elided or reordered by
+            /// the compiler.
+            ///
+            /// The `struct`s with such fields, and the types holding them, don't derive or
+            /// implement `Debug`, `Copy`, `Clone`, `Hash` or the comparison traits, whose implementations
+            /// would read the fields non-volatilely. Fields of `union`s and of packed `struct`s
+            /// are left as is.
+            pub fn volatile_accessors(mut self, doit: bool) -> Self {
+                self.options.volatile_accessors = doit;
+                self
+            }
+        },
+        as_args: "--volatile-accessors",
+    },
     /// Whether we should implement `Debug` for types that cannot derive it.
     impl_debug: bool {
         methods: {
@@ -865,6 +1251,61 @@ options! {
         },
         as_args: "--impl-partialeq",
     },
+    /// Whether we should implement `Hash` for types that cannot derive it.
+    impl_hash: bool {
+        methods: {
+            /// Set whether `Hash` should be implemented for structs that cannot derive it.
+            ///
+            /// The implementation hashes the fields that are `Hash`, including bitfields through
+            /// their backing storage, and skips the others, e.g. floating point fields. See
+            /// [`Builder::impl_hash_float_bits`] to hash floating point fields too.
+            ///
+            /// This option is disabled by default.
+            pub fn impl_hash(mut self, doit: bool) -> Self {
+                self.options.impl_hash = doit;
+                self
+            }
+        },
+        as_args: "--impl-hash",
+    },
+    /// Whether the `Hash` implementations should hash the bit patterns of floating point
+    /// fields.
+    impl_hash_float_bits: bool {
+        methods: {
+            /// Set whether the `Hash` implementations generated with [`Builder::impl_hash`] should
+            /// hash the bit patterns of `f32` and `f64` fields, instead of skipping them.
+            ///
+            /// Note that this breaks the requirement that equal values have equal hashes
+            /// in the presence of floats that are equal but have different bit patterns, such as
+            /// `0.0` and `-0.0`.
+            ///
+            /// This option is disabled by default.
+            pub fn impl_hash_float_bits(mut self, doit: bool) -> Self {
+                self.options.impl_hash_float_bits = doit;
+                self
+            }
+        },
+        as_args: "--impl-hash-float-bits",
+    },
+    /// Whether we should implement `Clone` for types that cannot derive `Copy`.
+    impl_clone_fallback: bool {
+        methods: {
+            /// Set whether `Clone` should be implemented for types that cannot derive `Copy`.
+            ///
+            /// `Clone` is usually derived along with `Copy`, so types for which `Copy` cannot be
+            /// derived, like the ones matched by [`Builder::no_copy`], are not `Clone` either.
+            /// When this option is enabled, a manual implementation of `Clone` that clones the
+            /// fields one by one is generated for such structs, as long as all their fields are
+            /// `Clone`.
+            ///
+            /// This option is disabled by default.
+            pub fn impl_clone_fallback(mut self, doit: bool) -> Self {
+                self.options.impl_clone_fallback = doit;
+                self
+            }
+        },
+        as_args: "--impl-clone-fallback",
+    },
     /// Whether we should derive `Copy` when possible.
     derive_copy: bool {
         default: true,
@@ -1013,6 +1454,38 @@ options! {
         },
         as_args: "--with-derive-eq",
     },
+    /// The order in which the derives of a type should be sorted.
+    derive_order: Vec<Box<str>> {
+        methods: {
+            /// Set the order in which the traits derived for a type are listed in its
+            /// `#[derive(...)]` attribute.
+            ///
+            /// The derives that aren't part of this order, e.g. the ones added with
+            /// [`ParseCallbacks::add_derives`], are listed after the ones that are, in the order
+            /// they would be listed in otherwise.
+            ///
+            /// By default, the derives are listed in a fixed order: `Debug`, `Default`, `Copy`,
+            /// `Clone`, `Hash`, `PartialOrd`, `Ord`, `PartialEq` and `Eq`, followed by the derives
+            /// added with annotations and then the ones added with
+            /// [`ParseCallbacks::add_derives`].
+            pub fn derive_order<I: IntoIterator>(mut self, derives: I) -> Builder
+            where
+                I::Item: AsRef<str>,
+            {
+                self.options.derive_order = derives
+                    .into_iter()
+                    .map(|derive| derive.as_ref().trim().into())
+                    .collect();
+                self
+            }
+        },
+        as_args: |derive_order, args| {
+            if !derive_order.is_empty() {
+                args.push("--derive-order".to_owned());
+                args.push(derive_order.join(","));
+            }
+        },
+    },
     /// Whether we should use `core` instead of `std`.
     ///
     /// If this option is enabled and the Rust target version is greater than 1.64, the prefix for
@@ -1044,6 +1517,53 @@ options! {
         },
         as_args: "--ctypes-prefix",
     },
+    /// The Rust types `float` and `double` are mapped to, instead of `f32` and `f64`.
+    mapped_float_types: Option<(String, String)> {
+        methods: {
+            /// Use the given Rust types for the C `float` and `double` types instead of `f32` and
+            /// `f64`.
+            ///
+            /// The types are used as is in every module, so they should be given as paths that can
+            /// be used anywhere, like `crate::float::Real32`, and should implement the traits
+            /// derived for the types containing them. The layout tests check that the types are
+            /// 4 and 8 bytes large. Floating point constants are still generated as `f32` and
+            /// `f64`. The option is ignored with a warning if either path isn't a valid type.
+            pub fn map_float<T: Into<String>, U: Into<String>>(
+                mut self,
+                f32_path: T,
+                f64_path: U,
+            ) -> Builder {
+                self.options.mapped_float_types = Some((f32_path.into(), f64_path.into()));
+                self
+            }
+        },
+        as_args: |mapped_float_types, args| {
+            if let Some((float, double)) = mapped_float_types {
+                args.push("--map-float".to_owned());
+                args.push(float.clone());
+                args.push(double.clone());
+            }
+        },
+    },
+    /// Whether to emit a module aliasing the C platform-specific types from either `libc` or
+    /// `core::ffi`, depending on the `libc` feature.
+    libc_ctypes_shim: bool {
+        methods: {
+            /// Set whether the C platform-specific types should be taken from a private module
+            /// that re-exports them from either `libc` or `core::ffi`.
+            ///
+            /// The generated `__bindgen_ctypes` module uses `libc` if the `libc` feature of the
+            /// crate including the bindings is enabled, and `core::ffi` otherwise. This option is
+            /// ignored if [`Builder::ctypes_prefix`] is used.
+            ///
+            /// This option is disabled by default.
+            pub fn libc_ctypes_shim(mut self, doit: bool) -> Self {
+                self.options.libc_ctypes_shim = doit;
+                self
+            }
+        },
+        as_args: "--libc-ctypes-shim",
+    },
     /// The prefix for anonymous fields.
     anon_fields_prefix: String {
         default: DEFAULT_ANON_FIELDS_PREFIX.into(),
@@ -1076,6 +1596,95 @@ options! {
             }
         },
     },
+    /// The name of the type of the bitfield allocation units.
+    bitfield_unit_name: Option<String> {
+        methods: {
+            /// Use the given name for the type of the bitfield allocation units of the generated
+            /// structs.
+            ///
+            /// This type is emitted once along with the bindings if any of the generated types has
+            /// bitfields, so giving it a different name in each bindings avoids a collision when
+            /// several of them are included in the same module.
+            ///
+            /// The default name is `__BindgenBitfieldUnit`.
+            pub fn bitfield_unit_name<T: Into<String>>(mut self, name: T) -> Builder {
+                self.options.bitfield_unit_name = Some(name.into());
+                self
+            }
+        },
+        as_args: "--bitfield-unit-name",
+    },
+    /// The visibility of the type of the bitfield allocation units.
+    bitfield_unit_visibility: FieldVisibilityKind {
+        methods: {
+            /// Set the visibility of the type of the bitfield allocation units of the generated
+            /// structs, see [`Builder::bitfield_unit_name`].
+            ///
+            /// The bitfield allocation unit fields and their `new_bitfield_N` constructors are
+            /// given the same visibility if they would be more visible than the type.
+            ///
+            /// The type is public by default.
+            pub fn bitfield_unit_visibility(
+                mut self,
+                visibility: FieldVisibilityKind,
+            ) -> Self {
+                self.options.bitfield_unit_visibility = visibility;
+                self
+            }
+        },
+        as_args: |visibility, args| {
+            if *visibility != Default::default() {
+                args.push("--bitfield-unit-visibility".to_owned());
+                args.push(visibility.to_string());
+            }
+        },
+    },
+    /// The maximum depth of nested type declarations to parse.
+    max_recursion_depth: usize {
+        default: 256,
+        methods: {
+            /// Set the maximum depth of nested type declarations that `bindgen` will parse.
+            ///
+            /// Types nested deeper than this limit are generated as opaque blobs and an error
+            /// diagnostic is emitted for them, instead of overflowing the stack.
+            ///
+            /// The maximum depth is 256 by default.
+            pub fn max_recursion_depth(mut self, depth: usize) -> Self {
+                self.options.max_recursion_depth = depth;
+                self
+            }
+        },
+        as_args: |depth, args| {
+            if *depth != 256 {
+                args.push("--max-recursion-depth".to_owned());
+                args.push(depth.to_string());
+            }
+        },
+    },
+    /// The maximum number of instantiations of a single template before a warning is emitted.
+    max_monomorphization_warning: Option<usize> {
+        methods: {
+            /// Emit a warning for each template that is instantiated with more than `limit`
+            /// different sets of template arguments.
+            ///
+            /// Every instantiation of a template adds items to the generated bindings, e.g. its
+            /// layout tests, so instantiating a template over many types by accident can bloat
+            /// the bindings and their compile times. The warning names the template and the
+            /// number of its instantiations.
+            ///
+            /// No warning is emitted by default.
+            pub fn max_monomorphization_warning(mut self, limit: usize) -> Self {
+                self.options.max_monomorphization_warning = Some(limit);
+                self
+            }
+        },
+        as_args: |limit, args| {
+            if let Some(limit) = limit {
+                args.push("--max-monomorphization-warning".to_owned());
+                args.push(limit.to_string());
+            }
+        },
+    },
     /// Whether to measure the time for each one of the `bindgen` phases.
     time_phases: bool {
         methods: {
@@ -1232,23 +1841,86 @@ options! {
         // This field is handled specially inside the macro.
         as_args: ignore,
     },
-    /// Tuples of unsaved file contents of the form (name, contents).
-    input_header_contents: Vec<(Box<str>, Box<str>)> {
+    /// The flags used by libclang to parse the translation unit.
+    translation_unit_flags: TranslationUnitFlags {
         methods: {
-            /// Add `contents` as an input C/C++ header named `name`.
+            /// Set the flags used by libclang to parse the translation unit.
             ///
-            /// This can be used to inject additional C/C++ code as an input without having to
-            /// create additional header files.
-            pub fn header_contents(mut self, name: &str, contents: &str) -> Builder {
-                // Apparently clang relies on having virtual FS correspondent to
-                // the real one, so we need absolute paths here
-                let absolute_path = env::current_dir()
-                    .expect("Cannot retrieve current directory")
-                    .join(name)
-                    .to_str()
-                    .expect("Cannot convert current directory name to string")
-                    .into();
-                self.options
+            /// These flags replace the default ones, so
+            /// [`TranslationUnitFlags::DETAILED_PREPROCESSING_RECORD`] should be kept unless macros
+            /// are meant to be ignored.
+            ///
+            /// By default, only [`TranslationUnitFlags::DETAILED_PREPROCESSING_RECORD`] is set.
+            pub fn translation_unit_flags(mut self, flags: TranslationUnitFlags) -> Self {
+                self.options.translation_unit_flags = flags;
+                self
+            }
+        },
+        as_args: |flags, args| {
+            if *flags != Default::default() {
+                args.push("--translation-unit-flags".to_owned());
+                args.push(flags.to_string());
+            }
+        },
+    },
+    /// The target triple the input headers are parsed for.
+    clang_target: Option<String> {
+        methods: {
+            /// Set the target triple Clang parses the input headers for.
+            ///
+            /// This takes precedence over any `--target` argument passed with
+            /// [`Builder::clang_arg`], and determines the layout of the generated types. It is
+            /// passed to Clang after the other arguments, but isn't added to them.
+            ///
+            /// If this method is not called, the target is taken from the Clang arguments, the
+            /// `TARGET` environment variable set by Cargo, or the host, in that order.
+            pub fn clang_target<T: Into<String>>(mut self, triple: T) -> Builder {
+                self.options.clang_target = Some(triple.into());
+                self
+            }
+        },
+        as_args: "--clang-target",
+    },
+    /// The target triple used for the choices that the layouts reported by Clang don't determine.
+    output_target: Option<String> {
+        methods: {
+            /// Set the target triple used for the few choices that the layouts reported by Clang
+            /// don't determine, when it differs from the one the input headers are parsed for.
+            ///
+            /// This is not a general codegen target, it only affects:
+            ///
+            /// - whether a 16-byte `long double` is mapped to `f128`, as its representation can't
+            ///   be deduced from the layout reported by Clang, and
+            /// - which OS-specific headers are gated with [`Builder::infer_os_cfg`].
+            ///
+            /// Everything else, including the layouts, the layout tests and the `c_*` types used
+            /// for C integer types, follows the target the headers are parsed for.
+            ///
+            /// If this method is not called, the target the headers are parsed for is used.
+            pub fn output_target<T: Into<String>>(mut self, triple: T) -> Builder {
+                self.options.output_target = Some(triple.into());
+                self
+            }
+        },
+        as_args: "--output-target",
+    },
+    /// Tuples of unsaved file contents of the form (name, contents).
+    input_header_contents: Vec<(Box<str>, Box<str>)> {
+        methods: {
+            /// Add `contents` as an input C/C++ header named `name`.
+            ///
+            /// This can be used to inject additional C/C++ code as an input without having to
+            /// create additional header files.
+            pub fn header_contents(mut self, name: &str, contents: &str) -> Builder {
+                // Apparently clang relies on having virtual FS correspondent to
+                // the real one, so we need absolute paths here
+                let absolute_path = env::current_dir()
+                    .expect("Cannot retrieve current directory")
+                    .join(name)
+                    .to_str()
+                    .expect("Cannot convert current directory name to string")
+                    .into();
+                self.options
                     .input_header_contents
                     .push((absolute_path, contents.into()));
                 self
@@ -1257,6 +1929,40 @@ options! {
         // Header contents cannot be added from the CLI.
         as_args: ignore,
     },
+    /// Tuples of the paths of included headers and the contents replacing them.
+    header_replacements: Vec<(Box<str>, Box<str>)> {
+        methods: {
+            /// Replace the contents of the header at `path` with `contents` while parsing.
+            ///
+            /// Unlike [`Builder::header_contents`], this doesn't add an input header: the header
+            /// is still only parsed where it is `#include`d, but Clang reads `contents` instead
+            /// of the file on disk. This can be used to work around a construct of a third-party
+            /// header that Clang can't parse, without patching the file.
+            ///
+            /// The `path` must be the one the `#include` directives resolve to. Relative paths
+            /// are relative to the current directory.
+            pub fn replace_header_content<P: AsRef<Path>>(
+                mut self,
+                path: P,
+                contents: &str,
+            ) -> Builder {
+                // Clang matches the unsaved files against the absolute paths
+                // of the included files.
+                let absolute_path = env::current_dir()
+                    .expect("Cannot retrieve current directory")
+                    .join(path)
+                    .to_str()
+                    .expect("Cannot convert header path to string")
+                    .into();
+                self.options
+                    .header_replacements
+                    .push((absolute_path, contents.into()));
+                self
+            }
+        },
+        // Header replacements cannot be added from the CLI.
+        as_args: ignore,
+    },
     /// A user-provided visitor to allow customizing different kinds of situations.
     parse_callbacks: Vec<Rc<dyn ParseCallbacks>> {
         methods: {
@@ -1276,6 +1982,30 @@ options! {
             }
         },
     },
+    /// User-provided visitors of the Clang AST.
+    ast_visitors: Vec<AstVisitor> {
+        methods: {
+            /// Add a function that is called with every declaration cursor bindgen visits while
+            /// parsing the input headers, before generating any code for it.
+            ///
+            /// This gives read access to the Clang AST, e.g. to collect information bindgen
+            /// doesn't model, like custom annotations. The visitor is called for top-level
+            /// declarations and the declarations inside namespaces, but not for the members of
+            /// types.
+            ///
+            /// This is an advanced option. The shape of the AST depends on the version of
+            /// Clang in use, so visitors may observe different cursors with different versions.
+            pub fn with_ast_visitor(
+                mut self,
+                visitor: Box<dyn Fn(&AstCursor<'_>)>,
+            ) -> Self {
+                self.options.ast_visitors.push(AstVisitor(Rc::from(visitor)));
+                self
+            }
+        },
+        // This option cannot be set from the CLI.
+        as_args: ignore,
+    },
     /// Which kind of items should we generate. We generate all of them by default.
     codegen_config: CodegenConfig {
         default: CodegenConfig::all(),
@@ -1409,6 +2139,30 @@ options! {
         },
         as_args: |value, args| (!value).as_args(args, "--no-doc-comments"),
     },
+    /// How Doxygen commands in documentation comments are translated.
+    doxygen_style: DoxygenStyle {
+        methods: {
+            /// Set how Doxygen commands in documentation comments are translated.
+            ///
+            /// With [`DoxygenStyle::Rustdoc`], `@param` and `@return` commands are collected into
+            /// `# Arguments` and `# Returns` sections, `@note` and `@warning` become blockquotes
+            /// and `@code`/`@endcode` blocks become fenced code blocks. Commands can start with
+            /// either `@` or `\`.
+            ///
+            /// This option only comes into effect if the [`Builder::generate_comments`] option
+            /// is enabled. Comments are kept verbatim by default.
+            pub fn doxygen_style(mut self, style: DoxygenStyle) -> Self {
+                self.options.doxygen_style = style;
+                self
+            }
+        },
+        as_args: |style, args| {
+            if *style != Default::default() {
+                args.push("--doxygen-style".to_owned());
+                args.push(style.to_string());
+            }
+        },
+    },
     /// Whether to generate inline functions.
     generate_inline_functions: bool {
         methods: {
@@ -1430,6 +2184,88 @@ options! {
         },
         as_args: "--generate-inline-functions",
     },
+    /// Whether to translate the body of trivial `static inline` functions into Rust.
+    translate_inline_functions: bool {
+        methods: {
+            /// Set whether to translate the body of trivial `static inline` functions into Rust.
+            ///
+            /// This option is disabled by default.
+            ///
+            /// A `static inline` function is trivial if its body is a single `return` of an
+            /// arithmetic expression over its parameters and integer literals, using the `+`, `-`,
+            /// `*`, `/`, `%`, `<<`, `>>`, `&`, `|`, `^` and `~` operators, and its parameters and
+            /// return value are all of the same integer type, no smaller than `int`. These
+            /// functions are emitted as Rust functions with the same behavior, so no symbol is
+            /// needed for them, unlike with [`Builder::generate_inline_functions`]. Overflowing
+            /// operations wrap around. Functions dividing by a constant other than a non-zero
+            /// literal are not trivial.
+            ///
+            /// Any other `static inline` function is reported and handled as if this option was
+            /// disabled instead.
+            #[cfg_attr(
+                feature = "experimental",
+                doc = "\nThis means that a wrapper is generated for it if [`Builder::wrap_static_fns`] is enabled."
+            )]
+            pub fn translate_inline_functions(mut self, doit: bool) -> Self {
+                self.options.translate_inline_functions = doit;
+                self
+            }
+        },
+        as_args: "--translate-inline-functions",
+    },
+    /// Whether to translate the function-like macros expanding to arithmetic expressions into
+    /// Rust.
+    translate_function_macros: bool {
+        methods: {
+            /// Set whether to translate the function-like macros expanding to arithmetic
+            /// expressions into Rust `const fn`s.
+            ///
+            /// This option is disabled by default.
+            ///
+            /// A function-like macro is translated if it expands to an arithmetic expression over
+            /// its parameters and integer literals, using the operators supported by
+            /// [`Builder::translate_inline_functions`], e.g. `#define SQUARE(x) ((x) * (x))`.
+            /// As the types of the arguments of a macro are only known where it is used, its
+            /// parameters and return value are all translated as `i64`s. Overflowing operations
+            /// wrap around.
+            ///
+            /// These macros are handled like functions, so, for example, they are allowlisted
+            /// with [`Builder::allowlist_function`]. Any other function-like macro, e.g. one
+            /// referring to an unknown identifier or with side effects, is reported and skipped.
+            pub fn translate_function_macros(mut self, doit: bool) -> Self {
+                self.options.translate_function_macros = doit;
+                self
+            }
+        },
+        as_args: "--translate-function-macros",
+    },
+    /// Whether to translate the function-like macros built on `_Generic` selections into Rust
+    /// traits.
+    translate_generic_macros: bool {
+        methods: {
+            /// Set whether to translate the function-like macros calling the function selected
+            /// by a C11 `_Generic` selection into Rust traits.
+            ///
+            /// This option is disabled by default.
+            ///
+            /// A macro of the form `#define cbrt(x) _Generic((x), float: cbrtf, double:
+            /// cbrt)(x)`, whose controlling expression is one of its parameters and which passes
+            /// its parameters in order to the selected function, is translated into a trait
+            /// named like the macro. The trait has a method named like the macro too, taking the
+            /// controlling parameter as `self`, and it is implemented for the type of every
+            /// association by calling the selected function.
+            ///
+            /// Only the associations with an arithmetic type, that select a generated function
+            /// whose parameters are the same as the ones of the other associations, apart from
+            /// the controlling one, are translated. The `default` association can't be
+            /// translated. Any other association or macro is reported and skipped.
+            pub fn translate_generic_macros(mut self, doit: bool) -> Self {
+                self.options.translate_generic_macros = doit;
+                self
+            }
+        },
+        as_args: "--translate-generic-macros",
+    },
     /// Whether to allowlist types recursively.
     allowlist_recursively: bool {
         default: true,
@@ -1557,6 +2393,12 @@ options! {
         methods: {
             /// Set whether to detect include paths using `clang_sys`.
             ///
+            /// The include search paths reported by the clang executable found by `clang_sys`,
+            /// which honors the `CLANG_PATH` environment variable, for the given clang arguments,
+            /// e.g. `--target` and `--sysroot`, are added as `-isystem` arguments. See
+            /// [`Builder::warn_on_include_path_detection_failure`] to be warned if they cannot be
+            /// detected.
+            ///
             /// `clang_sys` is used to detect include paths by default.
             pub fn detect_include_paths(mut self, doit: bool) -> Self {
                 self.options.detect_include_paths = doit;
@@ -1565,6 +2407,23 @@ options! {
         },
         as_args: |value, args| (!value).as_args(args, "--no-include-path-detection"),
     },
+    /// Whether to warn when the system include paths cannot be detected.
+    warn_on_include_path_detection_failure: bool {
+        methods: {
+            /// Set whether to warn when the system include paths cannot be detected.
+            ///
+            /// When enabled, a warning is emitted if no clang executable is found or it doesn't
+            /// report any include search paths while [`Builder::detect_include_paths`] is set.
+            /// Otherwise bindgen silently continues with the search paths known to libclang.
+            ///
+            /// This option is disabled by default.
+            pub fn warn_on_include_path_detection_failure(mut self, doit: bool) -> Self {
+                self.options.warn_on_include_path_detection_failure = doit;
+                self
+            }
+        },
+        as_args: "--warn-on-include-path-detection-failure",
+    },
     /// Whether we should try to fit macro constants into types smaller than `u32` and `i32`.
     fit_macro_constants: bool {
         methods: {
@@ -1593,11 +2452,78 @@ options! {
         },
         as_args: |value, args| (!value).as_args(args, "--no-prepend-enum-name"),
     },
+    /// Whether to emit a global constant for each variant of the `enum`s whose variants aren't
+    /// global constants already.
+    flat_enum_constants: bool {
+        methods: {
+            /// Set whether to emit a constant named after each variant of the named `enum`s
+            /// generated as Rust `enum`s, newtypes or modules, alongside them.
+            ///
+            /// For example, `pub const FOO_BAR: Foo = Foo::FOO_BAR;` is emitted for the `FOO_BAR`
+            /// variant of a Rust `enum` named `Foo`, so that code expecting the variants of the C
+            /// `enum` as global constants keeps working. If another `enum` of the same module
+            /// has a variant with the same name, no constant is emitted for it and a warning is
+            /// emitted instead.
+            ///
+            /// These constants are not emitted by default.
+            pub fn flat_enum_constants(mut self, doit: bool) -> Self {
+                self.options.flat_enum_constants = doit;
+                self
+            }
+        },
+        as_args: "--flat-enum-constants",
+    },
+    /// Whether to emit an `ALL` associated constant listing the variants of the Rust `enum`s.
+    enum_variants_slice: bool {
+        methods: {
+            /// Set whether to emit a `pub const ALL: &'static [Self]` associated constant
+            /// listing the variants of each `enum` generated as a Rust `enum`, in declaration
+            /// order.
+            ///
+            /// This is useful to iterate over the variants, e.g. in tests. Variants with the same
+            /// value as a previous one are only listed once, as they are not Rust variants. The
+            /// `enum`s generated as bitfields, newtypes, modules or constants are not affected.
+            ///
+            /// This constant is not emitted by default.
+            pub fn enum_variants_slice(mut self, doit: bool) -> Self {
+                self.options.enum_variants_slice = doit;
+                self
+            }
+        },
+        as_args: "--enum-variants-slice",
+    },
+    /// Whether to emit a `name` method returning the name of the C enumerator of the value of the
+    /// `enum`s.
+    enum_name_fn: bool {
+        methods: {
+            /// Set whether to emit a `pub fn name(&self) -> &'static str` method returning the
+            /// name of the C enumerator of the value of each `enum` generated as a Rust `enum` or
+            /// a newtype, e.g. for logging.
+            ///
+            /// Unlike the derived `Debug` implementation, this method returns the name of the C
+            /// enumerator, without the mangling of the variants or constants. If several
+            /// enumerators have the same value, the name of the first one is returned. For
+            /// values without an enumerator, e.g. the ones of a newtype or of the variant added
+            /// with [`Builder::with_enum_unknown_variant`], `"<unknown>"` is returned. The `enum`s
+            /// generated as bitfields, modules or constants are not affected.
+            ///
+            /// This method is not emitted by default.
+            pub fn enum_name_fn(mut self, doit: bool) -> Self {
+                self.options.enum_name_fn = doit;
+                self
+            }
+        },
+        as_args: "--enum-name-fn",
+    },
     /// Version of the Rust compiler to target.
     rust_target: RustTarget {
         methods: {
             /// Specify the Rust target version.
             ///
+            /// Generating the bindings fails if an enabled option needs a newer target, e.g.
+            /// overriding the ABI of a function with [`Abi::CUnwind`], which is only supported
+            /// since Rust 1.71.
+            ///
             /// The default target is the latest stable Rust version.
             pub fn rust_target(mut self, rust_target: RustTarget) -> Self {
                 self.options.set_rust_target(rust_target);
@@ -1609,6 +2535,28 @@ options! {
             args.push(rust_target.to_string());
         },
     },
+    /// Edition of the crate the bindings are used in.
+    rust_edition: RustEdition {
+        methods: {
+            /// Specify the Rust edition of the crate the bindings are used in.
+            ///
+            /// Newer editions let the bindings rely on the items of a larger prelude, e.g.
+            /// `TryFrom` on Rust 2021, instead of spelling out their paths. The edition must be
+            /// supported by the [`Builder::rust_target`], or generating the bindings fails.
+            ///
+            /// The default edition is Rust 2015.
+            pub fn rust_edition(mut self, rust_edition: RustEdition) -> Self {
+                self.options.rust_edition = rust_edition;
+                self
+            }
+        },
+        as_args: |rust_edition, args| {
+            if *rust_edition != RustEdition::default() {
+                args.push("--rust-edition".to_owned());
+                args.push(rust_edition.to_string());
+            }
+        },
+    },
     /// Features to be enabled. They are derived from `rust_target`.
     rust_features: RustFeatures {
         default: RustTarget::default().into(),
@@ -1616,6 +2564,79 @@ options! {
         // This field cannot be set from the CLI,
         as_args: ignore,
     },
+    /// The Cargo feature gating the nightly-only constructs in the generated bindings.
+    nightly_cfg_feature: Option<String> {
+        methods: {
+            /// Emit nightly-only constructs behind `#[cfg(feature = "...")]` using the given Cargo
+            /// feature, together with a fallback for stable Rust behind the negated `cfg`.
+            ///
+            /// This way the same bindings can use the better representation when the feature is
+            /// enabled on a nightly compiler and still compile on stable Rust. Currently this is
+            /// only done for fields of IEEE 754 binary128 floating point types, which use `f128`
+            /// behind the feature and an opaque blob otherwise. The crate including the bindings
+            /// must enable the `f128` language feature itself, for example with
+            /// `#![cfg_attr(feature = "nightly", feature(f128))]`.
+            ///
+            /// This has no effect if the Rust target is `nightly`, as the nightly-only constructs
+            /// are always used then.
+            pub fn nightly_cfg_feature<T: Into<String>>(mut self, feature: T) -> Self {
+                self.options.nightly_cfg_feature = Some(feature.into());
+                self
+            }
+        },
+        as_args: "--nightly-cfg-feature",
+    },
+    /// Whether to emit `#[cfg_attr(docsrs, doc(cfg(...)))]` alongside the generated `cfg`s.
+    emit_doc_cfg: bool {
+        methods: {
+            /// Set whether to emit `#[cfg_attr(docsrs, doc(cfg(...)))]` alongside every
+            /// `#[cfg(...)]` emitted by bindgen, e.g. the one guarding the items merged by
+            /// [`Bindings::merge_by_pointer_width`] or the fields behind the
+            /// [`Builder::nightly_cfg_feature`].
+            ///
+            /// This way the documentation built on docs.rs shows on which configurations the items
+            /// are available. The crate including the bindings must enable the `doc_cfg` language
+            /// feature itself, for example with `#![cfg_attr(docsrs, feature(doc_cfg))]`.
+            ///
+            /// This option is disabled by default.
+            ///
+            /// [`Bindings::merge_by_pointer_width`]: crate::Bindings::merge_by_pointer_width
+            pub fn emit_doc_cfg(mut self, doit: bool) -> Self {
+                self.options.emit_doc_cfg = doit;
+                self
+            }
+        },
+        as_args: "--emit-doc-cfg",
+    },
+    /// Whether to guard the items declared in OS-specific system headers with
+    /// `#[cfg(target_os = "...")]`.
+    infer_os_cfg: bool {
+        methods: {
+            /// Set whether to guard the items declared in OS-specific system headers with
+            /// `#[cfg(target_os = "...")]`, inferring their OS from the path of the header.
+            ///
+            /// The known OS-specific paths are the Linux kernel and multiarch headers, e.g.
+            /// `/usr/include/linux` and `/usr/include/x86_64-linux-gnu`, the Apple SDKs and
+            /// frameworks, e.g. `MacOSX.sdk` for `macos` and `iPhoneOS.sdk` for `ios`, and the
+            /// Windows Kits. The items of a header are not guarded, and a warning is emitted, if
+            /// its path is specific to several OSes or to another one than the OS of the target
+            /// the bindings are generated for, e.g. the Linux kernel headers of the Android NDK.
+            ///
+            /// The items referring to guarded items, e.g. a struct with a field whose type is
+            /// declared in the Linux kernel headers or a function taking it, are guarded the same
+            /// way, as they can't be compiled for other OSes either. Note that this includes the
+            /// items using the glibc types declared in the multiarch headers, e.g. `uint32_t`. An
+            /// item referring to items specific to different OSes is only guarded by the first
+            /// one, and a warning is emitted.
+            ///
+            /// This option is disabled by default.
+            pub fn infer_os_cfg(mut self, doit: bool) -> Self {
+                self.options.infer_os_cfg = doit;
+                self
+            }
+        },
+        as_args: "--infer-os-cfg",
+    },
     /// Enable support for native Rust unions if they are supported.
     untagged_union: bool {
         default: true,
@@ -1700,6 +2721,28 @@ options! {
             }
         },
     },
+    /// A user-provided formatter that overrides the `formatter` option.
+    custom_formatter: Option<CustomFormatter> {
+        methods: {
+            /// Set a custom function to format the generated bindings.
+            ///
+            /// The function receives the unformatted source code of the bindings and returns
+            /// the formatted source code. It overrides the tool set with the
+            /// [`Builder::formatter`] method, which can still be used to select one of the
+            /// built-in formatters if the custom formatter is not set.
+            ///
+            /// If the function returns an error, the unformatted bindings are written instead.
+            pub fn with_formatter(
+                mut self,
+                formatter: Box<dyn Fn(String) -> io::Result<String>>,
+            ) -> Self {
+                self.options.custom_formatter = Some(CustomFormatter(Rc::from(formatter)));
+                self
+            }
+        },
+        // This option cannot be set from the CLI.
+        as_args: ignore,
+    },
     /// The absolute path to the `rustfmt` configuration file.
     rustfmt_configuration_file: Option<PathBuf> {
         methods: {
@@ -1796,6 +2839,109 @@ options! {
         },
         as_args: "--must-use-type",
     },
+    /// Struct fields that should get a `CStr` accessor.
+    cstr_accessors: RegexSet {
+        methods: {
+            regex_option! {
+                /// Generate an accessor returning a `CStr` for the given `char` pointer field.
+                ///
+                /// Fields are matched by their path in the form `Struct::field`. For each matching
+                /// field of type `*const c_char` or `*mut c_char`, an `unsafe fn <field>_cstr(&self)
+                /// -> Option<&CStr>` method is generated, returning `None` for null pointers.
+                pub fn cstr_accessor<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.cstr_accessors.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--cstr-accessor",
+    },
+    /// Types that should not implement `Unpin`.
+    pinned_types: RegexSet {
+        methods: {
+            regex_option! {
+                /// Add a `PhantomPinned` field to the given type, so it doesn't implement `Unpin`.
+                ///
+                /// This is meant for types that must not be moved once constructed, like C++
+                /// classes holding pointers to themselves. The field is zero-sized, so it doesn't
+                /// change the layout of the type. The given type doesn't derive `Copy` and
+                /// `Clone` either, as copying it would move it.
+                pub fn mark_pinned<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.pinned_types.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--mark-pinned",
+    },
+    /// Types that should implement `AsRef` and `AsMut` for the type of their first field.
+    first_field_as_ref_types: RegexSet {
+        methods: {
+            regex_option! {
+                /// Implement `AsRef` and `AsMut` for the type of the first field of the given
+                /// `struct`, for the C idiom of inheritance through the first member, e.g.
+                /// `struct Derived { struct Base base; ... }`.
+                ///
+                /// This gives a zero-cost upcast from `&Derived` to `&Base`, as the first field
+                /// of a `#[repr(C)]` struct is at offset zero. The implementations are only
+                /// generated if the first field is a `struct` at offset zero. A warning is emitted
+                /// for other matching types.
+                pub fn first_field_as_ref<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.first_field_as_ref_types.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--first-field-as-ref",
+    },
+    /// Types that should get methods parsing them from bytes.
+    from_bytes_types: RegexSet {
+        methods: {
+            regex_option! {
+                /// Generate `from_bytes` and `read_from` methods parsing the given type from a
+                /// byte slice in the native byte order.
+                ///
+                /// `from_bytes(&[u8]) -> Option<&Self>` reinterprets the bytes without copying,
+                /// and returns `None` if they are too short or misaligned.
+                /// `read_from(&[u8]) -> Option<Self>` copies them instead, so they don't need to
+                /// be aligned.
+                ///
+                /// The methods are only generated for plain old data, that is, types whose fields
+                /// are all integers, floating point numbers, or arrays and structs of them. A
+                /// warning is emitted for other matching types.
+                pub fn derive_from_bytes<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.from_bytes_types.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--derive-from-bytes",
+    },
+    /// Types that should derive `arbitrary::Arbitrary`.
+    arbitrary_types: RegexSet {
+        methods: {
+            regex_option! {
+                /// Derive `arbitrary::Arbitrary` for the given `struct` or `enum`, e.g. to build
+                /// instances of it in fuzzing harnesses.
+                ///
+                /// The derive is emitted as
+                /// `#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]`, so the
+                /// `arbitrary` crate only needs to be an optional dependency enabled by the
+                /// `arbitrary` feature of the crate including the bindings. The types of the
+                /// fields must implement `Arbitrary` as well, so the `struct`s and `enum`s used in
+                /// matching types should match too.
+                ///
+                /// `struct`s containing raw pointers, bitfields, flexible array members or types
+                /// that don't derive `Arbitrary`, and `union`s, are generated without the derive
+                /// and a warning is emitted for them.
+                pub fn derive_arbitrary<T: AsRef<str>>(mut self, arg: T) -> Builder {
+                    self.options.arbitrary_types.insert(arg);
+                    self
+                }
+            }
+        },
+        as_args: "--derive-arbitrary",
+    },
     /// Whether C arrays should be regular pointers in rust or array pointers
     array_pointers_in_arguments: bool {
         methods: {
@@ -1909,6 +3055,42 @@ options! {
         },
         as_args: "--c-naming",
     },
+    /// The regular expressions replaced in the names of the generated items, and their
+    /// replacements.
+    rename_patterns: Vec<(Box<str>, Box<str>)> {
+        methods: {
+            /// Replace the matches of the given regular expression in the names of the generated
+            /// items with the given replacement.
+            ///
+            /// The replacement can refer to the capture groups of the regular expression, e.g.
+            /// `rename_pattern("^lib_(.*)$", "${1}")` strips the `lib_` prefix of every name.
+            ///
+            /// The names are renamed after [`ParseCallbacks::item_name`], and before they are
+            /// escaped if they are Rust keywords. Renaming an item updates all the references
+            /// to it as well, while the original names are still used to allowlist or blocklist
+            /// items and to link to the functions and variables. The patterns are applied in the
+            /// order in which they were added.
+            ///
+            /// The items whose different names are renamed to the same name are reported.
+            pub fn rename_pattern<T: AsRef<str>>(
+                mut self,
+                regex: T,
+                replacement: T,
+            ) -> Builder {
+                self.options
+                    .rename_patterns
+                    .push((regex.as_ref().into(), replacement.as_ref().into()));
+                self
+            }
+        },
+        as_args: |rename_patterns, args| {
+            for (regex, replacement) in rename_patterns {
+                args.push("--rename-pattern".to_owned());
+                args.push(regex.clone().into());
+                args.push(replacement.clone().into());
+            }
+        },
+    },
     /// Whether to always emit explicit padding fields.
     force_explicit_padding: bool {
         methods: {
@@ -1968,6 +3150,20 @@ options! {
         },
         as_args: "--merge-extern-blocks",
     },
+    /// Whether to deduplicate structurally identical anonymous types.
+    dedup_identical_anonymous_types: bool {
+        methods: {
+            /// Keep a single definition of each set of structurally identical anonymous structs
+            /// and unions under the same module, and use it wherever any of them is used.
+            ///
+            /// Anonymous types are not deduplicated by default.
+            pub fn dedup_identical_anonymous_types(mut self, doit: bool) -> Self {
+                self.options.dedup_identical_anonymous_types = doit;
+                self
+            }
+        },
+        as_args: "--dedup-identical-anonymous-types",
+    },
     /// Whether to wrap unsafe operations in unsafe blocks.
     wrap_unsafe_ops: bool {
         methods: {
@@ -1981,6 +3177,45 @@ options! {
         },
         as_args: "--wrap-unsafe-ops",
     },
+    /// Whether to allow the clippy lints known to be triggered by the generated code.
+    suppress_clippy: bool {
+        methods: {
+            /// Set whether to put targeted `#[allow(clippy::...)]` attributes on the generated
+            /// items known to trigger clippy lints, e.g. `clippy::missing_safety_doc` on the
+            /// generated `unsafe` wrappers or `clippy::useless_transmute` on the bitfield
+            /// accessors.
+            ///
+            /// Unlike a crate-wide `#![allow(clippy::all)]`, this keeps clippy useful for the
+            /// code around the included bindings.
+            ///
+            /// Clippy lints are not suppressed by default.
+            pub fn suppress_clippy(mut self, doit: bool) -> Self {
+                self.options.suppress_clippy = doit;
+                self
+            }
+        },
+        as_args: "--suppress-clippy",
+    },
+    /// Whether to emit stubs of the `extern` functions for `rustdoc`.
+    docsrs_stubs: bool {
+        methods: {
+            /// Set whether to emit a stub with an `unimplemented!()` body behind `#[cfg(doc)]` for
+            /// each function declared in an `extern` block, which is put behind
+            /// `#[cfg(not(doc))]`.
+            ///
+            /// This way the documentation can be built without the native library, e.g. on
+            /// docs.rs, while the regular builds still use the `extern` declarations. Variadic
+            /// functions have no stubs, as they cannot be defined in stable Rust, and functions
+            /// loaded dynamically are not affected.
+            ///
+            /// These stubs are not emitted by default.
+            pub fn docsrs_stubs(mut self, doit: bool) -> Self {
+                self.options.docsrs_stubs = doit;
+                self
+            }
+        },
+        as_args: "--docsrs-stubs",
+    },
     /// Use DSTs to represent structures with flexible array members.
     flexarray_dst: bool {
         methods: {
@@ -2008,6 +3243,18 @@ options! {
                     self
                 }
             }
+            regex_option! {
+                /// Mark the functions matching the given pattern as functions that can unwind,
+                /// e.g. because C++ exceptions propagate through them.
+                ///
+                /// These functions are declared with the `"C-unwind"` ABI instead of `"C"`, so that
+                /// unwinding through them is defined behavior. This is a shorthand for
+                /// [`Builder::override_abi`] with [`Abi::CUnwind`], which requires Rust 1.71 or
+                /// newer.
+                pub fn can_unwind<T: Into<String>>(self, arg: T) -> Self {
+                    self.override_abi(Abi::CUnwind, arg)
+                }
+            }
         },
         as_args: |overrides, args| {
             for (abi, set) in overrides {
@@ -2018,6 +3265,125 @@ options! {
             }
         },
     },
+    /// Patterns for integer macro constants that should be grouped into enums, by enum name.
+    constant_groups: HashMap<String, RegexSet> {
+        methods: {
+            regex_option! {
+                /// Group the integer macro constants matching the given pattern into an enum
+                /// with the given name.
+                ///
+                /// Each matching constant becomes a variant of the enum instead of being
+                /// generated on its own. The style of the enum is chosen like for any other enum,
+                /// so, for example, passing the same name to [`Builder::rustified_enum`] will
+                /// generate a Rust `enum` for it. Constants with duplicated values are handled in
+                /// the same way as duplicated variants of C enums.
+                pub fn constant_group_as_enum<T: Into<String>, N: Into<String>>(
+                    mut self,
+                    arg: T,
+                    enum_name: N,
+                ) -> Self {
+                    self.options
+                        .constant_groups
+                        .entry(enum_name.into())
+                        .or_default()
+                        .insert(arg.into());
+                    self
+                }
+            }
+        },
+        as_args: |groups, args| {
+            for (enum_name, set) in groups {
+                for item in set.get_items() {
+                    args.push("--constant-group-as-enum".to_owned());
+                    args.push(format!("{}={}", item, enum_name));
+                }
+            }
+        },
+    },
+    /// Patterns for functions that report errors through `errno`, by error convention.
+    errno_wrappers: HashMap<ErrnoConvention, RegexSet> {
+        methods: {
+            regex_option! {
+                /// Generate a wrapper returning a [`std::io::Result`] for the functions matching
+                /// the given pattern, which report errors by returning the given error value and
+                /// setting `errno`.
+                ///
+                /// The wrapper is an `unsafe` function named after the wrapped function with a
+                /// `_checked` suffix. It returns [`std::io::Error::last_os_error`] if the wrapped
+                /// function returned the error value, and otherwise the value returned by the
+                /// function or `()`, depending on the [`ErrnoSuccess`] of the convention.
+                ///
+                /// Wrappers are only generated for functions returning an integer, or a pointer
+                /// if the error value is [`ErrnoValue::Null`]. They are not generated if
+                /// [`Builder::use_core`] or [`Builder::dynamic_library_name`] is used, nor for
+                /// functions matching the patterns of several conventions.
+                ///
+                /// [`ErrnoSuccess`]: crate::ErrnoSuccess
+                /// [`ErrnoValue::Null`]: crate::ErrnoValue::Null
+                pub fn errno_wrappers<T: Into<String>, C: Into<ErrnoConvention>>(
+                    mut self,
+                    arg: T,
+                    error_value: C,
+                ) -> Self {
+                    self.options
+                        .errno_wrappers
+                        .entry(error_value.into())
+                        .or_default()
+                        .insert(arg.into());
+                    self
+                }
+            }
+        },
+        as_args: |wrappers, args| {
+            for (convention, set) in wrappers {
+                for item in set.get_items() {
+                    args.push("--errno-wrapper".to_owned());
+                    args.push(format!("{}={}", item, convention));
+                }
+            }
+        },
+    },
+    /// Patterns for functions that write a pointer through an out parameter, by parameter name.
+    out_params: HashMap<String, RegexSet> {
+        methods: {
+            regex_option! {
+                /// Generate a wrapper returning the pointer written through the parameter with
+                /// the given name for the functions matching the given pattern.
+                ///
+                /// The wrapper is an `unsafe` function named after the wrapped function with an
+                /// `_out` suffix, which doesn't take the out parameter. It initializes the
+                /// pointer to null, calls the function and returns the pointer, together with the
+                /// value returned by the function if it isn't `void`. Pointers to mutable
+                /// pointees are returned as an `Option<NonNull<T>>`, and pointers to `const`
+                /// pointees as a `*const T`, as `NonNull` doesn't preserve their constness.
+                ///
+                /// Wrappers are only generated for functions with a single such out parameter,
+                /// whose type is a pointer to a pointer, and if no function is already named like
+                /// the wrapper. They are not generated if [`Builder::dynamic_library_name`] is
+                /// used.
+                pub fn out_param<T: Into<String>, P: Into<String>>(
+                    mut self,
+                    arg: T,
+                    out_param: P,
+                ) -> Self {
+                    self.options
+                        .out_params
+                        .entry(out_param.into())
+                        .or_default()
+                        .insert(arg.into());
+                    self
+                }
+            }
+        },
+        as_args: |out_params, args| {
+            for (out_param, set) in out_params {
+                for item in set.get_items() {
+                    args.push("--out-param".to_owned());
+                    args.push(format!("{}={}", item, out_param));
+                }
+            }
+        },
+    },
     /// Whether to generate wrappers for `static` functions.
     wrap_static_fns: bool {
         methods: {
@@ -2028,6 +3394,11 @@ options! {
             /// functions that call the `static` functions found in the input headers and can be
             /// called from Rust once the source file is compiled.
             ///
+            /// `static` variables are accessed through a pair of generated functions instead: a
+            /// `<name>_get` function returning the value of the variable and, unless the variable
+            /// is `const`, a `<name>_set` function assigning it. Array and function variables
+            /// cannot be wrapped and no code is generated for them.
+            ///
             /// The path of this source file can be set using the [`Builder::wrap_static_fns_path`]
             /// method.
             pub fn wrap_static_fns(mut self, doit: bool) -> Self {
@@ -2119,6 +3490,112 @@ options! {
         },
         as_args: "--emit-diagnostics",
     },
+    /// Whether to return an error when no items were generated.
+    error_on_empty: bool {
+        methods: {
+            /// Set whether an error should be returned when no items were generated.
+            ///
+            /// A warning is always emitted when the generated bindings are empty, which usually
+            /// means that the include paths are wrong or that the allowlist didn't match the
+            /// intended items. If this option is enabled, [`Builder::generate`] returns
+            /// [`BindgenError::EmptyBindings`] instead.
+            ///
+            /// This option is disabled by default.
+            ///
+            /// [`BindgenError::EmptyBindings`]: crate::BindgenError::EmptyBindings
+            pub fn error_on_empty(mut self, doit: bool) -> Self {
+                self.options.error_on_empty = doit;
+                self
+            }
+        },
+        as_args: "--error-on-empty",
+    },
+    /// Whether to return an error when any warning was emitted.
+    treat_warnings_as_errors: bool {
+        methods: {
+            /// Set whether an error should be returned when any warning was emitted while
+            /// generating the bindings.
+            ///
+            /// If this option is enabled and any warning was emitted, e.g. for a macro that
+            /// couldn't be translated or an unused allowlist entry, [`Builder::generate`]
+            /// returns [`BindgenError::Warnings`] with the number of warnings instead. The
+            /// warnings themselves are still reported through the `log` crate and, if
+            /// enabled, as diagnostics. The warnings reported by clang are counted too.
+            /// Duplicated macro definitions are not counted, as they are also reported for
+            /// macros redefined after an `#undef`.
+            ///
+            /// As the bindings are only formatted when they are written, failing to format
+            /// them makes [`Bindings::write`](crate::Bindings::write) return an error instead of writing them
+            /// unformatted.
+            ///
+            /// This option is disabled by default.
+            ///
+            /// [`BindgenError::Warnings`]: crate::BindgenError::Warnings
+            pub fn treat_warnings_as_errors(mut self, doit: bool) -> Self {
+                self.options.treat_warnings_as_errors = doit;
+                self
+            }
+        },
+        as_args: "--treat-warnings-as-errors",
+    },
+    /// Whether to hide the items only generated as dependencies of allowlisted items from the
+    /// documentation.
+    hide_transitive_deps: bool {
+        methods: {
+            /// Set whether the items that are only generated because allowlisted items depend on
+            /// them should be marked with `#[doc(hidden)]`.
+            ///
+            /// This keeps the documentation of the bindings focused on the items matching the
+            /// allowlist patterns. The items nested in allowlisted items, like anonymous structs,
+            /// aren't hidden. Nothing is hidden if nothing is allowlisted.
+            ///
+            /// This option is disabled by default.
+            pub fn hide_transitive_deps(mut self, doit: bool) -> Self {
+                self.options.hide_transitive_deps = doit;
+                self
+            }
+        },
+        as_args: "--hide-transitive-deps",
+    },
+    /// Whether to record why each item was or wasn't generated.
+    trace_allowlist: bool {
+        methods: {
+            /// Set whether the reason each function, variable and named type was or wasn't
+            /// generated should be recorded.
+            ///
+            /// The recorded trace can be retrieved with [`Bindings::allowlist_trace`] and
+            /// explains which allowlist or blocklist pattern matched each item, or which item
+            /// pulled it in transitively.
+            ///
+            /// This option is disabled by default.
+            ///
+            /// [`Bindings::allowlist_trace`]: crate::Bindings::allowlist_trace
+            pub fn trace_allowlist(mut self, doit: bool) -> Self {
+                self.options.trace_allowlist = doit;
+                self
+            }
+        },
+        as_args: "--trace-allowlist",
+    },
+    /// The path of the coverage report listing whether each item was generated.
+    coverage_report: Option<PathBuf> {
+        methods: {
+            /// Write a report listing every function, variable and named type found in the
+            /// translation unit to the given path.
+            ///
+            /// Each line of the report contains the name of an item, followed by a tab, the
+            /// `generated`, `blocklisted` or `not-matched` tag, another tab, and the reason the
+            /// item was or wasn't generated, as recorded by [`Builder::trace_allowlist`]. Unlike
+            /// the generated bindings, the report also lists the items that were excluded.
+            ///
+            /// No report is written by default.
+            pub fn write_coverage_report<T: AsRef<Path>>(mut self, path: T) -> Self {
+                self.options.coverage_report = Some(path.as_ref().to_owned());
+                self
+            }
+        },
+        as_args: "--write-coverage-report",
+    },
     /// Whether to use Clang evaluation on temporary files as a fallback for macros that fail to
     /// parse.
     clang_macro_fallback: bool {
@@ -2151,5 +3628,27 @@ options! {
             }
         },
         as_args: "--clang-macro-fallback-build-dir",
-    }
+    },
+    /// Whether to ignore the Clang arguments that aren't supported by the loaded version of
+    /// `libclang`.
+    clang_version_fallback: bool {
+        methods: {
+            /// Ignore the Clang arguments that aren't supported by the loaded version of `libclang`
+            /// instead of failing.
+            ///
+            /// This currently covers the `-std=` arguments selecting a C++ standard that is newer
+            /// than the loaded version of `libclang`, such as `-std=c++23` before `libclang` 17,
+            /// which then parses the headers using its default standard instead.
+            ///
+            /// A warning is emitted for every argument ignored this way. The loaded version can be
+            /// queried using [`clang_version`][crate::clang_version].
+            ///
+            /// Arguments are not ignored by default.
+            pub fn clang_version_fallback(mut self, doit: bool) -> Self {
+                self.options.clang_version_fallback = doit;
+                self
+            }
+        },
+        as_args: "--clang-version-fallback",
+    },
 }
@@ -103,7 +103,7 @@ impl RegexSet {
         self.set = match RxSet::new(items) {
             Ok(x) => Some(x),
             Err(e) => {
-                warn!("Invalid regex in {:?}: {:?}", self.items, e);
+                warning!("Invalid regex in {:?}: {:?}", self.items, e);
                 #[cfg(feature = "experimental")]
                 if let Some(name) = _name {
                     invalid_regex_warning(self, e, name);
@@ -113,6 +113,18 @@ impl RegexSet {
         }
     }
 
+    /// Returns the first regex in this set matching the given `string`, if any.
+    ///
+    /// Unlike `matches()`, this doesn't record the match.
+    pub fn matching_item<S>(&self, string: S) -> Option<&str>
+    where
+        S: AsRef<str>,
+    {
+        let set = self.set.as_ref()?;
+        let i = set.matches(string.as_ref()).into_iter().next()?;
+        Some(&self.items[i])
+    }
+
     /// Does the given `string` match any of the regexes in this set?
     pub fn matches<S>(&self, string: S) -> bool
     where
@@ -0,0 +1,179 @@
+//! Splitting bindings into a module per kind of item.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// The kinds of items the bindings are split into, in the order they are
+/// returned by [`split_by_kind`].
+pub(crate) const KINDS: [&str; 3] = ["types", "functions", "vars"];
+
+/// Split the given bindings into the types, the functions and the variables
+/// they declare, in the order of [`KINDS`].
+///
+/// Each part imports the items of the parent module, which is expected to
+/// re-export the items of all the parts, so that an item can refer to items
+/// of other parts. For that reason, the private items are made `pub`.
+///
+/// Everything that isn't a function or a variable, e.g. the implementations
+/// and the layout tests of the types or the modules of
+/// [`crate::Builder::enable_cxx_namespaces`], is part of the types.
+pub(crate) fn split_by_kind(module: TokenStream) -> [TokenStream; 3] {
+    let items = syn::parse2::<syn::File>(module)
+        .expect("generated bindings should be valid")
+        .items;
+
+    let mut parts: [Vec<syn::Item>; 3] = Default::default();
+    for mut item in items {
+        if let syn::Item::ForeignMod(ref mut foreign_mod) = item {
+            let mut split: [Vec<syn::ForeignItem>; 3] = Default::default();
+            for foreign_item in std::mem::take(&mut foreign_mod.items) {
+                let index = match foreign_item {
+                    syn::ForeignItem::Fn(..) => 1,
+                    syn::ForeignItem::Static(..) => 2,
+                    _ => 0,
+                };
+                split[index].push(foreign_item);
+            }
+            for (index, foreign_items) in
+                IntoIterator::into_iter(split).enumerate()
+            {
+                if foreign_items.is_empty() {
+                    continue;
+                }
+                let mut foreign_mod = foreign_mod.clone();
+                foreign_mod.items = foreign_items;
+                parts[index].push(syn::Item::ForeignMod(foreign_mod));
+            }
+            continue;
+        }
+
+        let index = match item {
+            syn::Item::Fn(ref item) if !is_test(item) => 1,
+            syn::Item::Const(ref item) if item.ident != "_" => 2,
+            syn::Item::Static(..) => 2,
+            _ => 0,
+        };
+        make_pub(&mut item);
+        parts[index].push(item);
+    }
+
+    parts.map(|items| {
+        quote! {
+            #[allow(unused_imports)]
+            use super::*;
+            #( #items )*
+        }
+    })
+}
+
+/// The module declaring a module per kind of item, each including the file
+/// with the items of that kind, and re-exporting their items.
+pub(crate) fn root_module() -> TokenStream {
+    let modules = KINDS.iter().map(|kind| {
+        let ident = Ident::new(kind, Span::call_site());
+        let path = format!("{}.rs", kind);
+        quote! {
+            pub mod #ident {
+                include!(#path);
+            }
+            pub use self::#ident::*;
+        }
+    });
+    quote! { #( #modules )* }
+}
+
+/// Whether the given function is a test, e.g. a layout test.
+fn is_test(item: &syn::ItemFn) -> bool {
+    item.attrs.iter().any(|attr| attr.path().is_ident("test"))
+}
+
+/// Make the given item `pub` if it is private, so that it can be used from the
+/// other parts.
+fn make_pub(item: &mut syn::Item) {
+    let vis = match item {
+        syn::Item::Const(item) if item.ident != "_" => &mut item.vis,
+        syn::Item::Enum(item) => &mut item.vis,
+        syn::Item::Fn(item) if !is_test(item) => &mut item.vis,
+        syn::Item::Mod(item) => &mut item.vis,
+        syn::Item::Static(item) => &mut item.vis,
+        syn::Item::Struct(item) => &mut item.vis,
+        syn::Item::Trait(item) => &mut item.vis,
+        syn::Item::Type(item) => &mut item.vis,
+        syn::Item::Union(item) => &mut item.vis,
+        _ => return,
+    };
+    if let syn::Visibility::Inherited = vis {
+        *vis = syn::parse_quote! { pub };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splitting_by_kind() {
+        let module = quote! {
+            #[repr(C)]
+            pub struct Point {
+                pub x: ::std::os::raw::c_int,
+            }
+            const _: () = {
+                ["Size of Point"][::std::mem::size_of::<Point>() - 4usize];
+            };
+            mod __bindgen_ctypes {}
+            pub const ORIGIN: ::std::os::raw::c_int = 0;
+            extern "C" {
+                pub static mut point_count: ::std::os::raw::c_int;
+                pub fn point_norm(point: *const Point) -> ::std::os::raw::c_int;
+            }
+            pub unsafe fn point_norm_slice(points: &[Point]) {}
+            #[test]
+            fn bindgen_test_layout_Point() {}
+        };
+
+        let [types, functions, vars] = split_by_kind(module);
+        assert_eq!(
+            types.to_string(),
+            quote! {
+                #[allow(unused_imports)]
+                use super::*;
+                #[repr(C)]
+                pub struct Point {
+                    pub x: ::std::os::raw::c_int,
+                }
+                const _: () = {
+                    ["Size of Point"][::std::mem::size_of::<Point>() - 4usize];
+                };
+                pub mod __bindgen_ctypes {}
+                #[test]
+                fn bindgen_test_layout_Point() {}
+            }
+            .to_string()
+        );
+        assert_eq!(
+            functions.to_string(),
+            quote! {
+                #[allow(unused_imports)]
+                use super::*;
+                extern "C" {
+                    pub fn point_norm(point: *const Point) -> ::std::os::raw::c_int;
+                }
+                pub unsafe fn point_norm_slice(points: &[Point]) {}
+            }
+            .to_string()
+        );
+        assert_eq!(
+            vars.to_string(),
+            quote! {
+                #[allow(unused_imports)]
+                use super::*;
+                pub const ORIGIN: ::std::os::raw::c_int = 0;
+                extern "C" {
+                    pub static mut point_count: ::std::os::raw::c_int;
+                }
+            }
+            .to_string()
+        );
+    }
+}
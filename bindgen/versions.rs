@@ -0,0 +1,299 @@
+//! Combining bindings generated for different versions of a library.
+
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+
+use crate::{merge, BindgenError, HashSet};
+
+/// Combine the given bindings, generated for the named versions of a library,
+/// into a module per version guarded by `#[cfg(feature = "...")]`, and a
+/// trait named `trait_name` with the functions common to all the versions.
+///
+/// Each module implements the trait for a `Backend` unit struct, forwarding
+/// to the functions of that version.
+pub(crate) fn merge_versions(
+    trait_name: &str,
+    versions: Vec<(&str, TokenStream)>,
+) -> Result<TokenStream, BindgenError> {
+    let trait_ident = ident(trait_name)?;
+    let versions = versions
+        .into_iter()
+        .map(|(version, module)| {
+            Ok((version, ident(version)?, merge::parse(module)?))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (first, others) = versions
+        .split_first()
+        .expect("at least one version should be given");
+    let mut common = functions(&first.2);
+    for (_, _, items) in others {
+        let functions = functions(items);
+        common.retain(|sig| {
+            functions
+                .iter()
+                .any(|other| normalized(other) == normalized(sig))
+        });
+    }
+
+    let sigs: Vec<_> = common.iter().map(unsafe_sig).collect();
+    let calls: Vec<_> = common
+        .iter()
+        .map(|sig| {
+            let name = &sig.ident;
+            let args = arg_names(sig);
+            quote! { unsafe { self::#name( #( #args ),* ) } }
+        })
+        .collect();
+
+    let modules = versions.iter().map(|(version, module, items)| {
+        quote! {
+            #[cfg(feature = #version)]
+            pub mod #module {
+                #( #items )*
+                /// The implementation of the common functions for this version.
+                pub struct Backend;
+                impl super::#trait_ident for Backend {
+                    #(
+                        #[inline]
+                        #sigs {
+                            #calls
+                        }
+                    )*
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        /// The functions common to all the versions of the library.
+        pub trait #trait_ident {
+            #( #sigs ; )*
+        }
+        #( #modules )*
+    })
+}
+
+/// Parse the given name of the trait or a version as an identifier.
+fn ident(name: &str) -> Result<Ident, BindgenError> {
+    syn::parse_str(name)
+        .map_err(|_| BindgenError::InvalidIdentifier(name.to_owned()))
+}
+
+/// The signatures of the non-variadic functions declared in the given items,
+/// that only use types not defined in them.
+fn functions(items: &[syn::Item]) -> Vec<syn::Signature> {
+    let mut defined = HashSet::default();
+    for item in items {
+        let ident = match item {
+            syn::Item::Enum(item) => &item.ident,
+            syn::Item::Mod(item) => &item.ident,
+            syn::Item::Struct(item) => &item.ident,
+            syn::Item::Trait(item) => &item.ident,
+            syn::Item::Type(item) => &item.ident,
+            syn::Item::Union(item) => &item.ident,
+            syn::Item::ForeignMod(item) => {
+                for item in &item.items {
+                    if let syn::ForeignItem::Type(item) = item {
+                        defined.insert(item.ident.to_string());
+                    }
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        defined.insert(ident.to_string());
+    }
+
+    let mut functions = vec![];
+    for item in items {
+        if let syn::Item::ForeignMod(item) = item {
+            for item in &item.items {
+                if let syn::ForeignItem::Fn(item) = item {
+                    if item.sig.variadic.is_none() &&
+                        !mentions(
+                            item.sig.inputs.to_token_stream(),
+                            &defined,
+                        ) &&
+                        !mentions(
+                            item.sig.output.to_token_stream(),
+                            &defined,
+                        )
+                    {
+                        functions.push(item.sig.clone());
+                    }
+                }
+            }
+        }
+    }
+    functions
+}
+
+/// Whether the given tokens contain any of the given identifiers.
+fn mentions(tokens: TokenStream, idents: &HashSet<String>) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident.to_string()),
+        TokenTree::Group(group) => mentions(group.stream(), idents),
+        _ => false,
+    })
+}
+
+/// The names of the arguments of the given signature, in the form used by
+/// `unsafe_sig`.
+fn arg_names(sig: &syn::Signature) -> Vec<Ident> {
+    sig.inputs
+        .iter()
+        .enumerate()
+        .map(|(index, arg)| match arg {
+            syn::FnArg::Typed(syn::PatType { pat, .. }) => match &**pat {
+                syn::Pat::Ident(pat) => pat.ident.clone(),
+                _ => Ident::new(&format!("arg{}", index), Span::call_site()),
+            },
+            syn::FnArg::Receiver(_) => {
+                unreachable!("foreign functions have no receiver")
+            }
+        })
+        .collect()
+}
+
+/// The given signature as an `unsafe` function whose arguments are all named.
+fn unsafe_sig(sig: &syn::Signature) -> syn::Signature {
+    let mut sig = sig.clone();
+    sig.unsafety = Some(Default::default());
+    let names = arg_names(&sig);
+    for (arg, name) in sig.inputs.iter_mut().zip(names) {
+        if let syn::FnArg::Typed(arg) = arg {
+            *arg.pat = syn::parse_quote! { #name };
+        }
+    }
+    sig
+}
+
+/// The given signature without the argument names, which don't need to match
+/// between versions.
+fn normalized(sig: &syn::Signature) -> syn::Signature {
+    let mut sig = sig.clone();
+    for arg in sig.inputs.iter_mut() {
+        if let syn::FnArg::Typed(arg) = arg {
+            *arg.pat = syn::parse_quote! { _ };
+        }
+    }
+    sig
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merging_versions() {
+        let first = quote! {
+            #[repr(C)]
+            pub struct Config {
+                pub flags: ::std::os::raw::c_int,
+            }
+            extern "C" {
+                pub fn lib_version() -> ::std::os::raw::c_int;
+            }
+            extern "C" {
+                pub fn lib_add(a: ::std::os::raw::c_int, b: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+            }
+            extern "C" {
+                pub fn lib_configure(config: *mut Config);
+            }
+        };
+        let second = quote! {
+            #[repr(C)]
+            pub struct Config {
+                pub flags: ::std::os::raw::c_long,
+            }
+            extern "C" {
+                pub fn lib_version() -> ::std::os::raw::c_int;
+            }
+            extern "C" {
+                pub fn lib_add(x: ::std::os::raw::c_int, y: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+            }
+            extern "C" {
+                pub fn lib_configure(config: *mut Config);
+            }
+            extern "C" {
+                pub fn lib_reset();
+            }
+        };
+
+        let expected = quote! {
+            /// The functions common to all the versions of the library.
+            pub trait Library {
+                unsafe fn lib_version() -> ::std::os::raw::c_int;
+                unsafe fn lib_add(a: ::std::os::raw::c_int, b: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+            }
+            #[cfg(feature = "v1")]
+            pub mod v1 {
+                #first
+                /// The implementation of the common functions for this version.
+                pub struct Backend;
+                impl super::Library for Backend {
+                    #[inline]
+                    unsafe fn lib_version() -> ::std::os::raw::c_int {
+                        unsafe { self::lib_version() }
+                    }
+                    #[inline]
+                    unsafe fn lib_add(a: ::std::os::raw::c_int, b: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
+                        unsafe { self::lib_add(a, b) }
+                    }
+                }
+            }
+            #[cfg(feature = "v2")]
+            pub mod v2 {
+                #second
+                /// The implementation of the common functions for this version.
+                pub struct Backend;
+                impl super::Library for Backend {
+                    #[inline]
+                    unsafe fn lib_version() -> ::std::os::raw::c_int {
+                        unsafe { self::lib_version() }
+                    }
+                    #[inline]
+                    unsafe fn lib_add(a: ::std::os::raw::c_int, b: ::std::os::raw::c_int) -> ::std::os::raw::c_int {
+                        unsafe { self::lib_add(a, b) }
+                    }
+                }
+            }
+        };
+
+        let merged =
+            merge_versions("Library", vec![("v1", first), ("v2", second)])
+                .unwrap();
+        assert_eq!(merged.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn merging_versions_with_invalid_names() {
+        let bindings = quote! {
+            extern "C" {
+                pub fn lib_version() -> ::std::os::raw::c_int;
+            }
+        };
+
+        assert_eq!(
+            merge_versions("Lib rary", vec![("v1", bindings.clone())]).err(),
+            Some(BindgenError::InvalidIdentifier("Lib rary".to_owned()))
+        );
+        assert_eq!(
+            merge_versions("Library", vec![("1.0", bindings.clone())]).err(),
+            Some(BindgenError::InvalidIdentifier("1.0".to_owned()))
+        );
+        assert_eq!(
+            merge_versions(
+                "Library",
+                vec![("v1", bindings), ("mod", quote! {})]
+            )
+            .err(),
+            Some(BindgenError::InvalidIdentifier("mod".to_owned()))
+        );
+        assert!(matches!(
+            merge_versions("Library", vec![("v1", quote! { pub fn })]),
+            Err(BindgenError::UnparsableBindings(_))
+        ));
+    }
+}